@@ -0,0 +1,686 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Write};
+
+use aes::cipher::{block_padding::NoPadding, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use xml::reader::{EventReader, XmlEvent};
+
+use super::random_bytes;
+use crate::reader::ReaderError;
+
+// Please see [MS-OFFCRYPTO] 2.3.4.10 - 2.3.4.15 Agile Encryption.
+const SPIN_COUNT: u32 = 100_000;
+const SALT_SIZE: usize = 16;
+const BLOCK_SIZE: usize = 16;
+const KEY_BITS: usize = 256;
+const HASH_SIZE: usize = 64;
+const SEGMENT_LENGTH: usize = 4096;
+// ECMA-376 limits spinCount to 10,000,000. The value comes from the file, so it is checked before hashing.
+const MAX_SPIN_COUNT: u32 = 10_000_000;
+
+const VERIFIER_HASH_INPUT_BLOCK_KEY: [u8; 8] = [0xfe, 0xa7, 0xd2, 0x76, 0x3b, 0x4b, 0x9e, 0x79];
+const VERIFIER_HASH_VALUE_BLOCK_KEY: [u8; 8] = [0xd7, 0xaa, 0x0f, 0x6d, 0x30, 0x61, 0x34, 0x4e];
+const ENCRYPTED_KEY_VALUE_BLOCK_KEY: [u8; 8] = [0x14, 0x6e, 0x0b, 0xe7, 0xab, 0xac, 0xd0, 0xd6];
+const HMAC_KEY_BLOCK_KEY: [u8; 8] = [0x5f, 0xb2, 0xad, 0x01, 0x0c, 0xb9, 0xe1, 0xf6];
+const HMAC_VALUE_BLOCK_KEY: [u8; 8] = [0xa0, 0x67, 0x7f, 0x02, 0xb2, 0x2c, 0x84, 0x33];
+
+const ENCRYPTION_INFO_STREAM: &str = "/EncryptionInfo";
+const ENCRYPTED_PACKAGE_STREAM: &str = "/EncryptedPackage";
+const DATA_SPACES_STORAGE: &str = "/\u{6}DataSpaces";
+const PASSWORD_KEY_ENCRYPTOR_URI: &str =
+    "http://schemas.microsoft.com/office/2006/keyEncryptor/password";
+const STRONG_ENCRYPTION_TRANSFORM_ID: &str = "{FF9A3F03-56EF-4613-BDD5-5A41C1D07246}";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HashAlgorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "SHA256" => Some(HashAlgorithm::Sha256),
+            "SHA384" => Some(HashAlgorithm::Sha384),
+            "SHA512" => Some(HashAlgorithm::Sha512),
+            _ => None,
+        }
+    }
+
+    fn digest(&self, parts: &[&[u8]]) -> Vec<u8> {
+        fn digest<D: Digest>(parts: &[&[u8]]) -> Vec<u8> {
+            let mut d = D::new();
+            for p in parts {
+                d.update(p);
+            }
+            d.finalize().to_vec()
+        }
+        match self {
+            HashAlgorithm::Sha256 => digest::<Sha256>(parts),
+            HashAlgorithm::Sha384 => digest::<Sha384>(parts),
+            HashAlgorithm::Sha512 => digest::<Sha512>(parts),
+        }
+    }
+
+    fn hmac(&self, key: &[u8], data: &[u8]) -> Vec<u8> {
+        match self {
+            HashAlgorithm::Sha256 => {
+                let mut m =
+                    Hmac::<Sha256>::new_from_slice(key).expect("should accept any key size");
+                m.update(data);
+                m.finalize().into_bytes().to_vec()
+            }
+            HashAlgorithm::Sha384 => {
+                let mut m =
+                    Hmac::<Sha384>::new_from_slice(key).expect("should accept any key size");
+                m.update(data);
+                m.finalize().into_bytes().to_vec()
+            }
+            HashAlgorithm::Sha512 => {
+                let mut m =
+                    Hmac::<Sha512>::new_from_slice(key).expect("should accept any key size");
+                m.update(data);
+                m.finalize().into_bytes().to_vec()
+            }
+        }
+    }
+}
+
+// The parameters shared by keyData and p:encryptedKey.
+#[derive(Debug, Clone, PartialEq)]
+struct CipherParams {
+    salt: Vec<u8>,
+    block_size: usize,
+    key_bits: usize,
+    hash_size: usize,
+    hash: HashAlgorithm,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct EncryptionInfo {
+    key_data: CipherParams,
+    encrypted_key: CipherParams,
+    spin_count: u32,
+    encrypted_verifier_hash_input: Vec<u8>,
+    encrypted_verifier_hash_value: Vec<u8>,
+    encrypted_key_value: Vec<u8>,
+    encrypted_hmac_key: Option<Vec<u8>>,
+    encrypted_hmac_value: Option<Vec<u8>>,
+}
+
+// Wrap an OOXML package in a Compound File Binary container with Agile Encryption.
+pub(crate) fn encrypt_package(package: &[u8], password: &str) -> std::io::Result<Vec<u8>> {
+    let hash = HashAlgorithm::Sha512;
+    let params = |salt| CipherParams {
+        salt,
+        block_size: BLOCK_SIZE,
+        key_bits: KEY_BITS,
+        hash_size: HASH_SIZE,
+        hash,
+    };
+    let key_data = params(random_bytes(SALT_SIZE));
+    let encrypted_key = params(random_bytes(SALT_SIZE));
+    let secret_key = random_bytes(KEY_BITS / 8);
+
+    let password_hash = hash_password(hash, password, &encrypted_key.salt, SPIN_COUNT);
+    let encrypt_with_password = |block_key: &[u8], data: &[u8]| {
+        let key = derive_key(hash, &password_hash, block_key, KEY_BITS / 8);
+        aes_cbc_encrypt(&key, &encrypted_key.salt, data)
+    };
+    let verifier = random_bytes(SALT_SIZE);
+    let encrypted_verifier_hash_input =
+        encrypt_with_password(&VERIFIER_HASH_INPUT_BLOCK_KEY, &verifier);
+    let encrypted_verifier_hash_value =
+        encrypt_with_password(&VERIFIER_HASH_VALUE_BLOCK_KEY, &hash.digest(&[&verifier]));
+    let encrypted_key_value = encrypt_with_password(&ENCRYPTED_KEY_VALUE_BLOCK_KEY, &secret_key);
+
+    let encrypted_package = encrypt_segments(&key_data, &secret_key, package);
+
+    let hmac_key = random_bytes(HASH_SIZE);
+    let hmac_value = hash.hmac(&hmac_key, &encrypted_package);
+    let encrypted_hmac_key = aes_cbc_encrypt(
+        &secret_key,
+        &derive_iv(hash, &key_data.salt, &HMAC_KEY_BLOCK_KEY, BLOCK_SIZE),
+        &hmac_key,
+    );
+    let encrypted_hmac_value = aes_cbc_encrypt(
+        &secret_key,
+        &derive_iv(hash, &key_data.salt, &HMAC_VALUE_BLOCK_KEY, BLOCK_SIZE),
+        &hmac_value,
+    );
+
+    let info = EncryptionInfo {
+        key_data,
+        encrypted_key,
+        spin_count: SPIN_COUNT,
+        encrypted_verifier_hash_input,
+        encrypted_verifier_hash_value,
+        encrypted_key_value,
+        encrypted_hmac_key: Some(encrypted_hmac_key),
+        encrypted_hmac_value: Some(encrypted_hmac_value),
+    };
+    write_compound_file(&info.build(), &encrypted_package)
+}
+
+// Decrypt an Agile Encryption container and return the OOXML package in it.
+pub(crate) fn decrypt_package(buf: &[u8], password: &str) -> Result<Vec<u8>, ReaderError> {
+    let mut file =
+        cfb::CompoundFile::open(Cursor::new(buf)).map_err(|_| ReaderError::DecryptionError)?;
+    let info = read_stream(&mut file, ENCRYPTION_INFO_STREAM)?;
+    let encrypted_package = read_stream(&mut file, ENCRYPTED_PACKAGE_STREAM)?;
+    let info = EncryptionInfo::parse(&info)?;
+
+    let key = &info.encrypted_key;
+    let password_hash = hash_password(key.hash, password, &key.salt, info.spin_count);
+    let decrypt_with_password = |block_key: &[u8], data: &[u8]| {
+        let k = derive_key(key.hash, &password_hash, block_key, key.key_bits / 8);
+        aes_cbc_decrypt(&k, &fit(&key.salt, key.block_size, 0x36), data)
+    };
+    let verifier = decrypt_with_password(
+        &VERIFIER_HASH_INPUT_BLOCK_KEY,
+        &info.encrypted_verifier_hash_input,
+    )?;
+    let verifier_hash = decrypt_with_password(
+        &VERIFIER_HASH_VALUE_BLOCK_KEY,
+        &info.encrypted_verifier_hash_value,
+    )?;
+    let expected = key
+        .hash
+        .digest(&[&verifier[..verifier.len().min(SALT_SIZE)]]);
+    if verifier_hash.get(..key.hash_size) != Some(&expected[..]) {
+        return Err(ReaderError::InvalidPasswordError);
+    }
+    let mut secret_key =
+        decrypt_with_password(&ENCRYPTED_KEY_VALUE_BLOCK_KEY, &info.encrypted_key_value)?;
+    secret_key.truncate(info.key_data.key_bits / 8);
+
+    let key_data = &info.key_data;
+    if let (Some(hmac_key), Some(hmac_value)) =
+        (&info.encrypted_hmac_key, &info.encrypted_hmac_value)
+    {
+        let iv = derive_iv(
+            key_data.hash,
+            &key_data.salt,
+            &HMAC_KEY_BLOCK_KEY,
+            key_data.block_size,
+        );
+        let hmac_key = aes_cbc_decrypt(&secret_key, &iv, hmac_key)?;
+        let iv = derive_iv(
+            key_data.hash,
+            &key_data.salt,
+            &HMAC_VALUE_BLOCK_KEY,
+            key_data.block_size,
+        );
+        let hmac_value = aes_cbc_decrypt(&secret_key, &iv, hmac_value)?;
+        let actual = key_data.hash.hmac(
+            &hmac_key[..key_data.hash_size.min(hmac_key.len())],
+            &encrypted_package,
+        );
+        if hmac_value.get(..actual.len()) != Some(&actual[..]) {
+            return Err(ReaderError::DecryptionError);
+        }
+    }
+
+    decrypt_segments(key_data, &secret_key, &encrypted_package)
+}
+
+// Returns true when the buffer looks like a Compound File Binary container,
+// which is how encrypted packages are stored.
+pub(crate) fn is_compound_file(buf: &[u8]) -> bool {
+    buf.starts_with(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1])
+}
+
+fn hash_password(hash: HashAlgorithm, password: &str, salt: &[u8], spin_count: u32) -> Vec<u8> {
+    let password: Vec<u8> = password
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+        .collect();
+    let mut h = hash.digest(&[salt, &password]);
+    for i in 0..spin_count {
+        h = hash.digest(&[&i.to_le_bytes(), &h]);
+    }
+    h
+}
+
+fn derive_key(hash: HashAlgorithm, password_hash: &[u8], block_key: &[u8], len: usize) -> Vec<u8> {
+    fit(&hash.digest(&[password_hash, block_key]), len, 0x36)
+}
+
+fn derive_iv(hash: HashAlgorithm, salt: &[u8], block_key: &[u8], len: usize) -> Vec<u8> {
+    fit(&hash.digest(&[salt, block_key]), len, 0x36)
+}
+
+// Truncate or pad `v` to `len` bytes.
+fn fit(v: &[u8], len: usize, pad: u8) -> Vec<u8> {
+    let mut v = v[..v.len().min(len)].to_vec();
+    v.resize(len, pad);
+    v
+}
+
+fn aes_cbc_encrypt(key: &[u8], iv: &[u8], data: &[u8]) -> Vec<u8> {
+    let data = fit(
+        data,
+        (data.len() + BLOCK_SIZE - 1) / BLOCK_SIZE * BLOCK_SIZE,
+        0,
+    );
+    match key.len() {
+        16 => cbc::Encryptor::<aes::Aes128>::new_from_slices(key, iv)
+            .expect("should be a valid key and iv")
+            .encrypt_padded_vec_mut::<NoPadding>(&data),
+        24 => cbc::Encryptor::<aes::Aes192>::new_from_slices(key, iv)
+            .expect("should be a valid key and iv")
+            .encrypt_padded_vec_mut::<NoPadding>(&data),
+        _ => cbc::Encryptor::<aes::Aes256>::new_from_slices(key, iv)
+            .expect("should be a valid key and iv")
+            .encrypt_padded_vec_mut::<NoPadding>(&data),
+    }
+}
+
+fn aes_cbc_decrypt(key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>, ReaderError> {
+    let decrypted = match key.len() {
+        16 => cbc::Decryptor::<aes::Aes128>::new_from_slices(key, iv)
+            .map_err(|_| ReaderError::DecryptionError)?
+            .decrypt_padded_vec_mut::<NoPadding>(data),
+        24 => cbc::Decryptor::<aes::Aes192>::new_from_slices(key, iv)
+            .map_err(|_| ReaderError::DecryptionError)?
+            .decrypt_padded_vec_mut::<NoPadding>(data),
+        32 => cbc::Decryptor::<aes::Aes256>::new_from_slices(key, iv)
+            .map_err(|_| ReaderError::DecryptionError)?
+            .decrypt_padded_vec_mut::<NoPadding>(data),
+        _ => return Err(ReaderError::DecryptionError),
+    };
+    decrypted.map_err(|_| ReaderError::DecryptionError)
+}
+
+fn segment_iv(key_data: &CipherParams, index: usize) -> Vec<u8> {
+    derive_iv(
+        key_data.hash,
+        &key_data.salt,
+        &(index as u32).to_le_bytes(),
+        key_data.block_size,
+    )
+}
+
+// The EncryptedPackage stream is the package size followed by the package
+// encrypted in 4096 byte segments.
+fn encrypt_segments(key_data: &CipherParams, secret_key: &[u8], package: &[u8]) -> Vec<u8> {
+    let mut stream = (package.len() as u64).to_le_bytes().to_vec();
+    for (i, segment) in package.chunks(SEGMENT_LENGTH).enumerate() {
+        stream.extend(aes_cbc_encrypt(
+            secret_key,
+            &segment_iv(key_data, i),
+            segment,
+        ));
+    }
+    stream
+}
+
+fn decrypt_segments(
+    key_data: &CipherParams,
+    secret_key: &[u8],
+    stream: &[u8],
+) -> Result<Vec<u8>, ReaderError> {
+    if stream.len() < 8 {
+        return Err(ReaderError::DecryptionError);
+    }
+    let mut size = [0u8; 8];
+    size.copy_from_slice(&stream[..8]);
+    let size = u64::from_le_bytes(size) as usize;
+    let mut package = vec![];
+    for (i, segment) in stream[8..].chunks(SEGMENT_LENGTH).enumerate() {
+        if package.len() >= size {
+            break;
+        }
+        // Some writers leave trailing bytes that are not a whole block.
+        let len = segment.len() / key_data.block_size * key_data.block_size;
+        package.extend(aes_cbc_decrypt(
+            secret_key,
+            &segment_iv(key_data, i),
+            &segment[..len],
+        )?);
+    }
+    if package.len() < size {
+        return Err(ReaderError::DecryptionError);
+    }
+    package.truncate(size);
+    Ok(package)
+}
+
+fn read_stream<R: Read + std::io::Seek>(
+    file: &mut cfb::CompoundFile<R>,
+    path: &str,
+) -> Result<Vec<u8>, ReaderError> {
+    let mut stream = file
+        .open_stream(path)
+        .map_err(|_| ReaderError::DecryptionError)?;
+    let mut buf = vec![];
+    stream
+        .read_to_end(&mut buf)
+        .map_err(|_| ReaderError::DecryptionError)?;
+    Ok(buf)
+}
+
+fn write_compound_file(info: &[u8], encrypted_package: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut file = cfb::CompoundFile::create(Cursor::new(vec![]))?;
+    file.create_storage(DATA_SPACES_STORAGE)?;
+    file.create_storage(format!("{}/DataSpaceInfo", DATA_SPACES_STORAGE))?;
+    file.create_storage(format!("{}/TransformInfo", DATA_SPACES_STORAGE))?;
+    file.create_storage(format!(
+        "{}/TransformInfo/StrongEncryptionTransform",
+        DATA_SPACES_STORAGE
+    ))?;
+    let streams = [
+        (
+            format!("{}/Version", DATA_SPACES_STORAGE),
+            data_space_version(),
+        ),
+        (
+            format!("{}/DataSpaceMap", DATA_SPACES_STORAGE),
+            data_space_map(),
+        ),
+        (
+            format!(
+                "{}/DataSpaceInfo/StrongEncryptionDataSpace",
+                DATA_SPACES_STORAGE
+            ),
+            data_space_definition(),
+        ),
+        (
+            format!(
+                "{}/TransformInfo/StrongEncryptionTransform/\u{6}Primary",
+                DATA_SPACES_STORAGE
+            ),
+            transform_info(),
+        ),
+        (ENCRYPTION_INFO_STREAM.to_owned(), info.to_vec()),
+        (
+            ENCRYPTED_PACKAGE_STREAM.to_owned(),
+            encrypted_package.to_vec(),
+        ),
+    ];
+    for (path, data) in streams.iter() {
+        file.create_stream(path)?.write_all(data)?;
+    }
+    file.flush()?;
+    Ok(file.into_inner().into_inner())
+}
+
+// UNICODE-LP-P4: a length prefixed UTF-16 string padded to 4 bytes.
+fn unicode_lp_p4(s: &str) -> Vec<u8> {
+    let chars: Vec<u8> = s.encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+    let mut buf = (chars.len() as u32).to_le_bytes().to_vec();
+    buf.extend(&chars);
+    buf.resize((buf.len() + 3) / 4 * 4, 0);
+    buf
+}
+
+fn version(major: u16, minor: u16) -> Vec<u8> {
+    [major.to_le_bytes(), minor.to_le_bytes()].concat()
+}
+
+fn data_space_version() -> Vec<u8> {
+    let mut buf = unicode_lp_p4("Microsoft.Container.DataSpaces");
+    // reader, updater and writer versions
+    for _ in 0..3 {
+        buf.extend(version(1, 0));
+    }
+    buf
+}
+
+fn data_space_map() -> Vec<u8> {
+    let mut entry = 1u32.to_le_bytes().to_vec();
+    // reference component type 0 is a stream
+    entry.extend(0u32.to_le_bytes());
+    entry.extend(unicode_lp_p4("EncryptedPackage"));
+    entry.extend(unicode_lp_p4("StrongEncryptionDataSpace"));
+    let mut buf = 8u32.to_le_bytes().to_vec();
+    buf.extend(1u32.to_le_bytes());
+    buf.extend((entry.len() as u32 + 4).to_le_bytes());
+    buf.extend(entry);
+    buf
+}
+
+fn data_space_definition() -> Vec<u8> {
+    let mut buf = 8u32.to_le_bytes().to_vec();
+    buf.extend(1u32.to_le_bytes());
+    buf.extend(unicode_lp_p4("StrongEncryptionTransform"));
+    buf
+}
+
+fn transform_info() -> Vec<u8> {
+    let id = unicode_lp_p4(STRONG_ENCRYPTION_TRANSFORM_ID);
+    let mut buf = (id.len() as u32 + 8).to_le_bytes().to_vec();
+    buf.extend(1u32.to_le_bytes());
+    buf.extend(id);
+    buf.extend(unicode_lp_p4("Microsoft.Container.EncryptionTransform"));
+    for _ in 0..3 {
+        buf.extend(version(1, 0));
+    }
+    // EncryptionTransformInfo: empty name, block size, cipher mode and reserved.
+    for v in [0u32, 0, 0, 4] {
+        buf.extend(v.to_le_bytes());
+    }
+    buf
+}
+
+impl CipherParams {
+    fn attrs(&self) -> String {
+        format!(
+            r#"saltSize="{}" blockSize="{}" keyBits="{}" hashSize="{}" cipherAlgorithm="AES" cipherChaining="ChainingModeCBC" hashAlgorithm="{}" saltValue="{}""#,
+            self.salt.len(),
+            self.block_size,
+            self.key_bits,
+            self.hash_size,
+            match self.hash {
+                HashAlgorithm::Sha256 => "SHA256",
+                HashAlgorithm::Sha384 => "SHA384",
+                HashAlgorithm::Sha512 => "SHA512",
+            },
+            base64::encode(&self.salt),
+        )
+    }
+
+    fn parse(attrs: &HashMap<String, String>) -> Result<Self, ReaderError> {
+        if attrs.get("cipherAlgorithm").map(|s| s.as_str()) != Some("AES")
+            || attrs.get("cipherChaining").map(|s| s.as_str()) != Some("ChainingModeCBC")
+        {
+            return Err(ReaderError::DecryptionError);
+        }
+        let hash = attrs
+            .get("hashAlgorithm")
+            .and_then(|h| HashAlgorithm::from_name(h))
+            .ok_or(ReaderError::DecryptionError)?;
+        Ok(Self {
+            salt: decode_attr(attrs, "saltValue")?,
+            block_size: attrs
+                .get("blockSize")
+                .ok_or(ReaderError::DecryptionError)?
+                .parse()?,
+            key_bits: attrs
+                .get("keyBits")
+                .ok_or(ReaderError::DecryptionError)?
+                .parse()?,
+            hash_size: attrs
+                .get("hashSize")
+                .ok_or(ReaderError::DecryptionError)?
+                .parse()?,
+            hash,
+        })
+    }
+}
+
+fn decode_attr(attrs: &HashMap<String, String>, name: &str) -> Result<Vec<u8>, ReaderError> {
+    attrs
+        .get(name)
+        .and_then(|v| base64::decode(v).ok())
+        .ok_or(ReaderError::DecryptionError)
+}
+
+impl EncryptionInfo {
+    fn build(&self) -> Vec<u8> {
+        let xml = format!(
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                "\r\n",
+                r#"<encryption xmlns="http://schemas.microsoft.com/office/2006/encryption" xmlns:p="{uri}" xmlns:c="http://schemas.microsoft.com/office/2006/keyEncryptor/certificate">"#,
+                r#"<keyData {key_data}/>"#,
+                r#"<dataIntegrity encryptedHmacKey="{hmac_key}" encryptedHmacValue="{hmac_value}"/>"#,
+                r#"<keyEncryptors><keyEncryptor uri="{uri}">"#,
+                r#"<p:encryptedKey spinCount="{spin_count}" {encrypted_key} encryptedVerifierHashInput="{verifier_input}" encryptedVerifierHashValue="{verifier_value}" encryptedKeyValue="{key_value}"/>"#,
+                r#"</keyEncryptor></keyEncryptors></encryption>"#,
+            ),
+            uri = PASSWORD_KEY_ENCRYPTOR_URI,
+            key_data = self.key_data.attrs(),
+            hmac_key = base64::encode(self.encrypted_hmac_key.as_deref().unwrap_or_default()),
+            hmac_value = base64::encode(self.encrypted_hmac_value.as_deref().unwrap_or_default()),
+            spin_count = self.spin_count,
+            encrypted_key = self.encrypted_key.attrs(),
+            verifier_input = base64::encode(&self.encrypted_verifier_hash_input),
+            verifier_value = base64::encode(&self.encrypted_verifier_hash_value),
+            key_value = base64::encode(&self.encrypted_key_value),
+        );
+        // Agile Encryption is version 4.4 with the reserved flag 0x40.
+        let mut buf = version(4, 4);
+        buf.extend(0x40u32.to_le_bytes());
+        buf.extend(xml.as_bytes());
+        buf
+    }
+
+    fn parse(buf: &[u8]) -> Result<Self, ReaderError> {
+        if buf.len() < 8 || buf[..4] != version(4, 4)[..] {
+            // Standard and extensible encryption are not supported.
+            return Err(ReaderError::DecryptionError);
+        }
+        let mut key_data = None;
+        let mut encrypted_key = None;
+        let mut data_integrity = None;
+        let parser = EventReader::new(&buf[8..]);
+        for e in parser.into_iter().flatten() {
+            if let XmlEvent::StartElement {
+                name, attributes, ..
+            } = e
+            {
+                let attrs: HashMap<String, String> = attributes
+                    .into_iter()
+                    .map(|a| (a.name.local_name, a.value))
+                    .collect();
+                match name.local_name.as_str() {
+                    "keyData" => key_data = Some(attrs),
+                    "dataIntegrity" => data_integrity = Some(attrs),
+                    "encryptedKey"
+                        if name.namespace.as_deref() == Some(PASSWORD_KEY_ENCRYPTOR_URI) =>
+                    {
+                        encrypted_key = Some(attrs)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let key_data = key_data.ok_or(ReaderError::DecryptionError)?;
+        let encrypted_key = encrypted_key.ok_or(ReaderError::DecryptionError)?;
+        let spin_count: u32 = encrypted_key
+            .get("spinCount")
+            .ok_or(ReaderError::DecryptionError)?
+            .parse()?;
+        if spin_count > MAX_SPIN_COUNT {
+            return Err(ReaderError::DecryptionError);
+        }
+        Ok(Self {
+            key_data: CipherParams::parse(&key_data)?,
+            spin_count,
+            encrypted_verifier_hash_input: decode_attr(
+                &encrypted_key,
+                "encryptedVerifierHashInput",
+            )?,
+            encrypted_verifier_hash_value: decode_attr(
+                &encrypted_key,
+                "encryptedVerifierHashValue",
+            )?,
+            encrypted_key_value: decode_attr(&encrypted_key, "encryptedKeyValue")?,
+            encrypted_key: CipherParams::parse(&encrypted_key)?,
+            encrypted_hmac_key: data_integrity
+                .as_ref()
+                .and_then(|d| decode_attr(d, "encryptedHmacKey").ok()),
+            encrypted_hmac_value: data_integrity
+                .as_ref()
+                .and_then(|d| decode_attr(d, "encryptedHmacValue").ok()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_encrypt_and_decrypt_package() {
+        let package: Vec<u8> = (0..10_000).map(|i| (i % 251) as u8).collect();
+        let encrypted = encrypt_package(&package, "secret").unwrap();
+        assert!(is_compound_file(&encrypted));
+        assert_eq!(decrypt_package(&encrypted, "secret").unwrap(), package);
+        assert!(matches!(
+            decrypt_package(&encrypted, "wrong"),
+            Err(ReaderError::InvalidPasswordError)
+        ));
+    }
+
+    #[test]
+    fn test_encryption_info_roundtrip() {
+        let params = CipherParams {
+            salt: vec![1; 16],
+            block_size: 16,
+            key_bits: 256,
+            hash_size: 64,
+            hash: HashAlgorithm::Sha512,
+        };
+        let info = EncryptionInfo {
+            key_data: params.clone(),
+            encrypted_key: params,
+            spin_count: 10,
+            encrypted_verifier_hash_input: vec![2; 16],
+            encrypted_verifier_hash_value: vec![3; 64],
+            encrypted_key_value: vec![4; 32],
+            encrypted_hmac_key: Some(vec![5; 64]),
+            encrypted_hmac_value: Some(vec![6; 64]),
+        };
+        assert_eq!(EncryptionInfo::parse(&info.build()).unwrap(), info);
+    }
+
+    #[test]
+    fn test_reject_excessive_spin_count() {
+        let params = CipherParams {
+            salt: vec![1; 16],
+            block_size: 16,
+            key_bits: 256,
+            hash_size: 64,
+            hash: HashAlgorithm::Sha512,
+        };
+        let info = EncryptionInfo {
+            key_data: params.clone(),
+            encrypted_key: params,
+            spin_count: u32::MAX,
+            encrypted_verifier_hash_input: vec![2; 16],
+            encrypted_verifier_hash_value: vec![3; 64],
+            encrypted_key_value: vec![4; 32],
+            encrypted_hmac_key: None,
+            encrypted_hmac_value: None,
+        };
+        assert!(matches!(
+            EncryptionInfo::parse(&info.build()),
+            Err(ReaderError::DecryptionError)
+        ));
+    }
+
+    #[test]
+    fn test_data_space_map() {
+        // The map entry for EncryptedPackage is 0x68 bytes long.
+        let map = data_space_map();
+        assert_eq!(map[8..12], 0x68u32.to_le_bytes());
+        assert_eq!(map.len(), 8 + 0x68);
+    }
+}
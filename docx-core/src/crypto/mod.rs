@@ -0,0 +1,13 @@
+mod agile;
+mod word_password;
+mod xml_dsig;
+
+pub(crate) use agile::*;
+pub(crate) use word_password::*;
+pub(crate) use xml_dsig::*;
+
+pub(crate) fn random_bytes(len: usize) -> Vec<u8> {
+    let mut buf = vec![0; len];
+    getrandom::getrandom(&mut buf).expect("should get random bytes");
+    buf
+}
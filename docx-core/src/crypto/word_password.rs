@@ -0,0 +1,137 @@
+use sha2::{Digest, Sha512};
+
+// Please see ECMA-376 Part 4 2.15.1.28 documentProtection.
+const INITIAL_CODE_ARRAY: [u16; 15] = [
+    0xE1F0, 0x1D0F, 0xCC9C, 0x84C0, 0x110C, 0x0E10, 0xF1CE, 0x313E, 0x1872, 0xE139, 0xD40F, 0x84F9,
+    0x280C, 0xA96A, 0x4EC3,
+];
+
+const ENCRYPTION_MATRIX: [[u16; 7]; 15] = [
+    [0xAEFC, 0x4DD9, 0x9BB2, 0x2745, 0x4E8A, 0x9D14, 0x2A09],
+    [0x7B61, 0xF6C2, 0xFDA5, 0xEB6B, 0xC6F7, 0x9DCF, 0x2BBF],
+    [0x4563, 0x8AC6, 0x05AD, 0x0B5A, 0x16B4, 0x2D68, 0x5AD0],
+    [0x0375, 0x06EA, 0x0DD4, 0x1BA8, 0x3750, 0x6EA0, 0xDD40],
+    [0xD849, 0xA0B3, 0x5147, 0xA28E, 0x553D, 0xAA7A, 0x44D5],
+    [0x6F45, 0xDE8A, 0xAD35, 0x4A4B, 0x9496, 0x390D, 0x721A],
+    [0xEB23, 0xC667, 0x9CEF, 0x29FF, 0x53FE, 0xA7FC, 0x5FD9],
+    [0x47D3, 0x8FA6, 0x0F6D, 0x1EDA, 0x3DB4, 0x7B68, 0xF6D0],
+    [0xB861, 0x60E3, 0xC1C6, 0x93AD, 0x377B, 0x6EF6, 0xDDEC],
+    [0x45A0, 0x8B40, 0x06A1, 0x0D42, 0x1A84, 0x3508, 0x6A10],
+    [0xAA51, 0x4483, 0x8906, 0x022D, 0x045A, 0x08B4, 0x1168],
+    [0x76B4, 0xED68, 0xCAF1, 0x85C3, 0x1BA7, 0x374E, 0x6E9C],
+    [0x3730, 0x6E60, 0xDCC0, 0xA9A1, 0x4363, 0x86C6, 0x1DAD],
+    [0x3331, 0x6662, 0xCCC4, 0x89A9, 0x0373, 0x06E6, 0x0DCC],
+    [0x1021, 0x2042, 0x4084, 0x8108, 0x1231, 0x2462, 0x48C4],
+];
+
+const MAX_PASSWORD_LENGTH: usize = 15;
+
+pub(crate) const DEFAULT_SPIN_COUNT: u32 = 100_000;
+
+// The 32-bit key of the legacy Word algorithm,
+// written as hex digits in reversed byte order i.e. 0x64CEED7E becomes "7EEDCE64".
+fn legacy_password_key(password: &str) -> String {
+    // Take the low byte of each character, or the high byte if the low byte is zero.
+    let chars: Vec<u8> = password
+        .encode_utf16()
+        .take(MAX_PASSWORD_LENGTH)
+        .map(|c| {
+            let low = (c & 0x00FF) as u8;
+            if low == 0 {
+                (c >> 8) as u8
+            } else {
+                low
+            }
+        })
+        .collect();
+    if chars.is_empty() {
+        return "00000000".to_owned();
+    }
+
+    let mut high = INITIAL_CODE_ARRAY[chars.len() - 1];
+    for (i, c) in chars.iter().enumerate() {
+        let row = MAX_PASSWORD_LENGTH - chars.len() + i;
+        for (bit, v) in ENCRYPTION_MATRIX[row].iter().enumerate() {
+            if c & (1 << bit) != 0 {
+                high ^= v;
+            }
+        }
+    }
+
+    let mut low: u16 = 0;
+    for c in chars.iter().rev() {
+        low = (((low >> 14) & 0x0001) | ((low << 1) & 0x7FFF)) ^ *c as u16;
+    }
+    low = (((low >> 14) & 0x0001) | ((low << 1) & 0x7FFF)) ^ chars.len() as u16 ^ 0xCE4B;
+
+    let key = ((high as u32) << 16) | low as u32;
+    // Word writes the key bytes in little endian order.
+    format!("{:08X}", key.swap_bytes())
+}
+
+// Hash a password the way Word does for document and write protection.
+// The legacy key is hashed with the salt, then rehashed `spin_count` times
+// with the little endian iteration number appended.
+pub(crate) fn hash_word_password(password: &str, salt: &[u8], spin_count: u32) -> Vec<u8> {
+    let key: Vec<u8> = legacy_password_key(password)
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+        .collect();
+    let mut hash = Sha512::new()
+        .chain_update(salt)
+        .chain_update(&key)
+        .finalize()
+        .to_vec();
+    for i in 0..spin_count {
+        hash = Sha512::new()
+            .chain_update(&hash)
+            .chain_update(i.to_le_bytes())
+            .finalize()
+            .to_vec();
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_legacy_password_key() {
+        assert_eq!(legacy_password_key(""), "00000000");
+        assert_eq!(legacy_password_key("a"), legacy_password_key("a"));
+        assert_ne!(legacy_password_key("a"), legacy_password_key("b"));
+        assert_eq!(legacy_password_key("a").len(), 8);
+    }
+
+    #[test]
+    fn test_hash_word_password() {
+        let salt = [0u8; 16];
+        let a = hash_word_password("docx", &salt, 10);
+        assert_eq!(a.len(), 64);
+        assert_eq!(a, hash_word_password("docx", &salt, 10));
+        assert_ne!(a, hash_word_password("docx", &salt, 11));
+        assert_ne!(a, hash_word_password("docx", &[1u8; 16], 10));
+    }
+
+    #[test]
+    fn test_legacy_password_key_known_answer() {
+        // The low word is the verifier Excel writes for sheet protection,
+        // e.g. `<sheetProtection password="CBEB" />` for "test".
+        assert_eq!(legacy_password_key("test"), "EBCBC61F");
+        assert_eq!(legacy_password_key("password"), "AF837A14");
+    }
+
+    #[test]
+    fn test_hash_word_password_known_answer() {
+        // w:saltValue="AAECAwQFBgcICQoLDA0ODw==" w:spinCount="100000" w:algorithmName="SHA-512"
+        let salt = base64::decode("AAECAwQFBgcICQoLDA0ODw==").unwrap();
+        assert_eq!(
+            base64::encode(hash_word_password("docx", &salt, 100_000)),
+            "sXmHcxpLLPBr9qVkO5JRFBzfsfNORevHbAesyVeIuV354DhQYBV7L1U038XD3fyt5yVtPWrogsbD0+6sG30PYw=="
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use rsa::pkcs1v15::{Signature, SigningKey, VerifyingKey};
+use rsa::pkcs8::DecodePublicKey;
+use rsa::signature::{SignatureEncoding, Signer, Verifier};
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+use x509_cert::der::{Decode, Encode};
+use xml::name::OwnedName;
+use xml::reader::{EventReader, XmlEvent};
+
+// Please see https://www.w3.org/TR/xmldsig-core1/ and ECMA-376 Part 2 13 Digital Signatures.
+pub(crate) const C14N_URI: &str = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315";
+pub(crate) const RSA_SHA256_URI: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256";
+pub(crate) const RELATIONSHIP_TRANSFORM_URI: &str =
+    "http://schemas.openxmlformats.org/package/2006/RelationshipTransform";
+const RSA_SHA1_URI: &str = "http://www.w3.org/2000/09/xmldsig#rsa-sha1";
+const RSA_SHA512_URI: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha512";
+const RELATIONSHIPS_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DigestMethod {
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+impl DigestMethod {
+    pub(crate) fn from_uri(uri: &str) -> Option<Self> {
+        match uri {
+            "http://www.w3.org/2000/09/xmldsig#sha1" => Some(DigestMethod::Sha1),
+            "http://www.w3.org/2001/04/xmlenc#sha256" => Some(DigestMethod::Sha256),
+            "http://www.w3.org/2001/04/xmlenc#sha512" => Some(DigestMethod::Sha512),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn uri(&self) -> &'static str {
+        match self {
+            DigestMethod::Sha1 => "http://www.w3.org/2000/09/xmldsig#sha1",
+            DigestMethod::Sha256 => "http://www.w3.org/2001/04/xmlenc#sha256",
+            DigestMethod::Sha512 => "http://www.w3.org/2001/04/xmlenc#sha512",
+        }
+    }
+
+    pub(crate) fn digest(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            DigestMethod::Sha1 => Sha1::digest(data).to_vec(),
+            DigestMethod::Sha256 => Sha256::digest(data).to_vec(),
+            DigestMethod::Sha512 => Sha512::digest(data).to_vec(),
+        }
+    }
+}
+
+pub(crate) fn sign_rsa_sha256(key: &RsaPrivateKey, data: &[u8]) -> Vec<u8> {
+    SigningKey::<Sha256>::new(key.clone()).sign(data).to_vec()
+}
+
+pub(crate) fn verify_rsa(
+    certificate: &[u8],
+    signature_method: &str,
+    data: &[u8],
+    signature: &[u8],
+) -> bool {
+    let key = match public_key(certificate) {
+        Some(key) => key,
+        None => return false,
+    };
+    let signature = match Signature::try_from(signature) {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    match signature_method {
+        RSA_SHA1_URI => VerifyingKey::<Sha1>::new(key)
+            .verify(data, &signature)
+            .is_ok(),
+        RSA_SHA256_URI => VerifyingKey::<Sha256>::new(key)
+            .verify(data, &signature)
+            .is_ok(),
+        RSA_SHA512_URI => VerifyingKey::<Sha512>::new(key)
+            .verify(data, &signature)
+            .is_ok(),
+        _ => false,
+    }
+}
+
+// The RSA public key of a DER encoded X.509 certificate.
+pub(crate) fn public_key(certificate: &[u8]) -> Option<RsaPublicKey> {
+    let cert = x509_cert::Certificate::from_der(certificate).ok()?;
+    let spki = cert.tbs_certificate.subject_public_key_info.to_der().ok()?;
+    RsaPublicKey::from_public_key_der(&spki).ok()
+}
+
+// Inclusive Canonical XML 1.0 without comments of the first element matched
+// by its local name and/or its `Id` attribute.
+pub(crate) fn canonicalize(
+    xml: &[u8],
+    local_name: Option<&str>,
+    id: Option<&str>,
+) -> Option<Vec<u8>> {
+    let mut out = String::new();
+    // The namespaces rendered by each open element of the subtree.
+    let mut rendered: Vec<BTreeMap<String, String>> = vec![];
+    for e in EventReader::new(xml) {
+        match e.ok()? {
+            XmlEvent::StartElement {
+                name,
+                attributes,
+                namespace,
+            } => {
+                if rendered.is_empty() {
+                    let matched = local_name.map_or(true, |n| n == name.local_name)
+                        && id.map_or(true, |id| {
+                            attributes
+                                .iter()
+                                .any(|a| a.name.local_name == "Id" && a.value == id)
+                        });
+                    if !matched {
+                        continue;
+                    }
+                }
+                let parent = rendered.last().cloned().unwrap_or_default();
+                let mut current = parent.clone();
+                out.push('<');
+                out.push_str(&qualified_name(&name));
+                for (prefix, uri) in namespace.0.iter() {
+                    if prefix == "xml" || prefix == "xmlns" {
+                        continue;
+                    }
+                    if parent.get(prefix).map(|p| p.as_str()).unwrap_or_default() == uri {
+                        continue;
+                    }
+                    if prefix.is_empty() {
+                        out.push_str(&format!(r#" xmlns="{}""#, escape_attr(uri)));
+                    } else {
+                        out.push_str(&format!(r#" xmlns:{}="{}""#, prefix, escape_attr(uri)));
+                    }
+                    current.insert(prefix.clone(), uri.clone());
+                }
+                let mut attributes = attributes;
+                attributes.sort_by(|a, b| {
+                    (
+                        a.name.namespace.as_deref().unwrap_or_default(),
+                        &a.name.local_name,
+                    )
+                        .cmp(&(
+                            b.name.namespace.as_deref().unwrap_or_default(),
+                            &b.name.local_name,
+                        ))
+                });
+                for a in attributes.iter() {
+                    out.push_str(&format!(
+                        r#" {}="{}""#,
+                        qualified_name(&a.name),
+                        escape_attr(&a.value)
+                    ));
+                }
+                out.push('>');
+                rendered.push(current);
+            }
+            XmlEvent::EndElement { name } => {
+                if rendered.pop().is_some() {
+                    out.push_str(&format!("</{}>", qualified_name(&name)));
+                    if rendered.is_empty() {
+                        return Some(out.into_bytes());
+                    }
+                }
+            }
+            XmlEvent::Characters(t) | XmlEvent::Whitespace(t) | XmlEvent::CData(t) => {
+                if !rendered.is_empty() {
+                    out.push_str(&escape_text(&t));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// Relationships of a relationship part as `(Id, Target, TargetMode, Type)`.
+pub(crate) fn read_relationships(xml: &[u8]) -> Option<Vec<(String, String, String, String)>> {
+    let mut rels = vec![];
+    for e in EventReader::new(xml) {
+        if let XmlEvent::StartElement {
+            name, attributes, ..
+        } = e.ok()?
+        {
+            if name.local_name != "Relationship" {
+                continue;
+            }
+            let attr = |n: &str| {
+                attributes
+                    .iter()
+                    .find(|a| a.name.local_name == n)
+                    .map(|a| a.value.clone())
+                    .unwrap_or_default()
+            };
+            let mode = Some(attr("TargetMode"))
+                .filter(|m| !m.is_empty())
+                .unwrap_or_else(|| "Internal".to_owned());
+            rels.push((attr("Id"), attr("Target"), mode, attr("Type")));
+        }
+    }
+    Some(rels)
+}
+
+// The OPC relationship transform selects relationships by `Id` or `Type`
+// and renders them sorted by `Id` in canonical form.
+pub(crate) fn transform_relationships(
+    xml: &[u8],
+    source_ids: &[String],
+    source_types: &[String],
+) -> Option<Vec<u8>> {
+    let mut rels: Vec<_> = read_relationships(xml)?
+        .into_iter()
+        .filter(|(id, _, _, rel_type)| source_ids.contains(id) || source_types.contains(rel_type))
+        .collect();
+    rels.sort();
+    let mut out = format!(r#"<Relationships xmlns="{}">"#, RELATIONSHIPS_NAMESPACE);
+    for (id, target, mode, rel_type) in rels {
+        out.push_str(&format!(
+            r#"<Relationship Id="{}" Target="{}" TargetMode="{}" Type="{}"></Relationship>"#,
+            escape_attr(&id),
+            escape_attr(&target),
+            escape_attr(&mode),
+            escape_attr(&rel_type)
+        ));
+    }
+    out.push_str("</Relationships>");
+    Some(out.into_bytes())
+}
+
+fn qualified_name(name: &OwnedName) -> String {
+    match name.prefix {
+        Some(ref p) => format!("{}:{}", p, name.local_name),
+        None => name.local_name.clone(),
+    }
+}
+
+fn escape_text(t: &str) -> String {
+    t.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\r', "&#xD;")
+}
+
+fn escape_attr(t: &str) -> String {
+    t.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+        .replace('\t', "&#x9;")
+        .replace('\n', "&#xA;")
+        .replace('\r', "&#xD;")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_canonicalize() {
+        let xml = r#"<?xml version="1.0"?>
+<Signature xmlns="http://www.w3.org/2000/09/xmldsig#" xmlns:a="http://a">
+  <Object Id="b"><a:Foo/></Object>
+  <Object z="1" Id="a" a:y="&amp;">
+    <a:Bar xmlns:b="http://b"  b:x='"'>1 &lt; 2</a:Bar>
+  </Object>
+</Signature>"#;
+        let c = canonicalize(xml.as_bytes(), None, Some("a")).unwrap();
+        assert_eq!(
+            str::from_utf8(&c).unwrap(),
+            r#"<Object xmlns="http://www.w3.org/2000/09/xmldsig#" xmlns:a="http://a" Id="a" z="1" a:y="&amp;">
+    <a:Bar xmlns:b="http://b" b:x="&quot;">1 &lt; 2</a:Bar>
+  </Object>"#
+        );
+    }
+
+    #[test]
+    fn test_transform_relationships() {
+        let xml = r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId2" Type="http://b" Target="b.xml" />
+  <Relationship Id="rId1" Type="http://a" Target="a.xml" />
+  <Relationship Id="rId3" Type="http://c" Target="http://example.com" TargetMode="External" />
+</Relationships>"#;
+        let t = transform_relationships(
+            xml.as_bytes(),
+            &["rId2".to_owned(), "rId1".to_owned()],
+            &["http://c".to_owned()],
+        )
+        .unwrap();
+        assert_eq!(
+            str::from_utf8(&t).unwrap(),
+            r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Target="a.xml" TargetMode="Internal" Type="http://a"></Relationship><Relationship Id="rId2" Target="b.xml" TargetMode="Internal" Type="http://b"></Relationship><Relationship Id="rId3" Target="http://example.com" TargetMode="External" Type="http://c"></Relationship></Relationships>"#
+        );
+    }
+}
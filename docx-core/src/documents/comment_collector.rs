@@ -0,0 +1,197 @@
+use crate::{
+    CommentRangeStart, DeleteChild, Document, DocumentChild, Footer, FooterChild, Header,
+    HeaderChild, InsertChild, Paragraph, ParagraphChild, Run, RunChild, StructuredDataTag,
+    StructuredDataTagChild, Table, TableCellContent, TableChild, TableOfContents, TableRowChild,
+    TocContent,
+};
+
+// Visit every `w:commentRangeStart` in the body, headers, footers and footnotes.
+// Start and end markers are independent, so a range may begin in a table cell
+// and end in a later paragraph; only the starts carry the comment.
+pub(crate) fn visit_comments_in_document<F>(document: &mut Document, f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    for child in &mut document.children {
+        match child {
+            DocumentChild::Paragraph(p) => visit_comments_in_paragraph(p, f),
+            DocumentChild::Table(t) => visit_comments_in_table(t, f),
+            DocumentChild::CommentStart(c) => f(c),
+            DocumentChild::StructuredDataTag(t) => visit_comments_in_tag(t, f),
+            DocumentChild::TableOfContents(t) => visit_comments_in_toc(t, f),
+            _ => {}
+        }
+    }
+    let section = &mut document.section_property;
+    for header in vec![
+        section.header.as_mut(),
+        section.first_header.as_mut(),
+        section.even_header.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        visit_comments_in_header(header, f);
+    }
+    for footer in vec![
+        section.footer.as_mut(),
+        section.first_footer.as_mut(),
+        section.even_footer.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        visit_comments_in_footer(footer, f);
+    }
+}
+
+fn visit_comments_in_header<F>(header: &mut Header, f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    for child in &mut header.children {
+        match child {
+            HeaderChild::Paragraph(p) => visit_comments_in_paragraph(p, f),
+            HeaderChild::Table(t) => visit_comments_in_table(t, f),
+            HeaderChild::StructuredDataTag(t) => visit_comments_in_tag(t, f),
+        }
+    }
+}
+
+fn visit_comments_in_footer<F>(footer: &mut Footer, f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    for child in &mut footer.children {
+        match child {
+            FooterChild::Paragraph(p) => visit_comments_in_paragraph(p, f),
+            FooterChild::Table(t) => visit_comments_in_table(t, f),
+            FooterChild::StructuredDataTag(t) => visit_comments_in_tag(t, f),
+        }
+    }
+}
+
+fn visit_comments_in_paragraph<F>(paragraph: &mut Paragraph, f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    visit_comments_in_paragraph_children(&mut paragraph.children, f);
+}
+
+fn visit_comments_in_paragraph_children<F>(children: &mut [ParagraphChild], f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    for child in children {
+        match child {
+            ParagraphChild::CommentStart(c) => f(c),
+            ParagraphChild::Run(run) => visit_comments_in_run(run, f),
+            ParagraphChild::Hyperlink(h) => {
+                visit_comments_in_paragraph_children(&mut h.children, f)
+            }
+            ParagraphChild::Insert(ins) => visit_comments_in_insert(&mut ins.children, f),
+            ParagraphChild::MoveTo(m) => visit_comments_in_insert(&mut m.children, f),
+            ParagraphChild::Delete(del) => visit_comments_in_delete(&mut del.children, f),
+            ParagraphChild::MoveFrom(m) => visit_comments_in_delete(&mut m.children, f),
+            ParagraphChild::StructuredDataTag(t) => visit_comments_in_tag(t, f),
+            _ => {}
+        }
+    }
+}
+
+fn visit_comments_in_insert<F>(children: &mut [InsertChild], f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    for child in children {
+        match child {
+            InsertChild::CommentStart(c) => f(c),
+            InsertChild::Run(run) => visit_comments_in_run(run, f),
+            InsertChild::Delete(del) => visit_comments_in_delete(&mut del.children, f),
+            InsertChild::CommentEnd(_) => {}
+        }
+    }
+}
+
+fn visit_comments_in_delete<F>(children: &mut [DeleteChild], f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    for child in children {
+        match child {
+            DeleteChild::CommentStart(c) => f(c),
+            DeleteChild::Run(run) => visit_comments_in_run(run, f),
+            DeleteChild::CommentEnd(_) => {}
+        }
+    }
+}
+
+fn visit_comments_in_run<F>(run: &mut Run, f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    for child in &mut run.children {
+        match child {
+            RunChild::CommentStart(c) => f(c),
+            // Footnote content is written to footnotes.xml and may hold its own comments.
+            RunChild::FootnoteReference(r) => {
+                for p in &mut r.content {
+                    visit_comments_in_paragraph(p, f);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn visit_comments_in_table<F>(table: &mut Table, f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    for TableChild::TableRow(row) in &mut table.rows {
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for content in &mut cell.children {
+                match content {
+                    TableCellContent::Paragraph(p) => visit_comments_in_paragraph(p, f),
+                    TableCellContent::Table(t) => visit_comments_in_table(t, f),
+                    TableCellContent::StructuredDataTag(t) => visit_comments_in_tag(t, f),
+                    TableCellContent::TableOfContents(t) => visit_comments_in_toc(t, f),
+                }
+            }
+        }
+    }
+}
+
+fn visit_comments_in_tag<F>(tag: &mut StructuredDataTag, f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    for child in &mut tag.children {
+        match child {
+            StructuredDataTagChild::CommentStart(c) => f(c),
+            StructuredDataTagChild::Run(run) => visit_comments_in_run(run, f),
+            StructuredDataTagChild::Insert(ins) => visit_comments_in_insert(&mut ins.children, f),
+            StructuredDataTagChild::Delete(del) => visit_comments_in_delete(&mut del.children, f),
+            StructuredDataTagChild::Paragraph(p) => visit_comments_in_paragraph(p, f),
+            StructuredDataTagChild::Table(t) => visit_comments_in_table(t, f),
+            StructuredDataTagChild::StructuredDataTag(t) => visit_comments_in_tag(t, f),
+            _ => {}
+        }
+    }
+}
+
+fn visit_comments_in_toc<F>(toc: &mut TableOfContents, f: &mut F)
+where
+    F: FnMut(&mut CommentRangeStart),
+{
+    for child in toc
+        .before_contents
+        .iter_mut()
+        .chain(toc.after_contents.iter_mut())
+    {
+        match child {
+            TocContent::Paragraph(p) => visit_comments_in_paragraph(p, f),
+            TocContent::Table(t) => visit_comments_in_table(t, f),
+        }
+    }
+}
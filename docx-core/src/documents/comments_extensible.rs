@@ -0,0 +1,55 @@
+use serde::Serialize;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsExtensible {
+    pub children: Vec<CommentExtensible>,
+}
+
+impl CommentsExtensible {
+    pub fn new() -> CommentsExtensible {
+        Default::default()
+    }
+
+    pub fn add_comments_extensible(&mut self, c: Vec<CommentExtensible>) {
+        self.children = c;
+    }
+}
+
+impl BuildXML for CommentsExtensible {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .declaration(Some(true))
+            .open_comments_extensible()
+            .add_children(&self.children)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_comments_extensible() {
+        let mut c = CommentsExtensible::new();
+        c.add_comments_extensible(vec![
+            CommentExtensible::new("2C0F7A9E").date_utc("2023-04-01T00:00:00Z")
+        ]);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w16cex:commentsExtensible xmlns:w16cex="http://schemas.microsoft.com/office/word/2018/wordml/cex"><w16cex:commentExtensible w16cex:durableId="2C0F7A9E" w16cex:dateUtc="2023-04-01T00:00:00Z" /></w16cex:commentsExtensible>"#
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use serde::Serialize;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsIds {
+    pub children: Vec<CommentId>,
+}
+
+impl CommentsIds {
+    pub fn new() -> CommentsIds {
+        Default::default()
+    }
+
+    pub fn add_comment_ids(&mut self, c: Vec<CommentId>) {
+        self.children = c;
+    }
+}
+
+impl BuildXML for CommentsIds {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .declaration(Some(true))
+            .open_comments_ids()
+            .add_children(&self.children)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_comments_ids() {
+        let mut c = CommentsIds::new();
+        c.add_comment_ids(vec![CommentId::new("00000001", "2C0F7A9E")]);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w16cid:commentsIds xmlns:w16cid="http://schemas.microsoft.com/office/word/2016/wordml/cid"><w16cid:commentId w16cid:paraId="00000001" w16cid:durableId="2C0F7A9E" /></w16cid:commentsIds>"#
+        );
+    }
+}
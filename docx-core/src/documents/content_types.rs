@@ -7,9 +7,24 @@ use crate::documents::BuildXML;
 use crate::reader::{FromXML, ReaderError};
 use crate::xml_builder::*;
 
+const DEFAULT_TYPES: [(&str, &str); 7] = [
+    ("png", "image/png"),
+    ("jpeg", "image/jpeg"),
+    ("jpg", "image/jpg"),
+    ("bmp", "image/bmp"),
+    ("gif", "image/gif"),
+    (
+        "rels",
+        "application/vnd.openxmlformats-package.relationships+xml",
+    ),
+    ("xml", "application/xml"),
+];
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct ContentTypes {
     types: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    defaults: BTreeMap<String, String>,
     web_extension_count: usize,
     custom_xml_count: usize,
     header_count: usize,
@@ -26,6 +41,29 @@ impl ContentTypes {
         self
     }
 
+    pub fn add_default(
+        mut self,
+        extension: impl Into<String>,
+        content_type: impl Into<String>,
+    ) -> Self {
+        self.defaults.insert(extension.into(), content_type.into());
+        self
+    }
+
+    // Resolve the content type of a part such as `/word/document.xml`.
+    pub fn content_type(&self, part_name: &str) -> Option<&str> {
+        if let Some(t) = self.types.get(part_name) {
+            return Some(t);
+        }
+        let ext = part_name.rsplit('.').next()?.to_ascii_lowercase();
+        self.defaults.get(&ext).map(|t| t.as_str()).or_else(|| {
+            DEFAULT_TYPES
+                .iter()
+                .find(|(e, _)| *e == ext)
+                .map(|(_, t)| *t)
+        })
+    }
+
     pub fn set_default(mut self) -> ContentTypes {
         self.types.insert(
             "/_rels/.rels".to_owned(),
@@ -77,6 +115,20 @@ impl ContentTypes {
             "application/vnd.openxmlformats-officedocument.wordprocessingml.commentsExtended+xml"
                 .to_owned(),
         );
+        self.types.insert(
+            "/word/commentsIds.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.commentsIds+xml"
+                .to_owned(),
+        );
+        self.types.insert(
+            "/word/commentsExtensible.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.commentsExtensible+xml"
+                .to_owned(),
+        );
+        self.types.insert(
+            "/word/people.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.people+xml".to_owned(),
+        );
         self.types.insert(
             "/docProps/custom.xml".to_owned(),
             "application/vnd.openxmlformats-officedocument.custom-properties+xml".to_owned(),
@@ -106,7 +158,7 @@ impl ContentTypes {
 
     pub fn add_custom_xml(mut self) -> Self {
         self.types.insert(
-            format!("/customXml/itemProps{}.xml", self.web_extension_count),
+            format!("/customXml/itemProps{}.xml", self.custom_xml_count),
             "application/vnd.openxmlformats-officedocument.customXmlProperties+xml".to_owned(),
         );
         self.custom_xml_count += 1;
@@ -130,6 +182,14 @@ impl ContentTypes {
         );
         self
     }
+    pub fn add_theme(mut self) -> Self {
+        self.types.insert(
+            "/word/theme/theme1.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.theme+xml".to_owned(),
+        );
+        self
+    }
+
     pub fn add_footnotes(mut self) -> Self {
         self.types.insert(
             "/word/footnotes.xml".to_owned(),
@@ -144,6 +204,7 @@ impl Default for ContentTypes {
     fn default() -> Self {
         ContentTypes {
             types: BTreeMap::new(),
+            defaults: BTreeMap::new(),
             web_extension_count: 1,
             custom_xml_count: 1,
             header_count: 0,
@@ -159,17 +220,14 @@ impl BuildXML for ContentTypes {
             .declaration(None)
             .open_types("http://schemas.openxmlformats.org/package/2006/content-types");
 
-        b = b
-            .add_default("png", "image/png")
-            .add_default("jpeg", "image/jpeg")
-            .add_default("jpg", "image/jpg")
-            .add_default("bmp", "image/bmp")
-            .add_default("gif", "image/gif")
-            .add_default(
-                "rels",
-                "application/vnd.openxmlformats-package.relationships+xml",
-            )
-            .add_default("xml", "application/xml");
+        for (ext, t) in DEFAULT_TYPES.iter() {
+            b = b.add_default(ext, t);
+        }
+        for (ext, t) in self.defaults.iter() {
+            if !DEFAULT_TYPES.iter().any(|(e, _)| e == ext) {
+                b = b.add_default(ext, t);
+            }
+        }
 
         for (k, v) in self.types.iter() {
             b = b.add_override(k, v);
@@ -185,11 +243,29 @@ impl FromXML for ContentTypes {
         let mut depth = 0;
         for e in parser {
             match e {
-                Ok(XmlEvent::StartElement { attributes, .. }) => {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
                     if depth == 1 {
-                        let namespace = attributes[0].value.clone();
-                        let path = attributes[1].value.clone();
-                        s = s.add_content(path, namespace);
+                        let attr = |n: &str| {
+                            attributes
+                                .iter()
+                                .find(|a| a.name.local_name == n)
+                                .map(|a| a.value.clone())
+                        };
+                        match (name.local_name.as_str(), attr("ContentType")) {
+                            ("Override", Some(t)) => {
+                                if let Some(path) = attr("PartName") {
+                                    s = s.add_content(path, t);
+                                }
+                            }
+                            ("Default", Some(t)) => {
+                                if let Some(ext) = attr("Extension") {
+                                    s = s.add_default(ext.to_ascii_lowercase(), t);
+                                }
+                            }
+                            _ => {}
+                        }
                     }
                     depth += 1;
                 }
@@ -214,6 +290,7 @@ mod tests {
     #[test]
     fn test_from_xml() {
         let xml = r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+        <Default Extension="xml" ContentType="application/xml"/>
         <Override ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml" PartName="/word/document.xml"></Override></Types>"#;
         let c = ContentTypes::from_xml(xml.as_bytes()).unwrap();
         let mut types = BTreeMap::new();
@@ -222,9 +299,12 @@ mod tests {
             "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"
                 .to_owned(),
         );
+        let mut defaults = BTreeMap::new();
+        defaults.insert("xml".to_owned(), "application/xml".to_owned());
         assert_eq!(
             ContentTypes {
                 types,
+                defaults,
                 web_extension_count: 1,
                 custom_xml_count: 1,
                 header_count: 0,
@@ -232,5 +312,10 @@ mod tests {
             },
             c
         );
+        assert_eq!(c.content_type("/word/styles.xml"), Some("application/xml"));
+        assert_eq!(
+            c.content_type("/_rels/.rels"),
+            Some("application/vnd.openxmlformats-package.relationships+xml")
+        );
     }
 }
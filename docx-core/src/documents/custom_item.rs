@@ -15,6 +15,12 @@ impl FromStr for CustomItem {
     }
 }
 
+impl CustomItem {
+    pub fn find_by_xpath(&self, xpath: &str) -> Option<String> {
+        self.0.find_by_xpath(xpath)
+    }
+}
+
 impl Serialize for CustomItem {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -61,4 +67,23 @@ mod tests {
         "[{\"name\":\"ds:datastoreItem\",\"attributes\":[[\"ds:itemID\",\"{06AC5857-5C65-A94A-BCEC-37356A209BC3}\"],[\"xmlns:ds\",\"http://schemas.openxmlformats.org/officeDocument/2006/customXml\"]],\"data\":null,\"children\":[{\"name\":\"ds:schemaRefs\",\"attributes\":[],\"data\":null,\"children\":[{\"name\":\"ds:schemaRef\",\"attributes\":[[\"ds:uri\",\"https://hoge.com\"]],\"data\":null,\"children\":[]}]}]}]"
         );
     }
+
+    #[test]
+    fn test_find_by_xpath() {
+        let c = CustomItem::from_str(
+            r#"<ns0:root xmlns:ns0="https://example.com"><ns0:item name="a">Hello</ns0:item><ns0:item name="b">World</ns0:item></ns0:root>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            c.find_by_xpath("/ns0:root/ns0:item"),
+            Some("Hello".to_owned())
+        );
+        assert_eq!(
+            c.find_by_xpath("/ns0:root[1]/ns0:item[2]"),
+            Some("World".to_owned())
+        );
+        assert_eq!(c.find_by_xpath("/root/item[2]/@name"), Some("b".to_owned()));
+        assert_eq!(c.find_by_xpath("/root/item[3]"), None);
+    }
 }
@@ -5,7 +5,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct CustomItemProperty {
-    id: String,
+    pub id: String,
 }
 
 impl CustomItemProperty {
@@ -0,0 +1,248 @@
+use crate::{
+    CustomItem, CustomItemProperty, Document, DocumentChild, Footer, FooterChild, Header,
+    HeaderChild, Paragraph, ParagraphChild, StructuredDataTag, StructuredDataTagChild, Table,
+    TableCellContent, TableChild, TableRowChild,
+};
+
+pub(crate) fn visit_tags_in_document<F>(document: &mut Document, f: &mut F)
+where
+    F: FnMut(&mut StructuredDataTag),
+{
+    for child in &mut document.children {
+        match child {
+            DocumentChild::Paragraph(paragraph) => visit_tags_in_paragraph(paragraph, f),
+            DocumentChild::Table(table) => visit_tags_in_table(table, f),
+            DocumentChild::StructuredDataTag(tag) => visit_tag(tag, f),
+            _ => {}
+        }
+    }
+    let section = &mut document.section_property;
+    for header in vec![
+        section.header.as_mut(),
+        section.first_header.as_mut(),
+        section.even_header.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        visit_tags_in_header(header, f);
+    }
+    for footer in vec![
+        section.footer.as_mut(),
+        section.first_footer.as_mut(),
+        section.even_footer.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        visit_tags_in_footer(footer, f);
+    }
+}
+
+pub(crate) fn visit_tags_in_paragraph<F>(paragraph: &mut Paragraph, f: &mut F)
+where
+    F: FnMut(&mut StructuredDataTag),
+{
+    for child in &mut paragraph.children {
+        if let ParagraphChild::StructuredDataTag(tag) = child {
+            visit_tag(tag, f);
+        }
+    }
+}
+
+pub(crate) fn visit_tags_in_table<F>(table: &mut Table, f: &mut F)
+where
+    F: FnMut(&mut StructuredDataTag),
+{
+    for TableChild::TableRow(row) in &mut table.rows {
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for content in &mut cell.children {
+                match content {
+                    TableCellContent::Paragraph(paragraph) => {
+                        visit_tags_in_paragraph(paragraph, f);
+                    }
+                    TableCellContent::Table(table) => visit_tags_in_table(table, f),
+                    TableCellContent::StructuredDataTag(tag) => visit_tag(tag, f),
+                    TableCellContent::TableOfContents(_) => {}
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn visit_tags_in_header<F>(header: &mut Header, f: &mut F)
+where
+    F: FnMut(&mut StructuredDataTag),
+{
+    for child in &mut header.children {
+        match child {
+            HeaderChild::Paragraph(paragraph) => visit_tags_in_paragraph(paragraph, f),
+            HeaderChild::Table(table) => visit_tags_in_table(table, f),
+            HeaderChild::StructuredDataTag(tag) => visit_tag(tag, f),
+        }
+    }
+}
+
+pub(crate) fn visit_tags_in_footer<F>(footer: &mut Footer, f: &mut F)
+where
+    F: FnMut(&mut StructuredDataTag),
+{
+    for child in &mut footer.children {
+        match child {
+            FooterChild::Paragraph(paragraph) => visit_tags_in_paragraph(paragraph, f),
+            FooterChild::Table(table) => visit_tags_in_table(table, f),
+            FooterChild::StructuredDataTag(tag) => visit_tag(tag, f),
+        }
+    }
+}
+
+pub(crate) fn visit_tag<F>(tag: &mut StructuredDataTag, f: &mut F)
+where
+    F: FnMut(&mut StructuredDataTag),
+{
+    f(tag);
+    for child in &mut tag.children {
+        match child {
+            StructuredDataTagChild::Paragraph(paragraph) => visit_tags_in_paragraph(paragraph, f),
+            StructuredDataTagChild::Table(table) => visit_tags_in_table(table, f),
+            StructuredDataTagChild::StructuredDataTag(tag) => visit_tag(tag, f),
+            _ => {}
+        }
+    }
+}
+
+// Read-only counterparts of the visitors above, for collecting values without cloning the document.
+pub(crate) fn walk_tags_in_document<F>(document: &Document, f: &mut F)
+where
+    F: FnMut(&StructuredDataTag),
+{
+    for child in &document.children {
+        match child {
+            DocumentChild::Paragraph(paragraph) => walk_tags_in_paragraph(paragraph, f),
+            DocumentChild::Table(table) => walk_tags_in_table(table, f),
+            DocumentChild::StructuredDataTag(tag) => walk_tag(tag, f),
+            _ => {}
+        }
+    }
+    let section = &document.section_property;
+    for header in vec![
+        section.header.as_ref(),
+        section.first_header.as_ref(),
+        section.even_header.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        walk_tags_in_header(header, f);
+    }
+    for footer in vec![
+        section.footer.as_ref(),
+        section.first_footer.as_ref(),
+        section.even_footer.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        walk_tags_in_footer(footer, f);
+    }
+}
+
+fn walk_tags_in_paragraph<F>(paragraph: &Paragraph, f: &mut F)
+where
+    F: FnMut(&StructuredDataTag),
+{
+    for child in &paragraph.children {
+        if let ParagraphChild::StructuredDataTag(tag) = child {
+            walk_tag(tag, f);
+        }
+    }
+}
+
+fn walk_tags_in_table<F>(table: &Table, f: &mut F)
+where
+    F: FnMut(&StructuredDataTag),
+{
+    for TableChild::TableRow(row) in &table.rows {
+        for TableRowChild::TableCell(cell) in &row.cells {
+            for content in &cell.children {
+                match content {
+                    TableCellContent::Paragraph(paragraph) => walk_tags_in_paragraph(paragraph, f),
+                    TableCellContent::Table(table) => walk_tags_in_table(table, f),
+                    TableCellContent::StructuredDataTag(tag) => walk_tag(tag, f),
+                    TableCellContent::TableOfContents(_) => {}
+                }
+            }
+        }
+    }
+}
+
+fn walk_tags_in_header<F>(header: &Header, f: &mut F)
+where
+    F: FnMut(&StructuredDataTag),
+{
+    for child in &header.children {
+        match child {
+            HeaderChild::Paragraph(paragraph) => walk_tags_in_paragraph(paragraph, f),
+            HeaderChild::Table(table) => walk_tags_in_table(table, f),
+            HeaderChild::StructuredDataTag(tag) => walk_tag(tag, f),
+        }
+    }
+}
+
+fn walk_tags_in_footer<F>(footer: &Footer, f: &mut F)
+where
+    F: FnMut(&StructuredDataTag),
+{
+    for child in &footer.children {
+        match child {
+            FooterChild::Paragraph(paragraph) => walk_tags_in_paragraph(paragraph, f),
+            FooterChild::Table(table) => walk_tags_in_table(table, f),
+            FooterChild::StructuredDataTag(tag) => walk_tag(tag, f),
+        }
+    }
+}
+
+fn walk_tag<F>(tag: &StructuredDataTag, f: &mut F)
+where
+    F: FnMut(&StructuredDataTag),
+{
+    f(tag);
+    for child in &tag.children {
+        match child {
+            StructuredDataTagChild::Paragraph(paragraph) => walk_tags_in_paragraph(paragraph, f),
+            StructuredDataTagChild::Table(table) => walk_tags_in_table(table, f),
+            StructuredDataTagChild::StructuredDataTag(tag) => walk_tag(tag, f),
+            _ => {}
+        }
+    }
+}
+
+// Store item ids are GUIDs, written with or without braces in either case.
+fn normalize_store_item_id(id: &str) -> String {
+    id.trim_start_matches('{')
+        .trim_end_matches('}')
+        .to_ascii_uppercase()
+}
+
+/// Resolve the tag's data binding against custom xml items.
+/// If the binding has no store item id, every item is tried in order.
+pub(crate) fn resolve_data_binding(
+    tag: &StructuredDataTag,
+    items: &[CustomItem],
+    props: &[CustomItemProperty],
+) -> Option<String> {
+    let binding = tag.property.data_binding.as_ref()?;
+    let xpath = binding.xpath.as_ref()?;
+    let store_item_id = binding
+        .store_item_id
+        .as_ref()
+        .map(|id| normalize_store_item_id(id));
+    items
+        .iter()
+        .zip(props.iter())
+        .filter(|(_, prop)| match store_item_id {
+            Some(ref id) => &normalize_store_item_id(&prop.id) == id,
+            None => true,
+        })
+        .find_map(|(item, _)| item.find_by_xpath(xpath))
+}
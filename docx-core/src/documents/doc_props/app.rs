@@ -1,31 +1,148 @@
 use serde::Serialize;
 
 use crate::documents::BuildXML;
+use crate::escape::escape;
 use crate::xml_builder::*;
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct AppProps {}
+pub struct AppProps {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_time: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pages: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub words: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub characters: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paragraphs: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manager: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub characters_with_spaces: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_version: Option<String>,
+}
 
 impl AppProps {
     pub fn new() -> AppProps {
         Default::default()
     }
-}
 
-impl Default for AppProps {
-    fn default() -> Self {
-        Self {}
+    pub fn template(mut self, v: impl Into<String>) -> Self {
+        self.template = Some(v.into());
+        self
+    }
+
+    pub fn total_time(mut self, minutes: usize) -> Self {
+        self.total_time = Some(minutes);
+        self
+    }
+
+    pub fn pages(mut self, v: usize) -> Self {
+        self.pages = Some(v);
+        self
+    }
+
+    pub fn words(mut self, v: usize) -> Self {
+        self.words = Some(v);
+        self
+    }
+
+    pub fn characters(mut self, v: usize) -> Self {
+        self.characters = Some(v);
+        self
+    }
+
+    pub fn application(mut self, v: impl Into<String>) -> Self {
+        self.application = Some(v.into());
+        self
+    }
+
+    pub fn lines(mut self, v: usize) -> Self {
+        self.lines = Some(v);
+        self
+    }
+
+    pub fn paragraphs(mut self, v: usize) -> Self {
+        self.paragraphs = Some(v);
+        self
+    }
+
+    pub fn manager(mut self, v: impl Into<String>) -> Self {
+        self.manager = Some(v.into());
+        self
+    }
+
+    pub fn company(mut self, v: impl Into<String>) -> Self {
+        self.company = Some(v.into());
+        self
+    }
+
+    pub fn characters_with_spaces(mut self, v: usize) -> Self {
+        self.characters_with_spaces = Some(v);
+        self
+    }
+
+    // The version is written as `XX.YYYY`, e.g. `16.0000`.
+    pub fn app_version(mut self, v: impl Into<String>) -> Self {
+        self.app_version = Some(v.into());
+        self
     }
 }
 
 impl BuildXML for AppProps {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        let base = b.declaration(Some(true)).open_properties(
+        let mut base = b.declaration(Some(true)).open_properties(
             "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties",
             "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
         );
+        if let Some(v) = self.template.as_ref() {
+            base = base.template(&escape(v));
+        }
+        if let Some(v) = self.total_time {
+            base = base.total_time(&format!("{}", v));
+        }
+        if let Some(v) = self.pages {
+            base = base.pages(&format!("{}", v));
+        }
+        if let Some(v) = self.words {
+            base = base.words(&format!("{}", v));
+        }
+        if let Some(v) = self.characters {
+            base = base.characters(&format!("{}", v));
+        }
+        if let Some(v) = self.application.as_ref() {
+            base = base.application(&escape(v));
+        }
+        if let Some(v) = self.lines {
+            base = base.lines(&format!("{}", v));
+        }
+        if let Some(v) = self.paragraphs {
+            base = base.paragraphs(&format!("{}", v));
+        }
+        if let Some(v) = self.manager.as_ref() {
+            base = base.manager(&escape(v));
+        }
+        if let Some(v) = self.company.as_ref() {
+            base = base.company(&escape(v));
+        }
+        if let Some(v) = self.characters_with_spaces {
+            base = base.characters_with_spaces(&format!("{}", v));
+        }
+        if let Some(v) = self.app_version.as_ref() {
+            base = base.app_version(&escape(v));
+        }
         base.close().build()
     }
 }
@@ -48,4 +165,26 @@ mod tests {
 <Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes" />"#
         );
     }
+
+    #[test]
+    fn test_doc_props_app_build() {
+        let c = AppProps::new()
+            .template("Normal.dotm")
+            .pages(2)
+            .words(120)
+            .company("Acme & Co")
+            .app_version("16.0000");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+  <Template>Normal.dotm</Template>
+  <Pages>2</Pages>
+  <Words>120</Words>
+  <Company>Acme &amp; Co</Company>
+  <AppVersion>16.0000</AppVersion>
+</Properties>"#
+        );
+    }
 }
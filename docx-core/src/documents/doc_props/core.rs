@@ -1,50 +1,33 @@
 use serde::Serialize;
 
 use crate::documents::BuildXML;
+use crate::escape::escape;
 use crate::xml_builder::*;
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CoreProps {
-    config: CorePropsConfig,
+    pub config: CorePropsConfig,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CorePropsConfig {
-    created: Option<String>,
-    creator: Option<String>,
-    description: Option<String>,
-    language: Option<String>,
-    last_modified_by: Option<String>,
-    modified: Option<String>,
-    revision: Option<usize>,
-    subject: Option<String>,
-    title: Option<String>,
-}
-
-impl Default for CorePropsConfig {
-    fn default() -> Self {
-        Self {
-            created: None,
-            creator: None,
-            description: None,
-            language: None,
-            last_modified_by: None,
-            modified: None,
-            revision: None,
-            subject: None,
-            title: None,
-        }
-    }
-}
-
-impl Default for CoreProps {
-    fn default() -> Self {
-        Self {
-            config: CorePropsConfig::default(),
-        }
-    }
+    pub created: Option<String>,
+    pub creator: Option<String>,
+    pub description: Option<String>,
+    pub language: Option<String>,
+    pub last_modified_by: Option<String>,
+    pub modified: Option<String>,
+    pub revision: Option<usize>,
+    pub subject: Option<String>,
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_status: Option<String>,
 }
 
 impl CoreProps {
@@ -65,17 +48,67 @@ impl CoreProps {
 
 impl CorePropsConfig {
     pub fn new() -> Self {
-        CorePropsConfig {
-            created: None,
-            creator: None,
-            description: None,
-            language: None,
-            last_modified_by: None,
-            modified: None,
-            revision: None,
-            subject: None,
-            title: None,
-        }
+        Default::default()
+    }
+
+    pub fn created(mut self, date: impl Into<String>) -> Self {
+        self.created = Some(date.into());
+        self
+    }
+
+    pub fn modified(mut self, date: impl Into<String>) -> Self {
+        self.modified = Some(date.into());
+        self
+    }
+
+    pub fn creator(mut self, v: impl Into<String>) -> Self {
+        self.creator = Some(v.into());
+        self
+    }
+
+    pub fn last_modified_by(mut self, v: impl Into<String>) -> Self {
+        self.last_modified_by = Some(v.into());
+        self
+    }
+
+    pub fn description(mut self, v: impl Into<String>) -> Self {
+        self.description = Some(v.into());
+        self
+    }
+
+    pub fn language(mut self, v: impl Into<String>) -> Self {
+        self.language = Some(v.into());
+        self
+    }
+
+    pub fn revision(mut self, v: usize) -> Self {
+        self.revision = Some(v);
+        self
+    }
+
+    pub fn subject(mut self, v: impl Into<String>) -> Self {
+        self.subject = Some(v.into());
+        self
+    }
+
+    pub fn title(mut self, v: impl Into<String>) -> Self {
+        self.title = Some(v.into());
+        self
+    }
+
+    pub fn keywords(mut self, v: impl Into<String>) -> Self {
+        self.keywords = Some(v.into());
+        self
+    }
+
+    pub fn category(mut self, v: impl Into<String>) -> Self {
+        self.category = Some(v.into());
+        self
+    }
+
+    pub fn content_status(mut self, v: impl Into<String>) -> Self {
+        self.content_status = Some(v.into());
+        self
     }
 }
 
@@ -91,6 +124,9 @@ impl BuildXML for CoreProps {
         );
 
         let convert = |v: usize| format!("{}", v);
+        let text = |v: &Option<String>, default: &str| {
+            v.as_ref().map_or_else(|| default.to_owned(), |v| escape(v))
+        };
         let mut base = base
             .dcterms_created(
                 "dcterms:W3CDTF",
@@ -99,18 +135,8 @@ impl BuildXML for CoreProps {
                     .as_ref()
                     .map_or_else(|| "1970-01-01T00:00:00Z", |v| v),
             )
-            .dc_creator(
-                self.config
-                    .creator
-                    .as_ref()
-                    .map_or_else(|| "unknown", |v| v),
-            )
-            .cp_last_modified_by(
-                self.config
-                    .last_modified_by
-                    .as_ref()
-                    .map_or_else(|| "unknown", |v| v),
-            )
+            .dc_creator(&text(&self.config.creator, "unknown"))
+            .cp_last_modified_by(&text(&self.config.last_modified_by, "unknown"))
             .dcterms_modified(
                 "dcterms:W3CDTF",
                 self.config
@@ -120,16 +146,25 @@ impl BuildXML for CoreProps {
             )
             .cp_revision(&self.config.revision.map_or_else(|| "1".to_owned(), convert));
         if let Some(v) = self.config.description.as_ref() {
-            base = base.dc_description(v);
+            base = base.dc_description(&escape(v));
         }
         if let Some(v) = self.config.language.as_ref() {
-            base = base.dc_language(v);
+            base = base.dc_language(&escape(v));
         }
         if let Some(v) = self.config.subject.as_ref() {
-            base = base.dc_subject(v);
+            base = base.dc_subject(&escape(v));
         }
         if let Some(v) = self.config.title.as_ref() {
-            base = base.dc_title(v);
+            base = base.dc_title(&escape(v));
+        }
+        if let Some(v) = self.config.keywords.as_ref() {
+            base = base.cp_keywords(&escape(v));
+        }
+        if let Some(v) = self.config.category.as_ref() {
+            base = base.cp_category(&escape(v));
+        }
+        if let Some(v) = self.config.content_status.as_ref() {
+            base = base.cp_content_status(&escape(v));
         }
         base.close().build()
     }
@@ -155,6 +190,9 @@ mod tests {
             revision: None,
             subject: None,
             title: None,
+            keywords: None,
+            category: None,
+            content_status: None,
         });
         let b = c.build();
         assert_eq!(
@@ -182,6 +220,9 @@ mod tests {
             revision: Some(1),
             subject: Some("subject".to_owned()),
             title: Some("title".to_owned()),
+            keywords: None,
+            category: None,
+            content_status: None,
         });
         let b = c.build();
         assert_eq!(
@@ -197,6 +238,33 @@ mod tests {
   <dc:language>en</dc:language>
   <dc:subject>subject</dc:subject>
   <dc:title>title</dc:title>
+</cp:coreProperties>"#
+        );
+    }
+
+    #[test]
+    fn test_doc_props_core_with_builder() {
+        let c = CoreProps::new(
+            CorePropsConfig::new()
+                .title("Q3 & Q4")
+                .keywords("budget, plan")
+                .category("Report")
+                .content_status("Draft"),
+        );
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:dcmitype="http://purl.org/dc/dcmitype/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <dcterms:created xsi:type="dcterms:W3CDTF">1970-01-01T00:00:00Z</dcterms:created>
+  <dc:creator>unknown</dc:creator>
+  <cp:lastModifiedBy>unknown</cp:lastModifiedBy>
+  <dcterms:modified xsi:type="dcterms:W3CDTF">1970-01-01T00:00:00Z</dcterms:modified>
+  <cp:revision>1</cp:revision>
+  <dc:title>Q3 &amp; Q4</dc:title>
+  <cp:keywords>budget, plan</cp:keywords>
+  <cp:category>Report</cp:category>
+  <cp:contentStatus>Draft</cp:contentStatus>
 </cp:coreProperties>"#
         );
     }
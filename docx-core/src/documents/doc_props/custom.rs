@@ -1,12 +1,151 @@
 use serde::Serialize;
 
 use crate::documents::BuildXML;
+use crate::escape::escape;
+use crate::types::TypeError;
 use crate::xml_builder::*;
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum CustomPropertyValue {
+    Text(String),
+    Integer(i32),
+    Float(f64),
+    Bool(bool),
+    // W3CDTF date in UTC, e.g. `2024-01-01T00:00:00Z`.
+    Date(String),
+}
+
+impl From<&str> for CustomPropertyValue {
+    fn from(v: &str) -> Self {
+        CustomPropertyValue::Text(v.to_owned())
+    }
+}
+
+impl From<String> for CustomPropertyValue {
+    fn from(v: String) -> Self {
+        CustomPropertyValue::Text(v)
+    }
+}
+
+impl From<i32> for CustomPropertyValue {
+    fn from(v: i32) -> Self {
+        CustomPropertyValue::Integer(v)
+    }
+}
+
+impl From<f64> for CustomPropertyValue {
+    fn from(v: f64) -> Self {
+        CustomPropertyValue::Float(v)
+    }
+}
+
+impl From<bool> for CustomPropertyValue {
+    fn from(v: bool) -> Self {
+        CustomPropertyValue::Bool(v)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomProperty {
+    pub name: String,
+    pub value: CustomPropertyValue,
+    // Name of the bookmark the property is linked to. The value is the cached content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_target: Option<String>,
+}
+
+impl CustomProperty {
+    pub fn new(name: impl Into<String>, value: impl Into<CustomPropertyValue>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            link_target: None,
+        }
+    }
+
+    // `date` must be a W3CDTF date in UTC, e.g. `2024-01-01T00:00:00Z`.
+    pub fn date(name: impl Into<String>, date: impl Into<String>) -> Result<Self, TypeError> {
+        let date = date.into();
+        if !is_utc_date_time(&date) {
+            return Err(TypeError::Unsupported(date));
+        }
+        Ok(Self::new(name, CustomPropertyValue::Date(date)))
+    }
+
+    pub fn link_to_content(mut self, bookmark: impl Into<String>) -> Self {
+        self.link_target = Some(bookmark.into());
+        self
+    }
+}
+
+// `YYYY-MM-DDThh:mm:ss` with optional fractions of a second and the `Z` designator.
+fn is_utc_date_time(s: &str) -> bool {
+    let (s, fraction) = match s.strip_suffix('Z') {
+        Some(s) => s.split_once('.').map_or((s, None), |(s, f)| (s, Some(f))),
+        None => return false,
+    };
+    if let Some(f) = fraction {
+        if f.is_empty() || !f.bytes().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+    }
+    let b = s.as_bytes();
+    if b.len() != 19
+        || !(b[4] == b'-' && b[7] == b'-' && b[10] == b'T' && b[13] == b':' && b[16] == b':')
+    {
+        return false;
+    }
+    let number = |from: usize, to: usize| -> Option<u32> {
+        let digits = &b[from..to];
+        if digits.iter().all(|c| c.is_ascii_digit()) {
+            std::str::from_utf8(digits).ok()?.parse().ok()
+        } else {
+            None
+        }
+    };
+    match (
+        number(0, 4),
+        number(5, 7),
+        number(8, 10),
+        number(11, 13),
+        number(14, 16),
+        number(17, 19),
+    ) {
+        (Some(year), Some(month), Some(day), Some(hour), Some(minute), Some(second)) => {
+            let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+            let days = match month {
+                2 if leap => 29,
+                2 => 28,
+                4 | 6 | 9 | 11 => 30,
+                _ => 31,
+            };
+            (1..=12).contains(&month)
+                && (1..=days).contains(&day)
+                && hour < 24
+                && minute < 60
+                && second < 60
+        }
+        _ => false,
+    }
+}
+
+// xsd:double spells infinities and NaN as INF, -INF and NaN.
+fn xsd_double(v: f64) -> String {
+    if v.is_nan() {
+        "NaN".to_owned()
+    } else if v.is_infinite() {
+        if v > 0.0 { "INF" } else { "-INF" }.to_owned()
+    } else {
+        format!("{}", v)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomProps {
-    pub properties: std::collections::HashMap<String, String>,
+    pub properties: Vec<CustomProperty>,
 }
 
 impl CustomProps {
@@ -14,10 +153,26 @@ impl CustomProps {
         Self::default()
     }
 
-    pub fn add_custom_property(mut self, name: impl Into<String>, item: impl Into<String>) -> Self {
-        self.properties.insert(name.into(), item.into());
+    pub fn add_custom_property(self, name: impl Into<String>, item: impl Into<String>) -> Self {
+        self.add_property(CustomProperty::new(name, item.into()))
+    }
+
+    // A property with the same name is replaced in place to keep the order.
+    pub fn add_property(mut self, prop: CustomProperty) -> Self {
+        if let Some(p) = self.properties.iter_mut().find(|p| p.name == prop.name) {
+            *p = prop;
+        } else {
+            self.properties.push(prop);
+        }
         self
     }
+
+    pub fn get(&self, name: &str) -> Option<&CustomPropertyValue> {
+        self.properties
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| &p.value)
+    }
 }
 
 impl BuildXML for CustomProps {
@@ -28,19 +183,109 @@ impl BuildXML for CustomProps {
             "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
         );
 
-        for (i, (key, item)) in self.properties.iter().enumerate() {
-            base = base
-                .open_property(
+        for (i, prop) in self.properties.iter().enumerate() {
+            // I can not found spec about this id.
+            // It is invalid if pid started by 1....
+            let pid = format!("{}", i + 2);
+            let name = escape(&prop.name);
+            base = if let Some(target) = prop.link_target.as_ref() {
+                base.open_linked_property(
                     "{D5CDD505-2E9C-101B-9397-08002B2CF9AE}",
-                    // I can not found spec about this id.
-                    // It is invalid if pid started by 1....
-                    &format!("{}", i + 2),
-                    key,
+                    &pid,
+                    &name,
+                    &escape(target),
                 )
-                .lpwstr(item)
-                .close()
+            } else {
+                base.open_property("{D5CDD505-2E9C-101B-9397-08002B2CF9AE}", &pid, &name)
+            };
+            base = match &prop.value {
+                CustomPropertyValue::Text(v) => base.lpwstr(&escape(v)),
+                CustomPropertyValue::Integer(v) => base.i4(&format!("{}", v)),
+                CustomPropertyValue::Float(v) => base.r8(&xsd_double(*v)),
+                CustomPropertyValue::Bool(v) => base.vt_bool(&format!("{}", v)),
+                CustomPropertyValue::Date(v) => base.filetime(v),
+            };
+            base = base.close();
         }
 
         base.close().build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_typed_custom_props_build() {
+        let c = CustomProps::new()
+            .add_custom_property("Client", "Acme & Co")
+            .add_property(CustomProperty::new("Count", 3))
+            .add_property(CustomProperty::new("Rate", 1.5))
+            .add_property(CustomProperty::new("Approved", true))
+            .add_property(CustomProperty::date("Due", "2024-01-01T00:00:00Z").unwrap())
+            .add_property(CustomProperty::new("Ref", "A-1").link_to_content("_Ref1"))
+            .add_custom_property("Client", "Acme");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/custom-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+  <property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="2" name="Client">
+    <vt:lpwstr>Acme</vt:lpwstr>
+  </property>
+  <property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="3" name="Count">
+    <vt:i4>3</vt:i4>
+  </property>
+  <property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="4" name="Rate">
+    <vt:r8>1.5</vt:r8>
+  </property>
+  <property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="5" name="Approved">
+    <vt:bool>true</vt:bool>
+  </property>
+  <property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="6" name="Due">
+    <vt:filetime>2024-01-01T00:00:00Z</vt:filetime>
+  </property>
+  <property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="7" name="Ref" linkTarget="_Ref1">
+    <vt:lpwstr>A-1</vt:lpwstr>
+  </property>
+</Properties>"#
+        );
+    }
+    #[test]
+    fn test_date() {
+        assert!(CustomProperty::date("Due", "2024-02-29T23:59:59.5Z").is_ok());
+        for invalid in &[
+            "2024-01-01",
+            "2024-01-01T00:00:00",
+            "2024-01-01T00:00:00+09:00",
+            "2023-02-29T00:00:00Z",
+            "2024-13-01T00:00:00Z",
+            "2024-01-01T24:00:00Z",
+            "next friday",
+        ] {
+            assert!(
+                CustomProperty::date("Due", *invalid).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_special_floats() {
+        let c = CustomProps::new()
+            .add_property(CustomProperty::new("Max", f64::INFINITY))
+            .add_property(CustomProperty::new("Min", f64::NEG_INFINITY))
+            .add_property(CustomProperty::new("None", f64::NAN));
+        let b = c.build();
+        let xml = str::from_utf8(&b).unwrap();
+        assert!(xml.contains("<vt:r8>INF</vt:r8>"));
+        assert!(xml.contains("<vt:r8>-INF</vt:r8>"));
+        assert!(xml.contains("<vt:r8>NaN</vt:r8>"));
+    }
+}
@@ -36,11 +36,26 @@ impl DocProps {
         self
     }
 
+    pub fn core_props(mut self, config: CorePropsConfig) -> Self {
+        self.core = CoreProps::new(config);
+        self
+    }
+
+    pub fn app_props(mut self, app: AppProps) -> Self {
+        self.app = app;
+        self
+    }
+
     pub fn custom_property(mut self, name: impl Into<String>, item: impl Into<String>) -> Self {
         self.custom = self.custom.add_custom_property(name.into(), item.into());
         self
     }
 
+    pub fn add_custom_property(mut self, prop: CustomProperty) -> Self {
+        self.custom = self.custom.add_property(prop);
+        self
+    }
+
     pub(crate) fn build(&self) -> XMLDocProps {
         XMLDocProps {
             app: self.app.build(),
@@ -0,0 +1,1128 @@
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::documents::*;
+use crate::escape::replace_escaped;
+
+// Paired items at least this similar are compared in detail instead of being replaced.
+const SIMILARITY_THRESHOLD: f32 = 0.5;
+// Changed hunks with more candidate pairs than this are replaced without pairing.
+const MAX_CANDIDATE_PAIRS: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiffOp {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+// Shortest edit script by Myers' algorithm, which takes O((N + M) D) time and linear space.
+// Deletions come before insertions in each changed hunk.
+fn diff<A, B, F>(a: &[A], b: &[B], eq: F) -> Vec<DiffOp>
+where
+    F: Fn(&A, &B) -> bool,
+{
+    let mut script = vec![];
+    diff_range(a, b, &eq, (0, a.len()), (0, b.len()), &mut script);
+
+    let mut ops = Vec::with_capacity(script.len());
+    let mut inserted = vec![];
+    for op in script {
+        match op {
+            DiffOp::Delete(_) => ops.push(op),
+            DiffOp::Insert(_) => inserted.push(op),
+            DiffOp::Equal(_, _) => {
+                ops.append(&mut inserted);
+                ops.push(op);
+            }
+        }
+    }
+    ops.append(&mut inserted);
+    ops
+}
+
+fn diff_range<A, B, F>(
+    a: &[A],
+    b: &[B],
+    eq: &F,
+    (mut a_start, mut a_end): (usize, usize),
+    (mut b_start, mut b_end): (usize, usize),
+    ops: &mut Vec<DiffOp>,
+) where
+    F: Fn(&A, &B) -> bool,
+{
+    while a_start < a_end && b_start < b_end && eq(&a[a_start], &b[b_start]) {
+        ops.push(DiffOp::Equal(a_start, b_start));
+        a_start += 1;
+        b_start += 1;
+    }
+    let mut suffix = 0;
+    while a_start < a_end && b_start < b_end && eq(&a[a_end - 1], &b[b_end - 1]) {
+        a_end -= 1;
+        b_end -= 1;
+        suffix += 1;
+    }
+
+    if a_start == a_end {
+        ops.extend((b_start..b_end).map(DiffOp::Insert));
+    } else if b_start == b_end {
+        ops.extend((a_start..a_end).map(DiffOp::Delete));
+    } else if let Some((x, y)) = middle_snake(a, b, eq, (a_start, a_end), (b_start, b_end))
+        .filter(|&(x, y)| (x, y) != (a_start, b_start) && (x, y) != (a_end, b_end))
+    {
+        diff_range(a, b, eq, (a_start, x), (b_start, y), ops);
+        diff_range(a, b, eq, (x, a_end), (y, b_end), ops);
+    } else {
+        ops.extend((a_start..a_end).map(DiffOp::Delete));
+        ops.extend((b_start..b_end).map(DiffOp::Insert));
+    }
+
+    ops.extend((0..suffix).map(|k| DiffOp::Equal(a_end + k, b_end + k)));
+}
+
+// Searches the shortest path from both ends at once and returns a point on it where they meet.
+// Returns None if the ranges have nothing in common.
+fn middle_snake<A, B, F>(
+    a: &[A],
+    b: &[B],
+    eq: &F,
+    (a_start, a_end): (usize, usize),
+    (b_start, b_end): (usize, usize),
+) -> Option<(usize, usize)>
+where
+    F: Fn(&A, &B) -> bool,
+{
+    let n = (a_end - a_start) as isize;
+    let m = (b_end - b_start) as isize;
+    let max = (n + m + 1) / 2;
+    let offset = max + 1;
+    // forward[offset + k] is the furthest x reached on diagonal k = x - y from the start,
+    // backward[offset + k] the same from the end.
+    let mut forward = vec![-1isize; (2 * offset + 1) as usize];
+    let mut backward = forward.clone();
+    forward[(offset + 1) as usize] = 0;
+    backward[(offset + 1) as usize] = 0;
+    let delta = n - m;
+    let odd = delta % 2 != 0;
+    // Diagonals trimmed at each end because their paths have run off the grid.
+    let (mut forward_start, mut forward_end) = (0, 0);
+    let (mut backward_start, mut backward_end) = (0, 0);
+    for d in 0..max {
+        for k in (-d + forward_start..=d - forward_end).step_by(2) {
+            let i = (offset + k) as usize;
+            let mut x = if k == -d || (k != d && forward[i - 1] < forward[i + 1]) {
+                forward[i + 1]
+            } else {
+                forward[i - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && eq(&a[a_start + x as usize], &b[b_start + y as usize]) {
+                x += 1;
+                y += 1;
+            }
+            forward[i] = x;
+            if x > n {
+                forward_end += 2;
+            } else if y > m {
+                forward_start += 2;
+            } else if odd {
+                let j = offset + delta - k;
+                if j >= 0
+                    && (j as usize) < backward.len()
+                    && backward[j as usize] != -1
+                    && x >= n - backward[j as usize]
+                {
+                    return Some((a_start + x as usize, b_start + y as usize));
+                }
+            }
+        }
+        for k in (-d + backward_start..=d - backward_end).step_by(2) {
+            let i = (offset + k) as usize;
+            let mut x = if k == -d || (k != d && backward[i - 1] < backward[i + 1]) {
+                backward[i + 1]
+            } else {
+                backward[i - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && eq(&a[a_end - 1 - x as usize], &b[b_end - 1 - y as usize]) {
+                x += 1;
+                y += 1;
+            }
+            backward[i] = x;
+            if x > n {
+                backward_end += 2;
+            } else if y > m {
+                backward_start += 2;
+            } else if !odd {
+                let j = offset + delta - k;
+                if j >= 0 && (j as usize) < forward.len() && forward[j as usize] != -1 {
+                    let fx = forward[j as usize];
+                    let fy = fx - delta + k;
+                    if fx >= n - x && fx <= n && (0..=m).contains(&fy) {
+                        return Some((a_start + fx as usize, b_start + fy as usize));
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+#[derive(Debug, Clone)]
+enum Piece {
+    Text(String, RunProperty),
+    RunChild(RunChild, RunProperty),
+    // Paragraph children other than runs are compared as a whole.
+    Other(ParagraphChild),
+}
+
+// A word, a single separator character or any other content of a paragraph.
+#[derive(Debug, Clone)]
+struct Token {
+    key: String,
+    piece: Piece,
+}
+
+impl Token {
+    fn marker(key: &str) -> Token {
+        Token {
+            key: key.to_owned(),
+            piece: Piece::Text("".to_owned(), RunProperty::new()),
+        }
+    }
+}
+
+fn json_key<T: Serialize>(v: &T) -> String {
+    serde_json::to_string(v).unwrap_or_default()
+}
+
+fn split_words(text: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            word.push(c);
+        } else {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            words.push(c.to_string());
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn push_run_tokens(run: &Run, tokens: &mut Vec<Token>) {
+    for child in &run.children {
+        match child {
+            RunChild::Text(t) => {
+                for word in split_words(&replace_escaped(&t.text)) {
+                    tokens.push(Token {
+                        key: word.clone(),
+                        piece: Piece::Text(word, run.run_property.clone()),
+                    });
+                }
+            }
+            RunChild::DeleteText(_) | RunChild::DeleteInstrText(_) => {}
+            _ => tokens.push(Token {
+                key: json_key(child),
+                piece: Piece::RunChild(child.clone(), run.run_property.clone()),
+            }),
+        }
+    }
+}
+
+fn paragraph_tokens(paragraph: &Paragraph) -> Vec<Token> {
+    let mut tokens = vec![];
+    for child in &paragraph.children {
+        match child {
+            ParagraphChild::Run(run) => push_run_tokens(run, &mut tokens),
+            ParagraphChild::Insert(ins) => {
+                for c in &ins.children {
+                    if let InsertChild::Run(run) = c {
+                        push_run_tokens(run, &mut tokens);
+                    }
+                }
+            }
+            ParagraphChild::Delete(_) => {}
+            _ => tokens.push(Token {
+                key: json_key(child),
+                piece: Piece::Other(child.clone()),
+            }),
+        }
+    }
+    tokens
+}
+
+fn table_tokens(table: &Table) -> Vec<Token> {
+    let mut tokens = vec![];
+    for TableChild::TableRow(row) in &table.rows {
+        tokens.extend(row_tokens(row));
+    }
+    tokens
+}
+
+fn row_tokens(row: &TableRow) -> Vec<Token> {
+    let mut tokens = vec![];
+    for TableRowChild::TableCell(cell) in &row.cells {
+        for content in &cell.children {
+            tokens.extend(block_tokens(content));
+        }
+        tokens.push(Token::marker("\u{1}cell"));
+    }
+    tokens
+}
+
+// Block level children of the document body and table cells.
+trait Block: Clone + Serialize {
+    fn paragraph(&self) -> Option<&Paragraph>;
+    fn table(&self) -> Option<&Table>;
+    fn structured_data_tag(&self) -> Option<&StructuredDataTag>;
+    fn table_of_contents(&self) -> Option<&TableOfContents>;
+    fn from_paragraph(p: Paragraph) -> Self;
+    fn from_table(t: Table) -> Self;
+    fn from_structured_data_tag(t: StructuredDataTag) -> Self;
+    fn from_table_of_contents(t: TableOfContents) -> Self;
+}
+
+impl Block for DocumentChild {
+    fn paragraph(&self) -> Option<&Paragraph> {
+        match self {
+            DocumentChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    fn table(&self) -> Option<&Table> {
+        match self {
+            DocumentChild::Table(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn structured_data_tag(&self) -> Option<&StructuredDataTag> {
+        match self {
+            DocumentChild::StructuredDataTag(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn table_of_contents(&self) -> Option<&TableOfContents> {
+        match self {
+            DocumentChild::TableOfContents(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn from_paragraph(p: Paragraph) -> Self {
+        DocumentChild::Paragraph(Box::new(p))
+    }
+
+    fn from_table(t: Table) -> Self {
+        DocumentChild::Table(Box::new(t))
+    }
+
+    fn from_structured_data_tag(t: StructuredDataTag) -> Self {
+        DocumentChild::StructuredDataTag(Box::new(t))
+    }
+
+    fn from_table_of_contents(t: TableOfContents) -> Self {
+        DocumentChild::TableOfContents(Box::new(t))
+    }
+}
+
+impl Block for TableCellContent {
+    fn paragraph(&self) -> Option<&Paragraph> {
+        match self {
+            TableCellContent::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    fn table(&self) -> Option<&Table> {
+        match self {
+            TableCellContent::Table(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn structured_data_tag(&self) -> Option<&StructuredDataTag> {
+        match self {
+            TableCellContent::StructuredDataTag(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn table_of_contents(&self) -> Option<&TableOfContents> {
+        match self {
+            TableCellContent::TableOfContents(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn from_paragraph(p: Paragraph) -> Self {
+        TableCellContent::Paragraph(p)
+    }
+
+    fn from_table(t: Table) -> Self {
+        TableCellContent::Table(t)
+    }
+
+    fn from_structured_data_tag(t: StructuredDataTag) -> Self {
+        TableCellContent::StructuredDataTag(Box::new(t))
+    }
+
+    fn from_table_of_contents(t: TableOfContents) -> Self {
+        TableCellContent::TableOfContents(Box::new(t))
+    }
+}
+
+// The first token tells the kind of the block, so that only blocks of the same kind are paired.
+fn block_tokens<B: Block>(block: &B) -> Vec<Token> {
+    if let Some(p) = block.paragraph() {
+        let mut tokens = vec![Token::marker("\u{1}paragraph")];
+        tokens.extend(paragraph_tokens(p));
+        tokens
+    } else if let Some(t) = block.table() {
+        let mut tokens = vec![Token::marker("\u{1}table")];
+        tokens.extend(table_tokens(t));
+        tokens
+    } else {
+        vec![Token::marker(&format!("\u{1}{}", json_key(block)))]
+    }
+}
+
+fn same_tokens(a: &[Token], b: &[Token]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.key == y.key)
+}
+
+// Number of tokens the sequences have in common regardless of their order,
+// which bounds the length of their common subsequence.
+fn common_tokens(a: &[Token], b: &[Token]) -> usize {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for t in a {
+        *counts.entry(&t.key).or_default() += 1;
+    }
+    b.iter()
+        .filter(|t| match counts.get_mut(t.key.as_str()) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        })
+        .count()
+}
+
+fn similar(a: &[Token], b: &[Token]) -> bool {
+    if a.first().map(|t| &t.key) != b.first().map(|t| &t.key) {
+        return false;
+    }
+    let is_similar =
+        |common: usize| 2.0 * common as f32 / (a.len() + b.len()) as f32 >= SIMILARITY_THRESHOLD;
+    // The cheap bound rules out most pairs before they are diffed.
+    if !is_similar(common_tokens(a, b)) {
+        return false;
+    }
+    let common = diff(a, b, |x, y| x.key == y.key)
+        .iter()
+        .filter(|op| matches!(op, DiffOp::Equal(_, _)))
+        .count();
+    is_similar(common)
+}
+
+// Aligns two token sequences. Changed items which are similar are paired as equal,
+// so that their differences are compared in detail.
+fn align(a: &[Vec<Token>], b: &[Vec<Token>]) -> Vec<DiffOp> {
+    let mut aligned = vec![];
+    let mut deleted = vec![];
+    let mut inserted = vec![];
+    let flush = |deleted: &mut Vec<usize>, inserted: &mut Vec<usize>, aligned: &mut Vec<DiffOp>| {
+        let ops = if deleted.len() * inserted.len() > MAX_CANDIDATE_PAIRS {
+            (0..deleted.len())
+                .map(DiffOp::Delete)
+                .chain((0..inserted.len()).map(DiffOp::Insert))
+                .collect()
+        } else {
+            diff(deleted, inserted, |&i, &j| similar(&a[i], &b[j]))
+        };
+        aligned.extend(ops.into_iter().map(|op| match op {
+            DiffOp::Equal(i, j) => DiffOp::Equal(deleted[i], inserted[j]),
+            DiffOp::Delete(i) => DiffOp::Delete(deleted[i]),
+            DiffOp::Insert(j) => DiffOp::Insert(inserted[j]),
+        }));
+        deleted.clear();
+        inserted.clear();
+    };
+    for op in diff(a, b, |x, y| same_tokens(x, y)) {
+        match op {
+            DiffOp::Equal(_, _) => {
+                flush(&mut deleted, &mut inserted, &mut aligned);
+                aligned.push(op);
+            }
+            DiffOp::Delete(i) => deleted.push(i),
+            DiffOp::Insert(j) => inserted.push(j),
+        }
+    }
+    flush(&mut deleted, &mut inserted, &mut aligned);
+    aligned
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Change {
+    // Holds the original run property if the formatting has been changed.
+    Equal(Option<RunProperty>),
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Clone)]
+enum Draft {
+    Text(String),
+    RunChild(RunChild),
+}
+
+// Consecutive pieces with the same change and formatting written as one run.
+struct RunGroup {
+    change: Change,
+    property: RunProperty,
+    children: Vec<Draft>,
+}
+
+/// Compares two documents and expresses the differences as revisions.
+pub(crate) struct DocumentComparer {
+    author: String,
+    date: String,
+}
+
+impl DocumentComparer {
+    pub(crate) fn new(author: impl Into<String>, date: impl Into<String>) -> Self {
+        Self {
+            author: author.into(),
+            date: date.into(),
+        }
+    }
+
+    pub(crate) fn compare(&self, original: &Document, revised: &Document) -> Document {
+        let mut document = revised.clone();
+        document.children = self.compare_blocks(&original.children, &revised.children);
+        let prev = base_section_property(&original.section_property);
+        if prev != base_section_property(&revised.section_property) {
+            document.section_property.section_property_change = Some(
+                SectionPropertyChange::new()
+                    .property(prev)
+                    .author(&self.author)
+                    .date(&self.date),
+            );
+        }
+        document
+    }
+
+    fn compare_blocks<B: Block>(&self, original: &[B], revised: &[B]) -> Vec<B> {
+        let a: Vec<Vec<Token>> = original.iter().map(block_tokens).collect();
+        let b: Vec<Vec<Token>> = revised.iter().map(block_tokens).collect();
+        let mut blocks = vec![];
+        for op in align(&a, &b) {
+            match op {
+                DiffOp::Equal(i, j) => {
+                    let (o, r) = (&original[i], &revised[j]);
+                    if let (Some(o), Some(r)) = (o.paragraph(), r.paragraph()) {
+                        blocks.push(B::from_paragraph(self.compare_paragraphs(o, r)));
+                    } else if let (Some(o), Some(r)) = (o.table(), r.table()) {
+                        blocks.push(B::from_table(self.compare_tables(o, r)));
+                    } else {
+                        blocks.push(r.clone());
+                    }
+                }
+                DiffOp::Delete(i) => blocks.push(self.changed_block(&original[i], true)),
+                DiffOp::Insert(j) => blocks.push(self.changed_block(&revised[j], false)),
+            }
+        }
+        blocks
+    }
+
+    // Marks a whole block as deleted or inserted.
+    // Bookmarks and comment ranges between blocks can not be marked and are kept as they are.
+    fn changed_block<B: Block>(&self, block: &B, deleted: bool) -> B {
+        if let Some(p) = block.paragraph() {
+            B::from_paragraph(if deleted {
+                self.deleted_paragraph(p)
+            } else {
+                self.inserted_paragraph(p)
+            })
+        } else if let Some(t) = block.table() {
+            B::from_table(if deleted {
+                self.deleted_table(t)
+            } else {
+                self.inserted_table(t)
+            })
+        } else if let Some(tag) = block.structured_data_tag() {
+            B::from_structured_data_tag(self.changed_tag(tag, deleted))
+        } else if let Some(toc) = block.table_of_contents() {
+            B::from_table_of_contents(self.changed_table_of_contents(toc, deleted))
+        } else {
+            block.clone()
+        }
+    }
+
+    fn changed_tag(&self, tag: &StructuredDataTag, deleted: bool) -> StructuredDataTag {
+        let mut tag = tag.clone();
+        tag.children = std::mem::take(&mut tag.children)
+            .into_iter()
+            .map(|child| match child {
+                StructuredDataTagChild::Run(run) if deleted => {
+                    StructuredDataTagChild::Delete(self.deletion(*run))
+                }
+                StructuredDataTagChild::Run(run) => {
+                    StructuredDataTagChild::Insert(self.insertion(*run))
+                }
+                StructuredDataTagChild::Insert(ins) if deleted => {
+                    StructuredDataTagChild::Delete(self.deleted_insertion(&ins))
+                }
+                StructuredDataTagChild::Paragraph(p) if deleted => {
+                    StructuredDataTagChild::Paragraph(Box::new(self.deleted_paragraph(&p)))
+                }
+                StructuredDataTagChild::Paragraph(p) => {
+                    StructuredDataTagChild::Paragraph(Box::new(self.inserted_paragraph(&p)))
+                }
+                StructuredDataTagChild::Table(t) if deleted => {
+                    StructuredDataTagChild::Table(Box::new(self.deleted_table(&t)))
+                }
+                StructuredDataTagChild::Table(t) => {
+                    StructuredDataTagChild::Table(Box::new(self.inserted_table(&t)))
+                }
+                StructuredDataTagChild::StructuredDataTag(t) => {
+                    StructuredDataTagChild::StructuredDataTag(Box::new(
+                        self.changed_tag(&t, deleted),
+                    ))
+                }
+                child => child,
+            })
+            .collect();
+        tag
+    }
+
+    fn changed_table_of_contents(&self, toc: &TableOfContents, deleted: bool) -> TableOfContents {
+        let mut toc = if deleted {
+            toc.clone().delete(&self.author, &self.date)
+        } else {
+            toc.clone().insert(&self.author, &self.date)
+        };
+        for content in toc
+            .before_contents
+            .iter_mut()
+            .chain(toc.after_contents.iter_mut())
+        {
+            match content {
+                TocContent::Paragraph(p) if deleted => **p = self.deleted_paragraph(p),
+                TocContent::Paragraph(p) => **p = self.inserted_paragraph(p),
+                TocContent::Table(t) if deleted => **t = self.deleted_table(t),
+                TocContent::Table(t) => **t = self.inserted_table(t),
+            }
+        }
+        toc
+    }
+
+    fn compare_paragraphs(&self, original: &Paragraph, revised: &Paragraph) -> Paragraph {
+        let a = paragraph_tokens(original);
+        let b = paragraph_tokens(revised);
+        let mut paragraph = revised.clone();
+        paragraph.children = self.build_children(&a, &b, diff(&a, &b, |x, y| x.key == y.key));
+        let prev = base_paragraph_property(&original.property);
+        if prev != base_paragraph_property(&revised.property) {
+            paragraph.property.paragraph_property_change = Some(
+                ParagraphPropertyChange::new()
+                    .property(prev)
+                    .author(&self.author)
+                    .date(&self.date),
+            );
+        }
+        paragraph
+    }
+
+    fn deleted_paragraph(&self, original: &Paragraph) -> Paragraph {
+        let a = paragraph_tokens(original);
+        let ops = (0..a.len()).map(DiffOp::Delete).collect();
+        let mut paragraph = original.clone();
+        paragraph.id = crate::generate_para_id();
+        paragraph.children = self.build_children(&a, &[], ops);
+        paragraph.delete(&self.author, &self.date)
+    }
+
+    fn inserted_paragraph(&self, revised: &Paragraph) -> Paragraph {
+        let b = paragraph_tokens(revised);
+        let ops = (0..b.len()).map(DiffOp::Insert).collect();
+        let mut paragraph = revised.clone();
+        paragraph.children = self.build_children(&[], &b, ops);
+        paragraph.insert(&self.author, &self.date)
+    }
+
+    fn build_children(&self, a: &[Token], b: &[Token], ops: Vec<DiffOp>) -> Vec<ParagraphChild> {
+        let mut children = vec![];
+        let mut group: Option<RunGroup> = None;
+        for op in ops {
+            let (change, piece) = match op {
+                DiffOp::Equal(i, j) => {
+                    let prev = match (&a[i].piece, &b[j].piece) {
+                        (Piece::Text(_, o), Piece::Text(_, r))
+                        | (Piece::RunChild(_, o), Piece::RunChild(_, r))
+                            if o != r =>
+                        {
+                            Some(o.clone())
+                        }
+                        _ => None,
+                    };
+                    (Change::Equal(prev), &b[j].piece)
+                }
+                DiffOp::Delete(i) => (Change::Delete, &a[i].piece),
+                DiffOp::Insert(j) => (Change::Insert, &b[j].piece),
+            };
+            let (draft, property) = match piece {
+                Piece::Text(text, property) => (Draft::Text(text.clone()), property),
+                Piece::RunChild(child, property) => (Draft::RunChild(child.clone()), property),
+                Piece::Other(child) => {
+                    children.extend(group.take().map(|g| self.build_group(g)));
+                    children.push(match change {
+                        Change::Equal(_) => child.clone(),
+                        Change::Insert => self.changed_child(child, false),
+                        Change::Delete => self.changed_child(child, true),
+                    });
+                    continue;
+                }
+            };
+            match group {
+                Some(ref mut g) if g.change == change && &g.property == property => {
+                    match (g.children.last_mut(), draft) {
+                        (Some(Draft::Text(prev)), Draft::Text(text)) => prev.push_str(&text),
+                        (_, draft) => g.children.push(draft),
+                    }
+                }
+                _ => {
+                    children.extend(group.take().map(|g| self.build_group(g)));
+                    group = Some(RunGroup {
+                        change,
+                        property: property.clone(),
+                        children: vec![draft],
+                    });
+                }
+            }
+        }
+        children.extend(group.take().map(|g| self.build_group(g)));
+        children
+    }
+
+    fn build_group(&self, group: RunGroup) -> ParagraphChild {
+        let mut run = Run::new();
+        run.run_property = group.property;
+        run.children = group
+            .children
+            .into_iter()
+            .map(|draft| match draft {
+                Draft::Text(text) => RunChild::Text(Text::new(text)),
+                Draft::RunChild(child) => child,
+            })
+            .collect();
+        match group.change {
+            Change::Equal(None) => ParagraphChild::Run(Box::new(run)),
+            Change::Equal(Some(prev)) => ParagraphChild::Run(Box::new(
+                run.run_property_change(
+                    RunPropertyChange::new()
+                        .property(prev)
+                        .author(&self.author)
+                        .date(&self.date),
+                ),
+            )),
+            Change::Insert => ParagraphChild::Insert(self.insertion(run)),
+            Change::Delete => ParagraphChild::Delete(self.deletion(run)),
+        }
+    }
+
+    // Marks a whole paragraph child other than a run as deleted or inserted.
+    // The runs in hyperlinks and tags are marked, while bookmarks, comment ranges
+    // and the like can not be marked and are kept as they are.
+    fn changed_child(&self, child: &ParagraphChild, deleted: bool) -> ParagraphChild {
+        match child {
+            ParagraphChild::Run(run) if deleted => {
+                ParagraphChild::Delete(self.deletion(run.as_ref().clone()))
+            }
+            ParagraphChild::Run(run) => {
+                ParagraphChild::Insert(self.insertion(run.as_ref().clone()))
+            }
+            ParagraphChild::Insert(ins) if deleted => {
+                ParagraphChild::Delete(self.deleted_insertion(ins))
+            }
+            ParagraphChild::Hyperlink(link) => {
+                let mut link = link.clone();
+                link.children = link
+                    .children
+                    .iter()
+                    .map(|c| self.changed_child(c, deleted))
+                    .collect();
+                ParagraphChild::Hyperlink(link)
+            }
+            ParagraphChild::StructuredDataTag(tag) => {
+                ParagraphChild::StructuredDataTag(Box::new(self.changed_tag(tag, deleted)))
+            }
+            _ => child.clone(),
+        }
+    }
+
+    fn insertion(&self, run: Run) -> Insert {
+        Insert::new(run).author(&self.author).date(&self.date)
+    }
+
+    fn deletion(&self, run: Run) -> Delete {
+        Delete::new()
+            .add_run(into_deleted_run(run))
+            .author(&self.author)
+            .date(&self.date)
+    }
+
+    // Deletes content which has been inserted in the original.
+    fn deleted_insertion(&self, ins: &Insert) -> Delete {
+        let mut del = Delete::new().author(&self.author).date(&self.date);
+        for child in &ins.children {
+            match child {
+                InsertChild::Run(run) => {
+                    del.children
+                        .push(DeleteChild::Run(into_deleted_run(run.as_ref().clone())));
+                }
+                InsertChild::Delete(d) => del.children.extend(d.children.iter().cloned()),
+                InsertChild::CommentStart(c) => {
+                    del.children.push(DeleteChild::CommentStart(c.clone()))
+                }
+                InsertChild::CommentEnd(c) => del.children.push(DeleteChild::CommentEnd(c.clone())),
+            }
+        }
+        del
+    }
+
+    fn compare_tables(&self, original: &Table, revised: &Table) -> Table {
+        let original_rows: Vec<&TableRow> = original
+            .rows
+            .iter()
+            .map(|TableChild::TableRow(row)| row)
+            .collect();
+        let revised_rows: Vec<&TableRow> = revised
+            .rows
+            .iter()
+            .map(|TableChild::TableRow(row)| row)
+            .collect();
+        let a: Vec<Vec<Token>> = original_rows.iter().map(|row| row_tokens(row)).collect();
+        let b: Vec<Vec<Token>> = revised_rows.iter().map(|row| row_tokens(row)).collect();
+
+        let mut table = revised.clone();
+        table.rows = vec![];
+        for op in align(&a, &b) {
+            match op {
+                DiffOp::Equal(i, j) => {
+                    let (o, r) = (original_rows[i], revised_rows[j]);
+                    if o.cells.len() == r.cells.len() {
+                        table
+                            .rows
+                            .push(TableChild::TableRow(self.compare_rows(o, r)));
+                    } else {
+                        table.rows.push(TableChild::TableRow(self.deleted_row(o)));
+                        table.rows.push(TableChild::TableRow(self.inserted_row(r)));
+                    }
+                }
+                DiffOp::Delete(i) => table
+                    .rows
+                    .push(TableChild::TableRow(self.deleted_row(original_rows[i]))),
+                DiffOp::Insert(j) => table
+                    .rows
+                    .push(TableChild::TableRow(self.inserted_row(revised_rows[j]))),
+            }
+        }
+        if original.property != revised.property {
+            table = table.table_property_change(
+                TablePropertyChange::new()
+                    .property(original.property.clone())
+                    .author(&self.author)
+                    .date(&self.date),
+            );
+        }
+        table
+    }
+
+    fn compare_rows(&self, original: &TableRow, revised: &TableRow) -> TableRow {
+        let mut row = revised.clone();
+        for (TableRowChild::TableCell(o), TableRowChild::TableCell(r)) in
+            original.cells.iter().zip(row.cells.iter_mut())
+        {
+            r.children = self.compare_blocks(&o.children, &r.children);
+        }
+        row
+    }
+
+    fn deleted_table(&self, original: &Table) -> Table {
+        let mut table = original.clone();
+        for TableChild::TableRow(row) in &mut table.rows {
+            *row = self.deleted_row(row);
+        }
+        table
+    }
+
+    fn inserted_table(&self, revised: &Table) -> Table {
+        let mut table = revised.clone();
+        for TableChild::TableRow(row) in &mut table.rows {
+            *row = self.inserted_row(row);
+        }
+        table
+    }
+
+    fn deleted_row(&self, original: &TableRow) -> TableRow {
+        let mut row = original.clone();
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            cell.children = self.compare_blocks(&cell.children, &[]);
+        }
+        row.delete(Delete::new().author(&self.author).date(&self.date))
+    }
+
+    fn inserted_row(&self, revised: &TableRow) -> TableRow {
+        let mut row = revised.clone();
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            cell.children = self.compare_blocks(&[], &cell.children);
+        }
+        row.insert(
+            Insert::new_with_empty()
+                .author(&self.author)
+                .date(&self.date),
+        )
+    }
+}
+
+// Turns text into deleted text.
+fn into_deleted_run(mut run: Run) -> Run {
+    run.children = run
+        .children
+        .into_iter()
+        .map(|child| match child {
+            RunChild::Text(t) => RunChild::DeleteText(DeleteText::without_escape(t.text)),
+            RunChild::InstrText(i) => RunChild::DeleteInstrText(Box::new((*i).into())),
+            child => child,
+        })
+        .collect();
+    run
+}
+
+// Paragraph properties without the paragraph mark, the section and the change itself.
+fn base_paragraph_property(property: &ParagraphProperty) -> ParagraphProperty {
+    let mut property = property.clone();
+    property.run_property = RunProperty::new();
+    property.section_property = None;
+    property.paragraph_property_change = None;
+    property
+}
+
+// Section properties without headers and footers, which are not tracked.
+fn base_section_property(property: &SectionProperty) -> SectionProperty {
+    let mut property = property.clone();
+    property.header_reference = None;
+    property.header = None;
+    property.first_header_reference = None;
+    property.first_header = None;
+    property.even_header_reference = None;
+    property.even_header = None;
+    property.footer_reference = None;
+    property.footer = None;
+    property.first_footer_reference = None;
+    property.first_footer = None;
+    property.even_footer_reference = None;
+    property.even_footer = None;
+    property.section_property_change = None;
+    property
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::revision_resolver::RevisionResolver;
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn compare(original: Paragraph, revised: Paragraph) -> Paragraph {
+        let document = DocumentComparer::new("bokuweb", "2023-04-01T00:00:00Z").compare(
+            &Document::new().add_paragraph(original),
+            &Document::new().add_paragraph(revised),
+        );
+        match document.children.into_iter().next() {
+            Some(DocumentChild::Paragraph(p)) => *p,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let a: Vec<char> = "abcd".chars().collect();
+        let b: Vec<char> = "acde".chars().collect();
+        assert_eq!(
+            diff(&a, &b, |x, y| x == y),
+            vec![
+                DiffOp::Equal(0, 0),
+                DiffOp::Delete(1),
+                DiffOp::Equal(2, 1),
+                DiffOp::Equal(3, 2),
+                DiffOp::Insert(3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_words() {
+        let p = compare(
+            Paragraph::new().add_run(Run::new().add_text("Hello old world")),
+            Paragraph::new().add_run(Run::new().add_text("Hello new world")),
+        );
+        assert_eq!(
+            p.children,
+            vec![
+                ParagraphChild::Run(Box::new(Run::new().add_text("Hello "))),
+                ParagraphChild::Delete(
+                    Delete::new()
+                        .add_run(Run::new().add_delete_text("old"))
+                        .author("bokuweb")
+                        .date("2023-04-01T00:00:00Z")
+                ),
+                ParagraphChild::Insert(
+                    Insert::new(Run::new().add_text("new"))
+                        .author("bokuweb")
+                        .date("2023-04-01T00:00:00Z")
+                ),
+                ParagraphChild::Run(Box::new(Run::new().add_text(" world"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_formatting() {
+        let p = compare(
+            Paragraph::new().add_run(Run::new().add_text("Hello")),
+            Paragraph::new()
+                .add_run(Run::new().add_text("Hello").bold())
+                .align(AlignmentType::Center),
+        );
+        assert_eq!(
+            p.children,
+            vec![ParagraphChild::Run(Box::new(
+                Run::new().add_text("Hello").bold().run_property_change(
+                    RunPropertyChange::new()
+                        .author("bokuweb")
+                        .date("2023-04-01T00:00:00Z")
+                )
+            ))]
+        );
+        assert!(p.property.paragraph_property_change.is_some());
+    }
+
+    #[test]
+    fn test_compare_paragraphs() {
+        let document = DocumentComparer::new("bokuweb", "2023-04-01T00:00:00Z").compare(
+            &Document::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Removed"))),
+            &Document::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Something else"))),
+        );
+        let marks: Vec<(bool, bool)> = document
+            .children
+            .iter()
+            .map(|c| match c {
+                DocumentChild::Paragraph(p) => (
+                    p.property.run_property.del.is_some(),
+                    p.property.run_property.ins.is_some(),
+                ),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(marks, vec![(false, false), (true, false), (false, true)]);
+    }
+
+    #[test]
+    fn test_compare_hyperlinks() {
+        let original = Paragraph::new()
+            .add_run(Run::new().add_text("Hello "))
+            .add_hyperlink(
+                Hyperlink::new("old", HyperlinkType::Anchor).add_run(Run::new().add_text("link")),
+            );
+        let revised = Paragraph::new()
+            .add_run(Run::new().add_text("Hello "))
+            .add_hyperlink(
+                Hyperlink::new("new", HyperlinkType::Anchor).add_run(Run::new().add_text("site")),
+            );
+        let compared = Document::new().add_paragraph(compare(original.clone(), revised.clone()));
+        let resolve = |accept: bool| {
+            let mut document = compared.clone();
+            RevisionResolver::new(accept, &RevisionFilter::new()).resolve_document(&mut document);
+            match document.children.into_iter().next() {
+                Some(DocumentChild::Paragraph(p)) => p.children,
+                _ => unreachable!(),
+            }
+        };
+        assert_eq!(resolve(false), original.children);
+        assert_eq!(resolve(true), revised.children);
+    }
+
+    #[test]
+    fn test_compare_deleted_structured_data_tag() {
+        let tag = StructuredDataTag::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Tagged")));
+        let document = DocumentComparer::new("bokuweb", "2023-04-01T00:00:00Z").compare(
+            &Document::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+                .add_structured_data_tag(tag),
+            &Document::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello"))),
+        );
+        let p = match &document.children[1] {
+            DocumentChild::StructuredDataTag(tag) => match &tag.children[0] {
+                StructuredDataTagChild::Paragraph(p) => p.as_ref().clone(),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        assert!(p.property.run_property.del.is_some());
+        assert!(matches!(p.children[0], ParagraphChild::Delete(_)));
+
+        let mut rejected = document;
+        RevisionResolver::new(false, &RevisionFilter::new()).resolve_document(&mut rejected);
+        match &rejected.children[1] {
+            DocumentChild::StructuredDataTag(tag) => assert_eq!(tag.raw_text(), "Tagged"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_compare_table_rows() {
+        let table = |texts: &[&str]| {
+            Table::new(
+                texts
+                    .iter()
+                    .map(|t| {
+                        TableRow::new(vec![TableCell::new()
+                            .add_paragraph(Paragraph::new().add_run(Run::new().add_text(*t)))])
+                    })
+                    .collect(),
+            )
+        };
+        let document = DocumentComparer::new("bokuweb", "2023-04-01T00:00:00Z").compare(
+            &Document::new().add_table(table(&["A", "B"])),
+            &Document::new().add_table(table(&["A", "C", "B"])),
+        );
+        let t = match &document.children[0] {
+            DocumentChild::Table(t) => t,
+            _ => unreachable!(),
+        };
+        let inserted: Vec<bool> = t
+            .rows
+            .iter()
+            .map(|TableChild::TableRow(row)| row.property.ins.is_some())
+            .collect();
+        assert_eq!(inserted, vec![false, true, false]);
+    }
+}
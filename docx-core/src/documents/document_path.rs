@@ -0,0 +1,215 @@
+use crate::documents::*;
+
+// Location of a paragraph, or of a run in it, in the document body, a header or a footer.
+// i.e. the second run of the first paragraph in the cell at row 1, cell 2 of the fourth body element
+// DocumentPath::new(3).cell(1, 2).child(0).run(1)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentPath {
+    part: DocumentPart,
+    steps: Vec<DocumentPathStep>,
+    run: Option<usize>,
+}
+
+// Headers and footers are indexed in the order of SectionProperty::get_headers and get_footers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DocumentPart {
+    Body,
+    Header(usize),
+    Footer(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DocumentPathStep {
+    Child(usize),
+    Cell { row: usize, cell: usize },
+}
+
+// Innermost table cell holding a paragraph.
+pub(crate) struct CellLocation<'a> {
+    pub table: &'a Table,
+    pub row: usize,
+    pub cell: usize,
+}
+
+pub(crate) struct ParagraphLocation<'a> {
+    pub paragraph: &'a Paragraph,
+    pub cell: Option<CellLocation<'a>>,
+}
+
+enum Block<'a> {
+    Paragraph(&'a Paragraph),
+    Table(&'a Table),
+    Tag(&'a StructuredDataTag),
+    Toc(&'a TableOfContents),
+}
+
+impl DocumentPath {
+    // `index` of the element in the document body.
+    pub fn new(index: usize) -> DocumentPath {
+        DocumentPath {
+            part: DocumentPart::Body,
+            steps: vec![DocumentPathStep::Child(index)],
+            run: None,
+        }
+    }
+
+    // `index` of the element in the `header`th header of the section.
+    pub fn header(header: usize, index: usize) -> DocumentPath {
+        DocumentPath {
+            part: DocumentPart::Header(header),
+            ..DocumentPath::new(index)
+        }
+    }
+
+    // `index` of the element in the `footer`th footer of the section.
+    pub fn footer(footer: usize, index: usize) -> DocumentPath {
+        DocumentPath {
+            part: DocumentPart::Footer(footer),
+            ..DocumentPath::new(index)
+        }
+    }
+
+    // Cell of the table at the current step, followed by `child`.
+    pub fn cell(mut self, row: usize, cell: usize) -> Self {
+        self.steps.push(DocumentPathStep::Cell { row, cell });
+        self
+    }
+
+    // `index` of the element in the current cell, content control or table of contents.
+    // Contents of a table of contents are counted from its before_contents to its after_contents.
+    pub fn child(mut self, index: usize) -> Self {
+        self.steps.push(DocumentPathStep::Child(index));
+        self
+    }
+
+    // `index` of the run in the paragraph, counting runs in reading order,
+    // including those in insertions, hyperlinks and content controls.
+    pub fn run(mut self, index: usize) -> Self {
+        self.run = Some(index);
+        self
+    }
+
+    pub(crate) fn find_paragraph<'a>(
+        &self,
+        document: &'a Document,
+    ) -> Option<ParagraphLocation<'a>> {
+        let mut steps = self.steps.iter();
+        let index = match steps.next()? {
+            DocumentPathStep::Child(i) => *i,
+            DocumentPathStep::Cell { .. } => return None,
+        };
+        let mut block = match self.part {
+            DocumentPart::Body => match document.children.get(index)? {
+                DocumentChild::Paragraph(p) => Block::Paragraph(p),
+                DocumentChild::Table(t) => Block::Table(t),
+                DocumentChild::StructuredDataTag(t) => Block::Tag(t),
+                DocumentChild::TableOfContents(t) => Block::Toc(t),
+                _ => return None,
+            },
+            DocumentPart::Header(h) => {
+                let header = *document.section_property.get_headers().get(h)?;
+                match header.children.get(index)? {
+                    HeaderChild::Paragraph(p) => Block::Paragraph(p),
+                    HeaderChild::Table(t) => Block::Table(t),
+                    HeaderChild::StructuredDataTag(t) => Block::Tag(t),
+                }
+            }
+            DocumentPart::Footer(f) => {
+                let footer = *document.section_property.get_footers().get(f)?;
+                match footer.children.get(index)? {
+                    FooterChild::Paragraph(p) => Block::Paragraph(p),
+                    FooterChild::Table(t) => Block::Table(t),
+                    FooterChild::StructuredDataTag(t) => Block::Tag(t),
+                }
+            }
+        };
+        let mut location = None;
+        while let Some(step) = steps.next() {
+            block = match (block, step) {
+                (Block::Table(table), DocumentPathStep::Cell { row, cell }) => {
+                    let TableChild::TableRow(r) = table.rows.get(*row)?;
+                    let TableRowChild::TableCell(c) = r.cells.get(*cell)?;
+                    let index = match steps.next()? {
+                        DocumentPathStep::Child(i) => *i,
+                        DocumentPathStep::Cell { .. } => return None,
+                    };
+                    location = Some(CellLocation {
+                        table,
+                        row: *row,
+                        cell: *cell,
+                    });
+                    match c.children.get(index)? {
+                        TableCellContent::Paragraph(p) => Block::Paragraph(p),
+                        TableCellContent::Table(t) => Block::Table(t),
+                        TableCellContent::StructuredDataTag(t) => Block::Tag(t),
+                        TableCellContent::TableOfContents(t) => Block::Toc(t),
+                    }
+                }
+                (Block::Tag(tag), DocumentPathStep::Child(i)) => match tag.children.get(*i)? {
+                    StructuredDataTagChild::Paragraph(p) => Block::Paragraph(p),
+                    StructuredDataTagChild::Table(t) => Block::Table(t),
+                    StructuredDataTagChild::StructuredDataTag(t) => Block::Tag(t),
+                    _ => return None,
+                },
+                (Block::Toc(toc), DocumentPathStep::Child(i)) => {
+                    match toc
+                        .before_contents
+                        .iter()
+                        .chain(&toc.after_contents)
+                        .nth(*i)?
+                    {
+                        TocContent::Paragraph(p) => Block::Paragraph(p),
+                        TocContent::Table(t) => Block::Table(t),
+                    }
+                }
+                _ => return None,
+            };
+        }
+        match block {
+            Block::Paragraph(paragraph) => Some(ParagraphLocation {
+                paragraph,
+                cell: location,
+            }),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn find_run<'a>(&self, paragraph: &'a Paragraph) -> Option<&'a Run> {
+        let mut runs = vec![];
+        collect_runs(&paragraph.children, &mut runs);
+        runs.get(self.run?).copied()
+    }
+}
+
+// Deleted runs are not part of the text and are skipped.
+fn collect_runs<'a>(children: &'a [ParagraphChild], runs: &mut Vec<&'a Run>) {
+    for child in children {
+        match child {
+            ParagraphChild::Run(r) => runs.push(r),
+            ParagraphChild::Insert(ins) => collect_inserted_runs(&ins.children, runs),
+            ParagraphChild::MoveTo(m) => collect_inserted_runs(&m.children, runs),
+            ParagraphChild::Hyperlink(link) => collect_runs(&link.children, runs),
+            ParagraphChild::StructuredDataTag(tag) => collect_tag_runs(tag, runs),
+            _ => {}
+        }
+    }
+}
+
+fn collect_inserted_runs<'a>(children: &'a [InsertChild], runs: &mut Vec<&'a Run>) {
+    for child in children {
+        if let InsertChild::Run(r) = child {
+            runs.push(r);
+        }
+    }
+}
+
+fn collect_tag_runs<'a>(tag: &'a StructuredDataTag, runs: &mut Vec<&'a Run>) {
+    for child in &tag.children {
+        match child {
+            StructuredDataTagChild::Run(r) => runs.push(r),
+            StructuredDataTagChild::Insert(ins) => collect_inserted_runs(&ins.children, runs),
+            StructuredDataTagChild::StructuredDataTag(t) => collect_tag_runs(t, runs),
+            _ => {}
+        }
+    }
+}
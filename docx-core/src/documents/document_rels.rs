@@ -10,6 +10,7 @@ pub struct DocumentRels {
     pub has_comments: bool,
     pub has_numberings: bool,
     pub has_footnotes: bool,
+    pub has_theme: bool,
     pub images: Vec<(String, String)>,
     pub hyperlinks: Vec<(String, String, String)>,
     pub custom_xml_count: usize,
@@ -69,6 +70,21 @@ impl BuildXML for DocumentRels {
                 "rId5",
                 "http://schemas.microsoft.com/office/2011/relationships/commentsExtended",
                 "commentsExtended.xml",
+            )
+            .relationship(
+                "rIdCommentsIds",
+                "http://schemas.microsoft.com/office/2016/09/relationships/commentsIds",
+                "commentsIds.xml",
+            )
+            .relationship(
+                "rIdCommentsExtensible",
+                "http://schemas.microsoft.com/office/2018/08/relationships/commentsExtensible",
+                "commentsExtensible.xml",
+            )
+            .relationship(
+                "rIdPeople",
+                "http://schemas.microsoft.com/office/2011/relationships/people",
+                "people.xml",
             );
 
         if self.has_comments {
@@ -95,6 +111,14 @@ impl BuildXML for DocumentRels {
             )
         }
 
+        if self.has_theme {
+            b = b.relationship(
+                "rIdTheme",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme",
+                "theme/theme1.xml",
+            )
+        }
+
         for i in 0..self.header_count {
             b = b.relationship(
                 &create_header_rid(i + 1),
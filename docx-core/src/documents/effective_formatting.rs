@@ -0,0 +1,633 @@
+use std::collections::BTreeMap;
+
+use crate::documents::*;
+use crate::types::*;
+
+// Where an attribute of an effective property was set.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormattingSource {
+    DocDefaults,
+    TableStyle {
+        style_id: String,
+        condition: TableStyleOverrideType,
+    },
+    Numbering {
+        id: usize,
+        level: usize,
+    },
+    Style {
+        style_id: String,
+    },
+    Direct,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EffectiveRunProperty {
+    pub property: RunProperty,
+    // Keyed by field name of `RunProperty`, i.e. "sz" or "fonts".
+    pub sources: BTreeMap<String, FormattingSource>,
+}
+
+impl EffectiveRunProperty {
+    pub fn source(&self, field: &str) -> Option<&FormattingSource> {
+        self.sources.get(field)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EffectiveParagraphProperty {
+    // `run_property` holds the formatting of the paragraph mark.
+    pub property: ParagraphProperty,
+    // Keyed by field name of `ParagraphProperty`, fields of the paragraph mark
+    // are prefixed with "run_property.", i.e. "indent" or "run_property.sz".
+    pub sources: BTreeMap<String, FormattingSource>,
+    // Formatting of the list label, the paragraph mark with the numbering level's rPr applied.
+    pub numbering_label: Option<EffectiveRunProperty>,
+}
+
+impl EffectiveParagraphProperty {
+    pub fn source(&self, field: &str) -> Option<&FormattingSource> {
+        self.sources.get(field)
+    }
+}
+
+macro_rules! merge_fields {
+    ($target:expr, $from:expr, $sources:expr, $prefix:expr, $source:expr, [$($field:ident),*]) => {
+        $(
+            if $from.$field.is_some() {
+                $target.$field = $from.$field.clone();
+                $sources.insert(format!("{}{}", $prefix, stringify!($field)), $source.clone());
+            }
+        )*
+    };
+}
+
+// One level of the style hierarchy.
+struct Layer<'a> {
+    source: FormattingSource,
+    paragraph: Option<&'a ParagraphProperty>,
+    run: Option<&'a RunProperty>,
+}
+
+// Resolves formatting in the order of 17.7.2, document defaults, table style,
+// numbering, paragraph style, character style and direct formatting.
+// Later levels replace attributes of earlier ones; toggle properties such as
+// bold are not xor-ed across styles.
+pub(crate) struct FormattingResolver<'a> {
+    styles: &'a Styles,
+    numberings: &'a Numberings,
+}
+
+impl<'a> FormattingResolver<'a> {
+    pub fn new(styles: &'a Styles, numberings: &'a Numberings) -> Self {
+        Self { styles, numberings }
+    }
+
+    pub fn paragraph(&self, location: &ParagraphLocation<'a>) -> EffectiveParagraphProperty {
+        let direct = &location.paragraph.property;
+        let style_chain = self.paragraph_style_chain(direct);
+        let numbering = self.numbering(direct, &style_chain);
+
+        let mut layers = vec![self.defaults()];
+        layers.extend(self.table_layers(location));
+        if let Some((id, level, l)) = numbering {
+            layers.push(Layer {
+                source: FormattingSource::Numbering { id, level },
+                paragraph: Some(&l.paragraph_property),
+                run: None,
+            });
+        }
+        layers.extend(style_chain.iter().map(|s| style_layer(s)));
+        layers.push(Layer {
+            source: FormattingSource::Direct,
+            paragraph: Some(direct),
+            run: Some(&direct.run_property),
+        });
+
+        let mut effective = EffectiveParagraphProperty::default();
+        for layer in layers {
+            if let Some(p) = layer.paragraph {
+                merge_paragraph(
+                    &mut effective.property,
+                    p,
+                    &mut effective.sources,
+                    &layer.source,
+                );
+            }
+            if let Some(r) = layer.run {
+                merge_run(
+                    &mut effective.property.run_property,
+                    r,
+                    &mut effective.sources,
+                    "run_property.",
+                    &layer.source,
+                );
+            }
+        }
+        effective.numbering_label = numbering.map(|(id, level, l)| {
+            let mut label = EffectiveRunProperty {
+                property: effective.property.run_property.clone(),
+                sources: effective
+                    .sources
+                    .iter()
+                    .filter_map(|(k, v)| {
+                        k.strip_prefix("run_property.")
+                            .map(|k| (k.to_owned(), v.clone()))
+                    })
+                    .collect(),
+            };
+            merge_run(
+                &mut label.property,
+                &l.run_property,
+                &mut label.sources,
+                "",
+                &FormattingSource::Numbering { id, level },
+            );
+            label
+        });
+        effective
+    }
+
+    pub fn run(&self, location: &ParagraphLocation<'a>, run: &'a Run) -> EffectiveRunProperty {
+        let mut layers = vec![self.defaults()];
+        layers.extend(self.table_layers(location));
+        layers.extend(
+            self.paragraph_style_chain(&location.paragraph.property)
+                .iter()
+                .map(|s| style_layer(s)),
+        );
+        layers.extend(
+            self.character_style_chain(&run.run_property)
+                .iter()
+                .map(|s| Layer {
+                    source: FormattingSource::Style {
+                        style_id: s.style_id.clone(),
+                    },
+                    paragraph: None,
+                    run: Some(&s.run_property),
+                }),
+        );
+        layers.push(Layer {
+            source: FormattingSource::Direct,
+            paragraph: None,
+            run: Some(&run.run_property),
+        });
+
+        let mut effective = EffectiveRunProperty::default();
+        for layer in layers {
+            if let Some(r) = layer.run {
+                merge_run(
+                    &mut effective.property,
+                    r,
+                    &mut effective.sources,
+                    "",
+                    &layer.source,
+                );
+            }
+        }
+        effective
+    }
+
+    fn defaults(&self) -> Layer<'a> {
+        Layer {
+            source: FormattingSource::DocDefaults,
+            paragraph: Some(self.styles.get_default_paragraph_property()),
+            run: Some(self.styles.get_default_run_property()),
+        }
+    }
+
+    // Styles from the root of the basedOn chain to `id`.
+    fn style_chain(&self, id: &str) -> Vec<&'a Style> {
+        let mut chain: Vec<&'a Style> = vec![];
+        let mut next = self.styles.find_style_by_id(id);
+        while let Some(s) = next {
+            if chain.iter().any(|c| c.style_id == s.style_id) {
+                break;
+            }
+            chain.push(s);
+            next = s
+                .based_on
+                .as_ref()
+                .and_then(|b| self.styles.find_style_by_id(b.get_val()));
+        }
+        chain.reverse();
+        chain
+    }
+
+    // Paragraphs without a style take the default paragraph style,
+    // or "Normal" in documents built without one.
+    fn paragraph_style_chain(&self, direct: &ParagraphProperty) -> Vec<&'a Style> {
+        let id = match direct.style.as_ref() {
+            Some(s) => s.val.as_str(),
+            None => self
+                .styles
+                .find_default_style(StyleType::Paragraph)
+                .map(|s| s.style_id.as_str())
+                .unwrap_or("Normal"),
+        };
+        self.style_chain(id)
+    }
+
+    // A paragraph style given in rStyle formats the run with its linked character style.
+    fn character_style_chain(&self, direct: &RunProperty) -> Vec<&'a Style> {
+        let id = match direct.style.as_ref() {
+            Some(s) => s.val.as_str(),
+            None => return vec![],
+        };
+        match self.styles.find_style_by_id(id) {
+            Some(s) if s.style_type == StyleType::Paragraph => match s.link.as_ref() {
+                Some(link) => self.style_chain(link.get_val()),
+                None => vec![],
+            },
+            _ => self.style_chain(id),
+        }
+    }
+
+    // numId and ilvl of the paragraph, given directly or by its paragraph style.
+    pub fn paragraph_numbering(
+        &self,
+        direct: &ParagraphProperty,
+    ) -> Option<(usize, usize, &'a Level)> {
+        self.numbering(direct, &self.paragraph_style_chain(direct))
+    }
+
+    fn numbering(
+        &self,
+        direct: &ParagraphProperty,
+        style_chain: &[&'a Style],
+    ) -> Option<(usize, usize, &'a Level)> {
+        let numbering = direct.numbering_property.as_ref().or_else(|| {
+            style_chain
+                .iter()
+                .rev()
+                .find_map(|s| s.paragraph_property.numbering_property.as_ref())
+        })?;
+        // numId 0 removes numbering.
+        let id = numbering.id.as_ref().map(|n| n.id).filter(|id| *id != 0)?;
+        let level = numbering.level.as_ref().map(|l| l.val).unwrap_or_default();
+        let l = self.numberings.find_level(id, level)?;
+        Some((id, level, l))
+    }
+
+    fn table_layers(&self, location: &ParagraphLocation<'a>) -> Vec<Layer<'a>> {
+        let cell = match location.cell.as_ref() {
+            Some(cell) => cell,
+            None => return vec![],
+        };
+        let chain = match cell.table.property.get_style() {
+            Some(id) => self.style_chain(id),
+            None => return vec![],
+        };
+        let mut layers = vec![];
+        for condition in table_conditions(cell) {
+            for style in chain.iter() {
+                let source = FormattingSource::TableStyle {
+                    style_id: style.style_id.clone(),
+                    condition,
+                };
+                if condition == TableStyleOverrideType::WholeTable {
+                    layers.push(Layer {
+                        source: source.clone(),
+                        paragraph: Some(&style.paragraph_property),
+                        run: Some(&style.run_property),
+                    });
+                }
+                for p in style
+                    .table_style_properties
+                    .iter()
+                    .filter(|p| p.style_type == condition)
+                {
+                    layers.push(Layer {
+                        source: source.clone(),
+                        paragraph: Some(&p.paragraph_property),
+                        run: Some(&p.run_property),
+                    });
+                }
+            }
+        }
+        layers
+    }
+}
+
+fn style_layer(style: &Style) -> Layer<'_> {
+    Layer {
+        source: FormattingSource::Style {
+            style_id: style.style_id.clone(),
+        },
+        paragraph: Some(&style.paragraph_property),
+        run: Some(&style.run_property),
+    }
+}
+
+// Conditional formats of the table style which apply to the cell, in the order they are applied.
+fn table_conditions(location: &CellLocation) -> Vec<TableStyleOverrideType> {
+    let look = location
+        .table
+        .property
+        .get_look()
+        .cloned()
+        .unwrap_or_default();
+    let rows = location.table.rows.len();
+    let TableChild::TableRow(row) = &location.table.rows[location.row];
+    let cells = row.cells.len();
+    let first_row = look.first_row && location.row == 0;
+    let last_row = look.last_row && location.row + 1 == rows;
+    let first_col = look.first_column && location.cell == 0;
+    let last_col = look.last_column && location.cell + 1 == cells;
+
+    let mut conditions = vec![TableStyleOverrideType::WholeTable];
+    // Header and total rows and columns are not banded.
+    if !look.no_v_band && !first_col && !last_col {
+        let c = location.cell - look.first_column as usize;
+        conditions.push(if c % 2 == 0 {
+            TableStyleOverrideType::Band1Vert
+        } else {
+            TableStyleOverrideType::Band2Vert
+        });
+    }
+    if !look.no_h_band && !first_row && !last_row {
+        let r = location.row - look.first_row as usize;
+        conditions.push(if r % 2 == 0 {
+            TableStyleOverrideType::Band1Horz
+        } else {
+            TableStyleOverrideType::Band2Horz
+        });
+    }
+    let flags = [
+        (first_row, TableStyleOverrideType::FirstRow),
+        (last_row, TableStyleOverrideType::LastRow),
+        (first_col, TableStyleOverrideType::FirstCol),
+        (last_col, TableStyleOverrideType::LastCol),
+        (first_row && last_col, TableStyleOverrideType::NeCell),
+        (first_row && first_col, TableStyleOverrideType::NwCell),
+        (last_row && last_col, TableStyleOverrideType::SeCell),
+        (last_row && first_col, TableStyleOverrideType::SwCell),
+    ];
+    conditions.extend(flags.iter().filter(|(on, _)| *on).map(|(_, c)| *c));
+    conditions
+}
+
+fn merge_run(
+    target: &mut RunProperty,
+    from: &RunProperty,
+    sources: &mut BTreeMap<String, FormattingSource>,
+    prefix: &str,
+    source: &FormattingSource,
+) {
+    merge_fields!(
+        target,
+        from,
+        sources,
+        prefix,
+        source,
+        [
+            sz,
+            sz_cs,
+            color,
+            highlight,
+            vert_align,
+            underline,
+            bold,
+            bold_cs,
+            caps,
+            italic,
+            italic_cs,
+            vanish,
+            spec_vanish,
+            character_spacing,
+            text_border,
+            strike,
+            shading
+        ]
+    );
+    if let Some(fonts) = from.fonts.as_ref() {
+        target.fonts = Some(target.fonts.take().unwrap_or_default().merge(fonts));
+        sources.insert(format!("{}fonts", prefix), source.clone());
+    }
+}
+
+// The paragraph mark's run property is merged separately.
+fn merge_paragraph(
+    target: &mut ParagraphProperty,
+    from: &ParagraphProperty,
+    sources: &mut BTreeMap<String, FormattingSource>,
+    source: &FormattingSource,
+) {
+    merge_fields!(
+        target,
+        from,
+        sources,
+        "",
+        source,
+        [
+            style,
+            numbering_property,
+            alignment,
+            keep_next,
+            keep_lines,
+            page_break_before,
+            widow_control,
+            outline_lvl,
+            borders,
+            frame_property,
+            text_alignment,
+            adjust_right_ind
+        ]
+    );
+    if let Some(indent) = from.indent.as_ref() {
+        target.indent = Some(match target.indent.take() {
+            Some(i) => i.merge(indent),
+            None => indent.clone(),
+        });
+        sources.insert("indent".to_owned(), source.clone());
+    }
+    if let Some(spacing) = from.line_spacing.as_ref() {
+        target.line_spacing = Some(
+            target
+                .line_spacing
+                .take()
+                .unwrap_or_default()
+                .merge(spacing),
+        );
+        sources.insert("line_spacing".to_owned(), source.clone());
+    }
+    // A tab stop replaces the one at the same position, a clear tab removes it.
+    for tab in from.tabs.iter() {
+        target.tabs.retain(|t| t.pos != tab.pos);
+        if tab.val != Some(TabValueType::Clear) {
+            target.tabs.push(tab.clone());
+        }
+        sources.insert("tabs".to_owned(), source.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn style_source(id: &str) -> FormattingSource {
+        FormattingSource::Style {
+            style_id: id.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_paragraph_cascade() {
+        let styles = Styles::new()
+            .default_size(20)
+            .add_style(
+                Style::new("Normal", StyleType::Paragraph)
+                    .indent(Some(100), None, None, None)
+                    .color("333333"),
+            )
+            .add_style(
+                Style::new("List", StyleType::Paragraph)
+                    .based_on("Normal")
+                    .size(28),
+            );
+        let numberings = Numberings::new()
+            .add_abstract_numbering(
+                AbstractNumbering::new(1).add_level(
+                    Level::new(
+                        0,
+                        Start::new(1),
+                        NumberFormat::new("decimal"),
+                        LevelText::new("%1."),
+                        LevelJc::new("left"),
+                    )
+                    .indent(Some(720), Some(SpecialIndentType::Hanging(360)), None, None)
+                    .bold(),
+                ),
+            )
+            .add_numbering(Numbering::new(2, 1));
+        let paragraph = Paragraph::new()
+            .style("List")
+            .numbering(NumberingId::new(2), IndentLevel::new(0))
+            .align(AlignmentType::Center);
+        let location = ParagraphLocation {
+            paragraph: &paragraph,
+            cell: None,
+        };
+        let p = FormattingResolver::new(&styles, &numberings).paragraph(&location);
+
+        // The style indent replaces the start of the numbering level but keeps its hanging indent.
+        assert_eq!(
+            p.property.indent,
+            Some(Indent::new(
+                Some(100),
+                Some(SpecialIndentType::Hanging(360)),
+                None,
+                None
+            ))
+        );
+        assert_eq!(p.source("indent"), Some(&style_source("Normal")));
+        assert_eq!(p.source("alignment"), Some(&FormattingSource::Direct));
+        assert_eq!(p.source("run_property.sz"), Some(&style_source("List")));
+        assert_eq!(
+            p.source("run_property.color"),
+            Some(&style_source("Normal"))
+        );
+
+        let label = p.numbering_label.unwrap();
+        assert_eq!(
+            label.source("bold"),
+            Some(&FormattingSource::Numbering { id: 2, level: 0 })
+        );
+        assert_eq!(label.source("sz"), Some(&style_source("List")));
+    }
+
+    #[test]
+    fn test_run_cascade_with_table_style() {
+        let styles = Styles::new()
+            .default_size(20)
+            .add_style(
+                Style::new("Grid", StyleType::Table)
+                    .color("111111")
+                    .add_table_style_property(
+                        TableStyleProperty::new(TableStyleOverrideType::FirstRow)
+                            .bold()
+                            .color("FFFFFF"),
+                    )
+                    .add_table_style_property(
+                        TableStyleProperty::new(TableStyleOverrideType::Band1Horz).color("222222"),
+                    ),
+            )
+            .add_style(Style::new("Strong", StyleType::Character).italic())
+            .add_style(
+                Style::new("Quote", StyleType::Paragraph)
+                    .link("QuoteChar")
+                    .size(24),
+            )
+            .add_style(Style::new("QuoteChar", StyleType::Character).size(22));
+        let numberings = Numberings::new();
+        let paragraph = Paragraph::new()
+            .add_run(Run::new().style("Strong"))
+            .add_run(Run::new().style("Quote"));
+        let table = Table::new(vec![
+            TableRow::new(vec![TableCell::new().add_paragraph(paragraph.clone())]),
+            TableRow::new(vec![TableCell::new().add_paragraph(paragraph.clone())]),
+        ])
+        .style("Grid");
+        let resolver = FormattingResolver::new(&styles, &numberings);
+        let run = |row: usize, index: usize| {
+            let location = ParagraphLocation {
+                paragraph: &paragraph,
+                cell: Some(CellLocation {
+                    table: &table,
+                    row,
+                    cell: 0,
+                }),
+            };
+            match &paragraph.children[index] {
+                ParagraphChild::Run(r) => resolver.run(&location, r),
+                _ => unreachable!(),
+            }
+        };
+
+        let header = run(0, 0);
+        assert_eq!(header.property.bold, Some(Bold::new()));
+        assert_eq!(header.property.italic, Some(Italic::new()));
+        assert_eq!(
+            header.source("color"),
+            Some(&FormattingSource::TableStyle {
+                style_id: "Grid".to_owned(),
+                condition: TableStyleOverrideType::FirstRow
+            })
+        );
+        assert_eq!(header.source("italic"), Some(&style_source("Strong")));
+        assert_eq!(header.source("sz"), Some(&FormattingSource::DocDefaults));
+
+        let body = run(1, 0);
+        assert_eq!(body.property.bold, None);
+        assert_eq!(body.property.color, Some(Color::new("222222")));
+
+        // rStyle naming a paragraph style uses its linked character style.
+        let quoted = run(1, 1);
+        assert_eq!(quoted.property.sz, Some(Sz::new(22)));
+        assert_eq!(quoted.source("sz"), Some(&style_source("QuoteChar")));
+    }
+
+    #[test]
+    fn test_default_paragraph_style() {
+        let styles = Styles::new()
+            .add_style(Style::new("Normal", StyleType::Paragraph).size(20))
+            .add_style(
+                Style::new("Standard", StyleType::Paragraph)
+                    .size(24)
+                    .default_style(),
+            );
+        let numberings = Numberings::new();
+        let paragraph = Paragraph::new();
+        let location = ParagraphLocation {
+            paragraph: &paragraph,
+            cell: None,
+        };
+        let p = FormattingResolver::new(&styles, &numberings).paragraph(&location);
+
+        assert_eq!(p.property.run_property.sz, Some(Sz::new(24)));
+        assert_eq!(p.source("run_property.sz"), Some(&style_source("Standard")));
+    }
+}
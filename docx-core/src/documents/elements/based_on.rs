@@ -12,6 +12,10 @@ impl BasedOn {
     pub fn new(val: impl Into<String>) -> BasedOn {
         BasedOn { val: val.into() }
     }
+
+    pub(crate) fn get_val(&self) -> &str {
+        &self.val
+    }
 }
 
 impl Serialize for BasedOn {
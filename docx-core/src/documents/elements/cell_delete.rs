@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::escape;
+use crate::xml_builder::*;
+
+// A table cell deleted with revision tracking.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CellDelete {
+    pub author: String,
+    pub date: String,
+}
+
+impl Default for CellDelete {
+    fn default() -> CellDelete {
+        Self {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+        }
+    }
+}
+
+impl CellDelete {
+    pub fn new() -> CellDelete {
+        Default::default()
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> CellDelete {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> CellDelete {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for CellDelete {}
+
+impl BuildXML for CellDelete {
+    #[allow(clippy::needless_borrow)]
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .cell_delete(&self.generate(), &self.author, &self.date)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_cell_delete() {
+        let b = CellDelete::new().author("bokuweb").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:cellDel w:id="123" w:author="bokuweb" w:date="1970-01-01T00:00:00Z" />"#
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::escape;
+use crate::xml_builder::*;
+
+// A table cell inserted with revision tracking.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CellInsert {
+    pub author: String,
+    pub date: String,
+}
+
+impl Default for CellInsert {
+    fn default() -> CellInsert {
+        Self {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+        }
+    }
+}
+
+impl CellInsert {
+    pub fn new() -> CellInsert {
+        Default::default()
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> CellInsert {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> CellInsert {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for CellInsert {}
+
+impl BuildXML for CellInsert {
+    #[allow(clippy::needless_borrow)]
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .cell_insert(&self.generate(), &self.author, &self.date)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_cell_insert() {
+        let b = CellInsert::new().author("bokuweb").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:cellIns w:id="123" w:author="bokuweb" w:date="1970-01-01T00:00:00Z" />"#
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::escape;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// A vertical merge of a table cell made with revision tracking.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CellMerge {
+    pub author: String,
+    pub date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub v_merge: Option<CellMergeType>,
+    // The merge state of the cell before the revision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub v_merge_orig: Option<CellMergeType>,
+}
+
+impl Default for CellMerge {
+    fn default() -> CellMerge {
+        Self {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            v_merge: None,
+            v_merge_orig: None,
+        }
+    }
+}
+
+impl CellMerge {
+    pub fn new() -> CellMerge {
+        Default::default()
+    }
+
+    pub fn v_merge(mut self, v: CellMergeType) -> CellMerge {
+        self.v_merge = Some(v);
+        self
+    }
+
+    pub fn v_merge_orig(mut self, v: CellMergeType) -> CellMerge {
+        self.v_merge_orig = Some(v);
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> CellMerge {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> CellMerge {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for CellMerge {}
+
+impl BuildXML for CellMerge {
+    #[allow(clippy::needless_borrow)]
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .cell_merge(
+                &self.generate(),
+                &self.author,
+                &self.date,
+                self.v_merge,
+                self.v_merge_orig,
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_cell_merge() {
+        let b = CellMerge::new()
+            .v_merge(CellMergeType::Continue)
+            .v_merge_orig(CellMergeType::Restart)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:cellMerge w:id="123" w:author="unnamed" w:date="1970-01-01T00:00:00Z" w:vMerge="cont" w:vMergeOrig="rest" />"#
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Serialize, Serializer};
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// The table style regions a row or cell belongs to, as written by Word to cache
+// the result of conditional formatting.
+// i.e. <w:cnfStyle w:val="100000000000" />
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CnfStyle {
+    val: String,
+}
+
+impl CnfStyle {
+    pub fn new(val: impl Into<String>) -> CnfStyle {
+        CnfStyle { val: val.into() }
+    }
+}
+
+impl BuildXML for CnfStyle {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new().cnf_style(&self.val).build()
+    }
+}
+
+impl Serialize for CnfStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_cnf_style() {
+        let b = CnfStyle::new("100000000000").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:cnfStyle w:val="100000000000" />"#
+        );
+    }
+}
@@ -1,22 +1,34 @@
+use serde::ser::SerializeStruct;
 use serde::{Deserialize, Serialize, Serializer};
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, ThemeColor};
 use crate::xml_builder::*;
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Color {
     val: String,
+    theme: Option<ThemeColor>,
 }
 
 impl Color {
     pub fn new(val: impl Into<String>) -> Color {
-        Color { val: val.into() }
+        Color {
+            val: val.into(),
+            theme: None,
+        }
+    }
+
+    pub fn theme(mut self, theme: ThemeColor) -> Color {
+        self.theme = Some(theme);
+        self
     }
 }
 
 impl BuildXML for Color {
     fn build(&self) -> Vec<u8> {
-        XMLBuilder::new().color(&self.val).build()
+        XMLBuilder::new()
+            .color(&self.val, self.theme.as_ref())
+            .build()
     }
 }
 
@@ -25,7 +37,17 @@ impl Serialize for Color {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.val)
+        match self.theme {
+            Some(ref theme) => {
+                let mut t = serializer.serialize_struct("Color", 4)?;
+                t.serialize_field("val", &self.val)?;
+                t.serialize_field("themeColor", &theme.color)?;
+                t.serialize_field("themeTint", &theme.tint)?;
+                t.serialize_field("themeShade", &theme.shade)?;
+                t.end()
+            }
+            None => serializer.serialize_str(&self.val),
+        }
     }
 }
 
@@ -33,6 +55,7 @@ impl Serialize for Color {
 mod tests {
 
     use super::*;
+    use crate::types::ThemeColorType;
     #[cfg(test)]
     use pretty_assertions::assert_eq;
     use std::str;
@@ -43,4 +66,14 @@ mod tests {
         let b = c.build();
         assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:color w:val="FFFFFF" />"#);
     }
+
+    #[test]
+    fn test_build_with_theme() {
+        let c = Color::new("auto").theme(ThemeColor::new(ThemeColorType::Accent1).shade("BF"));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:color w:val="auto" w:themeColor="accent1" w:themeShade="BF" />"#
+        );
+    }
 }
@@ -0,0 +1,139 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// a:clrScheme of the theme. Colors are RGB hex values, i.e. "4472C4".
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ColorScheme {
+    pub name: String,
+    pub dk1: String,
+    pub lt1: String,
+    pub dk2: String,
+    pub lt2: String,
+    pub accent1: String,
+    pub accent2: String,
+    pub accent3: String,
+    pub accent4: String,
+    pub accent5: String,
+    pub accent6: String,
+    pub hlink: String,
+    pub fol_hlink: String,
+}
+
+impl ColorScheme {
+    // Colors of the default Office theme.
+    pub fn new() -> Self {
+        Self {
+            name: "Office".to_owned(),
+            dk1: "000000".to_owned(),
+            lt1: "FFFFFF".to_owned(),
+            dk2: "44546A".to_owned(),
+            lt2: "E7E6E6".to_owned(),
+            accent1: "4472C4".to_owned(),
+            accent2: "ED7D31".to_owned(),
+            accent3: "A5A5A5".to_owned(),
+            accent4: "FFC000".to_owned(),
+            accent5: "5B9BD5".to_owned(),
+            accent6: "70AD47".to_owned(),
+            hlink: "0563C1".to_owned(),
+            fol_hlink: "954F72".to_owned(),
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn color(mut self, t: ThemeColorType, val: impl Into<String>) -> Self {
+        if let Some(c) = self.slot_mut(t) {
+            *c = val.into();
+        }
+        self
+    }
+
+    // Text1/Background1 and Text2/Background2 are mapped to dk1/lt1 and dk2/lt2,
+    // as with the default w:clrSchemeMapping of settings.
+    pub fn get_color(&self, t: ThemeColorType) -> Option<&str> {
+        let c = match t {
+            ThemeColorType::Dark1 | ThemeColorType::Text1 => &self.dk1,
+            ThemeColorType::Light1 | ThemeColorType::Background1 => &self.lt1,
+            ThemeColorType::Dark2 | ThemeColorType::Text2 => &self.dk2,
+            ThemeColorType::Light2 | ThemeColorType::Background2 => &self.lt2,
+            ThemeColorType::Accent1 => &self.accent1,
+            ThemeColorType::Accent2 => &self.accent2,
+            ThemeColorType::Accent3 => &self.accent3,
+            ThemeColorType::Accent4 => &self.accent4,
+            ThemeColorType::Accent5 => &self.accent5,
+            ThemeColorType::Accent6 => &self.accent6,
+            ThemeColorType::Hyperlink => &self.hlink,
+            ThemeColorType::FollowedHyperlink => &self.fol_hlink,
+            ThemeColorType::None => return None,
+        };
+        Some(c.as_str())
+    }
+
+    fn slot_mut(&mut self, t: ThemeColorType) -> Option<&mut String> {
+        let c = match t {
+            ThemeColorType::Dark1 | ThemeColorType::Text1 => &mut self.dk1,
+            ThemeColorType::Light1 | ThemeColorType::Background1 => &mut self.lt1,
+            ThemeColorType::Dark2 | ThemeColorType::Text2 => &mut self.dk2,
+            ThemeColorType::Light2 | ThemeColorType::Background2 => &mut self.lt2,
+            ThemeColorType::Accent1 => &mut self.accent1,
+            ThemeColorType::Accent2 => &mut self.accent2,
+            ThemeColorType::Accent3 => &mut self.accent3,
+            ThemeColorType::Accent4 => &mut self.accent4,
+            ThemeColorType::Accent5 => &mut self.accent5,
+            ThemeColorType::Accent6 => &mut self.accent6,
+            ThemeColorType::Hyperlink => &mut self.hlink,
+            ThemeColorType::FollowedHyperlink => &mut self.fol_hlink,
+            ThemeColorType::None => return None,
+        };
+        Some(c)
+    }
+}
+
+impl BuildXML for ColorScheme {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .open_clr_scheme(&self.name)
+            .scheme_color("dk1", &self.dk1)
+            .scheme_color("lt1", &self.lt1)
+            .scheme_color("dk2", &self.dk2)
+            .scheme_color("lt2", &self.lt2)
+            .scheme_color("accent1", &self.accent1)
+            .scheme_color("accent2", &self.accent2)
+            .scheme_color("accent3", &self.accent3)
+            .scheme_color("accent4", &self.accent4)
+            .scheme_color("accent5", &self.accent5)
+            .scheme_color("accent6", &self.accent6)
+            .scheme_color("hlink", &self.hlink)
+            .scheme_color("folHlink", &self.fol_hlink)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_color_scheme_mapping() {
+        let s = ColorScheme::new()
+            .color(ThemeColorType::Text1, "111111")
+            .color(ThemeColorType::Accent2, "C00000");
+        assert_eq!(s.get_color(ThemeColorType::Dark1), Some("111111"));
+        assert_eq!(s.get_color(ThemeColorType::Accent2), Some("C00000"));
+        assert_eq!(s.get_color(ThemeColorType::Background2), Some("E7E6E6"));
+        assert_eq!(s.get_color(ThemeColorType::None), None);
+    }
+}
@@ -12,6 +12,8 @@ pub struct Comment {
     pub date: String,
     pub children: Vec<CommentChild>,
     pub parent_comment_id: Option<usize>,
+    pub done: bool,
+    pub durable_id: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -50,6 +52,8 @@ impl Default for Comment {
             date: "1970-01-01T00:00:00Z".to_owned(),
             children: vec![],
             parent_comment_id: None,
+            done: false,
+            durable_id: None,
         }
     }
 }
@@ -87,9 +91,28 @@ impl Comment {
         self
     }
 
+    // Mark the thread as resolved.
+    pub fn done(mut self) -> Comment {
+        self.done = true;
+        self
+    }
+
+    pub fn durable_id(mut self, id: impl Into<String>) -> Comment {
+        self.durable_id = Some(id.into());
+        self
+    }
+
     pub fn id(&self) -> usize {
         self.id
     }
+
+    // paraId of the last paragraph, which commentsExtended and commentsIds refer to.
+    pub fn paragraph_id(&self) -> Option<String> {
+        self.children.iter().rev().find_map(|child| match child {
+            CommentChild::Paragraph(p) => Some(p.id.clone()),
+            _ => None,
+        })
+    }
 }
 
 impl BuildXML for CommentChild {
@@ -0,0 +1,64 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e. <w16cex:commentExtensible w16cex:durableId="2C0F7A9E" w16cex:dateUtc="2023-04-01T00:00:00Z"/>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentExtensible {
+    pub durable_id: String,
+    // Unlike the local w:date of the comment, this is in UTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_utc: Option<String>,
+}
+
+impl CommentExtensible {
+    pub fn new(durable_id: impl Into<String>) -> CommentExtensible {
+        Self {
+            durable_id: durable_id.into(),
+            date_utc: None,
+        }
+    }
+
+    pub fn date_utc(mut self, date_utc: impl Into<String>) -> CommentExtensible {
+        self.date_utc = Some(date_utc.into());
+        self
+    }
+}
+
+impl BuildXML for CommentExtensible {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        match &self.date_utc {
+            Some(date_utc) => b.comment_extensible(&self.durable_id, date_utc),
+            None => b.comment_extensible_without_date(&self.durable_id),
+        }
+        .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_comment_extensible() {
+        let b = CommentExtensible::new("2C0F7A9E")
+            .date_utc("2023-04-01T00:00:00Z")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w16cex:commentExtensible w16cex:durableId="2C0F7A9E" w16cex:dateUtc="2023-04-01T00:00:00Z" />"#
+        );
+        let b = CommentExtensible::new("2C0F7A9E").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w16cex:commentExtensible w16cex:durableId="2C0F7A9E" />"#
+        );
+    }
+}
@@ -13,6 +13,36 @@ pub enum DeleteInstrText {
     Unsupported(String),
 }
 
+impl From<DeleteInstrText> for InstrText {
+    fn from(i: DeleteInstrText) -> InstrText {
+        match i {
+            DeleteInstrText::TOC(toc) => InstrText::TOC(toc),
+            DeleteInstrText::TC(tc) => InstrText::TC(tc),
+            DeleteInstrText::PAGEREF(page_ref) => InstrText::PAGEREF(page_ref),
+            DeleteInstrText::HYPERLINK(link) => InstrText::HYPERLINK(link),
+            DeleteInstrText::Unsupported(s) => InstrText::Unsupported(s),
+        }
+    }
+}
+
+impl From<InstrText> for DeleteInstrText {
+    fn from(i: InstrText) -> DeleteInstrText {
+        match i {
+            InstrText::TOC(toc) => DeleteInstrText::TOC(toc),
+            InstrText::TC(tc) => DeleteInstrText::TC(tc),
+            InstrText::PAGEREF(page_ref) => DeleteInstrText::PAGEREF(page_ref),
+            InstrText::HYPERLINK(link) => DeleteInstrText::HYPERLINK(link),
+            InstrText::PAGE(page) => {
+                DeleteInstrText::Unsupported(String::from_utf8_lossy(&page.build()).into_owned())
+            }
+            InstrText::NUMPAGES(pages) => {
+                DeleteInstrText::Unsupported(String::from_utf8_lossy(&pages.build()).into_owned())
+            }
+            InstrText::Unsupported(s) => DeleteInstrText::Unsupported(s),
+        }
+    }
+}
+
 impl BuildXML for DeleteInstrText {
     fn build(&self) -> Vec<u8> {
         let instr = match self {
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use super::Text;
 use crate::documents::BuildXML;
 use crate::escape::escape;
 use crate::xml_builder::*;
@@ -27,6 +28,13 @@ impl DeleteText {
     }
 }
 
+// Restores the text when a deletion is rejected.
+impl From<DeleteText> for Text {
+    fn from(t: DeleteText) -> Text {
+        Text::without_escape(t.text)
+    }
+}
+
 impl BuildXML for DeleteText {
     fn build(&self) -> Vec<u8> {
         XMLBuilder::new().delete_text(&self.text, true).build()
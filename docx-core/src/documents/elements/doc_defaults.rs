@@ -18,6 +18,14 @@ impl DocDefaults {
         Default::default()
     }
 
+    pub(crate) fn get_run_property(&self) -> &RunProperty {
+        self.run_property_default.get_run_property()
+    }
+
+    pub(crate) fn get_paragraph_property(&self) -> &ParagraphProperty {
+        self.paragraph_property_default.get_paragraph_property()
+    }
+
     pub fn size(mut self, size: usize) -> Self {
         self.run_property_default = self.run_property_default.size(size);
         self
@@ -0,0 +1,110 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+use super::PasswordHash;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentProtection {
+    pub edit: DocumentProtectionType,
+    pub enforcement: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_hash: Option<PasswordHash>,
+}
+
+impl DocumentProtection {
+    pub fn new(edit: DocumentProtectionType) -> Self {
+        Self {
+            edit,
+            enforcement: true,
+            password_hash: None,
+        }
+    }
+
+    pub fn read_only() -> Self {
+        Self::new(DocumentProtectionType::ReadOnly)
+    }
+
+    pub fn comments() -> Self {
+        Self::new(DocumentProtectionType::Comments)
+    }
+
+    pub fn tracked_changes() -> Self {
+        Self::new(DocumentProtectionType::TrackedChanges)
+    }
+
+    pub fn forms() -> Self {
+        Self::new(DocumentProtectionType::Forms)
+    }
+
+    pub fn enforcement(mut self, v: bool) -> Self {
+        self.enforcement = v;
+        self
+    }
+
+    pub fn password(mut self, password: &str) -> Self {
+        self.password_hash = Some(PasswordHash::new(password));
+        self
+    }
+
+    pub fn password_hash(mut self, h: PasswordHash) -> Self {
+        self.password_hash = Some(h);
+        self
+    }
+
+    // A protection without password can be removed by anyone.
+    pub fn verify_password(&self, password: &str) -> bool {
+        match self.password_hash {
+            Some(ref h) => h.verify(password),
+            None => true,
+        }
+    }
+}
+
+impl BuildXML for DocumentProtection {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .document_protection(
+                &self.edit.to_string(),
+                if self.enforcement { "1" } else { "0" },
+                self.password_hash.as_ref(),
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_document_protection() {
+        let b = DocumentProtection::new(DocumentProtectionType::Forms).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:documentProtection w:edit="forms" w:enforcement="1" />"#
+        );
+    }
+
+    #[test]
+    fn test_document_protection_with_password() {
+        let h = PasswordHash::with_salt("docx", &[0u8; 16], 100);
+        let b = DocumentProtection::read_only()
+            .password_hash(h.clone())
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            format!(
+                r#"<w:documentProtection w:edit="readOnly" w:enforcement="1" w:algorithmName="SHA-512" w:hashValue="{}" w:saltValue="AAAAAAAAAAAAAAAAAAAAAA==" w:spinCount="100" />"#,
+                h.hash_value
+            )
+        );
+    }
+}
@@ -11,6 +11,8 @@ use crate::xml_builder::*;
 pub struct FieldChar {
     pub field_char_type: FieldCharType,
     pub dirty: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_field_data: Option<FormFieldData>,
 }
 
 impl FieldChar {
@@ -18,6 +20,7 @@ impl FieldChar {
         Self {
             field_char_type: t,
             dirty: false,
+            form_field_data: None,
         }
     }
 
@@ -25,16 +28,25 @@ impl FieldChar {
         self.dirty = true;
         self
     }
+
+    pub fn form_field_data(mut self, d: FormFieldData) -> Self {
+        self.form_field_data = Some(d);
+        self
+    }
 }
 
 impl BuildXML for FieldChar {
     fn build(&self) -> Vec<u8> {
-        XMLBuilder::new()
-            .field_character(
-                &format!("{}", self.field_char_type),
-                &format!("{}", &self.dirty),
-            )
-            .build()
+        let t = format!("{}", self.field_char_type);
+        let dirty = format!("{}", &self.dirty);
+        match self.form_field_data {
+            Some(ref d) => XMLBuilder::new()
+                .open_field_character(&t, &dirty)
+                .add_child(d)
+                .close()
+                .build(),
+            None => XMLBuilder::new().field_character(&t, &dirty).build(),
+        }
     }
 }
 
@@ -54,4 +66,24 @@ mod tests {
             r#"<w:fldChar w:fldCharType="begin" w:dirty="true" />"#
         );
     }
+
+    #[test]
+    fn test_field_character_with_form_field_data() {
+        let b = FieldChar::new(FieldCharType::Begin)
+            .form_field_data(
+                FormFieldData::new()
+                    .name("Check1")
+                    .check_box(CheckBox::new()),
+            )
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:fldChar w:fldCharType="begin" w:dirty="false"><w:ffData>
+  <w:name w:val="Check1" />
+  <w:enabled w:val="1" /><w:checkBox>
+  <w:sizeAuto />
+  <w:default w:val="0" />
+</w:checkBox></w:ffData></w:fldChar>"#
+        );
+    }
 }
@@ -1,35 +1,231 @@
+use serde::Serialize;
+
 use crate::documents::BuildXML;
 use crate::types::*;
 use crate::xml_builder::*;
 
-#[derive(Debug)]
-pub struct Font<'a> {
-    name: &'a str,
-    charset: &'a str,
-    family: &'a str,
-    pitch: FontPitchType,
+// Panose, charset, family and pitch of fonts commonly used in documents.
+// Other fonts are written with a charset of "00" and an "auto" family.
+const KNOWN_FONTS: [(&str, &str, &str, &str, FontPitchType); 18] = [
+    (
+        "Arial",
+        "020B0604020202020204",
+        "00",
+        "swiss",
+        FontPitchType::Variable,
+    ),
+    (
+        "Calibri",
+        "020F0502020204030204",
+        "00",
+        "swiss",
+        FontPitchType::Variable,
+    ),
+    (
+        "Calibri Light",
+        "020F0302020204030204",
+        "00",
+        "swiss",
+        FontPitchType::Variable,
+    ),
+    (
+        "Cambria",
+        "02040503050406030204",
+        "00",
+        "roman",
+        FontPitchType::Variable,
+    ),
+    (
+        "Century",
+        "02040604050505020304",
+        "00",
+        "roman",
+        FontPitchType::Variable,
+    ),
+    (
+        "Courier New",
+        "02070309020205020404",
+        "00",
+        "modern",
+        FontPitchType::Fixed,
+    ),
+    (
+        "Georgia",
+        "02040502050405020303",
+        "00",
+        "roman",
+        FontPitchType::Variable,
+    ),
+    (
+        "Segoe UI",
+        "020B0502040204020203",
+        "00",
+        "swiss",
+        FontPitchType::Variable,
+    ),
+    (
+        "Symbol",
+        "05050102010706020507",
+        "02",
+        "roman",
+        FontPitchType::Variable,
+    ),
+    (
+        "Tahoma",
+        "020B0604030504040204",
+        "00",
+        "swiss",
+        FontPitchType::Variable,
+    ),
+    (
+        "Times New Roman",
+        "02020603050405020304",
+        "00",
+        "roman",
+        FontPitchType::Variable,
+    ),
+    (
+        "Verdana",
+        "020B0604030504040204",
+        "00",
+        "swiss",
+        FontPitchType::Variable,
+    ),
+    (
+        "Wingdings",
+        "05000000000000000000",
+        "02",
+        "auto",
+        FontPitchType::Variable,
+    ),
+    (
+        "MS Gothic",
+        "020B0609070205080204",
+        "80",
+        "modern",
+        FontPitchType::Fixed,
+    ),
+    (
+        "MS Mincho",
+        "02020609040205080304",
+        "80",
+        "modern",
+        FontPitchType::Fixed,
+    ),
+    (
+        "Yu Gothic",
+        "020B0400000000000000",
+        "80",
+        "swiss",
+        FontPitchType::Variable,
+    ),
+    (
+        "Yu Mincho",
+        "02020400000000000000",
+        "80",
+        "roman",
+        FontPitchType::Variable,
+    ),
+    (
+        "SimSun",
+        "02010600030101010101",
+        "86",
+        "auto",
+        FontPitchType::Variable,
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontEmbed {
+    pub embed_type: FontEmbedType,
+    pub id: String,
+    pub font_key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Font {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub panose: Option<String>,
+    pub charset: String,
+    pub family: String,
+    pub pitch: FontPitchType,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<FontEmbed>,
 }
 
-impl<'a> Font<'a> {
-    pub fn new(name: &'a str, charset: &'a str, family: &'a str, pitch: FontPitchType) -> Font<'a> {
+impl Font {
+    pub fn new(
+        name: impl Into<String>,
+        charset: impl Into<String>,
+        family: impl Into<String>,
+        pitch: FontPitchType,
+    ) -> Font {
         Font {
-            name,
-            charset,
-            family,
+            name: name.into(),
+            panose: None,
+            charset: charset.into(),
+            family: family.into(),
             pitch,
+            embeds: vec![],
         }
     }
+
+    // Font with the metadata of a well known font, if any.
+    pub fn from_name(name: impl Into<String>) -> Font {
+        let name = name.into();
+        match KNOWN_FONTS.iter().find(|f| f.0 == name) {
+            Some((_, panose, charset, family, pitch)) => {
+                Font::new(name, *charset, *family, *pitch).panose(*panose)
+            }
+            None => Font::new(name, "00", "auto", FontPitchType::Variable),
+        }
+    }
+
+    pub fn panose(mut self, panose: impl Into<String>) -> Font {
+        self.panose = Some(panose.into());
+        self
+    }
+
+    pub(crate) fn add_embed(
+        &mut self,
+        embed_type: FontEmbedType,
+        id: impl Into<String>,
+        font_key: impl Into<String>,
+    ) {
+        self.embeds.retain(|e| e.embed_type != embed_type);
+        self.embeds.push(FontEmbed {
+            embed_type,
+            id: id.into(),
+            font_key: font_key.into(),
+        });
+    }
 }
 
-impl<'a> BuildXML for Font<'a> {
+impl BuildXML for Font {
     fn build(&self) -> Vec<u8> {
-        let b = XMLBuilder::new();
-        b.open_font(self.name)
-            .charset(self.charset)
-            .family(self.family)
-            .pitch(&self.pitch.to_string())
-            .close()
-            .build()
+        let mut b = XMLBuilder::new().open_font(&self.name);
+        if let Some(ref panose) = self.panose {
+            b = b.panose(panose);
+        }
+        b = b
+            .charset(&self.charset)
+            .family(&self.family)
+            .pitch(&self.pitch.to_string());
+        // w:embedRegular, w:embedBold, w:embedItalic and w:embedBoldItalic in schema order.
+        for t in [
+            FontEmbedType::Regular,
+            FontEmbedType::Bold,
+            FontEmbedType::Italic,
+            FontEmbedType::BoldItalic,
+        ] {
+            if let Some(e) = self.embeds.iter().find(|e| e.embed_type == t) {
+                b = b.embed_font(t, &e.id, &e.font_key);
+            }
+        }
+        b.close().build()
     }
 }
 
@@ -54,4 +250,38 @@ mod tests {
 </w:font>"#
         );
     }
+
+    #[test]
+    fn test_build_with_embed() {
+        let mut c = Font::from_name("Calibri");
+        c.add_embed(
+            FontEmbedType::Bold,
+            "rIdFont2",
+            "{00000000-0000-0000-0000-000000000002}",
+        );
+        c.add_embed(
+            FontEmbedType::Regular,
+            "rIdFont1",
+            "{00000000-0000-0000-0000-000000000001}",
+        );
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:font w:name="Calibri">
+  <w:panose1 w:val="020F0502020204030204" />
+  <w:charset w:val="00" />
+  <w:family w:val="swiss" />
+  <w:pitch w:val="variable" />
+  <w:embedRegular r:id="rIdFont1" w:fontKey="{00000000-0000-0000-0000-000000000001}" />
+  <w:embedBold r:id="rIdFont2" w:fontKey="{00000000-0000-0000-0000-000000000002}" />
+</w:font>"#
+        );
+    }
+
+    #[test]
+    fn test_unknown_font() {
+        let c = Font::from_name("Corporate Sans");
+        assert_eq!(c.family, "auto");
+        assert_eq!(c.panose, None);
+    }
 }
@@ -1,5 +1,8 @@
 use serde::Serialize;
 
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
 #[cfg_attr(feature = "wasm", ts(export))]
@@ -25,13 +28,114 @@ pub struct FontGroup {
 #[cfg_attr(feature = "wasm", ts(export))]
 #[serde(rename_all = "camelCase")]
 pub struct FontScheme {
+    pub name: String,
     pub major_font: FontGroup,
     pub minor_font: FontGroup,
 }
 
-// For now reader only
+impl FontGroup {
+    pub fn new(latin: impl Into<String>) -> Self {
+        Self {
+            latin: latin.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn ea(mut self, ea: impl Into<String>) -> Self {
+        self.ea = ea.into();
+        self
+    }
+
+    pub fn cs(mut self, cs: impl Into<String>) -> Self {
+        self.cs = cs.into();
+        self
+    }
+
+    pub fn add_font(mut self, script: impl Into<String>, typeface: impl Into<String>) -> Self {
+        self.fonts.push(FontSchemeFont {
+            script: script.into(),
+            typeface: typeface.into(),
+        });
+        self
+    }
+}
+
 impl FontScheme {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn major_font(mut self, f: FontGroup) -> Self {
+        self.major_font = f;
+        self
+    }
+
+    pub fn minor_font(mut self, f: FontGroup) -> Self {
+        self.minor_font = f;
+        self
+    }
+}
+
+fn build_font_group(b: XMLBuilder, f: &FontGroup) -> XMLBuilder {
+    let b = b
+        .theme_latin_font(&f.latin)
+        .theme_ea_font(&f.ea)
+        .theme_cs_font(&f.cs);
+    f.fonts.iter().fold(b, |b, font| {
+        b.theme_script_font(&font.script, &font.typeface)
+    })
+}
+
+impl BuildXML for FontScheme {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new()
+            .open_font_scheme(&self.name)
+            .open_major_font();
+        let b = build_font_group(b, &self.major_font)
+            .close()
+            .open_minor_font();
+        build_font_group(b, &self.minor_font)
+            .close()
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let s = FontScheme::new()
+            .name("Office")
+            .major_font(FontGroup::new("Calibri Light").add_font("Jpan", "游ゴシック Light"))
+            .minor_font(FontGroup::new("Calibri"));
+        let b = s.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<a:fontScheme name="Office">
+  <a:majorFont>
+    <a:latin typeface="Calibri Light" />
+    <a:ea typeface="" />
+    <a:cs typeface="" />
+    <a:font script="Jpan" typeface="游ゴシック Light" />
+  </a:majorFont>
+  <a:minorFont>
+    <a:latin typeface="Calibri" />
+    <a:ea typeface="" />
+    <a:cs typeface="" />
+  </a:minorFont>
+</a:fontScheme>"#
+        );
+    }
 }
@@ -0,0 +1,378 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// Legacy form field data.
+// Please see https://c-rex.net/projects/samples/ooxml/e1/Part4/OOXML_P4_DOCX_ffData_topic_ID0E5YJQ.html
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct FormFieldData {
+    pub name: Option<String>,
+    pub enabled: bool,
+    pub calc_on_exit: bool,
+    pub help_text: Option<String>,
+    pub status_text: Option<String>,
+    pub field: Option<FormField>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+pub enum FormField {
+    TextInput(TextInput),
+    CheckBox(CheckBox),
+    DropDownList(DropDownList),
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct TextInput {
+    pub input_type: Option<TextInputType>,
+    pub default: Option<String>,
+    pub max_length: Option<usize>,
+    pub format: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct CheckBox {
+    // Size in half-points. `None` means auto size.
+    pub size: Option<usize>,
+    pub default: bool,
+    pub checked: Option<bool>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct DropDownList {
+    pub result: Option<usize>,
+    pub default: Option<usize>,
+    pub entries: Vec<String>,
+}
+
+// The current value of a legacy form field.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+pub enum FormFieldValue {
+    Text(String),
+    CheckBox(bool),
+    DropDownList(Option<String>),
+}
+
+impl Default for FormFieldData {
+    fn default() -> Self {
+        Self {
+            name: None,
+            enabled: true,
+            calc_on_exit: false,
+            help_text: None,
+            status_text: None,
+            field: None,
+        }
+    }
+}
+
+impl FormFieldData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn disable(mut self) -> Self {
+        self.enabled = false;
+        self
+    }
+
+    pub fn calc_on_exit(mut self) -> Self {
+        self.calc_on_exit = true;
+        self
+    }
+
+    pub fn help_text(mut self, t: impl Into<String>) -> Self {
+        self.help_text = Some(t.into());
+        self
+    }
+
+    pub fn status_text(mut self, t: impl Into<String>) -> Self {
+        self.status_text = Some(t.into());
+        self
+    }
+
+    pub fn text_input(mut self, t: TextInput) -> Self {
+        self.field = Some(FormField::TextInput(t));
+        self
+    }
+
+    pub fn check_box(mut self, c: CheckBox) -> Self {
+        self.field = Some(FormField::CheckBox(c));
+        self
+    }
+
+    pub fn drop_down_list(mut self, d: DropDownList) -> Self {
+        self.field = Some(FormField::DropDownList(d));
+        self
+    }
+
+    // i.e. FORMTEXT, FORMCHECKBOX or FORMDROPDOWN
+    pub(crate) fn instr(&self) -> &'static str {
+        match self.field {
+            Some(FormField::CheckBox(_)) => "FORMCHECKBOX",
+            Some(FormField::DropDownList(_)) => "FORMDROPDOWN",
+            _ => "FORMTEXT",
+        }
+    }
+
+    // `result` is the text between the separate and end field chars.
+    pub(crate) fn value(&self, result: &str) -> FormFieldValue {
+        match self.field {
+            Some(FormField::CheckBox(ref c)) => FormFieldValue::CheckBox(c.is_checked()),
+            Some(FormField::DropDownList(ref d)) => {
+                FormFieldValue::DropDownList(d.selected().map(|s| s.to_owned()))
+            }
+            _ => FormFieldValue::Text(result.to_owned()),
+        }
+    }
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn input_type(mut self, t: TextInputType) -> Self {
+        self.input_type = Some(t);
+        self
+    }
+
+    pub fn default_value(mut self, v: impl Into<String>) -> Self {
+        self.default = Some(v.into());
+        self
+    }
+
+    pub fn max_length(mut self, l: usize) -> Self {
+        self.max_length = Some(l);
+        self
+    }
+
+    pub fn format(mut self, f: impl Into<String>) -> Self {
+        self.format = Some(f.into());
+        self
+    }
+}
+
+impl CheckBox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn size(mut self, s: usize) -> Self {
+        self.size = Some(s);
+        self
+    }
+
+    pub fn default_checked(mut self, v: bool) -> Self {
+        self.default = v;
+        self
+    }
+
+    pub fn checked(mut self, v: bool) -> Self {
+        self.checked = Some(v);
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.checked.unwrap_or(self.default)
+    }
+}
+
+impl DropDownList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_entry(mut self, e: impl Into<String>) -> Self {
+        self.entries.push(e.into());
+        self
+    }
+
+    pub fn default_index(mut self, i: usize) -> Self {
+        self.default = Some(i);
+        self
+    }
+
+    pub fn result(mut self, i: usize) -> Self {
+        self.result = Some(i);
+        self
+    }
+
+    pub fn selected(&self) -> Option<&str> {
+        let i = self.result.or(self.default).unwrap_or(0);
+        self.entries.get(i).map(|e| e.as_str())
+    }
+}
+
+impl BuildXML for TextInput {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new().open_text_input();
+        if let Some(ref t) = self.input_type {
+            b = b.type_tag(&t.to_string());
+        }
+        if let Some(ref d) = self.default {
+            b = b.form_field_default(d);
+        }
+        if let Some(l) = self.max_length {
+            b = b.max_length(l);
+        }
+        if let Some(ref f) = self.format {
+            b = b.text_input_format(f);
+        }
+        b.close().build()
+    }
+}
+
+impl BuildXML for CheckBox {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new().open_check_box();
+        b = match self.size {
+            Some(s) => b.check_box_size(s),
+            None => b.size_auto(),
+        };
+        b = b.form_field_default(if self.default { "1" } else { "0" });
+        if let Some(c) = self.checked {
+            b = b.checked(if c { "1" } else { "0" });
+        }
+        b.close().build()
+    }
+}
+
+impl BuildXML for DropDownList {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new().open_drop_down_list();
+        if let Some(r) = self.result {
+            b = b.drop_down_result(r);
+        }
+        if let Some(d) = self.default {
+            b = b.form_field_default(&format!("{}", d));
+        }
+        for e in self.entries.iter() {
+            b = b.list_entry(e);
+        }
+        b.close().build()
+    }
+}
+
+impl BuildXML for FormFieldData {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new().open_form_field_data();
+        if let Some(ref name) = self.name {
+            b = b.name(name);
+        }
+        b = b.form_field_enabled(if self.enabled { "1" } else { "0" });
+        if self.calc_on_exit {
+            b = b.calc_on_exit("1");
+        }
+        if let Some(ref t) = self.help_text {
+            b = b.help_text("text", t);
+        }
+        if let Some(ref t) = self.status_text {
+            b = b.status_text("text", t);
+        }
+        b = match self.field {
+            Some(FormField::TextInput(ref t)) => b.add_child(t),
+            Some(FormField::CheckBox(ref c)) => b.add_child(c),
+            Some(FormField::DropDownList(ref d)) => b.add_child(d),
+            None => b,
+        };
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_text_input() {
+        let b = FormFieldData::new()
+            .name("Text1")
+            .text_input(
+                TextInput::new()
+                    .input_type(TextInputType::Number)
+                    .default_value("0")
+                    .max_length(10)
+                    .format("0.00"),
+            )
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:ffData>
+  <w:name w:val="Text1" />
+  <w:enabled w:val="1" /><w:textInput>
+  <w:type w:val="number" />
+  <w:default w:val="0" />
+  <w:maxLength w:val="10" />
+  <w:format w:val="0.00" />
+</w:textInput></w:ffData>"#
+        );
+    }
+
+    #[test]
+    fn test_check_box() {
+        let b = FormFieldData::new()
+            .name("Check1")
+            .check_box(CheckBox::new().size(20).checked(true))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:ffData>
+  <w:name w:val="Check1" />
+  <w:enabled w:val="1" /><w:checkBox>
+  <w:size w:val="20" />
+  <w:default w:val="0" />
+  <w:checked w:val="1" />
+</w:checkBox></w:ffData>"#
+        );
+    }
+
+    #[test]
+    fn test_drop_down_list() {
+        let d = FormFieldData::new().drop_down_list(
+            DropDownList::new()
+                .add_entry("Apple")
+                .add_entry("Banana")
+                .result(1),
+        );
+        assert_eq!(
+            str::from_utf8(&d.build()).unwrap(),
+            r#"<w:ffData>
+  <w:enabled w:val="1" /><w:ddList>
+  <w:result w:val="1" />
+  <w:listEntry w:val="Apple" />
+  <w:listEntry w:val="Banana" />
+</w:ddList></w:ffData>"#
+        );
+        assert_eq!(
+            d.value(""),
+            FormFieldValue::DropDownList(Some("Banana".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// a:fmtScheme of the theme. The fill, line and effect style matrices of a new
+// scheme are the plain Office defaults, a read scheme is written back as read.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct FormatScheme {
+    pub name: String,
+    #[serde(skip)]
+    raw: Option<String>,
+}
+
+impl FormatScheme {
+    pub fn new() -> Self {
+        Self {
+            name: "Office".to_owned(),
+            raw: None,
+        }
+    }
+
+    // reader only
+    pub(crate) fn raw(mut self, raw: String) -> Self {
+        self.raw = Some(raw);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+}
+
+impl BuildXML for FormatScheme {
+    fn build(&self) -> Vec<u8> {
+        if let Some(raw) = &self.raw {
+            return raw.as_bytes().to_vec();
+        }
+        XMLBuilder::new()
+            .open_fmt_scheme(&self.name)
+            .default_format_matrices()
+            .close()
+            .build()
+    }
+}
@@ -48,6 +48,17 @@ impl Indent {
         self.first_line_chars = Some(chars);
         self
     }
+
+    // Attributes given in `other` replace those of self.
+    pub(crate) fn merge(mut self, other: &Indent) -> Self {
+        self.start = other.start.or(self.start);
+        self.end = other.end.or(self.end);
+        self.special_indent = other.special_indent.or(self.special_indent);
+        self.start_chars = other.start_chars.or(self.start_chars);
+        self.hanging_chars = other.hanging_chars.or(self.hanging_chars);
+        self.first_line_chars = other.first_line_chars.or(self.first_line_chars);
+        self
+    }
 }
 
 impl BuildXML for Indent {
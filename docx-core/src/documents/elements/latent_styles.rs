@@ -0,0 +1,177 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// Defaults of the built-in styles which are not defined in styles.xml, i.e. whether
+// Word shows them in the style gallery. Values are Word's own unless changed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatentStyles {
+    pub def_locked_state: bool,
+    pub def_ui_priority: usize,
+    pub def_semi_hidden: bool,
+    pub def_unhide_when_used: bool,
+    pub def_q_format: bool,
+    pub count: usize,
+    pub exceptions: Vec<LatentStyleException>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LatentStyleException {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_priority: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semi_hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unhide_when_used: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q_format: Option<bool>,
+}
+
+impl LatentStyles {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn def_ui_priority(mut self, v: usize) -> Self {
+        self.def_ui_priority = v;
+        self
+    }
+
+    pub fn def_semi_hidden(mut self, v: bool) -> Self {
+        self.def_semi_hidden = v;
+        self
+    }
+
+    pub fn def_unhide_when_used(mut self, v: bool) -> Self {
+        self.def_unhide_when_used = v;
+        self
+    }
+
+    pub fn def_q_format(mut self, v: bool) -> Self {
+        self.def_q_format = v;
+        self
+    }
+
+    pub fn count(mut self, v: usize) -> Self {
+        self.count = v;
+        self
+    }
+
+    pub fn add_exception(mut self, e: LatentStyleException) -> Self {
+        self.exceptions.push(e);
+        self
+    }
+}
+
+impl Default for LatentStyles {
+    fn default() -> Self {
+        Self {
+            def_locked_state: false,
+            def_ui_priority: 99,
+            def_semi_hidden: false,
+            def_unhide_when_used: false,
+            def_q_format: false,
+            count: 376,
+            exceptions: vec![],
+        }
+    }
+}
+
+impl LatentStyleException {
+    // `name` is the style name, i.e. "heading 1", not its id.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn ui_priority(mut self, v: usize) -> Self {
+        self.ui_priority = Some(v);
+        self
+    }
+
+    pub fn semi_hidden(mut self) -> Self {
+        self.semi_hidden = Some(true);
+        self
+    }
+
+    pub fn unhide_when_used(mut self) -> Self {
+        self.unhide_when_used = Some(true);
+        self
+    }
+
+    pub fn q_format(mut self) -> Self {
+        self.q_format = Some(true);
+        self
+    }
+}
+
+fn flag(v: bool) -> &'static str {
+    if v {
+        "1"
+    } else {
+        "0"
+    }
+}
+
+impl BuildXML for LatentStyles {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new().open_latent_styles(
+            flag(self.def_locked_state),
+            &format!("{}", self.def_ui_priority),
+            flag(self.def_semi_hidden),
+            flag(self.def_unhide_when_used),
+            flag(self.def_q_format),
+            &format!("{}", self.count),
+        );
+        for e in &self.exceptions {
+            b = b.lsd_exception(
+                &e.name,
+                e.ui_priority,
+                e.semi_hidden,
+                e.unhide_when_used,
+                e.q_format,
+            );
+        }
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_latent_styles() {
+        let b = LatentStyles::new()
+            .add_exception(
+                LatentStyleException::new("Normal")
+                    .ui_priority(0)
+                    .q_format(),
+            )
+            .add_exception(
+                LatentStyleException::new("heading 2")
+                    .ui_priority(9)
+                    .semi_hidden()
+                    .unhide_when_used()
+                    .q_format(),
+            )
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:latentStyles w:defLockedState="0" w:defUIPriority="99" w:defSemiHidden="0" w:defUnhideWhenUsed="0" w:defQFormat="0" w:count="376">
+  <w:lsdException w:name="Normal" w:uiPriority="0" w:qFormat="1" />
+  <w:lsdException w:name="heading 2" w:uiPriority="9" w:semiHidden="1" w:unhideWhenUsed="1" w:qFormat="1" />
+</w:latentStyles>"#
+        );
+    }
+}
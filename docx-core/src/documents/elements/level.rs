@@ -19,6 +19,8 @@ pub struct Level {
     pub level_restart: Option<LevelRestart>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_lgl: Option<IsLgl>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pic_bullet_id: Option<usize>,
 }
 
 impl Level {
@@ -41,6 +43,7 @@ impl Level {
             pstyle: None,
             level_restart: None,
             is_lgl: None,
+            pic_bullet_id: None,
         }
     }
 
@@ -122,6 +125,12 @@ impl Level {
         self.is_lgl = Some(IsLgl::new());
         self
     }
+
+    // Shows the image of `NumPicBullet` `id` instead of the level text.
+    pub fn pic_bullet(mut self, id: usize) -> Self {
+        self.pic_bullet_id = Some(id);
+        self
+    }
 }
 
 impl BuildXML for Level {
@@ -130,7 +139,11 @@ impl BuildXML for Level {
             .open_level(&format!("{}", self.level))
             .add_child(&self.start)
             .add_child(&self.format)
-            .add_child(&self.text)
+            .add_child(&self.text);
+        if let Some(id) = self.pic_bullet_id {
+            b = b.level_pic_bullet_id(id);
+        }
+        b = b
             .add_child(&self.jc)
             .add_child(&self.paragraph_property)
             .add_child(&self.run_property)
@@ -12,6 +12,10 @@ impl LevelRestart {
     pub fn new(val: impl Into<u32>) -> Self {
         Self { val: val.into() }
     }
+
+    pub(crate) fn get_val(&self) -> u32 {
+        self.val
+    }
 }
 
 impl BuildXML for LevelRestart {
@@ -55,6 +55,17 @@ impl LineSpacing {
         self.line = Some(line as i32);
         self
     }
+
+    // Attributes given in `other` replace those of self.
+    pub(crate) fn merge(mut self, other: &LineSpacing) -> Self {
+        self.line_rule = other.line_rule.or(self.line_rule);
+        self.before = other.before.or(self.before);
+        self.after = other.after.or(self.after);
+        self.before_lines = other.before_lines.or(self.before_lines);
+        self.after_lines = other.after_lines.or(self.after_lines);
+        self.line = other.line.or(self.line);
+        self
+    }
 }
 
 impl BuildXML for LineSpacing {
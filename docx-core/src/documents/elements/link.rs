@@ -9,6 +9,10 @@ pub struct Link {
 }
 
 impl Link {
+    pub(crate) fn get_val(&self) -> &str {
+        &self.val
+    }
+
     pub fn new(val: impl Into<String>) -> Link {
         Link { val: val.into() }
     }
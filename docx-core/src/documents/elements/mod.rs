@@ -10,11 +10,18 @@ mod bookmark_start;
 mod br;
 mod cant_split;
 mod caps;
+mod cell_delete;
+mod cell_insert;
 mod cell_margins;
+mod cell_merge;
 mod character_spacing;
+mod cnf_style;
 mod color;
+mod color_scheme;
 mod comment;
 mod comment_extended;
+mod comment_extensible;
+mod comment_id;
 mod comment_range_end;
 mod comment_range_start;
 mod data_binding;
@@ -27,6 +34,7 @@ mod doc_defaults;
 mod doc_grid;
 mod doc_id;
 mod doc_var;
+mod document_protection;
 mod drawing;
 mod fld_char;
 mod font;
@@ -34,6 +42,8 @@ mod font_scheme;
 mod footer_reference;
 mod footnote;
 mod footnote_reference;
+mod form_field_data;
+mod format_scheme;
 mod frame_property;
 mod grid_span;
 mod header_reference;
@@ -53,6 +63,7 @@ mod is_lgl;
 mod italic;
 mod italic_cs;
 mod justification;
+mod latent_styles;
 mod level;
 mod level_jc;
 mod level_override;
@@ -61,9 +72,16 @@ mod level_text;
 mod line_spacing;
 mod link;
 mod mc_fallback;
+mod move_from;
+mod move_from_range_end;
+mod move_from_range_start;
+mod move_to;
+mod move_to_range_end;
+mod move_to_range_start;
 mod name;
 mod next;
 mod num_pages;
+mod num_pic_bullet;
 mod number_format;
 mod numbering;
 mod numbering_id;
@@ -79,16 +97,23 @@ mod paragraph_property;
 mod paragraph_property_change;
 mod paragraph_property_default;
 mod paragraph_style;
+mod password_hash;
+mod perm_end;
+mod perm_start;
+mod person;
 mod pic;
 mod positional_tab;
+mod proof_state;
 mod q_format;
 mod run;
 mod run_fonts;
 mod run_property;
+mod run_property_change;
 mod run_property_default;
 mod run_style;
 mod section;
 mod section_property;
+mod section_property_change;
 mod shading;
 mod shape;
 mod spec_vanish;
@@ -107,17 +132,27 @@ mod table_cell;
 mod table_cell_borders;
 mod table_cell_margins;
 mod table_cell_property;
+mod table_cell_property_change;
+mod table_cell_spacing;
 mod table_cell_width;
+mod table_data;
 mod table_grid;
+mod table_grid_estimator;
 mod table_indent;
 mod table_layout;
+mod table_look;
 mod table_of_contents;
 mod table_of_contents_item;
+mod table_operations;
 mod table_position_property;
 mod table_property;
+mod table_property_change;
+mod table_property_exceptions;
 mod table_row;
 mod table_row_property;
+mod table_row_property_change;
 mod table_style;
+mod table_style_property;
 mod table_width;
 mod tabs;
 mod text;
@@ -126,6 +161,8 @@ mod text_border;
 mod text_box;
 mod text_box_content;
 mod text_direction;
+mod theme_color;
+mod theme_font_lang;
 mod underline;
 mod v_align;
 mod vanish;
@@ -134,6 +171,7 @@ mod vertical_merge;
 mod wp_anchor;
 mod wps_shape;
 mod wps_text_box;
+mod write_protection;
 mod zoom;
 
 pub use a_graphic::*;
@@ -148,11 +186,18 @@ pub use bookmark_start::*;
 pub use br::*;
 pub use cant_split::*;
 pub use caps::*;
+pub use cell_delete::*;
+pub use cell_insert::*;
 pub use cell_margins::*;
+pub use cell_merge::*;
 pub use character_spacing::*;
+pub use cnf_style::*;
 pub use color::*;
+pub use color_scheme::*;
 pub use comment::*;
 pub use comment_extended::*;
+pub use comment_extensible::*;
+pub use comment_id::*;
 pub use comment_range_end::*;
 pub use comment_range_start::*;
 pub use data_binding::*;
@@ -165,6 +210,7 @@ pub use doc_defaults::*;
 pub use doc_grid::*;
 pub use doc_id::*;
 pub use doc_var::*;
+pub use document_protection::*;
 pub use drawing::*;
 pub use fld_char::*;
 pub use font::*;
@@ -172,6 +218,8 @@ pub use font_scheme::*;
 pub use footer_reference::*;
 pub use footnote::*;
 pub use footnote_reference::*;
+pub use form_field_data::*;
+pub use format_scheme::*;
 pub use frame_property::*;
 pub use grid_span::*;
 pub use header_reference::*;
@@ -191,6 +239,7 @@ pub use is_lgl::*;
 pub use italic::*;
 pub use italic_cs::*;
 pub use justification::*;
+pub use latent_styles::*;
 pub use level::*;
 pub use level_jc::*;
 pub use level_override::*;
@@ -199,9 +248,16 @@ pub use level_text::*;
 pub use line_spacing::*;
 pub use link::*;
 pub use mc_fallback::*;
+pub use move_from::*;
+pub use move_from_range_end::*;
+pub use move_from_range_start::*;
+pub use move_to::*;
+pub use move_to_range_end::*;
+pub use move_to_range_start::*;
 pub use name::*;
 pub use next::*;
 pub use num_pages::*;
+pub use num_pic_bullet::*;
 pub use number_format::*;
 pub use numbering::*;
 pub use numbering_id::*;
@@ -217,16 +273,23 @@ pub use paragraph_property::*;
 pub use paragraph_property_change::*;
 pub use paragraph_property_default::*;
 pub use paragraph_style::*;
+pub use password_hash::*;
+pub use perm_end::*;
+pub use perm_start::*;
+pub use person::*;
 pub use pic::*;
 pub use positional_tab::*;
+pub use proof_state::*;
 pub use q_format::*;
 pub use run::*;
 pub use run_fonts::*;
 pub use run_property::*;
+pub use run_property_change::*;
 pub use run_property_default::*;
 pub use run_style::*;
 pub use section::*;
 pub use section_property::*;
+pub use section_property_change::*;
 pub use shading::*;
 pub use shape::*;
 pub use spec_vanish::*;
@@ -245,17 +308,25 @@ pub use table_cell::*;
 pub use table_cell_borders::*;
 pub use table_cell_margins::*;
 pub use table_cell_property::*;
+pub use table_cell_property_change::*;
+pub use table_cell_spacing::*;
 pub use table_cell_width::*;
+pub use table_data::*;
 pub use table_grid::*;
 pub use table_indent::*;
 pub use table_layout::*;
+pub use table_look::*;
 pub use table_of_contents::*;
 pub use table_of_contents_item::*;
 pub use table_position_property::*;
 pub use table_property::*;
+pub use table_property_change::*;
+pub use table_property_exceptions::*;
 pub use table_row::*;
 pub use table_row_property::*;
+pub use table_row_property_change::*;
 pub use table_style::*;
+pub use table_style_property::*;
 pub use table_width::*;
 pub use tabs::*;
 pub use text::*;
@@ -264,6 +335,8 @@ pub use text_border::*;
 pub use text_box::*;
 pub use text_box_content::*;
 pub use text_direction::*;
+pub use theme_color::*;
+pub use theme_font_lang::*;
 pub use underline::*;
 pub use v_align::*;
 pub use vanish::*;
@@ -272,4 +345,5 @@ pub use vertical_merge::*;
 pub use wp_anchor::*;
 pub use wps_shape::*;
 pub use wps_text_box::*;
+pub use write_protection::*;
 pub use zoom::*;
@@ -0,0 +1,96 @@
+use serde::Serialize;
+
+use crate::xml_builder::*;
+use crate::{documents::*, escape};
+
+// Content moved away from this location with revision tracking.
+// The runs are written with `w:delText` like deleted runs.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MoveFrom {
+    pub author: String,
+    pub date: String,
+    pub children: Vec<DeleteChild>,
+}
+
+impl Default for MoveFrom {
+    fn default() -> MoveFrom {
+        MoveFrom {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            children: vec![],
+        }
+    }
+}
+
+impl MoveFrom {
+    pub fn new() -> MoveFrom {
+        Default::default()
+    }
+
+    pub fn add_run(mut self, run: Run) -> MoveFrom {
+        self.children.push(DeleteChild::Run(run));
+        self
+    }
+
+    pub fn add_comment_start(mut self, comment: Comment) -> MoveFrom {
+        self.children
+            .push(DeleteChild::CommentStart(Box::new(CommentRangeStart::new(
+                comment,
+            ))));
+        self
+    }
+
+    pub fn add_comment_end(mut self, id: usize) -> MoveFrom {
+        self.children
+            .push(DeleteChild::CommentEnd(CommentRangeEnd::new(id)));
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> MoveFrom {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> MoveFrom {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for MoveFrom {}
+
+impl BuildXML for MoveFrom {
+    #[allow(clippy::needless_borrow)]
+    fn build(&self) -> Vec<u8> {
+        let id = self.generate();
+        let mut b = XMLBuilder::new().open_move_from(&id, &self.author, &self.date);
+        for c in &self.children {
+            match c {
+                DeleteChild::Run(t) => b = b.add_child(t),
+                DeleteChild::CommentStart(c) => b = b.add_child(c),
+                DeleteChild::CommentEnd(c) => b = b.add_child(c),
+            }
+        }
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_move_from() {
+        let b = MoveFrom::new()
+            .add_run(Run::new().add_delete_text("Hello"))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:moveFrom w:id="123" w:author="unnamed" w:date="1970-01-01T00:00:00Z"><w:r><w:rPr /><w:delText xml:space="preserve">Hello</w:delText></w:r></w:moveFrom>"#
+        );
+    }
+}
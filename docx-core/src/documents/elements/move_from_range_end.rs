@@ -0,0 +1,41 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MoveFromRangeEnd {
+    pub id: usize,
+}
+
+impl MoveFromRangeEnd {
+    pub fn new(id: usize) -> MoveFromRangeEnd {
+        MoveFromRangeEnd { id }
+    }
+}
+
+impl BuildXML for MoveFromRangeEnd {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .move_from_range_end(&format!("{}", self.id))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_move_from_range_end() {
+        let b = MoveFromRangeEnd::new(1).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:moveFromRangeEnd w:id="1" />"#
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::escape;
+use crate::xml_builder::*;
+
+// Start of the source of a move. `name` pairs it with the `w:moveToRangeStart` of the destination.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveFromRangeStart {
+    pub id: usize,
+    pub name: String,
+    pub author: String,
+    pub date: String,
+}
+
+impl MoveFromRangeStart {
+    pub fn new(id: usize, name: impl Into<String>) -> MoveFromRangeStart {
+        MoveFromRangeStart {
+            id,
+            name: name.into(),
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+        }
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> MoveFromRangeStart {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> MoveFromRangeStart {
+        self.date = date.into();
+        self
+    }
+}
+
+impl BuildXML for MoveFromRangeStart {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .move_from_range_start(
+                &format!("{}", self.id),
+                &self.name,
+                &self.author,
+                &self.date,
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_move_from_range_start() {
+        let b = MoveFromRangeStart::new(1, "move1").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:moveFromRangeStart w:id="1" w:name="move1" w:author="unnamed" w:date="1970-01-01T00:00:00Z" />"#
+        );
+    }
+}
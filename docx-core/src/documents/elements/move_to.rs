@@ -0,0 +1,93 @@
+use serde::Serialize;
+
+use super::*;
+
+use crate::documents::{BuildXML, HistoryId, Run};
+use crate::{escape, xml_builder::*};
+
+// Content moved to this location with revision tracking.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MoveTo {
+    pub children: Vec<InsertChild>,
+    pub author: String,
+    pub date: String,
+}
+
+impl Default for MoveTo {
+    fn default() -> MoveTo {
+        MoveTo {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            children: vec![],
+        }
+    }
+}
+
+impl MoveTo {
+    pub fn new() -> MoveTo {
+        Default::default()
+    }
+
+    pub fn add_run(mut self, run: Run) -> MoveTo {
+        self.children.push(InsertChild::Run(Box::new(run)));
+        self
+    }
+
+    pub fn add_comment_start(mut self, comment: Comment) -> MoveTo {
+        self.children
+            .push(InsertChild::CommentStart(Box::new(CommentRangeStart::new(
+                comment,
+            ))));
+        self
+    }
+
+    pub fn add_comment_end(mut self, id: usize) -> MoveTo {
+        self.children
+            .push(InsertChild::CommentEnd(CommentRangeEnd::new(id)));
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> MoveTo {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> MoveTo {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for MoveTo {}
+
+impl BuildXML for MoveTo {
+    #[allow(clippy::needless_borrow)]
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .open_move_to(&self.generate(), &self.author, &self.date)
+            .add_children(&self.children)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_move_to() {
+        let b = MoveTo::new()
+            .add_run(Run::new().add_text("Hello"))
+            .author("bokuweb")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:moveTo w:id="123" w:author="bokuweb" w:date="1970-01-01T00:00:00Z"><w:r><w:rPr /><w:t xml:space="preserve">Hello</w:t></w:r></w:moveTo>"#
+        );
+    }
+}
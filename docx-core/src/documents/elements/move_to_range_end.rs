@@ -0,0 +1,41 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MoveToRangeEnd {
+    pub id: usize,
+}
+
+impl MoveToRangeEnd {
+    pub fn new(id: usize) -> MoveToRangeEnd {
+        MoveToRangeEnd { id }
+    }
+}
+
+impl BuildXML for MoveToRangeEnd {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .move_to_range_end(&format!("{}", self.id))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_move_to_range_end() {
+        let b = MoveToRangeEnd::new(1).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:moveToRangeEnd w:id="1" />"#
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::escape;
+use crate::xml_builder::*;
+
+// Start of the destination of a move. `name` pairs it with the `w:moveFromRangeStart` of the source.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveToRangeStart {
+    pub id: usize,
+    pub name: String,
+    pub author: String,
+    pub date: String,
+}
+
+impl MoveToRangeStart {
+    pub fn new(id: usize, name: impl Into<String>) -> MoveToRangeStart {
+        MoveToRangeStart {
+            id,
+            name: name.into(),
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+        }
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> MoveToRangeStart {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> MoveToRangeStart {
+        self.date = date.into();
+        self
+    }
+}
+
+impl BuildXML for MoveToRangeStart {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .move_to_range_start(
+                &format!("{}", self.id),
+                &self.name,
+                &self.author,
+                &self.date,
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_move_to_range_start() {
+        let b = MoveToRangeStart::new(1, "move1").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:moveToRangeStart w:id="1" w:name="move1" w:author="unnamed" w:date="1970-01-01T00:00:00Z" />"#
+        );
+    }
+}
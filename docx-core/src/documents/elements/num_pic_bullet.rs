@@ -0,0 +1,71 @@
+use serde::Serialize;
+
+use crate::documents::{BuildXML, Pic};
+use crate::xml_builder::*;
+
+const EMU_PER_PT: f32 = 12700.0;
+
+// An image used as list bullet, referenced by `Level::pic_bullet`.
+// Written as a VML shape in numbering.xml, the image goes to the media of numbering.xml.rels.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NumPicBullet {
+    pub id: usize,
+    pub pic: Pic,
+}
+
+impl NumPicBullet {
+    pub fn new(id: usize, pic: Pic) -> Self {
+        Self { id, pic }
+    }
+}
+
+impl BuildXML for NumPicBullet {
+    fn build(&self) -> Vec<u8> {
+        let (w, h) = self.pic.size;
+        let style = format!(
+            "width:{}pt;height:{}pt",
+            w as f32 / EMU_PER_PT,
+            h as f32 / EMU_PER_PT
+        );
+        XMLBuilder::new()
+            .open_num_pic_bullet(&format!("{}", self.id))
+            .open_pict()
+            .open_bullet_shape(
+                &format!("_x0000_i{}", 1025 + self.id),
+                "#_x0000_t75",
+                &style,
+                "t",
+            )
+            .image_data(&self.pic.id, "")
+            .close()
+            .close()
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_num_pic_bullet() {
+        let b =
+            NumPicBullet::new(0, Pic::with_empty().id("rIdImage1").size(114300, 114300)).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r##"<w:numPicBullet w:numPicBulletId="0">
+  <w:pict>
+    <v:shape id="_x0000_i1025" type="#_x0000_t75" style="width:9pt;height:9pt" o:bullet="t">
+      <v:imagedata r:id="rIdImage1" o:title="" />
+    </v:shape>
+  </w:pict>
+</w:numPicBullet>"##
+        );
+    }
+}
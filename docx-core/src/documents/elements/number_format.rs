@@ -12,6 +12,219 @@ impl NumberFormat {
     pub fn new(val: impl Into<String>) -> Self {
         Self { val: val.into() }
     }
+
+    // Renders `n` as the list number of this format, i.e. 4 is "iv" in lowerRoman.
+    // Unknown formats, and numbers too large for roman numerals, letters or words, fall back
+    // to decimal.
+    pub fn format(&self, n: usize) -> String {
+        match self.val.as_str() {
+            "none" | "bullet" => "".to_owned(),
+            "decimalZero" => format!("{:02}", n),
+            "decimalFullWidth" | "decimalFullWidth2" => n
+                .to_string()
+                .chars()
+                .map(|c| char::from_u32(c as u32 - '0' as u32 + 0xFF10).unwrap_or(c))
+                .collect(),
+            "decimalEnclosedCircle" if (1..=20).contains(&n) => {
+                char::from_u32(0x2460 + n as u32 - 1).unwrap().to_string()
+            }
+            "upperRoman" if n <= MAX_ROMAN => roman(n),
+            "lowerRoman" if n <= MAX_ROMAN => roman(n).to_lowercase(),
+            "upperLetter" if n <= MAX_LETTER => letter(n),
+            "lowerLetter" if n <= MAX_LETTER => letter(n).to_lowercase(),
+            "ordinal" => format!("{}{}", n, ordinal_suffix(n)),
+            "cardinalText" if n as u64 <= MAX_TEXT => capitalize(&english(n)),
+            "ordinalText" if n as u64 <= MAX_TEXT => capitalize(&english_ordinal(n)),
+            "japaneseCounting" => cjk_counting(n, false),
+            "chineseCounting" | "chineseCountingThousand" | "taiwaneseCounting" => {
+                cjk_counting(n, true)
+            }
+            "ideographDigital" | "japaneseDigitalTenThousand" => n
+                .to_string()
+                .chars()
+                .map(|c| CJK_DIGITS[c as usize - '0' as usize])
+                .collect(),
+            "ideographTraditional" if n > 0 => {
+                IDEOGRAPH_TRADITIONAL[(n - 1) % IDEOGRAPH_TRADITIONAL.len()].to_string()
+            }
+            _ => n.to_string(),
+        }
+    }
+}
+
+// The largest number written without a run of four or more M.
+const MAX_ROMAN: usize = 3999;
+// Word repeats a letter at most 30 times, ZZZ...Z being 780.
+const MAX_LETTER: usize = 26 * 30;
+const MAX_TEXT: u64 = 999_999_999_999;
+const SCALES: [(usize, &str); 3] = [
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1000, "thousand"),
+];
+const CJK_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+const IDEOGRAPH_TRADITIONAL: [char; 10] =
+    ['甲', '乙', '丙', '丁', '戊', '己', '庚', '辛', '壬', '癸'];
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+fn roman(mut n: usize) -> String {
+    if n == 0 {
+        return "0".to_owned();
+    }
+    let numerals = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut s = String::new();
+    for (v, r) in numerals.iter() {
+        while n >= *v {
+            s.push_str(r);
+            n -= v;
+        }
+    }
+    s
+}
+
+// A, B, ..., Z, AA, BB, ... as Word repeats the letter after Z.
+fn letter(n: usize) -> String {
+    if n == 0 {
+        return "0".to_owned();
+    }
+    let c = (b'A' + ((n - 1) % 26) as u8) as char;
+    c.to_string().repeat((n - 1) / 26 + 1)
+}
+
+fn ordinal_suffix(n: usize) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+fn english(n: usize) -> String {
+    match n {
+        0..=19 => ONES[n].to_owned(),
+        20..=99 if n % 10 == 0 => TENS[n / 10].to_owned(),
+        20..=99 => format!("{}-{}", TENS[n / 10], ONES[n % 10]),
+        100..=999 if n % 100 == 0 => format!("{} hundred", ONES[n / 100]),
+        100..=999 => format!("{} hundred {}", ONES[n / 100], english(n % 100)),
+        _ => {
+            let (unit, name) = SCALES.iter().find(|(unit, _)| n >= *unit).unwrap();
+            if n % unit == 0 {
+                format!("{} {}", english(n / unit), name)
+            } else {
+                format!("{} {} {}", english(n / unit), name, english(n % unit))
+            }
+        }
+    }
+}
+
+fn english_ordinal(n: usize) -> String {
+    let cardinal = english(n);
+    let (head, last) = match cardinal.rfind(|c| c == ' ' || c == '-') {
+        Some(i) => cardinal.split_at(i + 1),
+        None => ("", cardinal.as_str()),
+    };
+    let last = match last {
+        "one" => "first".to_owned(),
+        "two" => "second".to_owned(),
+        "three" => "third".to_owned(),
+        "five" => "fifth".to_owned(),
+        "eight" => "eighth".to_owned(),
+        "nine" => "ninth".to_owned(),
+        "twelve" => "twelfth".to_owned(),
+        l if l.ends_with('y') => format!("{}ieth", &l[..l.len() - 1]),
+        l => format!("{}th", l),
+    };
+    format!("{}{}", head, last)
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// Counting with 十, 百, 千, 万 and 億. Chinese writes the leading 一 of 百 and 千, and of 十
+// after a higher digit, and marks skipped digits with 零, Japanese omits both.
+fn cjk_counting(n: usize, chinese: bool) -> String {
+    if n == 0 {
+        return CJK_DIGITS[0].to_string();
+    }
+    let mut s = String::new();
+    let mut rest = n;
+    for (unit, name) in [(100_000_000, "億"), (10_000, "万")].iter() {
+        if rest >= *unit {
+            s.push_str(&cjk_counting(rest / unit, chinese));
+            s.push_str(name);
+            rest %= unit;
+            if chinese && rest > 0 && rest < unit / 10 {
+                s.push('零');
+            }
+        }
+    }
+    let mut zero = false;
+    for (unit, name) in [(1000, "千"), (100, "百"), (10, "十"), (1, "")].iter() {
+        let d = rest / unit;
+        rest %= unit;
+        if d == 0 {
+            zero = !s.is_empty();
+            continue;
+        }
+        if chinese && zero {
+            s.push('零');
+        }
+        zero = false;
+        // 十 alone is ten, 一 is only written before 百 and 千, or a 十 following them, in chinese.
+        if d > 1 || *unit == 1 || (chinese && (*unit >= 100 || !s.is_empty())) {
+            s.push(CJK_DIGITS[d]);
+        }
+        s.push_str(name);
+        if rest == 0 {
+            break;
+        }
+    }
+    s
 }
 
 impl BuildXML for NumberFormat {
@@ -38,6 +251,45 @@ mod tests {
     use pretty_assertions::assert_eq;
     use std::str;
 
+    #[test]
+    fn test_format() {
+        let cases = [
+            ("decimal", 12, "12"),
+            ("decimalZero", 7, "07"),
+            ("upperRoman", 1994, "MCMXCIV"),
+            ("lowerRoman", 4, "iv"),
+            ("lowerLetter", 28, "bb"),
+            ("ordinal", 112, "112th"),
+            ("ordinal", 22, "22nd"),
+            ("cardinalText", 121, "One hundred twenty-one"),
+            ("ordinalText", 40, "Fortieth"),
+            ("cardinalText", 1_000_000, "One million"),
+            (
+                "cardinalText",
+                2_001_000_003,
+                "Two billion one million three",
+            ),
+            ("ordinalText", 3_000_000, "Three millionth"),
+            ("upperRoman", 4000, "4000"),
+            ("cardinalText", 1_000_000_000_000, "1000000000000"),
+            ("upperLetter", 780, &"Z".repeat(30)),
+            ("lowerLetter", 781, "781"),
+            ("japaneseCounting", 10, "十"),
+            ("japaneseCounting", 105, "百五"),
+            ("chineseCounting", 105, "一百零五"),
+            ("chineseCounting", 110, "一百一十"),
+            ("chineseCounting", 1010, "一千零一十"),
+            ("chineseCounting", 10, "十"),
+            ("japaneseCounting", 23456, "二万三千四百五十六"),
+            ("ideographDigital", 205, "二〇五"),
+            ("decimalEnclosedCircle", 3, "③"),
+            ("bullet", 3, ""),
+        ];
+        for (format, n, expected) in cases.iter() {
+            assert_eq!(NumberFormat::new(*format).format(*n), *expected);
+        }
+    }
+
     #[test]
     fn test_start() {
         let c = NumberFormat::new("decimal");
@@ -37,6 +37,10 @@ impl PageSize {
         }
     }
 
+    pub(crate) fn get_width(&self) -> u32 {
+        self.w
+    }
+
     pub fn width(mut self, w: u32) -> PageSize {
         self.w = w;
         self
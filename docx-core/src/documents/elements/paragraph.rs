@@ -3,6 +3,7 @@ use serde::Serialize;
 
 use super::*;
 use crate::documents::BuildXML;
+use crate::escape::replace_escaped;
 use crate::types::*;
 use crate::xml_builder::*;
 
@@ -39,6 +40,14 @@ pub enum ParagraphChild {
     StructuredDataTag(Box<StructuredDataTag>),
     PageNum(Box<PageNum>),
     NumPages(Box<NumPages>),
+    PermStart(PermStart),
+    PermEnd(PermEnd),
+    MoveFrom(MoveFrom),
+    MoveTo(MoveTo),
+    MoveFromRangeStart(MoveFromRangeStart),
+    MoveFromRangeEnd(MoveFromRangeEnd),
+    MoveToRangeStart(MoveToRangeStart),
+    MoveToRangeEnd(MoveToRangeEnd),
 }
 
 impl BuildXML for ParagraphChild {
@@ -55,6 +64,14 @@ impl BuildXML for ParagraphChild {
             ParagraphChild::StructuredDataTag(v) => v.build(),
             ParagraphChild::PageNum(v) => v.build(),
             ParagraphChild::NumPages(v) => v.build(),
+            ParagraphChild::PermStart(v) => v.build(),
+            ParagraphChild::PermEnd(v) => v.build(),
+            ParagraphChild::MoveFrom(v) => v.build(),
+            ParagraphChild::MoveTo(v) => v.build(),
+            ParagraphChild::MoveFromRangeStart(v) => v.build(),
+            ParagraphChild::MoveFromRangeEnd(v) => v.build(),
+            ParagraphChild::MoveToRangeStart(v) => v.build(),
+            ParagraphChild::MoveToRangeEnd(v) => v.build(),
         }
     }
 }
@@ -131,6 +148,54 @@ impl Serialize for ParagraphChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            ParagraphChild::PermStart(ref r) => {
+                let mut t = serializer.serialize_struct("PermStart", 2)?;
+                t.serialize_field("type", "permStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::PermEnd(ref r) => {
+                let mut t = serializer.serialize_struct("PermEnd", 2)?;
+                t.serialize_field("type", "permEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveFrom(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFrom", 2)?;
+                t.serialize_field("type", "moveFrom")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveTo(ref r) => {
+                let mut t = serializer.serialize_struct("MoveTo", 2)?;
+                t.serialize_field("type", "moveTo")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveFromRangeStart(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFromRangeStart", 2)?;
+                t.serialize_field("type", "moveFromRangeStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveFromRangeEnd(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFromRangeEnd", 2)?;
+                t.serialize_field("type", "moveFromRangeEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveToRangeStart(ref r) => {
+                let mut t = serializer.serialize_struct("MoveToRangeStart", 2)?;
+                t.serialize_field("type", "moveToRangeStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveToRangeEnd(ref r) => {
+                let mut t = serializer.serialize_struct("MoveToRangeEnd", 2)?;
+                t.serialize_field("type", "moveToRangeEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
         }
     }
 }
@@ -203,6 +268,49 @@ impl Paragraph {
         self
     }
 
+    pub fn add_perm_start(mut self, p: PermStart) -> Paragraph {
+        self.children.push(ParagraphChild::PermStart(p));
+        self
+    }
+
+    pub fn add_perm_end(mut self, id: usize) -> Paragraph {
+        self.children
+            .push(ParagraphChild::PermEnd(PermEnd::new(id)));
+        self
+    }
+
+    pub fn add_move_from(mut self, m: MoveFrom) -> Paragraph {
+        self.children.push(ParagraphChild::MoveFrom(m));
+        self
+    }
+
+    pub fn add_move_to(mut self, m: MoveTo) -> Paragraph {
+        self.children.push(ParagraphChild::MoveTo(m));
+        self
+    }
+
+    pub fn add_move_from_range_start(mut self, s: MoveFromRangeStart) -> Paragraph {
+        self.children.push(ParagraphChild::MoveFromRangeStart(s));
+        self
+    }
+
+    pub fn add_move_from_range_end(mut self, id: usize) -> Paragraph {
+        self.children
+            .push(ParagraphChild::MoveFromRangeEnd(MoveFromRangeEnd::new(id)));
+        self
+    }
+
+    pub fn add_move_to_range_start(mut self, s: MoveToRangeStart) -> Paragraph {
+        self.children.push(ParagraphChild::MoveToRangeStart(s));
+        self
+    }
+
+    pub fn add_move_to_range_end(mut self, id: usize) -> Paragraph {
+        self.children
+            .push(ParagraphChild::MoveToRangeEnd(MoveToRangeEnd::new(id)));
+        self
+    }
+
     pub fn add_comment_start(mut self, comment: Comment) -> Paragraph {
         self.children.push(ParagraphChild::CommentStart(Box::new(
             CommentRangeStart::new(comment),
@@ -384,6 +492,79 @@ impl Paragraph {
         self
     }
 
+    // Add a legacy form field. Text inputs show their default value as the result.
+    pub fn add_form_field(self, d: FormFieldData) -> Self {
+        let instr = InstrText::Unsupported(format!(" {} ", d.instr()));
+        let result = match d.field {
+            Some(FormField::TextInput(ref t)) => {
+                Some(t.default.clone().unwrap_or_else(|| "\u{2002}".repeat(5)))
+            }
+            _ => None,
+        };
+        let mut run = Run::new().add_form_field_char(d).add_instr_text(instr);
+        if let Some(result) = result {
+            run = run
+                .add_field_char(FieldCharType::Separate, false)
+                .add_text(result);
+        }
+        self.add_run(run.add_field_char(FieldCharType::End, false))
+    }
+
+    // Returns legacy form fields in this paragraph with their current values.
+    pub fn form_field_values(&self) -> Vec<(FormFieldData, FormFieldValue)> {
+        let mut values = vec![];
+        let mut current: Option<FormFieldData> = None;
+        let mut result = String::new();
+        let mut in_result = false;
+        // Depth of fields nested in the current form field.
+        let mut depth = 0;
+        let runs = self.children.iter().flat_map(|c| match c {
+            ParagraphChild::Run(r) => vec![r.as_ref()],
+            ParagraphChild::Insert(i) => i
+                .children
+                .iter()
+                .filter_map(|c| match c {
+                    InsertChild::Run(r) => Some(r.as_ref()),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        });
+        for run in runs {
+            for c in run.children.iter() {
+                match c {
+                    RunChild::FieldChar(f) if current.is_none() => {
+                        if let (FieldCharType::Begin, Some(d)) =
+                            (f.field_char_type, &f.form_field_data)
+                        {
+                            current = Some(d.clone());
+                            result = String::new();
+                            in_result = false;
+                            depth = 0;
+                        }
+                    }
+                    RunChild::FieldChar(f) => match f.field_char_type {
+                        FieldCharType::Begin => depth += 1,
+                        FieldCharType::Separate if depth == 0 => in_result = true,
+                        FieldCharType::End if depth > 0 => depth -= 1,
+                        FieldCharType::End => {
+                            if let Some(d) = current.take() {
+                                let v = d.value(&result);
+                                values.push((d, v));
+                            }
+                        }
+                        _ => {}
+                    },
+                    RunChild::Text(t) if current.is_some() && in_result && depth == 0 => {
+                        result.push_str(&replace_escaped(&t.text));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        values
+    }
+
     // frameProperty
     pub fn wrap(mut self, wrap: impl Into<String>) -> Self {
         self.property.frame_property = Some(FrameProperty {
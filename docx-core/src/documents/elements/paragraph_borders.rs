@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, ThemeColor};
 use crate::types::*;
 use crate::xml_builder::*;
 
@@ -12,10 +12,9 @@ pub struct ParagraphBorder {
     pub size: usize,
     pub space: usize,
     pub color: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_color: Option<ThemeColor>,
     // pub shadow: Option<bool>,
-    // pub theme_color: Option<String>,
-    // pub theme_shade: Option<String>,
-    // pub theme_tint: Option<String>,
     // pub frame: Option<bool>,
 }
 
@@ -27,10 +26,8 @@ impl ParagraphBorder {
             size: 2,
             space: 0,
             color: "auto".to_owned(),
+            theme_color: None,
             // shadow: None,
-            // theme_color: None,
-            // theme_shade: None,
-            // theme_tint: None,
             // frame: None,
         }
     }
@@ -54,26 +51,16 @@ impl ParagraphBorder {
         self
     }
 
+    pub fn theme_color(mut self, theme_color: ThemeColor) -> Self {
+        self.theme_color = Some(theme_color);
+        self
+    }
+
     // pub fn shadow(mut self, shadow: bool) -> Self {
     //     self.shadow = Some(shadow);
     //     self
     // }
     //
-    // pub fn theme_color(mut self, theme_color: impl Into<String>) -> Self {
-    //     self.theme_color = Some(theme_color.into());
-    //     self
-    // }
-    //
-    // pub fn theme_shade(mut self, theme_shade: impl Into<String>) -> Self {
-    //     self.theme_shade = Some(theme_shade.into());
-    //     self
-    // }
-    //
-    // pub fn theme_tint(mut self, theme_tint: impl Into<String>) -> Self {
-    //     self.theme_tint = Some(theme_tint.into());
-    //     self
-    // }
-    //
     // pub fn frame(mut self, frame: bool) -> Self {
     //     self.frame = Some(frame);
     //     self
@@ -87,13 +74,26 @@ impl BuildXML for ParagraphBorder {
             let val = self.val.to_string();
             let space = self.space.to_string();
             let size = self.size.to_string();
+            let theme = self.theme_color.as_ref();
             match self.position {
-                ParagraphBorderPosition::Top => base.paragraph_border_top(&val, &space, &size, &self.color),
-                ParagraphBorderPosition::Left => base.paragraph_border_left(&val, &space, &size, &self.color),
-                ParagraphBorderPosition::Bottom => base.paragraph_border_bottom(&val, &space, &size, &self.color),
-                ParagraphBorderPosition::Right => base.paragraph_border_right(&val, &space, &size, &self.color),
-                ParagraphBorderPosition::Between => base.paragraph_border_between(&val, &space, &size, &self.color),
-                ParagraphBorderPosition::Bar => base.paragraph_border_bar(&val, &space, &size, &self.color),
+                ParagraphBorderPosition::Top => {
+                    base.paragraph_border_top(&val, &space, &size, &self.color, theme)
+                }
+                ParagraphBorderPosition::Left => {
+                    base.paragraph_border_left(&val, &space, &size, &self.color, theme)
+                }
+                ParagraphBorderPosition::Bottom => {
+                    base.paragraph_border_bottom(&val, &space, &size, &self.color, theme)
+                }
+                ParagraphBorderPosition::Right => {
+                    base.paragraph_border_right(&val, &space, &size, &self.color, theme)
+                }
+                ParagraphBorderPosition::Between => {
+                    base.paragraph_border_between(&val, &space, &size, &self.color, theme)
+                }
+                ParagraphBorderPosition::Bar => {
+                    base.paragraph_border_bar(&val, &space, &size, &self.color, theme)
+                }
             }
         };
         base.build()
@@ -24,6 +24,10 @@ impl ParagraphPropertyDefault {
         self.paragraph_property = p;
         self
     }
+
+    pub(crate) fn get_paragraph_property(&self) -> &ParagraphProperty {
+        &self.paragraph_property
+    }
 }
 
 impl Default for ParagraphPropertyDefault {
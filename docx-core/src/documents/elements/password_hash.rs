@@ -0,0 +1,57 @@
+use serde::Serialize;
+
+use crate::crypto::*;
+
+// Hashed password for document and write protection.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordHash {
+    pub algorithm_name: String,
+    // base64 encoded
+    pub hash_value: String,
+    // base64 encoded
+    pub salt_value: String,
+    pub spin_count: u32,
+}
+
+impl PasswordHash {
+    pub fn new(password: &str) -> Self {
+        Self::with_salt(password, &random_bytes(16), DEFAULT_SPIN_COUNT)
+    }
+
+    pub fn with_salt(password: &str, salt: &[u8], spin_count: u32) -> Self {
+        Self {
+            algorithm_name: "SHA-512".to_owned(),
+            hash_value: base64::encode(hash_word_password(password, salt, spin_count)),
+            salt_value: base64::encode(salt),
+            spin_count,
+        }
+    }
+
+    // Only SHA-512 hashes can be verified.
+    pub fn verify(&self, password: &str) -> bool {
+        if self.algorithm_name != "SHA-512" {
+            return false;
+        }
+        match base64::decode(&self.salt_value) {
+            Ok(salt) => {
+                Self::with_salt(password, &salt, self.spin_count).hash_value == self.hash_value
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        let h = PasswordHash::with_salt("secret", &[7u8; 16], 100);
+        assert!(h.verify("secret"));
+        assert!(!h.verify("Secret"));
+        assert!(PasswordHash::new("secret").verify("secret"));
+    }
+}
@@ -0,0 +1,36 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PermEnd {
+    pub id: usize,
+}
+
+impl PermEnd {
+    pub fn new(id: usize) -> PermEnd {
+        PermEnd { id }
+    }
+}
+
+impl BuildXML for PermEnd {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new().perm_end(&format!("{}", self.id)).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_perm_end() {
+        let b = PermEnd::new(1).build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:permEnd w:id="1" />"#);
+    }
+}
@@ -0,0 +1,81 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// Start of a range which can be edited in a protected document.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PermStart {
+    pub id: usize,
+    // A single user, i.e. "DOMAIN\user" or an email address.
+    pub ed: Option<String>,
+    pub ed_grp: Option<EditGroupType>,
+    pub col_first: Option<usize>,
+    pub col_last: Option<usize>,
+}
+
+impl PermStart {
+    pub fn new(id: usize) -> PermStart {
+        PermStart {
+            id,
+            ed: None,
+            ed_grp: None,
+            col_first: None,
+            col_last: None,
+        }
+    }
+
+    pub fn ed(mut self, ed: impl Into<String>) -> Self {
+        self.ed = Some(ed.into());
+        self
+    }
+
+    pub fn ed_grp(mut self, g: EditGroupType) -> Self {
+        self.ed_grp = Some(g);
+        self
+    }
+
+    pub fn col_first(mut self, c: usize) -> Self {
+        self.col_first = Some(c);
+        self
+    }
+
+    pub fn col_last(mut self, c: usize) -> Self {
+        self.col_last = Some(c);
+        self
+    }
+}
+
+impl BuildXML for PermStart {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .perm_start(
+                &format!("{}", self.id),
+                self.ed.as_ref(),
+                self.ed_grp,
+                self.col_first,
+                self.col_last,
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_perm_start() {
+        let b = PermStart::new(1).ed_grp(EditGroupType::Everyone).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:permStart w:id="1" w:edGrp="everyone" />"#
+        );
+    }
+}
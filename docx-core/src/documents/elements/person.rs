@@ -0,0 +1,72 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e. <w15:person w15:author="bokuweb"><w15:presenceInfo w15:providerId="None" w15:userId="bokuweb"/></w15:person>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub author: String,
+    pub presence_info: Option<PresenceInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresenceInfo {
+    pub provider_id: String,
+    pub user_id: String,
+}
+
+impl Person {
+    pub fn new(author: impl Into<String>) -> Person {
+        Self {
+            author: author.into(),
+            presence_info: None,
+        }
+    }
+
+    pub fn presence_info(
+        mut self,
+        provider_id: impl Into<String>,
+        user_id: impl Into<String>,
+    ) -> Person {
+        self.presence_info = Some(PresenceInfo {
+            provider_id: provider_id.into(),
+            user_id: user_id.into(),
+        });
+        self
+    }
+}
+
+impl BuildXML for Person {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new().open_person(&self.author);
+        if let Some(ref p) = self.presence_info {
+            b = b.presence_info(&p.provider_id, &p.user_id);
+        }
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_person() {
+        let b = Person::new("bokuweb")
+            .presence_info("AD", "S::bokuweb@example.com::1234")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w15:person w15:author="bokuweb">
+  <w15:presenceInfo w15:providerId="AD" w15:userId="S::bokuweb@example.com::1234" />
+</w15:person>"#
+        );
+    }
+}
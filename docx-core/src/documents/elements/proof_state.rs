@@ -0,0 +1,62 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::types::ProofStateType;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofState {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spelling: Option<ProofStateType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grammar: Option<ProofStateType>,
+}
+
+impl ProofState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn spelling(mut self, s: ProofStateType) -> Self {
+        self.spelling = Some(s);
+        self
+    }
+
+    pub fn grammar(mut self, s: ProofStateType) -> Self {
+        self.grammar = Some(s);
+        self
+    }
+}
+
+impl BuildXML for ProofState {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .proof_state(
+                self.spelling.map(|s| s.to_string()).as_deref(),
+                self.grammar.map(|s| s.to_string()).as_deref(),
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_proof_state() {
+        let b = ProofState::new()
+            .spelling(ProofStateType::Clean)
+            .grammar(ProofStateType::Dirty)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:proofState w:spelling="clean" w:grammar="dirty" />"#
+        );
+    }
+}
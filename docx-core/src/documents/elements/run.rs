@@ -186,6 +186,12 @@ impl Run {
         self
     }
 
+    pub fn add_form_field_char(mut self, d: FormFieldData) -> Run {
+        let f = FieldChar::new(FieldCharType::Begin).form_field_data(d);
+        self.children.push(RunChild::FieldChar(f));
+        self
+    }
+
     pub fn add_instr_text(mut self, i: InstrText) -> Run {
         self.children.push(RunChild::InstrText(Box::new(i)));
         self
@@ -248,6 +254,11 @@ impl Run {
         self
     }
 
+    pub fn theme_color(mut self, theme: ThemeColor) -> Run {
+        self.run_property = self.run_property.theme_color(theme);
+        self
+    }
+
     pub fn highlight(mut self, color: impl Into<String>) -> Run {
         self.run_property = self.run_property.highlight(color);
         self
@@ -314,6 +325,10 @@ impl Run {
         self.run_property = self.run_property.shading(shading);
         self
     }
+    pub fn run_property_change(mut self, c: RunPropertyChange) -> Run {
+        self.run_property = self.run_property.run_property_change(c);
+        self
+    }
 }
 
 impl BuildXML for Run {
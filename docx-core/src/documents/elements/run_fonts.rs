@@ -83,6 +83,39 @@ impl RunFonts {
         self.hint = Some(f.into());
         self
     }
+
+    // Explicit font names, without theme font references.
+    pub(crate) fn font_names(&self) -> impl Iterator<Item = &String> {
+        vec![
+            self.ascii.as_ref(),
+            self.hi_ansi.as_ref(),
+            self.east_asia.as_ref(),
+            self.cs.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    // Fonts given in `other` replace those of self.
+    pub(crate) fn merge(mut self, other: &RunFonts) -> Self {
+        let fonts = [
+            (&mut self.ascii, &other.ascii),
+            (&mut self.hi_ansi, &other.hi_ansi),
+            (&mut self.east_asia, &other.east_asia),
+            (&mut self.cs, &other.cs),
+            (&mut self.ascii_theme, &other.ascii_theme),
+            (&mut self.hi_ansi_theme, &other.hi_ansi_theme),
+            (&mut self.east_asia_theme, &other.east_asia_theme),
+            (&mut self.cs_theme, &other.cs_theme),
+            (&mut self.hint, &other.hint),
+        ];
+        for (target, font) in fonts {
+            if font.is_some() {
+                *target = font.clone();
+            }
+        }
+        self
+    }
 }
 
 impl BuildXML for RunFonts {
@@ -52,6 +52,8 @@ pub struct RunProperty {
     pub positional_tab: Option<PositionalTab>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shading: Option<Shading>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_property_change: Option<RunPropertyChange>,
 }
 
 impl RunProperty {
@@ -80,6 +82,13 @@ impl RunProperty {
         self
     }
 
+    // Keeps the literal color as the fallback for readers without the theme.
+    pub fn theme_color(mut self, theme: ThemeColor) -> RunProperty {
+        let color = self.color.take().unwrap_or_else(|| Color::new("auto"));
+        self.color = Some(color.theme(theme));
+        self
+    }
+
     pub fn highlight(mut self, color: impl Into<String>) -> RunProperty {
         self.highlight = Some(Highlight::new(color));
         self
@@ -168,6 +177,11 @@ impl RunProperty {
         self.shading = Some(s);
         self
     }
+
+    pub fn run_property_change(mut self, c: RunPropertyChange) -> Self {
+        self.run_property_change = Some(c);
+        self
+    }
 }
 
 impl BuildXML for RunProperty {
@@ -196,6 +210,7 @@ impl BuildXML for RunProperty {
             .add_optional_child(&self.style)
             .add_optional_child(&self.positional_tab)
             .add_optional_child(&self.shading)
+            .add_optional_child(&self.run_property_change)
             .close()
             .build()
     }
@@ -34,6 +34,10 @@ impl RunPropertyDefault {
         self.run_property = p;
         self
     }
+
+    pub(crate) fn get_run_property(&self) -> &RunProperty {
+        &self.run_property
+    }
 }
 
 impl Default for RunPropertyDefault {
@@ -45,6 +45,8 @@ pub struct SectionProperty {
     pub section_type: Option<SectionType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_num_type: Option<PageNumType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section_property_change: Option<SectionPropertyChange>,
 }
 
 impl SectionProperty {
@@ -164,6 +166,11 @@ impl SectionProperty {
         self.page_num_type = Some(h);
         self
     }
+
+    pub fn section_property_change(mut self, c: SectionPropertyChange) -> Self {
+        self.section_property_change = Some(c);
+        self
+    }
 }
 
 impl Default for SectionProperty {
@@ -192,6 +199,7 @@ impl Default for SectionProperty {
             even_footer: None,
             section_type: None,
             page_num_type: None,
+            section_property_change: None,
         }
     }
 }
@@ -224,7 +232,9 @@ impl BuildXML for SectionProperty {
             b = b.title_pg();
         }
 
-        b.close().build()
+        b.add_optional_child(&self.section_property_change)
+            .close()
+            .build()
     }
 }
 
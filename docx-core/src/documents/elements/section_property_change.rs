@@ -0,0 +1,81 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::escape;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionPropertyChange {
+    pub author: String,
+    pub date: String,
+    pub property: Box<SectionProperty>,
+}
+
+impl Default for SectionPropertyChange {
+    fn default() -> SectionPropertyChange {
+        Self {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            property: Box::default(),
+        }
+    }
+}
+
+impl SectionPropertyChange {
+    pub fn new() -> SectionPropertyChange {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn property(mut self, p: SectionProperty) -> SectionPropertyChange {
+        self.property = Box::new(p);
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> SectionPropertyChange {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> SectionPropertyChange {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for SectionPropertyChange {}
+
+impl BuildXML for SectionPropertyChange {
+    #[allow(clippy::needless_borrow)]
+    fn build(&self) -> Vec<u8> {
+        let id = self.generate();
+        XMLBuilder::new()
+            .open_section_property_change(&id, &self.author, &self.date)
+            .add_child(self.property.as_ref())
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_sect_pr_change_default() {
+        let b = SectionPropertyChange::new()
+            .property(SectionProperty::new())
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:sectPrChange w:id="123" w:author="unnamed" w:date="1970-01-01T00:00:00Z"><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:cols w:space="425" w:num="1" />
+</w:sectPr></w:sectPrChange>"#
+        );
+    }
+}
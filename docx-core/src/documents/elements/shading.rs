@@ -1,16 +1,19 @@
 use serde::Serialize;
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, ThemeColor};
 use crate::types::*;
 use crate::xml_builder::*;
 
-// INFO: Theme is not supported now.
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Shading {
     pub shd_type: ShdType,
     pub color: String,
     pub fill: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_color: Option<ThemeColor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_fill: Option<ThemeColor>,
 }
 
 impl Default for Shading {
@@ -19,6 +22,8 @@ impl Default for Shading {
             shd_type: ShdType::Clear,
             color: "auto".to_owned(),
             fill: "FFFFFF".to_owned(),
+            theme_color: None,
+            theme_fill: None,
         }
     }
 }
@@ -38,6 +43,16 @@ impl Shading {
         self
     }
 
+    pub fn theme_color(mut self, theme_color: ThemeColor) -> Shading {
+        self.theme_color = Some(theme_color);
+        self
+    }
+
+    pub fn theme_fill(mut self, theme_fill: ThemeColor) -> Shading {
+        self.theme_fill = Some(theme_fill);
+        self
+    }
+
     pub fn shd_type(mut self, shd_type: ShdType) -> Shading {
         self.shd_type = shd_type;
         self
@@ -47,7 +62,13 @@ impl Shading {
 impl BuildXML for Shading {
     fn build(&self) -> Vec<u8> {
         XMLBuilder::new()
-            .shd(&self.shd_type.to_string(), &self.color, &self.fill)
+            .shd(
+                &self.shd_type.to_string(),
+                &self.color,
+                &self.fill,
+                self.theme_color.as_ref(),
+                self.theme_fill.as_ref(),
+            )
             .build()
     }
 }
@@ -27,6 +27,8 @@ impl Default for StructuredDataTag {
 #[derive(Debug, Clone, PartialEq)]
 pub enum StructuredDataTagChild {
     Run(Box<Run>),
+    Insert(Insert),
+    Delete(Delete),
     Paragraph(Box<Paragraph>),
     Table(Box<Table>),
     BookmarkStart(BookmarkStart),
@@ -40,6 +42,8 @@ impl BuildXML for StructuredDataTagChild {
     fn build(&self) -> Vec<u8> {
         match self {
             StructuredDataTagChild::Run(v) => v.build(),
+            StructuredDataTagChild::Insert(v) => v.build(),
+            StructuredDataTagChild::Delete(v) => v.build(),
             StructuredDataTagChild::Paragraph(v) => v.build(),
             StructuredDataTagChild::Table(v) => v.build(),
             StructuredDataTagChild::BookmarkStart(v) => v.build(),
@@ -63,6 +67,18 @@ impl Serialize for StructuredDataTagChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            StructuredDataTagChild::Insert(ref r) => {
+                let mut t = serializer.serialize_struct("Insert", 2)?;
+                t.serialize_field("type", "insert")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            StructuredDataTagChild::Delete(ref r) => {
+                let mut t = serializer.serialize_struct("Delete", 2)?;
+                t.serialize_field("type", "delete")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
             StructuredDataTagChild::Paragraph(ref r) => {
                 let mut t = serializer.serialize_struct("Paragraph", 2)?;
                 t.serialize_field("type", "paragraph")?;
@@ -120,6 +136,16 @@ impl StructuredDataTag {
         self
     }
 
+    pub fn add_insert(mut self, insert: Insert) -> Self {
+        self.children.push(StructuredDataTagChild::Insert(insert));
+        self
+    }
+
+    pub fn add_delete(mut self, delete: Delete) -> Self {
+        self.children.push(StructuredDataTagChild::Delete(delete));
+        self
+    }
+
     pub fn add_paragraph(mut self, p: Paragraph) -> Self {
         if p.has_numbering {
             self.has_numbering = true
@@ -148,6 +174,71 @@ impl StructuredDataTag {
         self
     }
 
+    pub fn raw_text(&self) -> String {
+        let mut texts = vec![];
+        let mut inline = String::new();
+        for c in self.children.iter() {
+            match c {
+                StructuredDataTagChild::Run(run) => {
+                    for c in run.children.iter() {
+                        if let RunChild::Text(t) = c {
+                            inline.push_str(&t.text);
+                        }
+                    }
+                }
+                StructuredDataTagChild::Paragraph(p) => texts.push(p.raw_text()),
+                StructuredDataTagChild::StructuredDataTag(t) => inline.push_str(&t.raw_text()),
+                _ => {}
+            }
+        }
+        if !inline.is_empty() {
+            texts.push(inline);
+        }
+        texts.join("\n")
+    }
+
+    // Replace the content with `text`, keeping the formatting of the existing content.
+    // Block level tags get one paragraph per line.
+    pub(crate) fn set_bound_text(&mut self, text: &str) {
+        let paragraph = self.children.iter().find_map(|c| match c {
+            StructuredDataTagChild::Paragraph(p) => Some(p.clone()),
+            _ => None,
+        });
+        if let Some(paragraph) = paragraph {
+            let run_property = paragraph
+                .children
+                .iter()
+                .find_map(|c| match c {
+                    ParagraphChild::Run(r) => Some(r.run_property.clone()),
+                    _ => None,
+                })
+                .unwrap_or_else(|| self.property.run_property.clone());
+            self.children = text
+                .split('\n')
+                .map(|line| {
+                    let mut p = Paragraph::new();
+                    p.property = paragraph.property.clone();
+                    p.has_numbering = paragraph.has_numbering;
+                    let mut run = Run::new().add_text(line);
+                    run.run_property = run_property.clone();
+                    StructuredDataTagChild::Paragraph(Box::new(p.add_run(run)))
+                })
+                .collect();
+        } else {
+            let run_property = self
+                .children
+                .iter()
+                .find_map(|c| match c {
+                    StructuredDataTagChild::Run(r) => Some(r.run_property.clone()),
+                    _ => None,
+                })
+                .unwrap_or_else(|| self.property.run_property.clone());
+            let mut run = Run::new().add_text(text);
+            run.run_property = run_property;
+            self.children = vec![StructuredDataTagChild::Run(Box::new(run))];
+        }
+    }
+
     fn inner_build(&self) -> Vec<u8> {
         XMLBuilder::new()
             .open_structured_tag()
@@ -192,4 +283,19 @@ mod tests {
 </w:sdt>"#
         );
     }
+
+    #[test]
+    fn test_sdt_set_bound_text() {
+        let mut sdt = StructuredDataTag::new()
+            .data_binding(DataBinding::new().xpath("root/hello"))
+            .add_run(Run::new().add_text("placeholder").bold());
+        sdt.set_bound_text("World");
+        assert_eq!(
+            sdt.children,
+            vec![StructuredDataTagChild::Run(Box::new(
+                Run::new().add_text("World").bold()
+            ))]
+        );
+        assert_eq!(sdt.raw_text(), "World");
+    }
 }
@@ -21,6 +21,18 @@ pub struct Style {
     pub next: Option<Next>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub link: Option<Link>,
+    // The style applied to content without a style of this type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub table_style_properties: Vec<TableStyleProperty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_priority: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semi_hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unhide_when_used: Option<bool>,
+    pub q_format: bool,
 }
 
 impl Default for Style {
@@ -39,6 +51,12 @@ impl Default for Style {
             based_on: None,
             next: None,
             link: None,
+            default: None,
+            table_style_properties: vec![],
+            ui_priority: None,
+            semi_hidden: None,
+            unhide_when_used: None,
+            q_format: true,
         }
     }
 }
@@ -73,6 +91,11 @@ impl Style {
         self
     }
 
+    pub fn default_style(mut self) -> Self {
+        self.default = Some(true);
+        self
+    }
+
     pub fn size(mut self, size: usize) -> Self {
         self.run_property = self.run_property.size(size);
         self
@@ -83,6 +106,11 @@ impl Style {
         self
     }
 
+    pub fn theme_color(mut self, theme: ThemeColor) -> Self {
+        self.run_property = self.run_property.theme_color(theme);
+        self
+    }
+
     pub fn highlight(mut self, color: impl Into<String>) -> Self {
         self.run_property = self.run_property.highlight(color);
         self
@@ -156,6 +184,45 @@ impl Style {
         self
     }
 
+    pub fn line_spacing(mut self, spacing: LineSpacing) -> Self {
+        self.paragraph_property = self.paragraph_property.line_spacing(spacing);
+        self
+    }
+
+    pub fn keep_next(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.keep_next(v);
+        self
+    }
+
+    pub fn keep_lines(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.keep_lines(v);
+        self
+    }
+
+    // Sort order in Word's style gallery, lower comes first.
+    pub fn ui_priority(mut self, v: usize) -> Self {
+        self.ui_priority = Some(v);
+        self
+    }
+
+    // Hidden from Word's style lists.
+    pub fn semi_hidden(mut self) -> Self {
+        self.semi_hidden = Some(true);
+        self
+    }
+
+    // Shown in Word's style lists once the style is used.
+    pub fn unhide_when_used(mut self) -> Self {
+        self.unhide_when_used = Some(true);
+        self
+    }
+
+    // Shown in Word's style gallery, new styles are by default.
+    pub fn q_format(mut self, v: bool) -> Self {
+        self.q_format = v;
+        self
+    }
+
     pub fn table_property(mut self, p: TableProperty) -> Self {
         self.table_property = p;
         self
@@ -216,6 +283,11 @@ impl Style {
         self
     }
 
+    pub fn add_table_style_property(mut self, p: TableStyleProperty) -> Self {
+        self.table_style_properties.push(p);
+        self
+    }
+
     // frameProperty
     pub fn wrap(mut self, wrap: impl Into<String>) -> Self {
         self.paragraph_property.frame_property = Some(FrameProperty {
@@ -319,7 +391,7 @@ impl BuildXML for Style {
         let b = XMLBuilder::new();
         // Set "Normal" as default if you need change these values please fix it
         let mut b = b
-            .open_style(self.style_type, &self.style_id)
+            .open_style(self.style_type, &self.style_id, self.default == Some(true))
             .add_child(&self.name)
             .add_child(&self.run_property)
             .add_child(&self.paragraph_property);
@@ -338,8 +410,24 @@ impl BuildXML for Style {
             b = b.add_child(link)
         }
 
-        b.add_child(&QFormat::new())
-            .add_optional_child(&self.based_on)
+        if let Some(v) = self.ui_priority {
+            b = b.ui_priority(v);
+        }
+
+        if self.semi_hidden == Some(true) {
+            b = b.semi_hidden();
+        }
+
+        if self.unhide_when_used == Some(true) {
+            b = b.unhide_when_used();
+        }
+
+        if self.q_format {
+            b = b.add_child(&QFormat::new());
+        }
+
+        b.add_optional_child(&self.based_on)
+            .add_children(&self.table_style_properties)
             .close()
             .build()
     }
@@ -362,4 +450,35 @@ mod tests {
             r#"<w:style w:type="paragraph" w:styleId="Heading"><w:name w:val="Heading1" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:qFormat /></w:style>"#
         );
     }
+
+    #[test]
+    fn test_build_without_q_format() {
+        let c = Style::new("Header", StyleType::Paragraph)
+            .name("header")
+            .ui_priority(99)
+            .unhide_when_used()
+            .q_format(false);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:style w:type="paragraph" w:styleId="Header"><w:name w:val="header" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:uiPriority w:val="99" />
+  <w:unhideWhenUsed />
+</w:style>"#
+        );
+    }
+
+    #[test]
+    fn test_build_table_style() {
+        let c = Style::new("Zebra", StyleType::Table)
+            .name("Zebra")
+            .add_table_style_property(
+                TableStyleProperty::new(TableStyleOverrideType::Band1Horz)
+                    .shading(Shading::new().fill("EEEEEE")),
+            );
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:style w:type="table" w:styleId="Zebra"><w:name w:val="Zebra" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:tcPr /><w:tblPr><w:tblW w:w="0" w:type="dxa" /><w:jc w:val="left" /><w:tblBorders><w:top w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:left w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:bottom w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:right w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:insideH w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:insideV w:val="single" w:sz="2" w:space="0" w:color="000000" /></w:tblBorders></w:tblPr><w:qFormat /><w:tblStylePr w:type="band1Horz"><w:pPr><w:rPr /></w:pPr><w:rPr /><w:tcPr><w:shd w:val="clear" w:color="auto" w:fill="EEEEEE" /></w:tcPr></w:tblStylePr></w:style>"#
+        );
+    }
 }
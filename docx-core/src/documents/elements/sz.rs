@@ -12,6 +12,10 @@ impl Sz {
     pub fn new(val: usize) -> Sz {
         Sz { val }
     }
+
+    pub(crate) fn get_val(&self) -> usize {
+        self.val
+    }
 }
 
 impl BuildXML for Sz {
@@ -5,6 +5,7 @@ use super::*;
 use crate::documents::BuildXML;
 use crate::types::*;
 use crate::xml_builder::*;
+use crate::DocxError;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -55,6 +56,39 @@ impl Table {
         }
     }
 
+    // Build a table from a grid of cells, computing tblGrid, gridSpan and vMerge
+    // from the column widths and merge regions.
+    // Percent and auto columns share `text_width`, the width between the page margins in twips.
+    pub fn from_rows(data: TableData, text_width: usize) -> Result<Table, DocxError> {
+        data.into_table(text_width)
+    }
+
+    // Estimate tblGrid from the text in each column, for tables without an explicit grid.
+    // `text_width` is the available width in twips and `default_size` the font size in half-points.
+    pub fn estimate_grid(&self, text_width: usize, default_size: usize) -> Vec<usize> {
+        table_grid_estimator::estimate_grid(self, text_width, default_size)
+    }
+
+    // Fill empty grids of this table and of tables nested in its cells.
+    pub(crate) fn fill_grid(&mut self, text_width: usize, default_size: usize) {
+        if self.grid.is_empty() {
+            self.grid = self.estimate_grid(text_width, default_size);
+        }
+        for TableChild::TableRow(row) in &mut self.rows {
+            let mut column = 0;
+            for TableRowChild::TableCell(cell) in &mut row.cells {
+                let span = cell.property.get_grid_span();
+                let width = self.grid.iter().skip(column).take(span).sum();
+                for c in &mut cell.children {
+                    if let TableCellContent::Table(t) = c {
+                        t.fill_grid(width, default_size);
+                    }
+                }
+                column += span;
+            }
+        }
+    }
+
     pub fn add_row(mut self, row: TableRow) -> Table {
         self.rows.push(TableChild::TableRow(row));
         self
@@ -90,6 +124,26 @@ impl Table {
         self
     }
 
+    pub fn look(mut self, l: TableLook) -> Self {
+        self.property = self.property.look(l);
+        self
+    }
+
+    pub fn caption(mut self, v: impl Into<String>) -> Self {
+        self.property = self.property.caption(v);
+        self
+    }
+
+    pub fn description(mut self, v: impl Into<String>) -> Self {
+        self.property = self.property.description(v);
+        self
+    }
+
+    pub fn table_property_change(mut self, c: TablePropertyChange) -> Self {
+        self.property = self.property.table_property_change(c);
+        self
+    }
+
     pub fn width(mut self, w: usize, t: WidthType) -> Table {
         self.property = self.property.width(w, t);
         self
@@ -194,4 +248,60 @@ mod tests {
             r#"{"rows":[],"grid":[100,200,300],"hasNumbering":false,"property":{"width":{"width":0,"widthType":"auto"},"justification":"left","borders":{"top":{"borderType":"single","size":2,"color":"000000","position":"top","space":0},"left":{"borderType":"single","size":2,"color":"000000","position":"left","space":0},"bottom":{"borderType":"single","size":2,"color":"000000","position":"bottom","space":0},"right":{"borderType":"single","size":2,"color":"000000","position":"right","space":0},"insideH":{"borderType":"single","size":2,"color":"000000","position":"insideH","space":0},"insideV":{"borderType":"single","size":2,"color":"000000","position":"insideV","space":0}}}}"#
         );
     }
+
+    #[test]
+    fn test_from_rows_with_merges() {
+        let t = Table::from_rows(
+            TableData::new(vec![
+                vec!["Region", "Q1", "Q2"],
+                vec!["North", "1", "2"],
+                vec!["", "3", "4"],
+                vec!["Total", "", ""],
+            ])
+            .header()
+            .column_widths(vec![
+                TableColumnWidth::Dxa(2000),
+                TableColumnWidth::Dxa(1000),
+                TableColumnWidth::Dxa(1000),
+            ])
+            .merge(TableMergeRegion::new(1, 0, 2, 1))
+            .merge(TableMergeRegion::new(3, 0, 1, 3)),
+            8504,
+        )
+        .unwrap();
+        let expected = Table::new(vec![
+            TableRow::new(vec![
+                TableCell::from("Region").width(2000, WidthType::Dxa),
+                TableCell::from("Q1").width(1000, WidthType::Dxa),
+                TableCell::from("Q2").width(1000, WidthType::Dxa),
+            ])
+            .table_header(),
+            TableRow::new(vec![
+                TableCell::from("North")
+                    .vertical_merge(VMergeType::Restart)
+                    .width(2000, WidthType::Dxa),
+                TableCell::from("1").width(1000, WidthType::Dxa),
+                TableCell::from("2").width(1000, WidthType::Dxa),
+            ]),
+            TableRow::new(vec![
+                TableCell::new()
+                    .vertical_merge(VMergeType::Continue)
+                    .width(2000, WidthType::Dxa),
+                TableCell::from("3").width(1000, WidthType::Dxa),
+                TableCell::from("4").width(1000, WidthType::Dxa),
+            ]),
+            TableRow::new(vec![TableCell::from("Total")
+                .grid_span(3)
+                .width(4000, WidthType::Dxa)]),
+        ])
+        .set_grid(vec![2000, 1000, 1000])
+        .width(4000, WidthType::Dxa);
+        assert_eq!(t, expected);
+    }
+
+    #[test]
+    fn test_from_rows_with_uneven_rows() {
+        let t = Table::from_rows(TableData::new(vec![vec!["a", "b"], vec!["c"]]), 8504);
+        assert!(matches!(t, Err(DocxError::InvalidTable(_))));
+    }
 }
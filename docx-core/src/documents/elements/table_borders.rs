@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, ThemeColor};
 use crate::types::*;
 use crate::xml_builder::*;
 
@@ -24,6 +24,8 @@ pub struct TableBorder {
     pub color: String,
     position: TableBorderPosition,
     space: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_color: Option<ThemeColor>,
 }
 
 impl TableBorder {
@@ -34,6 +36,7 @@ impl TableBorder {
             size: 2,
             space: 0,
             color: "000000".to_owned(),
+            theme_color: None,
         }
     }
 
@@ -42,6 +45,11 @@ impl TableBorder {
         self
     }
 
+    pub fn theme_color(mut self, theme_color: ThemeColor) -> TableBorder {
+        self.theme_color = Some(theme_color);
+        self
+    }
+
     pub fn size(mut self, size: usize) -> TableBorder {
         self.size = size;
         self
@@ -57,24 +65,48 @@ impl BuildXML for TableBorder {
     fn build(&self) -> Vec<u8> {
         let base = XMLBuilder::new();
         let base = match self.position {
-            TableBorderPosition::Top => {
-                base.border_top(self.border_type, self.size, self.space, &self.color)
-            }
-            TableBorderPosition::Left => {
-                base.border_left(self.border_type, self.size, self.space, &self.color)
-            }
-            TableBorderPosition::Bottom => {
-                base.border_bottom(self.border_type, self.size, self.space, &self.color)
-            }
-            TableBorderPosition::Right => {
-                base.border_right(self.border_type, self.size, self.space, &self.color)
-            }
-            TableBorderPosition::InsideH => {
-                base.border_inside_h(self.border_type, self.size, self.space, &self.color)
-            }
-            TableBorderPosition::InsideV => {
-                base.border_inside_v(self.border_type, self.size, self.space, &self.color)
-            }
+            TableBorderPosition::Top => base.border_top(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableBorderPosition::Left => base.border_left(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableBorderPosition::Bottom => base.border_bottom(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableBorderPosition::Right => base.border_right(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableBorderPosition::InsideH => base.border_inside_h(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableBorderPosition::InsideV => base.border_inside_v(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
         };
         base.build()
     }
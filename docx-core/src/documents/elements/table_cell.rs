@@ -138,6 +138,46 @@ impl TableCell {
         self.property = self.property.clear_all_border();
         self
     }
+
+    pub fn cnf_style(mut self, val: impl Into<String>) -> Self {
+        self.property = self.property.cnf_style(val);
+        self
+    }
+
+    pub fn no_wrap(mut self) -> Self {
+        self.property = self.property.no_wrap();
+        self
+    }
+
+    pub fn fit_text(mut self) -> Self {
+        self.property = self.property.fit_text();
+        self
+    }
+
+    pub fn hide_mark(mut self) -> Self {
+        self.property = self.property.hide_mark();
+        self
+    }
+
+    pub fn cell_insert(mut self, i: CellInsert) -> Self {
+        self.property = self.property.cell_insert(i);
+        self
+    }
+
+    pub fn cell_delete(mut self, d: CellDelete) -> Self {
+        self.property = self.property.cell_delete(d);
+        self
+    }
+
+    pub fn cell_merge(mut self, m: CellMerge) -> Self {
+        self.property = self.property.cell_merge(m);
+        self
+    }
+
+    pub fn table_cell_property_change(mut self, c: TableCellPropertyChange) -> Self {
+        self.property = self.property.table_cell_property_change(c);
+        self
+    }
 }
 
 impl Default for TableCell {
@@ -152,6 +192,18 @@ impl Default for TableCell {
     }
 }
 
+impl From<&str> for TableCell {
+    fn from(text: &str) -> Self {
+        TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(text)))
+    }
+}
+
+impl From<String> for TableCell {
+    fn from(text: String) -> Self {
+        TableCell::from(text.as_str())
+    }
+}
+
 impl BuildXML for TableCell {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
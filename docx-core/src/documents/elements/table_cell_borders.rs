@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, ThemeColor};
 use crate::types::*;
 use crate::xml_builder::*;
 
@@ -26,6 +26,8 @@ pub struct TableCellBorder {
     pub color: String,
     position: TableCellBorderPosition,
     space: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_color: Option<ThemeColor>,
 }
 
 impl TableCellBorder {
@@ -36,6 +38,7 @@ impl TableCellBorder {
             size: 2,
             space: 0,
             color: "000000".to_owned(),
+            theme_color: None,
         }
     }
 
@@ -44,6 +47,11 @@ impl TableCellBorder {
         self
     }
 
+    pub fn theme_color(mut self, theme_color: ThemeColor) -> TableCellBorder {
+        self.theme_color = Some(theme_color);
+        self
+    }
+
     pub fn size(mut self, size: usize) -> TableCellBorder {
         self.size = size;
         self
@@ -71,30 +79,62 @@ impl BuildXML for TableCellBorder {
     fn build(&self) -> Vec<u8> {
         let base = XMLBuilder::new();
         let base = match self.position {
-            TableCellBorderPosition::Top => {
-                base.border_top(self.border_type, self.size, self.space, &self.color)
-            }
-            TableCellBorderPosition::Left => {
-                base.border_left(self.border_type, self.size, self.space, &self.color)
-            }
-            TableCellBorderPosition::Bottom => {
-                base.border_bottom(self.border_type, self.size, self.space, &self.color)
-            }
-            TableCellBorderPosition::Right => {
-                base.border_right(self.border_type, self.size, self.space, &self.color)
-            }
-            TableCellBorderPosition::InsideH => {
-                base.border_inside_h(self.border_type, self.size, self.space, &self.color)
-            }
-            TableCellBorderPosition::InsideV => {
-                base.border_inside_v(self.border_type, self.size, self.space, &self.color)
-            }
-            TableCellBorderPosition::Tr2bl => {
-                base.border_tr2bl(self.border_type, self.size, self.space, &self.color)
-            }
-            TableCellBorderPosition::Tl2br => {
-                base.border_tl2br(self.border_type, self.size, self.space, &self.color)
-            }
+            TableCellBorderPosition::Top => base.border_top(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableCellBorderPosition::Left => base.border_left(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableCellBorderPosition::Bottom => base.border_bottom(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableCellBorderPosition::Right => base.border_right(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableCellBorderPosition::InsideH => base.border_inside_h(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableCellBorderPosition::InsideV => base.border_inside_v(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableCellBorderPosition::Tr2bl => base.border_tr2bl(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
+            TableCellBorderPosition::Tl2br => base.border_tl2br(
+                self.border_type,
+                self.size,
+                self.space,
+                &self.color,
+                self.theme_color.as_ref(),
+            ),
         };
         base.build()
     }
@@ -14,12 +14,28 @@ pub struct TableCellProperty {
     width: Option<TableCellWidth>,
     borders: Option<TableCellBorders>,
     grid_span: Option<GridSpan>,
-    vertical_merge: Option<VMerge>,
+    pub(crate) vertical_merge: Option<VMerge>,
     vertical_align: Option<VAlign>,
     text_direction: Option<TextDirection>,
     shading: Option<Shading>,
     #[serde(skip_serializing_if = "Option::is_none")]
     margins: Option<CellMargins>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cnf_style: Option<CnfStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    no_wrap: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fit_text: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_mark: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cell_insert: Option<CellInsert>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cell_delete: Option<CellDelete>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cell_merge: Option<CellMerge>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) table_cell_property_change: Option<TableCellPropertyChange>,
 }
 
 impl TableCellProperty {
@@ -52,6 +68,29 @@ impl TableCellProperty {
         self
     }
 
+    pub(crate) fn get_grid_span(&self) -> usize {
+        self.grid_span
+            .as_ref()
+            .map(|s| s.get_val().max(1))
+            .unwrap_or(1)
+    }
+
+    pub(crate) fn set_grid_span(&mut self, v: usize) {
+        self.grid_span = if v > 1 { Some(GridSpan::new(v)) } else { None };
+    }
+
+    pub(crate) fn get_vertical_merge(&self) -> Option<VMergeType> {
+        self.vertical_merge.as_ref().map(|m| m.get_val())
+    }
+
+    pub(crate) fn set_vertical_merge(&mut self, t: Option<VMergeType>) {
+        self.vertical_merge = t.map(VMerge::new);
+    }
+
+    pub(crate) fn set_width(&mut self, v: usize, t: WidthType) {
+        self.width = Some(TableCellWidth::new(v, t));
+    }
+
     pub fn shading(mut self, s: Shading) -> Self {
         self.shading = Some(s);
         self
@@ -121,20 +160,76 @@ impl TableCellProperty {
         }
         self
     }
+
+    pub fn cnf_style(mut self, val: impl Into<String>) -> Self {
+        self.cnf_style = Some(CnfStyle::new(val));
+        self
+    }
+
+    pub fn no_wrap(mut self) -> Self {
+        self.no_wrap = Some(true);
+        self
+    }
+
+    // Squeeze or stretch the text to fill the cell width.
+    pub fn fit_text(mut self) -> Self {
+        self.fit_text = Some(true);
+        self
+    }
+
+    // Ignore the end of cell mark when sizing the row, so an empty cell can be shorter than a line.
+    pub fn hide_mark(mut self) -> Self {
+        self.hide_mark = Some(true);
+        self
+    }
+
+    pub fn cell_insert(mut self, i: CellInsert) -> Self {
+        self.cell_insert = Some(i);
+        self
+    }
+
+    pub fn cell_delete(mut self, d: CellDelete) -> Self {
+        self.cell_delete = Some(d);
+        self
+    }
+
+    pub fn cell_merge(mut self, m: CellMerge) -> Self {
+        self.cell_merge = Some(m);
+        self
+    }
+
+    pub fn table_cell_property_change(mut self, c: TableCellPropertyChange) -> Self {
+        self.table_cell_property_change = Some(c);
+        self
+    }
 }
 
 impl BuildXML for TableCellProperty {
     fn build(&self) -> Vec<u8> {
-        XMLBuilder::new()
+        let mut b = XMLBuilder::new()
             .open_table_cell_property()
+            .add_optional_child(&self.cnf_style)
             .add_optional_child(&self.width)
             .add_optional_child(&self.borders)
             .add_optional_child(&self.grid_span)
             .add_optional_child(&self.vertical_merge)
             .add_optional_child(&self.vertical_align)
             .add_optional_child(&self.text_direction)
-            .add_optional_child(&self.shading)
-            .add_optional_child(&self.margins)
+            .add_optional_child(&self.shading);
+        if self.no_wrap == Some(true) {
+            b = b.no_wrap()
+        }
+        b = b.add_optional_child(&self.margins);
+        if self.fit_text == Some(true) {
+            b = b.table_cell_fit_text()
+        }
+        if self.hide_mark == Some(true) {
+            b = b.hide_mark()
+        }
+        b.add_optional_child(&self.cell_insert)
+            .add_optional_child(&self.cell_delete)
+            .add_optional_child(&self.cell_merge)
+            .add_optional_child(&self.table_cell_property_change)
             .close()
             .build()
     }
@@ -216,4 +311,21 @@ mod tests {
             r#"{"width":null,"borders":null,"gridSpan":null,"verticalMerge":null,"verticalAlign":"center","textDirection":null,"shading":null}"#
         );
     }
+
+    #[test]
+    fn test_no_wrap_and_hide_mark() {
+        let c = TableCellProperty::new()
+            .cnf_style("001000000000")
+            .no_wrap()
+            .fit_text()
+            .hide_mark();
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tcPr><w:cnfStyle w:val="001000000000" /><w:noWrap />
+  <w:tcFitText />
+  <w:hideMark />
+</w:tcPr>"#
+        );
+    }
 }
@@ -0,0 +1,80 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::escape;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TableCellPropertyChange {
+    pub author: String,
+    pub date: String,
+    pub property: Box<TableCellProperty>,
+}
+
+impl Default for TableCellPropertyChange {
+    fn default() -> TableCellPropertyChange {
+        Self {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            property: Box::default(),
+        }
+    }
+}
+
+impl TableCellPropertyChange {
+    pub fn new() -> TableCellPropertyChange {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn property(mut self, p: TableCellProperty) -> TableCellPropertyChange {
+        self.property = Box::new(p);
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> TableCellPropertyChange {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> TableCellPropertyChange {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for TableCellPropertyChange {}
+
+impl BuildXML for TableCellPropertyChange {
+    #[allow(clippy::needless_borrow)]
+    fn build(&self) -> Vec<u8> {
+        let id = self.generate();
+        XMLBuilder::new()
+            .open_table_cell_property_change(&id, &self.author, &self.date)
+            .add_child(self.property.as_ref())
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_tc_pr_change_default() {
+        let b = TableCellPropertyChange::new()
+            .property(TableCellProperty::new().grid_span(2))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tcPrChange w:id="123" w:author="unnamed" w:date="1970-01-01T00:00:00Z"><w:tcPr><w:gridSpan w:val="2" /></w:tcPr></w:tcPrChange>"#
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use super::*;
+use crate::documents::DEFAULT_FONT_SIZE;
+use crate::types::*;
+use crate::DocxError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TableColumnWidth {
+    // twips
+    Dxa(usize),
+    // percent of the text width, e.g. 25.0
+    Pct(f32),
+    Auto,
+}
+
+// A rectangular block of cells merged into its top-left cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableMergeRegion {
+    pub row: usize,
+    pub column: usize,
+    pub row_span: usize,
+    pub column_span: usize,
+}
+
+impl TableMergeRegion {
+    pub fn new(row: usize, column: usize, row_span: usize, column_span: usize) -> Self {
+        Self {
+            row,
+            column,
+            row_span,
+            column_span,
+        }
+    }
+
+    fn contains(&self, row: usize, column: usize) -> bool {
+        row >= self.row
+            && row < self.row + self.row_span
+            && column >= self.column
+            && column < self.column + self.column_span
+    }
+}
+
+// Input of `Table::from_rows`. Every row must have one cell per grid column,
+// cells covered by a merge region other than its top-left one are dropped.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TableData {
+    rows: Vec<Vec<TableCell>>,
+    header: bool,
+    column_widths: Vec<TableColumnWidth>,
+    merges: Vec<TableMergeRegion>,
+}
+
+impl TableData {
+    pub fn new<T: Into<TableCell>>(rows: Vec<Vec<T>>) -> Self {
+        Self {
+            rows: rows
+                .into_iter()
+                .map(|r| r.into_iter().map(Into::into).collect())
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    // Repeat the first row on each page.
+    pub fn header(mut self) -> Self {
+        self.header = true;
+        self
+    }
+
+    pub fn column_widths(mut self, widths: Vec<TableColumnWidth>) -> Self {
+        self.column_widths = widths;
+        self
+    }
+
+    pub fn merge(mut self, region: TableMergeRegion) -> Self {
+        self.merges.push(region);
+        self
+    }
+
+    fn column_count(&self) -> usize {
+        self.rows.first().map(|r| r.len()).unwrap_or_default()
+    }
+
+    fn validate(&self) -> Result<(), DocxError> {
+        let columns = self.column_count();
+        if let Some(i) = self.rows.iter().position(|r| r.len() != columns) {
+            return Err(DocxError::InvalidTable(format!(
+                "row {} has {} cells but the first row has {}",
+                i,
+                self.rows[i].len(),
+                columns
+            )));
+        }
+        if !self.column_widths.is_empty() && self.column_widths.len() != columns {
+            return Err(DocxError::InvalidTable(format!(
+                "{} column widths are given for {} columns",
+                self.column_widths.len(),
+                columns
+            )));
+        }
+        for (i, m) in self.merges.iter().enumerate() {
+            if m.row_span == 0 || m.column_span == 0 {
+                return Err(DocxError::InvalidTable(format!(
+                    "merge region {:?} is empty",
+                    m
+                )));
+            }
+            if m.row + m.row_span > self.rows.len() || m.column + m.column_span > columns {
+                return Err(DocxError::InvalidTable(format!(
+                    "merge region {:?} is outside of the {}x{} table",
+                    m,
+                    self.rows.len(),
+                    columns
+                )));
+            }
+            let overlapped = self.merges[..i].iter().find(|o| {
+                m.row < o.row + o.row_span
+                    && o.row < m.row + m.row_span
+                    && m.column < o.column + o.column_span
+                    && o.column < m.column + m.column_span
+            });
+            if let Some(o) = overlapped {
+                return Err(DocxError::InvalidTable(format!(
+                    "merge regions {:?} and {:?} overlap",
+                    o, m
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // Grid columns in twips. Percent columns are resolved against the text width
+    // and auto columns fit their content into what is left.
+    fn grid(&self, natural: &[usize], text_width: usize) -> Vec<usize> {
+        let columns = self.column_count();
+        let widths = if self.column_widths.is_empty() {
+            vec![TableColumnWidth::Auto; columns]
+        } else {
+            self.column_widths.clone()
+        };
+        let fixed: usize = widths
+            .iter()
+            .map(|w| match w {
+                TableColumnWidth::Dxa(v) => *v,
+                TableColumnWidth::Pct(p) => (text_width as f32 * p / 100.0) as usize,
+                TableColumnWidth::Auto => 0,
+            })
+            .sum();
+        let remaining = text_width.saturating_sub(fixed);
+        let autos: Vec<usize> = widths
+            .iter()
+            .zip(natural)
+            .filter(|(w, _)| **w == TableColumnWidth::Auto)
+            .map(|(_, n)| *n)
+            .collect();
+        let mut autos = if remaining == 0 {
+            vec![text_width / columns.max(1); autos.len()]
+        } else {
+            table_grid_estimator::fit(autos, remaining)
+        }
+        .into_iter();
+        widths
+            .iter()
+            .map(|w| match w {
+                TableColumnWidth::Dxa(v) => *v,
+                TableColumnWidth::Pct(p) => (text_width as f32 * p / 100.0) as usize,
+                TableColumnWidth::Auto => autos.next().unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    // tcW of a cell spanning `columns`. Mixed width types fall back to auto.
+    fn cell_width(&self, columns: std::ops::Range<usize>) -> Option<(usize, WidthType)> {
+        let widths = self.column_widths.get(columns)?;
+        if widths.iter().all(|w| matches!(w, TableColumnWidth::Dxa(_))) {
+            let w = widths
+                .iter()
+                .map(|w| match w {
+                    TableColumnWidth::Dxa(v) => *v,
+                    _ => 0,
+                })
+                .sum();
+            Some((w, WidthType::Dxa))
+        } else if widths.iter().all(|w| matches!(w, TableColumnWidth::Pct(_))) {
+            let p: f32 = widths
+                .iter()
+                .map(|w| match w {
+                    TableColumnWidth::Pct(p) => *p,
+                    _ => 0.0,
+                })
+                .sum();
+            // fiftieths of a percent
+            Some(((p * 50.0).round() as usize, WidthType::Pct))
+        } else {
+            Some((0, WidthType::Auto))
+        }
+    }
+
+    pub(crate) fn into_table(self, text_width: usize) -> Result<Table, DocxError> {
+        self.validate()?;
+        let columns = self.column_count();
+        let table_width = self.cell_width(0..columns);
+        let mut rows = vec![];
+        for (r, cells) in self.rows.iter().enumerate() {
+            let mut row_cells = vec![];
+            let mut c = 0;
+            while c < columns {
+                let region = self.merges.iter().find(|m| m.contains(r, c));
+                let (span, mut cell) = match region {
+                    Some(m) => {
+                        let mut cell = if m.row == r {
+                            cells[c].clone()
+                        } else {
+                            TableCell::new()
+                        };
+                        if m.row_span > 1 {
+                            cell = cell.vertical_merge(if m.row == r {
+                                VMergeType::Restart
+                            } else {
+                                VMergeType::Continue
+                            });
+                        }
+                        if m.column_span > 1 {
+                            cell = cell.grid_span(m.column_span);
+                        }
+                        (m.column_span, cell)
+                    }
+                    None => (1, cells[c].clone()),
+                };
+                if let Some((w, t)) = self.cell_width(c..c + span) {
+                    cell = cell.width(w, t);
+                }
+                row_cells.push(cell);
+                c += span;
+            }
+            let mut row = TableRow::new(row_cells);
+            if self.header && r == 0 {
+                row = row.table_header();
+            }
+            rows.push(row);
+        }
+        let table = Table::new(rows);
+        let natural = table_grid_estimator::natural_widths(&table, text_width, DEFAULT_FONT_SIZE);
+        let mut table = table.set_grid(self.grid(&natural, text_width));
+        if let Some((w, t)) = table_width {
+            table = table.width(w, t);
+        }
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_grid_with_auto_columns() {
+        let data = TableData::new(vec![vec!["a", "b", "c"]]).column_widths(vec![
+            TableColumnWidth::Dxa(2504),
+            TableColumnWidth::Pct(50.0),
+            TableColumnWidth::Auto,
+        ]);
+        // "c" only needs one character and the cell margins.
+        assert_eq!(data.grid(&[316, 316, 316], 8504), vec![2504, 4252, 316]);
+    }
+
+    #[test]
+    fn test_invalid_merge() {
+        let data = TableData::new(vec![vec!["a", "b"], vec!["c", "d"]])
+            .merge(TableMergeRegion::new(0, 0, 2, 1))
+            .merge(TableMergeRegion::new(1, 0, 1, 2));
+        assert!(data.into_table(8504).is_err());
+        let data = TableData::new(vec![vec!["a", "b"]]).merge(TableMergeRegion::new(0, 1, 1, 2));
+        assert!(data.into_table(8504).is_err());
+    }
+}
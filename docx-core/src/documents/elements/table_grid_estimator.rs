@@ -0,0 +1,241 @@
+use super::*;
+use crate::escape::replace_escaped;
+
+// Word's default left and right cell margins (tblCellMar) in twips.
+const CELL_MARGIN: usize = 108 * 2;
+
+// Widths are estimated from character counts since font metrics are not available.
+// Latin glyphs average about half an em, CJK and other wide glyphs take a full em.
+fn char_width(c: char, size: usize) -> usize {
+    // sz is in half-points, so one em is `size * 10` twips.
+    let em = size * 10;
+    if c == '\t' {
+        em * 2
+    } else if is_wide(c) {
+        em
+    } else {
+        em / 2
+    }
+}
+
+fn is_wide(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x115F
+        | 0x2E80..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x20000..=0x3FFFD)
+}
+
+fn run_size(run: &Run, default_size: usize) -> usize {
+    run.run_property
+        .sz
+        .as_ref()
+        .map(|s| s.get_val())
+        .unwrap_or(default_size)
+}
+
+// The widest line of a run list, in twips. Breaks start a new line.
+fn runs_width<'a>(runs: impl Iterator<Item = &'a Run>, size: usize) -> (usize, usize) {
+    let mut widest = 0;
+    let mut line = 0;
+    for run in runs {
+        let size = run_size(run, size);
+        for c in &run.children {
+            match c {
+                RunChild::Text(t) => {
+                    // Text is kept escaped, `&amp;` is a single character.
+                    line += replace_escaped(&t.text)
+                        .chars()
+                        .map(|c| char_width(c, size))
+                        .sum::<usize>();
+                }
+                RunChild::Tab(_) => line += char_width('\t', size),
+                RunChild::Break(_) => {
+                    widest = widest.max(line);
+                    line = 0;
+                }
+                _ => {}
+            }
+        }
+    }
+    (widest, line)
+}
+
+// The run properties of the paragraph only apply to its mark, not to its runs.
+fn paragraph_width(p: &Paragraph, default_size: usize) -> usize {
+    let runs = p.children.iter().flat_map(|c| match c {
+        ParagraphChild::Run(r) => vec![r.as_ref()],
+        ParagraphChild::Insert(i) => i
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                InsertChild::Run(r) => Some(r.as_ref()),
+                _ => None,
+            })
+            .collect(),
+        ParagraphChild::Hyperlink(h) => h
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                ParagraphChild::Run(r) => Some(r.as_ref()),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    });
+    let (widest, last) = runs_width(runs, default_size);
+    widest.max(last)
+}
+
+fn cell_width(cell: &TableCell, text_width: usize, default_size: usize) -> usize {
+    let content = cell
+        .children
+        .iter()
+        .map(|c| match c {
+            TableCellContent::Paragraph(p) => paragraph_width(p, default_size),
+            TableCellContent::Table(t) => t
+                .estimate_grid(text_width.saturating_sub(CELL_MARGIN), default_size)
+                .iter()
+                .sum(),
+            _ => 0,
+        })
+        .max()
+        .unwrap_or_default();
+    content + CELL_MARGIN
+}
+
+pub(crate) fn estimate_grid(table: &Table, text_width: usize, default_size: usize) -> Vec<usize> {
+    fit(natural_widths(table, text_width, default_size), text_width)
+}
+
+pub(crate) fn natural_widths(table: &Table, text_width: usize, default_size: usize) -> Vec<usize> {
+    let columns = table
+        .rows
+        .iter()
+        .map(|TableChild::TableRow(row)| {
+            row.property.get_grid_before()
+                + row
+                    .cells
+                    .iter()
+                    .map(|TableRowChild::TableCell(c)| c.property.get_grid_span())
+                    .sum::<usize>()
+                + row.property.get_grid_after()
+        })
+        .max()
+        .unwrap_or_default();
+    if columns == 0 {
+        return vec![];
+    }
+
+    // Spanning cells only widen a table when single cells leave too little room,
+    // so the natural width of a column is the widest single cell in it.
+    let min = CELL_MARGIN + char_width('0', default_size);
+    let mut desired = vec![min; columns];
+    for TableChild::TableRow(row) in &table.rows {
+        // Cells start after the columns skipped by gridBefore.
+        let mut column = row.property.get_grid_before();
+        for TableRowChild::TableCell(cell) in &row.cells {
+            let span = cell.property.get_grid_span();
+            if span == 1 && column < columns {
+                let w = cell_width(cell, text_width, default_size).min(text_width);
+                desired[column] = desired[column].max(w);
+            }
+            column += span;
+        }
+    }
+    desired
+}
+
+// Shrink natural widths to `text_width`. Columns narrower than an equal share keep
+// their width and the rest split what is left in proportion to their content.
+pub(crate) fn fit(desired: Vec<usize>, text_width: usize) -> Vec<usize> {
+    if desired.iter().sum::<usize>() <= text_width {
+        return desired;
+    }
+    let mut fixed = vec![false; desired.len()];
+    let mut remaining = text_width;
+    loop {
+        let open = fixed.iter().filter(|f| !**f).count();
+        if open == 0 {
+            break;
+        }
+        let share = remaining / open;
+        let mut changed = false;
+        for (i, w) in desired.iter().enumerate() {
+            if !fixed[i] && *w <= share {
+                fixed[i] = true;
+                remaining -= w;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let open_total: usize = desired
+        .iter()
+        .zip(&fixed)
+        .filter(|(_, f)| !**f)
+        .map(|(w, _)| *w)
+        .sum();
+    desired
+        .iter()
+        .zip(&fixed)
+        .map(|(w, f)| {
+            if *f {
+                *w
+            } else {
+                remaining * w / open_total.max(1)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(fit(vec![1000, 2000], 9000), vec![1000, 2000]);
+        assert_eq!(fit(vec![1000, 6000, 12000], 9000), vec![1000, 2666, 5333]);
+    }
+
+    #[test]
+    fn test_estimate_grid() {
+        let table = Table::new(vec![
+            TableRow::new(vec![TableCell::from("No"), TableCell::from("Description")]),
+            TableRow::new(vec![
+                TableCell::from("1"),
+                TableCell::new().add_paragraph(
+                    Paragraph::new().add_run(Run::new().add_text("日本語").size(40)),
+                ),
+            ]),
+            TableRow::new(vec![TableCell::from("merged").grid_span(2)]),
+        ]);
+        // "Description" at 10pt: 11 * 100 + margins, "日本語" at 20pt: 3 * 400 + margins
+        assert_eq!(estimate_grid(&table, 9000, 20), vec![416, 1416]);
+    }
+
+    #[test]
+    fn test_estimate_grid_with_grid_before() {
+        let table = Table::new(vec![
+            TableRow::new(vec![TableCell::from("A"), TableCell::from("B & C")]),
+            TableRow::new(vec![TableCell::new().add_paragraph(
+                Paragraph::new()
+                    .size(40)
+                    .add_run(Run::new().add_text("Description")),
+            )])
+            .grid_before(1),
+        ]);
+        // "B & C" is 5 characters and the 20pt paragraph mark does not widen its runs.
+        assert_eq!(estimate_grid(&table, 9000, 20), vec![316, 1316]);
+    }
+}
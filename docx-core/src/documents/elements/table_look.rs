@@ -0,0 +1,145 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// Selects which conditional formats of the table style apply to a table.
+// i.e. <w:tblLook w:val="04A0" w:firstRow="1" w:lastRow="0" w:firstColumn="1" w:lastColumn="0" w:noHBand="0" w:noVBand="1"/>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableLook {
+    pub first_row: bool,
+    pub last_row: bool,
+    pub first_column: bool,
+    pub last_column: bool,
+    pub no_h_band: bool,
+    pub no_v_band: bool,
+}
+
+impl Default for TableLook {
+    // Same as the look Word applies to new tables.
+    fn default() -> Self {
+        Self {
+            first_row: true,
+            last_row: false,
+            first_column: true,
+            last_column: false,
+            no_h_band: false,
+            no_v_band: true,
+        }
+    }
+}
+
+impl TableLook {
+    pub fn new() -> TableLook {
+        Default::default()
+    }
+
+    pub fn first_row(mut self, v: bool) -> Self {
+        self.first_row = v;
+        self
+    }
+
+    pub fn last_row(mut self, v: bool) -> Self {
+        self.last_row = v;
+        self
+    }
+
+    pub fn first_column(mut self, v: bool) -> Self {
+        self.first_column = v;
+        self
+    }
+
+    pub fn last_column(mut self, v: bool) -> Self {
+        self.last_column = v;
+        self
+    }
+
+    pub fn no_h_band(mut self, v: bool) -> Self {
+        self.no_h_band = v;
+        self
+    }
+
+    pub fn no_v_band(mut self, v: bool) -> Self {
+        self.no_v_band = v;
+        self
+    }
+
+    // Older consumers only read the bitmask in w:val.
+    pub(crate) fn val(&self) -> String {
+        let flags = [
+            (self.first_row, 0x0020),
+            (self.last_row, 0x0040),
+            (self.first_column, 0x0080),
+            (self.last_column, 0x0100),
+            (self.no_h_band, 0x0200),
+            (self.no_v_band, 0x0400),
+        ];
+        let v = flags
+            .iter()
+            .filter(|(on, _)| *on)
+            .fold(0, |acc, (_, bit)| acc | bit);
+        format!("{:04X}", v)
+    }
+
+    // Read the bitmask in w:val, used when the flag attributes are missing.
+    pub(crate) fn from_val(v: &str) -> TableLook {
+        let v = u32::from_str_radix(v, 16).unwrap_or(0);
+        TableLook {
+            first_row: v & 0x0020 != 0,
+            last_row: v & 0x0040 != 0,
+            first_column: v & 0x0080 != 0,
+            last_column: v & 0x0100 != 0,
+            no_h_band: v & 0x0200 != 0,
+            no_v_band: v & 0x0400 != 0,
+        }
+    }
+}
+
+impl BuildXML for TableLook {
+    fn build(&self) -> Vec<u8> {
+        let flag = |v: bool| if v { "1" } else { "0" };
+        XMLBuilder::new()
+            .table_look(
+                &self.val(),
+                flag(self.first_row),
+                flag(self.last_row),
+                flag(self.first_column),
+                flag(self.last_column),
+                flag(self.no_h_band),
+                flag(self.no_v_band),
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_table_look() {
+        let b = TableLook::new().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblLook w:val="04A0" w:firstRow="1" w:lastRow="0" w:firstColumn="1" w:lastColumn="0" w:noHBand="0" w:noVBand="1" />"#
+        );
+    }
+
+    #[test]
+    fn test_table_look_from_val() {
+        assert_eq!(
+            TableLook::from_val("0260"),
+            TableLook::new()
+                .first_row(true)
+                .last_row(true)
+                .first_column(false)
+                .no_h_band(true)
+                .no_v_band(false)
+        );
+    }
+}
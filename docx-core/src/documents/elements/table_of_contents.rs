@@ -60,6 +60,8 @@ pub struct TableOfContents {
     pub after_contents: Vec<TocContent>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub delete: Option<TableOfContentsReviewData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insert: Option<TableOfContentsReviewData>,
 }
 
 impl TableOfContents {
@@ -103,6 +105,14 @@ impl TableOfContents {
         self
     }
 
+    pub fn insert(mut self, author: impl Into<String>, date: impl Into<String>) -> Self {
+        self.insert = Some(TableOfContentsReviewData {
+            author: escape::escape(&author.into()),
+            date: date.into(),
+        });
+        self
+    }
+
     // pub fn tc_field_level_range(mut self, start: usize, end: usize) -> Self {
     //     self.instr = self.instr.tc_field_level_range(start, end);
     //     self
@@ -184,6 +194,17 @@ impl TableOfContents {
                             .add_field_char(FieldCharType::Separate, false),
                     ),
                 )
+            } else if let Some(ref ins) = self.insert {
+                Paragraph::new().add_insert(
+                    Insert::new(
+                        Run::new()
+                            .add_field_char(FieldCharType::Begin, true)
+                            .add_instr_text(InstrText::TOC(self.instr.clone()))
+                            .add_field_char(FieldCharType::Separate, false),
+                    )
+                    .author(&ins.author)
+                    .date(&ins.date),
+                )
             } else {
                 Paragraph::new().add_run(
                     Run::new()
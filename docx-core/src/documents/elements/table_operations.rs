@@ -0,0 +1,603 @@
+use super::*;
+use crate::types::*;
+use crate::DocxError;
+
+// Editing operations on a built table. Cells are addressed by grid column,
+// gridSpan and vMerge are re-numbered after each operation.
+impl Table {
+    pub fn column_count(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|TableChild::TableRow(r)| {
+                layout(r)
+                    .last()
+                    .map(|(start, span)| start + span)
+                    .unwrap_or_else(|| r.property.get_grid_before())
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn insert_row(mut self, index: usize, row: TableRow) -> Result<Table, DocxError> {
+        if index > self.rows.len() {
+            return Err(out_of_range("row", index, self.rows.len() + 1));
+        }
+        self.has_numbering = self.has_numbering || row.has_numbering;
+        self.rows.insert(index, TableChild::TableRow(row));
+        self.normalize_vertical_merges();
+        Ok(self)
+    }
+
+    // Content of a vertically merged cell which starts in the deleted row moves to the row below.
+    pub fn delete_row(mut self, index: usize) -> Result<Table, DocxError> {
+        if index >= self.rows.len() {
+            return Err(out_of_range("row", index, self.rows.len()));
+        }
+        if self.rows.len() == 1 {
+            return Err(DocxError::InvalidTable(
+                "can not delete the last row".to_owned(),
+            ));
+        }
+        let TableChild::TableRow(removed) = self.rows.remove(index);
+        if let Some(TableChild::TableRow(next)) = self.rows.get_mut(index) {
+            let next_layout = layout(next);
+            for (cell, pos) in cells(&removed).zip(layout(&removed)) {
+                if cell.property.get_vertical_merge() != Some(VMergeType::Restart) {
+                    continue;
+                }
+                if let Some(i) = next_layout.iter().position(|p| *p == pos) {
+                    let below = cell_mut(next, i);
+                    if below.property.get_vertical_merge() == Some(VMergeType::Continue) {
+                        below.children = cell.children.clone();
+                        below.has_numbering = cell.has_numbering;
+                    }
+                }
+            }
+        }
+        self.normalize_vertical_merges();
+        Ok(self)
+    }
+
+    // Cells which the new column falls inside are widened instead of getting a new cell.
+    pub fn insert_column(mut self, index: usize, width: usize) -> Result<Table, DocxError> {
+        let columns = self.column_count();
+        if index > columns {
+            return Err(out_of_range("column", index, columns + 1));
+        }
+        if !self.grid.is_empty() {
+            let at = index.min(self.grid.len());
+            self.grid.insert(at, width);
+        }
+        for TableChild::TableRow(row) in self.rows.iter_mut() {
+            let before = row.property.get_grid_before();
+            if index < before {
+                row.property.set_grid_before(before + 1);
+                continue;
+            }
+            let positions = layout(row);
+            if let Some(i) = positions
+                .iter()
+                .position(|(start, span)| *start < index && index < start + span)
+            {
+                let cell = cell_mut(row, i);
+                cell.property.set_grid_span(positions[i].1 + 1);
+                continue;
+            }
+            let end = positions
+                .last()
+                .map(|(start, span)| start + span)
+                .unwrap_or(before);
+            if index > end {
+                continue;
+            }
+            let at = positions
+                .iter()
+                .position(|(start, _)| *start >= index)
+                .unwrap_or(positions.len());
+            row.cells.insert(
+                at,
+                TableRowChild::TableCell(TableCell::new().width(width, WidthType::Dxa)),
+            );
+        }
+        self.refresh_cell_widths();
+        self.normalize_vertical_merges();
+        Ok(self)
+    }
+
+    pub fn delete_column(mut self, index: usize) -> Result<Table, DocxError> {
+        let columns = self.column_count();
+        if index >= columns {
+            return Err(out_of_range("column", index, columns));
+        }
+        if columns == 1 {
+            return Err(DocxError::InvalidTable(
+                "can not delete the last column".to_owned(),
+            ));
+        }
+        if index < self.grid.len() {
+            self.grid.remove(index);
+        }
+        for TableChild::TableRow(row) in self.rows.iter_mut() {
+            let before = row.property.get_grid_before();
+            if index < before {
+                row.property.set_grid_before(before - 1);
+                continue;
+            }
+            let positions = layout(row);
+            if let Some(i) = positions
+                .iter()
+                .position(|(start, span)| *start <= index && index < start + span)
+            {
+                if positions[i].1 > 1 {
+                    cell_mut(row, i).property.set_grid_span(positions[i].1 - 1);
+                } else {
+                    row.cells.remove(i);
+                }
+            }
+        }
+        self.refresh_cell_widths();
+        self.normalize_vertical_merges();
+        Ok(self)
+    }
+
+    // The region must not cut through any cell. Non-empty content of the merged
+    // cells is collected into the top-left one.
+    pub fn merge_cells(mut self, region: TableMergeRegion) -> Result<Table, DocxError> {
+        let columns = self.column_count();
+        if region.row_span == 0 || region.column_span == 0 {
+            return Err(DocxError::InvalidTable(format!(
+                "merge region {:?} is empty",
+                region
+            )));
+        }
+        if region.row + region.row_span > self.rows.len()
+            || region.column + region.column_span > columns
+        {
+            return Err(DocxError::InvalidTable(format!(
+                "merge region {:?} is outside of the {}x{} table",
+                region,
+                self.rows.len(),
+                columns
+            )));
+        }
+        let first = region.column;
+        let last = region.column + region.column_span;
+        let mut ranges = vec![];
+        for r in region.row..region.row + region.row_span {
+            let TableChild::TableRow(row) = &self.rows[r];
+            let positions = layout(row);
+            let aligned = positions.iter().all(|(start, span)| {
+                start + span <= first || *start >= last || (*start >= first && start + span <= last)
+            });
+            let inside: Vec<usize> = positions
+                .iter()
+                .enumerate()
+                .filter(|(_, (start, span))| *start >= first && start + span <= last)
+                .map(|(i, _)| i)
+                .collect();
+            let covered: usize = inside.iter().map(|i| positions[*i].1).sum();
+            if !aligned || covered != region.column_span {
+                return Err(DocxError::InvalidTable(format!(
+                    "cells in row {} do not align with merge region {:?}",
+                    r, region
+                )));
+            }
+            let continued = inside.iter().any(|i| {
+                cells(row)
+                    .nth(*i)
+                    .and_then(|c| c.property.get_vertical_merge())
+                    == Some(VMergeType::Continue)
+            });
+            if r == region.row && continued {
+                return Err(DocxError::InvalidTable(format!(
+                    "merge region {:?} cuts through a vertically merged cell",
+                    region
+                )));
+            }
+            ranges.push((inside[0], inside[inside.len() - 1] + 1));
+        }
+        if let Some(TableChild::TableRow(below)) = self.rows.get(region.row + region.row_span) {
+            let continued = cells(below).zip(layout(below)).any(|(c, (start, span))| {
+                start < last
+                    && first < start + span
+                    && c.property.get_vertical_merge() == Some(VMergeType::Continue)
+            });
+            if continued {
+                return Err(DocxError::InvalidTable(format!(
+                    "merge region {:?} cuts through a vertically merged cell",
+                    region
+                )));
+            }
+        }
+
+        let mut children = vec![];
+        let mut has_numbering = false;
+        for (r, (from, to)) in ranges.iter().enumerate() {
+            let TableChild::TableRow(row) = &self.rows[region.row + r];
+            for cell in cells(row).skip(*from).take(to - from) {
+                if !is_empty(cell) {
+                    children.extend(cell.children.iter().cloned());
+                    has_numbering = has_numbering || cell.has_numbering;
+                }
+            }
+        }
+        for (r, (from, to)) in ranges.into_iter().enumerate() {
+            let TableChild::TableRow(row) = &mut self.rows[region.row + r];
+            let mut removed = row.cells.drain(from..to);
+            let TableRowChild::TableCell(mut cell) =
+                removed.next().expect("merge range should not be empty");
+            drop(removed);
+            if r == 0 {
+                cell.children = std::mem::take(&mut children);
+                cell.has_numbering = has_numbering;
+            } else {
+                cell.children = vec![];
+                cell.has_numbering = false;
+            }
+            cell.property.set_grid_span(region.column_span);
+            cell.property.set_vertical_merge(if region.row_span == 1 {
+                None
+            } else if r == 0 {
+                Some(VMergeType::Restart)
+            } else {
+                Some(VMergeType::Continue)
+            });
+            row.cells.insert(from, TableRowChild::TableCell(cell));
+            let has_numbering = cells(row).any(|c| c.has_numbering);
+            row.has_numbering = has_numbering;
+        }
+        self.refresh_cell_widths();
+        self.normalize_vertical_merges();
+        Ok(self)
+    }
+
+    // Split the cell starting at grid `column` into `columns` cells. If the cell spans fewer
+    // grid columns than requested its last grid column is halved until it fits.
+    // Vertically merged cells are split in every row of the merge.
+    pub fn split_cell(
+        mut self,
+        row: usize,
+        column: usize,
+        columns: usize,
+    ) -> Result<Table, DocxError> {
+        if row >= self.rows.len() {
+            return Err(out_of_range("row", row, self.rows.len()));
+        }
+        if columns == 0 {
+            return Err(DocxError::InvalidTable(
+                "a cell can not be split into 0 cells".to_owned(),
+            ));
+        }
+        let TableChild::TableRow(target) = &self.rows[row];
+        let mut span = match layout(target).iter().find(|(start, _)| *start == column) {
+            Some((_, span)) => *span,
+            None => {
+                return Err(DocxError::InvalidTable(format!(
+                    "no cell starts at column {} of row {}",
+                    column, row
+                )))
+            }
+        };
+        let group = self.vertical_group(row, (column, span));
+        while span < columns {
+            self.split_grid_column(column + span - 1);
+            span += 1;
+        }
+        let base = span / columns;
+        let extra = span % columns;
+        let spans: Vec<usize> = (0..columns)
+            .map(|i| if i < extra { base + 1 } else { base })
+            .collect();
+        for r in group {
+            let TableChild::TableRow(row) = &mut self.rows[r];
+            let i = match layout(row).iter().position(|(start, _)| *start == column) {
+                Some(i) => i,
+                None => continue,
+            };
+            let original = cell_mut(row, i).clone();
+            let mut split = vec![];
+            for (n, s) in spans.iter().enumerate() {
+                let mut cell = if n == 0 {
+                    original.clone()
+                } else {
+                    let mut c = TableCell::new();
+                    c.property = original.property.clone();
+                    c
+                };
+                cell.property.set_grid_span(*s);
+                split.push(TableRowChild::TableCell(cell));
+            }
+            row.cells.splice(i..i + 1, split);
+        }
+        self.refresh_cell_widths();
+        self.normalize_vertical_merges();
+        Ok(self)
+    }
+
+    // Rows of the vertical merge containing the cell at `pos` in `row`.
+    fn vertical_group(&self, row: usize, pos: (usize, usize)) -> Vec<usize> {
+        let merge_at = |r: usize| -> Option<VMergeType> {
+            let TableChild::TableRow(row) = &self.rows[r];
+            let i = layout(row).iter().position(|p| *p == pos)?;
+            cells(row).nth(i)?.property.get_vertical_merge()
+        };
+        let mut top = row;
+        if merge_at(row).is_none() {
+            return vec![row];
+        }
+        while top > 0 && merge_at(top) == Some(VMergeType::Continue) && merge_at(top - 1).is_some()
+        {
+            top -= 1;
+        }
+        let mut bottom = row;
+        while bottom + 1 < self.rows.len() && merge_at(bottom + 1) == Some(VMergeType::Continue) {
+            bottom += 1;
+        }
+        (top..=bottom).collect()
+    }
+
+    // Halve grid column `index`, widening every cell which covers it.
+    fn split_grid_column(&mut self, index: usize) {
+        if let Some(w) = self.grid.get(index).copied() {
+            self.grid[index] = w / 2;
+            self.grid.insert(index + 1, w - w / 2);
+        }
+        for TableChild::TableRow(row) in self.rows.iter_mut() {
+            let before = row.property.get_grid_before();
+            if index < before {
+                row.property.set_grid_before(before + 1);
+                continue;
+            }
+            let positions = layout(row);
+            if let Some(i) = positions
+                .iter()
+                .position(|(start, span)| *start <= index && index < start + span)
+            {
+                cell_mut(row, i).property.set_grid_span(positions[i].1 + 1);
+            }
+        }
+    }
+
+    // Set tcW of every cell from the grid, if the table has one.
+    fn refresh_cell_widths(&mut self) {
+        if self.grid.is_empty() {
+            return;
+        }
+        let grid = self.grid.clone();
+        for TableChild::TableRow(row) in self.rows.iter_mut() {
+            let positions = layout(row);
+            for (i, (start, span)) in positions.into_iter().enumerate() {
+                if start + span <= grid.len() {
+                    let w = grid[start..start + span].iter().sum();
+                    cell_mut(row, i).property.set_width(w, WidthType::Dxa);
+                }
+            }
+        }
+    }
+
+    // Re-number vMerge from top to bottom. A continue cell without a cell to continue
+    // starts a new merge, and a merge of a single cell is removed.
+    pub(crate) fn normalize_vertical_merges(&mut self) {
+        let layouts: Vec<Vec<(usize, usize)>> = self
+            .rows
+            .iter()
+            .map(|TableChild::TableRow(r)| layout(r))
+            .collect();
+        let merge_at = |rows: &[TableChild], r: usize, pos: (usize, usize)| {
+            let TableChild::TableRow(row) = rows.get(r)?;
+            let i = layouts[r].iter().position(|p| *p == pos)?;
+            cells(row).nth(i)?.property.get_vertical_merge()
+        };
+        for (r, positions) in layouts.iter().enumerate() {
+            for (i, pos) in positions.iter().copied().enumerate() {
+                let current = merge_at(&self.rows, r, pos);
+                if current.is_none() {
+                    continue;
+                }
+                let above = if r == 0 {
+                    None
+                } else {
+                    merge_at(&self.rows, r - 1, pos)
+                };
+                let continues_below =
+                    merge_at(&self.rows, r + 1, pos) == Some(VMergeType::Continue);
+                let next = match (current, above) {
+                    (Some(VMergeType::Continue), Some(_)) => Some(VMergeType::Continue),
+                    _ if continues_below => Some(VMergeType::Restart),
+                    _ => None,
+                };
+                if next != current {
+                    let TableChild::TableRow(row) = &mut self.rows[r];
+                    cell_mut(row, i).property.set_vertical_merge(next);
+                }
+            }
+        }
+    }
+}
+
+fn out_of_range(name: &str, index: usize, len: usize) -> DocxError {
+    DocxError::InvalidTable(format!(
+        "{} {} is out of range, the table has {}",
+        name, index, len
+    ))
+}
+
+fn cells(row: &TableRow) -> impl Iterator<Item = &TableCell> {
+    row.cells.iter().map(|TableRowChild::TableCell(c)| c)
+}
+
+fn cell_mut(row: &mut TableRow, i: usize) -> &mut TableCell {
+    let TableRowChild::TableCell(c) = &mut row.cells[i];
+    c
+}
+
+// (first grid column, gridSpan) of each cell in the row.
+fn layout(row: &TableRow) -> Vec<(usize, usize)> {
+    let mut start = row.property.get_grid_before();
+    cells(row)
+        .map(|c| {
+            let span = c.property.get_grid_span();
+            start += span;
+            (start - span, span)
+        })
+        .collect()
+}
+
+fn is_empty(cell: &TableCell) -> bool {
+    cell.children.iter().all(|c| match c {
+        TableCellContent::Paragraph(p) => p.children.is_empty(),
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn text(t: &str) -> TableCell {
+        TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(t)))
+    }
+
+    fn shape(table: &Table) -> Vec<Vec<(usize, usize, Option<VMergeType>)>> {
+        table
+            .rows
+            .iter()
+            .map(|TableChild::TableRow(r)| {
+                cells(r)
+                    .zip(layout(r))
+                    .map(|(c, (start, span))| (start, span, c.property.get_vertical_merge()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn grid_3x3() -> Table {
+        Table::new(
+            (0..3)
+                .map(|r| TableRow::new((0..3).map(|c| text(&format!("{}{}", r, c))).collect()))
+                .collect(),
+        )
+        .set_grid(vec![1000, 2000, 3000])
+    }
+
+    #[test]
+    fn test_merge_cells() {
+        let table = grid_3x3()
+            .merge_cells(TableMergeRegion::new(0, 1, 2, 2))
+            .unwrap();
+        assert_eq!(
+            shape(&table),
+            vec![
+                vec![(0, 1, None), (1, 2, Some(VMergeType::Restart))],
+                vec![(0, 1, None), (1, 2, Some(VMergeType::Continue))],
+                vec![(0, 1, None), (1, 1, None), (2, 1, None)],
+            ]
+        );
+        let TableChild::TableRow(row) = &table.rows[0];
+        let anchor = cells(row).nth(1).unwrap();
+        assert_eq!(anchor.children.len(), 4);
+        assert_eq!(anchor.property, {
+            let mut p = TableCellProperty::new()
+                .grid_span(2)
+                .vertical_merge(VMergeType::Restart);
+            p.set_width(5000, WidthType::Dxa);
+            p
+        });
+        // overlapping the merged cell partially
+        assert!(table
+            .merge_cells(TableMergeRegion::new(1, 0, 2, 2))
+            .is_err());
+    }
+
+    #[test]
+    fn test_split_merged_cell() {
+        let table = grid_3x3()
+            .merge_cells(TableMergeRegion::new(0, 0, 2, 2))
+            .unwrap()
+            .split_cell(1, 0, 3)
+            .unwrap();
+        assert_eq!(table.grid, vec![1000, 1000, 1000, 3000]);
+        assert_eq!(
+            shape(&table),
+            vec![
+                vec![
+                    (0, 1, Some(VMergeType::Restart)),
+                    (1, 1, Some(VMergeType::Restart)),
+                    (2, 1, Some(VMergeType::Restart)),
+                    (3, 1, None)
+                ],
+                vec![
+                    (0, 1, Some(VMergeType::Continue)),
+                    (1, 1, Some(VMergeType::Continue)),
+                    (2, 1, Some(VMergeType::Continue)),
+                    (3, 1, None)
+                ],
+                vec![(0, 1, None), (1, 2, None), (3, 1, None)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_and_delete_column() {
+        let table = grid_3x3()
+            .merge_cells(TableMergeRegion::new(0, 0, 1, 2))
+            .unwrap()
+            .insert_column(1, 500)
+            .unwrap();
+        assert_eq!(table.grid, vec![1000, 500, 2000, 3000]);
+        assert_eq!(
+            shape(&table)[..2],
+            vec![
+                vec![(0, 3, None), (3, 1, None)],
+                vec![(0, 1, None), (1, 1, None), (2, 1, None), (3, 1, None)],
+            ]
+        );
+        let table = table.delete_column(0).unwrap().delete_column(0).unwrap();
+        assert_eq!(table.grid, vec![2000, 3000]);
+        assert_eq!(shape(&table)[0], vec![(0, 1, None), (1, 1, None)]);
+        assert!(table.delete_column(0).unwrap().delete_column(0).is_err());
+    }
+
+    #[test]
+    fn test_delete_row_moves_merged_content() {
+        let table = grid_3x3()
+            .merge_cells(TableMergeRegion::new(0, 0, 3, 1))
+            .unwrap()
+            .delete_row(0)
+            .unwrap();
+        assert_eq!(
+            shape(&table),
+            vec![
+                vec![
+                    (0, 1, Some(VMergeType::Restart)),
+                    (1, 1, None),
+                    (2, 1, None)
+                ],
+                vec![
+                    (0, 1, Some(VMergeType::Continue)),
+                    (1, 1, None),
+                    (2, 1, None)
+                ],
+            ]
+        );
+        let TableChild::TableRow(row) = &table.rows[0];
+        assert_eq!(cells(row).next().unwrap().children.len(), 3);
+
+        let table = table.delete_row(1).unwrap();
+        assert_eq!(shape(&table)[0][0], (0, 1, None));
+    }
+
+    #[test]
+    fn test_insert_row_breaks_merge() {
+        let table = grid_3x3()
+            .merge_cells(TableMergeRegion::new(0, 0, 2, 1))
+            .unwrap()
+            .insert_row(1, TableRow::new(vec![text("a"), text("b"), text("c")]))
+            .unwrap();
+        assert_eq!(shape(&table)[0][0], (0, 1, None));
+        assert_eq!(shape(&table)[2][0], (0, 1, None));
+    }
+}
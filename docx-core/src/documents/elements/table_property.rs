@@ -24,6 +24,14 @@ pub struct TableProperty {
     layout: Option<TableLayout>,
     #[serde(skip_serializing_if = "Option::is_none")]
     position: Option<TablePositionProperty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    look: Option<TableLook>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) table_property_change: Option<TablePropertyChange>,
 }
 
 impl Default for TableProperty {
@@ -37,6 +45,10 @@ impl Default for TableProperty {
             style: None,
             layout: None,
             position: None,
+            look: None,
+            caption: None,
+            description: None,
+            table_property_change: None,
         }
     }
 }
@@ -53,6 +65,14 @@ impl TableProperty {
         }
     }
 
+    pub(crate) fn get_style(&self) -> Option<&str> {
+        self.style.as_ref().map(|s| s.get_val())
+    }
+
+    pub(crate) fn get_look(&self) -> Option<&TableLook> {
+        self.look.as_ref()
+    }
+
     pub fn indent(mut self, v: i32) -> TableProperty {
         self.indent = Some(TableIndent::new(v, WidthType::Dxa));
         self
@@ -147,11 +167,32 @@ impl TableProperty {
         self.position = Some(p);
         self
     }
+
+    pub fn look(mut self, l: TableLook) -> Self {
+        self.look = Some(l);
+        self
+    }
+
+    // Alternative text read out by assistive technology.
+    pub fn caption(mut self, v: impl Into<String>) -> Self {
+        self.caption = Some(v.into());
+        self
+    }
+
+    pub fn description(mut self, v: impl Into<String>) -> Self {
+        self.description = Some(v.into());
+        self
+    }
+
+    pub fn table_property_change(mut self, c: TablePropertyChange) -> Self {
+        self.table_property_change = Some(c);
+        self
+    }
 }
 
 impl BuildXML for TableProperty {
     fn build(&self) -> Vec<u8> {
-        XMLBuilder::new()
+        let mut b = XMLBuilder::new()
             .open_table_property()
             .add_child(&self.width)
             .add_child(&self.justification)
@@ -161,6 +202,14 @@ impl BuildXML for TableProperty {
             .add_optional_child(&self.style)
             .add_optional_child(&self.layout)
             .add_optional_child(&self.position)
+            .add_optional_child(&self.look);
+        if let Some(ref c) = self.caption {
+            b = b.table_caption(c)
+        }
+        if let Some(ref d) = self.description {
+            b = b.table_description(d)
+        }
+        b.add_optional_child(&self.table_property_change)
             .close()
             .build()
     }
@@ -192,4 +241,18 @@ mod tests {
             r#"{"width":{"width":0,"widthType":"auto"},"justification":"left","borders":{"top":{"borderType":"single","size":2,"color":"000000","position":"top","space":0},"left":{"borderType":"single","size":2,"color":"000000","position":"left","space":0},"bottom":{"borderType":"single","size":2,"color":"000000","position":"bottom","space":0},"right":{"borderType":"single","size":2,"color":"000000","position":"right","space":0},"insideH":{"borderType":"single","size":2,"color":"000000","position":"insideH","space":0},"insideV":{"borderType":"single","size":2,"color":"000000","position":"insideV","space":0}},"indent":{"width":100,"widthType":"dxa"}}"#
         );
     }
+
+    #[test]
+    fn test_caption_and_description() {
+        let c = TableProperty::without_borders()
+            .caption("Sales")
+            .description("Quarterly sales by region");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblPr><w:tblW w:w="0" w:type="dxa" /><w:jc w:val="left" /><w:tblBorders /><w:tblCaption w:val="Sales" />
+  <w:tblDescription w:val="Quarterly sales by region" />
+</w:tblPr>"#
+        );
+    }
 }
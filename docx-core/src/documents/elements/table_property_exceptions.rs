@@ -0,0 +1,121 @@
+use serde::Serialize;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// Table level properties overridden for a single row.
+// i.e. <w:tblPrEx><w:tblBorders><w:top w:val="nil" /></w:tblBorders></w:tblPrEx>
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TablePropertyExceptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<TableWidth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    justification: Option<Justification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cell_spacing: Option<TableCellSpacing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    indent: Option<TableIndent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    borders: Option<TableBorders>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shading: Option<Shading>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout: Option<TableLayout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    margins: Option<TableCellMargins>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    look: Option<TableLook>,
+}
+
+impl TablePropertyExceptions {
+    pub fn new() -> TablePropertyExceptions {
+        Default::default()
+    }
+
+    pub fn width(mut self, v: usize, t: WidthType) -> Self {
+        self.width = Some(TableWidth::new(v, t));
+        self
+    }
+
+    pub fn align(mut self, v: TableAlignmentType) -> Self {
+        self.justification = Some(Justification::new(v.to_string()));
+        self
+    }
+
+    pub fn cell_spacing(mut self, v: usize, t: WidthType) -> Self {
+        self.cell_spacing = Some(TableCellSpacing::new(v, t));
+        self
+    }
+
+    pub fn indent(mut self, v: i32) -> Self {
+        self.indent = Some(TableIndent::new(v, WidthType::Dxa));
+        self
+    }
+
+    pub fn set_borders(mut self, borders: TableBorders) -> Self {
+        self.borders = Some(borders);
+        self
+    }
+
+    pub fn shading(mut self, s: Shading) -> Self {
+        self.shading = Some(s);
+        self
+    }
+
+    pub fn layout(mut self, t: TableLayoutType) -> Self {
+        self.layout = Some(TableLayout::new(t));
+        self
+    }
+
+    pub fn set_margins(mut self, margins: TableCellMargins) -> Self {
+        self.margins = Some(margins);
+        self
+    }
+
+    pub fn look(mut self, l: TableLook) -> Self {
+        self.look = Some(l);
+        self
+    }
+}
+
+impl BuildXML for TablePropertyExceptions {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .open_table_property_exceptions()
+            .add_optional_child(&self.width)
+            .add_optional_child(&self.justification)
+            .add_optional_child(&self.cell_spacing)
+            .add_optional_child(&self.indent)
+            .add_optional_child(&self.borders)
+            .add_optional_child(&self.shading)
+            .add_optional_child(&self.layout)
+            .add_optional_child(&self.margins)
+            .add_optional_child(&self.look)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_table_property_exceptions() {
+        let b = TablePropertyExceptions::new()
+            .indent(100)
+            .set_borders(TableBorders::with_empty())
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblPrEx><w:tblInd w:w="100" w:type="dxa" /><w:tblBorders /></w:tblPrEx>"#
+        );
+    }
+}
@@ -1,7 +1,10 @@
 use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 
-use super::{Delete, Insert, TableCell, TableRowProperty};
+use super::{
+    Delete, Insert, TableCell, TablePropertyExceptions, TableRowProperty, TableRowPropertyChange,
+};
+use crate::types::WidthType;
 use crate::xml_builder::*;
 use crate::{documents::BuildXML, HeightRule};
 
@@ -11,6 +14,8 @@ pub struct TableRow {
     pub cells: Vec<TableRowChild>,
     pub has_numbering: bool,
     pub property: TableRowProperty,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub property_exceptions: Option<TablePropertyExceptions>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -34,6 +39,7 @@ impl TableRow {
         Self {
             cells,
             property,
+            property_exceptions: None,
             has_numbering,
         }
     }
@@ -78,16 +84,47 @@ impl TableRow {
         self
     }
 
+    pub fn table_row_property_change(mut self, c: TableRowPropertyChange) -> TableRow {
+        self.property = self.property.table_row_property_change(c);
+        self
+    }
+
+    pub fn property_exceptions(mut self, e: TablePropertyExceptions) -> TableRow {
+        self.property_exceptions = Some(e);
+        self
+    }
+
     pub fn cant_split(mut self) -> TableRow {
         self.property = self.property.cant_split();
         self
     }
+
+    pub fn table_header(mut self) -> TableRow {
+        self.property = self.property.table_header();
+        self
+    }
+
+    pub fn hidden(mut self) -> TableRow {
+        self.property = self.property.hidden();
+        self
+    }
+
+    pub fn cnf_style(mut self, val: impl Into<String>) -> TableRow {
+        self.property = self.property.cnf_style(val);
+        self
+    }
+
+    pub fn cell_spacing(mut self, v: usize, t: WidthType) -> TableRow {
+        self.property = self.property.cell_spacing(v, t);
+        self
+    }
 }
 
 impl BuildXML for TableRow {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new()
             .open_table_row()
+            .add_optional_child(&self.property_exceptions)
             .add_child(&self.property)
             .add_children(&self.cells);
         b.close().build()
@@ -1,6 +1,7 @@
 use serde::Serialize;
 
 use super::*;
+use crate::types::*;
 use crate::xml_builder::*;
 use crate::{documents::BuildXML, HeightRule};
 
@@ -21,6 +22,16 @@ pub struct TableRowProperty {
     pub ins: Option<Insert>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cant_split: Option<CantSplit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_header: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cnf_style: Option<CnfStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cell_spacing: Option<TableCellSpacing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_row_property_change: Option<TableRowPropertyChange>,
 }
 
 impl TableRowProperty {
@@ -38,6 +49,22 @@ impl TableRowProperty {
         self
     }
 
+    pub(crate) fn get_grid_after(&self) -> usize {
+        self.grid_after.unwrap_or_default() as usize
+    }
+
+    pub(crate) fn get_grid_before(&self) -> usize {
+        self.grid_before.unwrap_or_default() as usize
+    }
+
+    pub(crate) fn set_grid_before(&mut self, before: usize) {
+        self.grid_before = if before > 0 {
+            Some(before as u32)
+        } else {
+            None
+        };
+    }
+
     pub fn grid_before(mut self, before: u32) -> Self {
         self.grid_before = Some(before);
         self
@@ -72,22 +99,71 @@ impl TableRowProperty {
         self.cant_split = Some(CantSplit::default());
         self
     }
+
+    // Repeat this row at the top of each page the table continues on.
+    // Only a contiguous run of rows at the start of the table is repeated.
+    pub fn table_header(mut self) -> Self {
+        self.table_header = Some(true);
+        self
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.hidden = Some(true);
+        self
+    }
+
+    pub fn cnf_style(mut self, val: impl Into<String>) -> Self {
+        self.cnf_style = Some(CnfStyle::new(val));
+        self
+    }
+
+    pub fn cell_spacing(mut self, v: usize, t: WidthType) -> Self {
+        self.cell_spacing = Some(TableCellSpacing::new(v, t));
+        self
+    }
+
+    pub fn table_row_property_change(mut self, c: TableRowPropertyChange) -> Self {
+        self.table_row_property_change = Some(c);
+        self
+    }
 }
 
 impl BuildXML for TableRowProperty {
     fn build(&self) -> Vec<u8> {
         let mut b = XMLBuilder::new()
             .open_table_row_property()
-            .add_optional_child(&self.del)
-            .add_optional_child(&self.ins)
-            .add_optional_child(&self.cant_split);
+            .add_optional_child(&self.cnf_style);
+        if let Some(g) = self.grid_before {
+            b = b.grid_before(g as usize)
+        }
+        if let Some(g) = self.grid_after {
+            b = b.grid_after(g as usize)
+        }
+        if let Some(w) = self.width_before {
+            b = b.width_before(w as i32, WidthType::Dxa)
+        }
+        if let Some(w) = self.width_after {
+            b = b.width_after(w as i32, WidthType::Dxa)
+        }
+        b = b.add_optional_child(&self.cant_split);
         if let Some(h) = self.row_height {
             b = b.table_row_height(
                 &format!("{}", h),
                 &self.height_rule.unwrap_or_default().to_string(),
             )
         }
-        b.close().build()
+        if self.table_header == Some(true) {
+            b = b.table_header()
+        }
+        b = b.add_optional_child(&self.cell_spacing);
+        if self.hidden == Some(true) {
+            b = b.table_row_hidden()
+        }
+        b.add_optional_child(&self.ins)
+            .add_optional_child(&self.del)
+            .add_optional_child(&self.table_row_property_change)
+            .close()
+            .build()
     }
 }
 
@@ -113,4 +189,54 @@ mod tests {
             r#"<w:trPr><w:cantSplit /></w:trPr>"#
         );
     }
+
+    #[test]
+    fn test_grid_before_and_after() {
+        let b = TableRowProperty::new()
+            .grid_before(1)
+            .width_before(500.0)
+            .grid_after(2)
+            .width_after(1000.0)
+            .cant_split()
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:trPr>
+  <w:gridBefore w:val="1" />
+  <w:gridAfter w:val="2" />
+  <w:wBefore w:w="500" w:type="dxa" />
+  <w:wAfter w:w="1000" w:type="dxa" /><w:cantSplit /></w:trPr>"#
+        );
+    }
+
+    #[test]
+    fn test_schema_order() {
+        let b = TableRowProperty::new()
+            .delete(Delete::new())
+            .insert(Insert::new_with_empty())
+            .hidden()
+            .cell_spacing(15, WidthType::Dxa)
+            .table_header()
+            .row_height(300.0)
+            .cant_split()
+            .grid_before(1)
+            .cnf_style("100000000000")
+            .build();
+        let xml = str::from_utf8(&b).unwrap();
+        let positions: Vec<usize> = [
+            "<w:cnfStyle",
+            "<w:gridBefore",
+            "<w:cantSplit",
+            "<w:trHeight",
+            "<w:tblHeader",
+            "<w:tblCellSpacing",
+            "<w:hidden",
+            "<w:ins",
+            "<w:del",
+        ]
+        .iter()
+        .map(|el| xml.find(el).unwrap())
+        .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]), "{}", xml);
+    }
 }
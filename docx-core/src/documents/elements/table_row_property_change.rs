@@ -0,0 +1,80 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::escape;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TableRowPropertyChange {
+    pub author: String,
+    pub date: String,
+    pub property: Box<TableRowProperty>,
+}
+
+impl Default for TableRowPropertyChange {
+    fn default() -> TableRowPropertyChange {
+        Self {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            property: Box::default(),
+        }
+    }
+}
+
+impl TableRowPropertyChange {
+    pub fn new() -> TableRowPropertyChange {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn property(mut self, p: TableRowProperty) -> TableRowPropertyChange {
+        self.property = Box::new(p);
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> TableRowPropertyChange {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> TableRowPropertyChange {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for TableRowPropertyChange {}
+
+impl BuildXML for TableRowPropertyChange {
+    #[allow(clippy::needless_borrow)]
+    fn build(&self) -> Vec<u8> {
+        let id = self.generate();
+        XMLBuilder::new()
+            .open_table_row_property_change(&id, &self.author, &self.date)
+            .add_child(self.property.as_ref())
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_tr_pr_change_default() {
+        let b = TableRowPropertyChange::new()
+            .property(TableRowProperty::new().cant_split())
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:trPrChange w:id="123" w:author="unnamed" w:date="1970-01-01T00:00:00Z"><w:trPr><w:cantSplit /></w:trPr></w:trPrChange>"#
+        );
+    }
+}
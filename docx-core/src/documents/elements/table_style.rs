@@ -12,6 +12,10 @@ impl TableStyle {
     pub fn new(val: impl Into<String>) -> TableStyle {
         TableStyle { val: val.into() }
     }
+
+    pub(crate) fn get_val(&self) -> &str {
+        &self.val
+    }
 }
 
 impl BuildXML for TableStyle {
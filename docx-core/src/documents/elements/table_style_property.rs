@@ -0,0 +1,111 @@
+use serde::Serialize;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// Conditional formatting of a table style, applied to the region given by `style_type`
+// when the table's tblLook enables it.
+// i.e. <w:tblStylePr w:type="firstRow"><w:rPr><w:b/></w:rPr><w:tcPr><w:shd w:val="clear" w:fill="4472C4"/></w:tcPr></w:tblStylePr>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableStyleProperty {
+    pub style_type: TableStyleOverrideType,
+    pub run_property: RunProperty,
+    pub paragraph_property: ParagraphProperty,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_property: Option<TableProperty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_row_property: Option<TableRowProperty>,
+    pub table_cell_property: TableCellProperty,
+}
+
+impl TableStyleProperty {
+    pub fn new(style_type: TableStyleOverrideType) -> TableStyleProperty {
+        Self {
+            style_type,
+            run_property: RunProperty::new(),
+            paragraph_property: ParagraphProperty::new(),
+            table_property: None,
+            table_row_property: None,
+            table_cell_property: TableCellProperty::new(),
+        }
+    }
+
+    pub fn run_property(mut self, p: RunProperty) -> Self {
+        self.run_property = p;
+        self
+    }
+
+    pub fn paragraph_property(mut self, p: ParagraphProperty) -> Self {
+        self.paragraph_property = p;
+        self
+    }
+
+    // Borders set here only apply to the region, so start from `TableProperty::without_borders`.
+    pub fn table_property(mut self, p: TableProperty) -> Self {
+        self.table_property = Some(p);
+        self
+    }
+
+    pub fn table_row_property(mut self, p: TableRowProperty) -> Self {
+        self.table_row_property = Some(p);
+        self
+    }
+
+    pub fn table_cell_property(mut self, p: TableCellProperty) -> Self {
+        self.table_cell_property = p;
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.run_property = self.run_property.bold();
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.run_property = self.run_property.color(color);
+        self
+    }
+
+    pub fn shading(mut self, s: Shading) -> Self {
+        self.table_cell_property = self.table_cell_property.shading(s);
+        self
+    }
+}
+
+impl BuildXML for TableStyleProperty {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .open_table_style_property(&self.style_type.to_string())
+            .add_child(&self.paragraph_property)
+            .add_child(&self.run_property)
+            .add_optional_child(&self.table_property)
+            .add_optional_child(&self.table_row_property)
+            .add_child(&self.table_cell_property)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_table_style_property() {
+        let b = TableStyleProperty::new(TableStyleOverrideType::FirstRow)
+            .bold()
+            .shading(Shading::new().fill("4472C4"))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblStylePr w:type="firstRow"><w:pPr><w:rPr /></w:pPr><w:rPr><w:b /><w:bCs /></w:rPr><w:tcPr><w:shd w:val="clear" w:color="auto" w:fill="4472C4" /></w:tcPr></w:tblStylePr>"#
+        );
+    }
+}
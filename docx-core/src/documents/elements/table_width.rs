@@ -19,8 +19,13 @@ impl TableWidth {
 
 impl BuildXML for TableWidth {
     fn build(&self) -> Vec<u8> {
+        // Auto widths are written as dxa, relying on tblGrid for the column widths.
+        let width_type = match self.width_type {
+            WidthType::Auto => WidthType::Dxa,
+            t => t,
+        };
         XMLBuilder::new()
-            .table_width(self.width as i32, WidthType::Dxa)
+            .table_width(self.width as i32, width_type)
             .build()
     }
 }
@@ -41,4 +46,13 @@ mod tests {
             r#"<w:tblW w:w="20" w:type="dxa" />"#
         );
     }
+
+    #[test]
+    fn test_table_width_pct() {
+        let b = TableWidth::new(5000, WidthType::Pct).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblW w:w="5000" w:type="pct" />"#
+        );
+    }
 }
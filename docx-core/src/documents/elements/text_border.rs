@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, ThemeColor};
 use crate::types::*;
 use crate::xml_builder::*;
 
@@ -11,6 +11,8 @@ pub struct TextBorder {
     pub size: usize,
     pub color: String,
     pub space: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_color: Option<ThemeColor>,
 }
 
 impl TextBorder {
@@ -23,6 +25,11 @@ impl TextBorder {
         self
     }
 
+    pub fn theme_color(mut self, theme_color: ThemeColor) -> Self {
+        self.theme_color = Some(theme_color);
+        self
+    }
+
     pub fn size(mut self, size: usize) -> Self {
         self.size = size;
         self
@@ -46,6 +53,7 @@ impl Default for TextBorder {
             size: 4,
             space: 0,
             color: "auto".to_owned(),
+            theme_color: None,
         }
     }
 }
@@ -53,7 +61,13 @@ impl Default for TextBorder {
 impl BuildXML for TextBorder {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        b.text_border(self.border_type, self.size, self.space, &self.color)
-            .build()
+        b.text_border(
+            self.border_type,
+            self.size,
+            self.space,
+            &self.color,
+            self.theme_color.as_ref(),
+        )
+        .build()
     }
 }
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::*;
+
+// Reference to a color of the theme, written as w:themeColor, w:themeTint and w:themeShade
+// (w:themeFill... for shading fills) next to the literal color, which Word shows when the
+// theme is missing. Tint and shade are hex bytes, i.e. "BF".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeColor {
+    pub color: ThemeColorType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shade: Option<String>,
+}
+
+impl ThemeColor {
+    pub fn new(color: ThemeColorType) -> Self {
+        Self {
+            color,
+            tint: None,
+            shade: None,
+        }
+    }
+
+    pub fn tint(mut self, tint: impl Into<String>) -> Self {
+        self.tint = Some(tint.into());
+        self
+    }
+
+    pub fn shade(mut self, shade: impl Into<String>) -> Self {
+        self.shade = Some(shade.into());
+        self
+    }
+}
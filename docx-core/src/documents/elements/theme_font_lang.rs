@@ -0,0 +1,65 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// Languages used to pick the theme fonts for each script, e.g. `en-US`, `ja-JP` and `ar-SA`.
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeFontLang {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub val: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub east_asia: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bidi: Option<String>,
+}
+
+impl ThemeFontLang {
+    pub fn new(val: impl Into<String>) -> Self {
+        Self {
+            val: Some(val.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn east_asia(mut self, lang: impl Into<String>) -> Self {
+        self.east_asia = Some(lang.into());
+        self
+    }
+
+    pub fn bidi(mut self, lang: impl Into<String>) -> Self {
+        self.bidi = Some(lang.into());
+        self
+    }
+}
+
+impl BuildXML for ThemeFontLang {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .theme_font_lang(
+                self.val.as_deref(),
+                self.east_asia.as_deref(),
+                self.bidi.as_deref(),
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_theme_font_lang() {
+        let b = ThemeFontLang::new("en-US").east_asia("ja-JP").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:themeFontLang w:val="en-US" w:eastAsia="ja-JP" />"#
+        );
+    }
+}
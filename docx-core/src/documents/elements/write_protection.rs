@@ -0,0 +1,69 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+use super::PasswordHash;
+
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteProtection {
+    pub recommended: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_hash: Option<PasswordHash>,
+}
+
+impl WriteProtection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Recommend opening the document as read-only.
+    pub fn recommended(mut self) -> Self {
+        self.recommended = true;
+        self
+    }
+
+    pub fn password(mut self, password: &str) -> Self {
+        self.password_hash = Some(PasswordHash::new(password));
+        self
+    }
+
+    pub fn password_hash(mut self, h: PasswordHash) -> Self {
+        self.password_hash = Some(h);
+        self
+    }
+
+    pub fn verify_password(&self, password: &str) -> bool {
+        match self.password_hash {
+            Some(ref h) => h.verify(password),
+            None => true,
+        }
+    }
+}
+
+impl BuildXML for WriteProtection {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .write_protection(self.recommended, self.password_hash.as_ref())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_write_protection() {
+        let b = WriteProtection::new().recommended().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:writeProtection w:recommended="1" />"#
+        );
+    }
+}
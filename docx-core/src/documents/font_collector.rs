@@ -0,0 +1,199 @@
+use crate::documents::*;
+
+// Collects the font names of every run property in styles, numberings and content,
+// in order of appearance. Theme font references are not resolved here.
+#[derive(Debug, Default)]
+pub(crate) struct FontCollector {
+    names: Vec<String>,
+}
+
+impl FontCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn into_names(self) -> Vec<String> {
+        self.names
+    }
+
+    pub fn add_name(&mut self, name: &str) {
+        if !name.is_empty() && !self.names.iter().any(|n| n == name) {
+            self.names.push(name.to_owned());
+        }
+    }
+
+    pub fn visit_run_property(&mut self, rp: &RunProperty) {
+        if let Some(fonts) = &rp.fonts {
+            for name in fonts.font_names() {
+                self.add_name(name);
+            }
+        }
+    }
+
+    pub fn visit_styles(&mut self, styles: &Styles) {
+        self.visit_run_property(styles.get_default_run_property());
+        for s in styles.get_styles() {
+            self.visit_run_property(&s.run_property);
+            self.visit_run_property(&s.paragraph_property.run_property);
+        }
+    }
+
+    pub fn visit_numberings(&mut self, numberings: &Numberings) {
+        for a in &numberings.abstract_nums {
+            for l in &a.levels {
+                self.visit_run_property(&l.run_property);
+            }
+        }
+    }
+
+    pub fn visit_document(&mut self, document: &Document) {
+        for child in &document.children {
+            match child {
+                DocumentChild::Paragraph(p) => self.visit_paragraph(p),
+                DocumentChild::Table(t) => self.visit_table(t),
+                DocumentChild::CommentStart(c) => self.visit_comment(&c.comment),
+                DocumentChild::StructuredDataTag(t) => self.visit_tag(t),
+                DocumentChild::TableOfContents(t) => self.visit_toc(t),
+                _ => {}
+            }
+        }
+        let section = &document.section_property;
+        for header in vec![
+            section.header.as_ref(),
+            section.first_header.as_ref(),
+            section.even_header.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for child in &header.children {
+                match child {
+                    HeaderChild::Paragraph(p) => self.visit_paragraph(p),
+                    HeaderChild::Table(t) => self.visit_table(t),
+                    HeaderChild::StructuredDataTag(t) => self.visit_tag(t),
+                }
+            }
+        }
+        for footer in vec![
+            section.footer.as_ref(),
+            section.first_footer.as_ref(),
+            section.even_footer.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for child in &footer.children {
+                match child {
+                    FooterChild::Paragraph(p) => self.visit_paragraph(p),
+                    FooterChild::Table(t) => self.visit_table(t),
+                    FooterChild::StructuredDataTag(t) => self.visit_tag(t),
+                }
+            }
+        }
+    }
+
+    pub fn visit_comment(&mut self, comment: &Comment) {
+        for child in &comment.children {
+            match child {
+                CommentChild::Paragraph(p) => self.visit_paragraph(p),
+                CommentChild::Table(t) => self.visit_table(t),
+            }
+        }
+    }
+
+    fn visit_paragraph(&mut self, paragraph: &Paragraph) {
+        self.visit_run_property(&paragraph.property.run_property);
+        self.visit_paragraph_children(&paragraph.children);
+    }
+
+    fn visit_paragraph_children(&mut self, children: &[ParagraphChild]) {
+        for child in children {
+            match child {
+                ParagraphChild::Run(run) => self.visit_run(run),
+                ParagraphChild::Hyperlink(h) => self.visit_paragraph_children(&h.children),
+                ParagraphChild::Insert(ins) => self.visit_insert(&ins.children),
+                ParagraphChild::MoveTo(m) => self.visit_insert(&m.children),
+                ParagraphChild::Delete(del) => self.visit_delete(&del.children),
+                ParagraphChild::MoveFrom(m) => self.visit_delete(&m.children),
+                ParagraphChild::CommentStart(c) => self.visit_comment(&c.comment),
+                ParagraphChild::StructuredDataTag(t) => self.visit_tag(t),
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_insert(&mut self, children: &[InsertChild]) {
+        for child in children {
+            match child {
+                InsertChild::Run(run) => self.visit_run(run),
+                InsertChild::Delete(del) => self.visit_delete(&del.children),
+                InsertChild::CommentStart(c) => self.visit_comment(&c.comment),
+                InsertChild::CommentEnd(_) => {}
+            }
+        }
+    }
+
+    fn visit_delete(&mut self, children: &[DeleteChild]) {
+        for child in children {
+            match child {
+                DeleteChild::Run(run) => self.visit_run(run),
+                DeleteChild::CommentStart(c) => self.visit_comment(&c.comment),
+                DeleteChild::CommentEnd(_) => {}
+            }
+        }
+    }
+
+    fn visit_run(&mut self, run: &Run) {
+        self.visit_run_property(&run.run_property);
+        for child in &run.children {
+            match child {
+                RunChild::CommentStart(c) => self.visit_comment(&c.comment),
+                RunChild::FootnoteReference(r) => {
+                    for p in &r.content {
+                        self.visit_paragraph(p);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_table(&mut self, table: &Table) {
+        for TableChild::TableRow(row) in &table.rows {
+            for TableRowChild::TableCell(cell) in &row.cells {
+                for content in &cell.children {
+                    match content {
+                        TableCellContent::Paragraph(p) => self.visit_paragraph(p),
+                        TableCellContent::Table(t) => self.visit_table(t),
+                        TableCellContent::StructuredDataTag(t) => self.visit_tag(t),
+                        TableCellContent::TableOfContents(t) => self.visit_toc(t),
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_tag(&mut self, tag: &StructuredDataTag) {
+        for child in &tag.children {
+            match child {
+                StructuredDataTagChild::Run(run) => self.visit_run(run),
+                StructuredDataTagChild::Insert(ins) => self.visit_insert(&ins.children),
+                StructuredDataTagChild::Delete(del) => self.visit_delete(&del.children),
+                StructuredDataTagChild::Paragraph(p) => self.visit_paragraph(p),
+                StructuredDataTagChild::Table(t) => self.visit_table(t),
+                StructuredDataTagChild::CommentStart(c) => self.visit_comment(&c.comment),
+                StructuredDataTagChild::StructuredDataTag(t) => self.visit_tag(t),
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_toc(&mut self, toc: &TableOfContents) {
+        for child in toc.before_contents.iter().chain(toc.after_contents.iter()) {
+            match child {
+                TocContent::Paragraph(p) => self.visit_paragraph(p),
+                TocContent::Table(t) => self.visit_table(t),
+            }
+        }
+    }
+}
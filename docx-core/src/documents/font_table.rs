@@ -1,70 +1,159 @@
 use super::Font;
 use crate::documents::BuildXML;
-use crate::types::FontPitchType;
+use crate::types::FontEmbedType;
 use crate::xml_builder::*;
 
 use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct FontTable {}
+pub struct FontTable {
+    pub fonts: Vec<Font>,
+}
 
 impl FontTable {
     pub fn new() -> FontTable {
         Default::default()
     }
-}
 
-impl Default for FontTable {
-    fn default() -> Self {
-        Self {}
+    // A font of the same name is replaced.
+    pub fn add_font(mut self, font: Font) -> Self {
+        match self.fonts.iter_mut().find(|f| f.name == font.name) {
+            Some(f) => *f = font,
+            None => self.fonts.push(font),
+        }
+        self
+    }
+
+    pub fn find_font(&self, name: &str) -> Option<&Font> {
+        self.fonts.iter().find(|f| f.name == name)
+    }
+
+    pub(crate) fn find_font_mut(&mut self, name: &str) -> Option<&mut Font> {
+        self.fonts.iter_mut().find(|f| f.name == name)
     }
 }
 
 impl BuildXML for FontTable {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        let times = Font::new("Times New Roman", "00", "roman", FontPitchType::Variable);
-        let symbol = Font::new("Symbol", "02", "roman", FontPitchType::Variable);
-        let arial = Font::new("Arial", "00", "swiss", FontPitchType::Variable);
         b.declaration(Some(true))
             .open_fonts()
-            .add_child(&times)
-            .add_child(&symbol)
-            .add_child(&arial)
+            .add_children(&self.fonts)
             .close()
             .build()
     }
 }
 
+// A TrueType font to embed, written as an obfuscated word/fonts/fontN.odttf part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddedFont {
+    pub name: String,
+    pub embed_type: FontEmbedType,
+    pub data: Vec<u8>,
+}
+
+impl EmbeddedFont {
+    pub fn new(name: impl Into<String>, embed_type: FontEmbedType, data: Vec<u8>) -> Self {
+        Self {
+            name: name.into(),
+            embed_type,
+            data,
+        }
+    }
+
+    // The key is derived from the font itself so the same input packs the same bytes.
+    pub(crate) fn font_key(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.name.as_bytes());
+        hasher.update(self.embed_type.to_string().as_bytes());
+        hasher.update(&self.data);
+        let h = hasher.finalize();
+        let mut key = String::from("{");
+        for (i, b) in h[..16].iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                key.push('-');
+            }
+            let _ = write!(key, "{:02X}", b);
+        }
+        key.push('}');
+        key
+    }
+
+    // 17.8.1 Font Embedding: the first 32 bytes are XORed with the font key,
+    // read as hex from its last to its first byte.
+    pub(crate) fn obfuscate(&self, font_key: &str) -> Vec<u8> {
+        let digits: Vec<u8> = font_key
+            .chars()
+            .filter(|c| c.is_ascii_hexdigit())
+            .map(|c| c.to_digit(16).unwrap_or_default() as u8)
+            .collect();
+        let key: Vec<u8> = digits
+            .chunks(2)
+            .rev()
+            .map(|d| (d[0] << 4) | d.get(1).copied().unwrap_or_default())
+            .collect();
+        let mut data = self.data.clone();
+        if key.len() == 16 {
+            for (i, b) in data.iter_mut().take(32).enumerate() {
+                *b ^= key[i % 16];
+            }
+        }
+        data
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::types::FontPitchType;
     #[cfg(test)]
     use pretty_assertions::assert_eq;
     use std::str;
 
     #[test]
-    fn test_settings() {
-        let c = FontTable::new();
+    fn test_font_table() {
+        let c = FontTable::new()
+            .add_font(Font::new("Arial", "00", "swiss", FontPitchType::Variable))
+            .add_font(Font::new("Symbol", "02", "roman", FontPitchType::Variable));
         let b = c.build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
             r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
-<w:fonts xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><w:font w:name="Times New Roman">
+<w:fonts xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><w:font w:name="Arial">
   <w:charset w:val="00" />
-  <w:family w:val="roman" />
+  <w:family w:val="swiss" />
   <w:pitch w:val="variable" />
 </w:font><w:font w:name="Symbol">
   <w:charset w:val="02" />
   <w:family w:val="roman" />
   <w:pitch w:val="variable" />
-</w:font><w:font w:name="Arial">
-  <w:charset w:val="00" />
-  <w:family w:val="swiss" />
-  <w:pitch w:val="variable" />
 </w:font></w:fonts>"#
         );
     }
+
+    #[test]
+    fn test_obfuscate() {
+        let f = EmbeddedFont::new("Corp", FontEmbedType::Regular, vec![0; 40]);
+        let data = f.obfuscate("{00112233-4455-6677-8899-AABBCCDDEEFF}");
+        assert_eq!(&data[0..4], &[0xFF, 0xEE, 0xDD, 0xCC]);
+        assert_eq!(&data[16..20], &[0xFF, 0xEE, 0xDD, 0xCC]);
+        assert_eq!(&data[32..], &[0; 8]);
+        // XOR again restores the font.
+        let restored = EmbeddedFont::new("Corp", FontEmbedType::Regular, data)
+            .obfuscate("{00112233-4455-6677-8899-AABBCCDDEEFF}");
+        assert_eq!(restored, vec![0; 40]);
+    }
+
+    #[test]
+    fn test_font_key() {
+        let key = EmbeddedFont::new("Corp", FontEmbedType::Regular, vec![0; 40]).font_key();
+        assert_eq!(key.len(), 38);
+        let dashes: Vec<usize> = key.match_indices('-').map(|(i, _)| i).collect();
+        assert_eq!(dashes, vec![9, 14, 19, 24]);
+        assert!(key.starts_with('{') && key.ends_with('}'));
+    }
 }
@@ -0,0 +1,63 @@
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+use serde::Serialize;
+
+// Relationships of fontTable.xml, i.e. obfuscated embedded fonts.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FontTableRels {
+    pub fonts: Vec<(String, String)>,
+}
+
+impl FontTableRels {
+    pub fn new() -> FontTableRels {
+        Default::default()
+    }
+
+    pub fn add_font(mut self, id: impl Into<String>, path: impl Into<String>) -> Self {
+        self.fonts.push((id.into(), path.into()));
+        self
+    }
+}
+
+impl BuildXML for FontTableRels {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new();
+        b = b
+            .declaration(None)
+            .open_relationships("http://schemas.openxmlformats.org/package/2006/relationships");
+
+        for (id, path) in self.fonts.iter() {
+            b = b.relationship(
+                id,
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/font",
+                path,
+            )
+        }
+
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let b = FontTableRels::new()
+            .add_font("rIdFont1", "fonts/font1.odttf")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rIdFont1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/font" Target="fonts/font1.odttf" />
+</Relationships>"#
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    DeleteChild, DrawingData, InsertChild, Paragraph, ParagraphChild, RunChild,
+    DeleteChild, DrawingData, InsertChild, NumPicBullet, Paragraph, ParagraphChild, RunChild,
     StructuredDataTagChild, Table, TableCellContent, TableChild, TableRowChild, TocContent,
 };
 
@@ -153,3 +153,59 @@ pub(crate) fn collect_images_from_table(
         }
     }
 }
+
+// The extension and content type of an image, detected from its bytes.
+// Images of runs are converted to png by `Pic::new`, bullets read from a docx keep their format.
+pub(crate) fn image_type(buf: &[u8]) -> (&'static str, &'static str) {
+    if buf.starts_with(b"GIF8") {
+        ("gif", "image/gif")
+    } else if buf.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        ("jpeg", "image/jpeg")
+    } else if buf.starts_with(b"BM") {
+        ("bmp", "image/bmp")
+    } else if buf.starts_with(b"II*\0") || buf.starts_with(b"MM\0*") {
+        ("tiff", "image/tiff")
+    } else if buf.len() >= 44 && buf.starts_with(&[1, 0, 0, 0]) && &buf[40..44] == b" EMF" {
+        ("emf", "image/x-emf")
+    } else if buf.starts_with(&[0xD7, 0xCD, 0xC6, 0x9A]) || buf.starts_with(&[1, 0, 9, 0]) {
+        ("wmf", "image/x-wmf")
+    } else {
+        ("png", "image/png")
+    }
+}
+
+// Png bullets may share media with the document, other formats are written to
+// `media`, named with their own extension.
+pub(crate) fn collect_images_from_num_pic_bullets(
+    bullets: &mut [NumPicBullet],
+    images: &mut Vec<(String, String)>,
+    image_bufs: &mut Vec<(String, Vec<u8>)>,
+    media: &mut Vec<(String, Vec<u8>)>,
+) {
+    for bullet in bullets {
+        let pic = &mut bullet.pic;
+        let b = std::mem::take(&mut pic.image);
+        // Every bullet is related from numbering.xml under its own id, an identical image of the
+        // document only shares the media file.
+        let pic_id = format!("numberingPic{}", bullet.id);
+        let name = match image_type(&b).0 {
+            "png" => {
+                let media_id = match image_bufs.iter().find(|x| !b.is_empty() && x.1 == b) {
+                    Some(buf) => buf.0.clone(),
+                    None => {
+                        image_bufs.push((pic_id.clone(), b));
+                        pic_id.clone()
+                    }
+                };
+                format!("{}.png", media_id)
+            }
+            ext => {
+                let name = format!("{}.{}", pic_id, ext);
+                media.push((name.clone(), b));
+                name
+            }
+        };
+        images.push((pic_id.clone(), format!("media/{}", name)));
+        pic.id = pic_id;
+    }
+}
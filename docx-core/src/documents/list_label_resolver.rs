@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use crate::documents::*;
+
+use super::effective_formatting::FormattingResolver;
+
+const MAX_LEVELS: usize = 9;
+
+// The rendered list number of a numbered paragraph, i.e. "1.2.a)" or a bullet character.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListLabel {
+    pub path: DocumentPath,
+    pub numbering_id: usize,
+    pub level: usize,
+    pub text: String,
+}
+
+// Counts numbered paragraphs of the body, table cells, content controls and tables of contents
+// in document order. Headers and footers are counted after the body, each as a list of its own.
+// Numberings of the same abstractNum continue each other, except those with a
+// lvlOverride which are counted as a list of their own.
+pub(crate) struct ListLabelResolver<'a> {
+    numberings: &'a Numberings,
+    formatting: FormattingResolver<'a>,
+    // Keyed by abstractNum id, or by numId of numberings with overrides.
+    counters: HashMap<(usize, bool), [Option<usize>; MAX_LEVELS]>,
+    labels: Vec<ListLabel>,
+}
+
+impl<'a> ListLabelResolver<'a> {
+    pub fn new(styles: &'a Styles, numberings: &'a Numberings) -> Self {
+        Self {
+            numberings,
+            formatting: FormattingResolver::new(styles, numberings),
+            counters: HashMap::new(),
+            labels: vec![],
+        }
+    }
+
+    pub fn resolve(mut self, document: &Document) -> Vec<ListLabel> {
+        for (i, c) in document.children.iter().enumerate() {
+            let path = DocumentPath::new(i);
+            match c {
+                DocumentChild::Paragraph(p) => self.visit_paragraph(p, path),
+                DocumentChild::Table(t) => self.visit_table(t, path),
+                DocumentChild::StructuredDataTag(t) => self.visit_tag(t, path),
+                DocumentChild::TableOfContents(t) => self.visit_toc(t, path),
+                _ => {}
+            }
+        }
+        for (h, header) in document.section_property.get_headers().iter().enumerate() {
+            self.counters.clear();
+            for (i, c) in header.children.iter().enumerate() {
+                let path = DocumentPath::header(h, i);
+                match c {
+                    HeaderChild::Paragraph(p) => self.visit_paragraph(p, path),
+                    HeaderChild::Table(t) => self.visit_table(t, path),
+                    HeaderChild::StructuredDataTag(t) => self.visit_tag(t, path),
+                }
+            }
+        }
+        for (f, footer) in document.section_property.get_footers().iter().enumerate() {
+            self.counters.clear();
+            for (i, c) in footer.children.iter().enumerate() {
+                let path = DocumentPath::footer(f, i);
+                match c {
+                    FooterChild::Paragraph(p) => self.visit_paragraph(p, path),
+                    FooterChild::Table(t) => self.visit_table(t, path),
+                    FooterChild::StructuredDataTag(t) => self.visit_tag(t, path),
+                }
+            }
+        }
+        self.labels
+    }
+
+    fn visit_table(&mut self, table: &Table, path: DocumentPath) {
+        for (r, TableChild::TableRow(row)) in table.rows.iter().enumerate() {
+            for (c, TableRowChild::TableCell(cell)) in row.cells.iter().enumerate() {
+                for (i, child) in cell.children.iter().enumerate() {
+                    let path = path.clone().cell(r, c).child(i);
+                    match child {
+                        TableCellContent::Paragraph(p) => self.visit_paragraph(p, path),
+                        TableCellContent::Table(t) => self.visit_table(t, path),
+                        TableCellContent::StructuredDataTag(t) => self.visit_tag(t, path),
+                        TableCellContent::TableOfContents(t) => self.visit_toc(t, path),
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_tag(&mut self, tag: &StructuredDataTag, path: DocumentPath) {
+        for (i, child) in tag.children.iter().enumerate() {
+            let path = path.clone().child(i);
+            match child {
+                StructuredDataTagChild::Paragraph(p) => self.visit_paragraph(p, path),
+                StructuredDataTagChild::Table(t) => self.visit_table(t, path),
+                StructuredDataTagChild::StructuredDataTag(t) => self.visit_tag(t, path),
+                _ => {}
+            }
+        }
+    }
+
+    // Generated items are never numbered, only the contents around them are visited.
+    fn visit_toc(&mut self, toc: &TableOfContents, path: DocumentPath) {
+        let contents = toc.before_contents.iter().chain(&toc.after_contents);
+        for (i, content) in contents.enumerate() {
+            let path = path.clone().child(i);
+            match content {
+                TocContent::Paragraph(p) => self.visit_paragraph(p, path),
+                TocContent::Table(t) => self.visit_table(t, path),
+            }
+        }
+    }
+
+    fn visit_paragraph(&mut self, paragraph: &Paragraph, path: DocumentPath) {
+        let (id, level, l) = match self.formatting.paragraph_numbering(&paragraph.property) {
+            Some(n) => n,
+            None => return,
+        };
+        if level >= MAX_LEVELS {
+            return;
+        }
+        let text = self.next_label(id, level, l);
+        self.labels.push(ListLabel {
+            path,
+            numbering_id: id,
+            level,
+            text,
+        });
+    }
+
+    fn start(&self, id: usize, level: usize) -> usize {
+        let overridden = self
+            .numberings
+            .find_numbering(id)
+            .and_then(|n| n.level_overrides.iter().find(|o| o.level == level))
+            .and_then(|o| o.override_start);
+        match overridden {
+            Some(s) => s,
+            None => self
+                .numberings
+                .find_level(id, level)
+                .map(|l| l.start.get_val())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn next_label(&mut self, id: usize, level: usize, l: &Level) -> String {
+        let num = match self.numberings.find_numbering(id) {
+            Some(num) => num,
+            None => return "".to_owned(),
+        };
+        let key = if num.level_overrides.is_empty() {
+            (num.abstract_num_id, false)
+        } else {
+            (id, true)
+        };
+        let mut counters = self.counters.get(&key).copied().unwrap_or_default();
+        counters[level] = Some(match counters[level] {
+            Some(c) => c + 1,
+            None => self.start(id, level),
+        });
+        // Without lvlRestart a level restarts after any upper level, 0 never restarts.
+        for (k, counter) in counters.iter_mut().enumerate().skip(level + 1) {
+            let restart = self
+                .numberings
+                .find_level(id, k)
+                .and_then(|l| l.level_restart.as_ref())
+                .map(|r| r.get_val() as usize);
+            let reset = match restart {
+                Some(r) => level < r,
+                None => true,
+            };
+            if reset {
+                *counter = None;
+            }
+        }
+        self.counters.insert(key, counters);
+
+        let mut text = String::new();
+        let mut chars = l.text.get_val().chars().peekable();
+        while let Some(c) = chars.next() {
+            let referenced = match chars.peek().and_then(|d| d.to_digit(10)) {
+                Some(d) if c == '%' && (1..=MAX_LEVELS as u32).contains(&d) => d as usize - 1,
+                _ => {
+                    text.push(c);
+                    continue;
+                }
+            };
+            chars.next();
+            let n = counters[referenced].unwrap_or_else(|| self.start(id, referenced));
+            // isLgl shows every level in arabic numerals.
+            let formatted = if l.is_lgl.is_some() {
+                n.to_string()
+            } else {
+                match self.numberings.find_level(id, referenced) {
+                    Some(r) => r.format.format(n),
+                    None => n.to_string(),
+                }
+            };
+            text.push_str(&formatted);
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn level(level: usize, format: &str, text: &str) -> Level {
+        Level::new(
+            level,
+            Start::new(1),
+            NumberFormat::new(format),
+            LevelText::new(text),
+            LevelJc::new("left"),
+        )
+    }
+
+    fn item(id: usize, level: usize) -> Paragraph {
+        Paragraph::new().numbering(NumberingId::new(id), IndentLevel::new(level))
+    }
+
+    fn labels(document: &Document, numberings: &Numberings) -> Vec<String> {
+        ListLabelResolver::new(&Styles::new(), numberings)
+            .resolve(document)
+            .into_iter()
+            .map(|l| l.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_outline_labels() {
+        let numberings = Numberings::new()
+            .add_abstract_numbering(
+                AbstractNumbering::new(1)
+                    .add_level(level(0, "decimal", "%1."))
+                    .add_level(level(1, "decimal", "%1.%2"))
+                    .add_level(level(2, "lowerLetter", "%1.%2.%3)"))
+                    .add_level(level(3, "upperRoman", "%4.").level_restart(1))
+                    .add_level(level(4, "lowerRoman", "%1.%5").is_lgl()),
+            )
+            .add_numbering(Numbering::new(1, 1))
+            .add_numbering(Numbering::new(3, 1))
+            .add_numbering(Numbering::new(2, 1).add_override(LevelOverride::new(0).start(5)));
+        let document = Document::new()
+            .add_paragraph(item(1, 0))
+            .add_paragraph(item(1, 1))
+            .add_paragraph(item(1, 2))
+            .add_paragraph(item(1, 2))
+            .add_paragraph(item(1, 3))
+            .add_paragraph(item(1, 1))
+            .add_paragraph(item(1, 2))
+            .add_paragraph(item(1, 3))
+            .add_paragraph(item(1, 4))
+            .add_paragraph(Paragraph::new())
+            .add_paragraph(item(1, 0))
+            .add_paragraph(item(1, 3))
+            .add_paragraph(item(2, 0))
+            .add_paragraph(item(2, 0))
+            .add_paragraph(item(3, 0));
+        assert_eq!(
+            labels(&document, &numberings),
+            vec![
+                "1.", "1.1", "1.1.a)", "1.1.b)", "I.", "1.2", "1.2.a)",
+                // level 3 only restarts after level 0
+                "II.", "1.1", "2.", "I.",
+                // numbering 2 restarts at 5, numbering 3 continues numbering 1
+                "5.", "6.", "3."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_labels_in_table_and_bullets() {
+        let numberings = Numberings::new()
+            .add_abstract_numbering(AbstractNumbering::new(1).add_level(level(0, "bullet", "●")))
+            .add_abstract_numbering(AbstractNumbering::new(2).add_level(level(
+                0,
+                "japaneseCounting",
+                "第%1章",
+            )))
+            .add_numbering(Numbering::new(1, 1))
+            .add_numbering(Numbering::new(2, 2));
+        let document = Document::new()
+            .add_paragraph(item(2, 0))
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(item(1, 0))
+                .add_paragraph(item(2, 0))])]));
+        let labels = ListLabelResolver::new(&Styles::new(), &numberings).resolve(&document);
+        assert_eq!(
+            labels[2],
+            ListLabel {
+                path: DocumentPath::new(1).cell(0, 0).child(1),
+                numbering_id: 2,
+                level: 0,
+                text: "第二章".to_owned(),
+            }
+        );
+        assert_eq!(labels[1].text, "●");
+    }
+
+    #[test]
+    fn test_labels_in_content_controls_and_headers() {
+        let numberings = Numberings::new()
+            .add_abstract_numbering(AbstractNumbering::new(1).add_level(level(0, "decimal", "%1.")))
+            .add_numbering(Numbering::new(1, 1));
+        let document = Document::new()
+            .add_paragraph(item(1, 0))
+            .add_structured_data_tag(StructuredDataTag::new().add_paragraph(item(1, 0)))
+            .add_table_of_contents(
+                TableOfContents::new()
+                    .add_before_paragraph(Paragraph::new())
+                    .add_after_paragraph(item(1, 0)),
+            )
+            .add_paragraph(item(1, 0))
+            .header(Header::new().add_paragraph(item(1, 0)), "rId4")
+            .footer(Footer::new().add_paragraph(item(1, 0)), "rId5");
+        let labels = ListLabelResolver::new(&Styles::new(), &numberings).resolve(&document);
+        let texts: Vec<_> = labels.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(texts, vec!["1.", "2.", "3.", "4.", "1.", "1."]);
+        assert_eq!(labels[1].path, DocumentPath::new(1).child(0));
+        assert_eq!(labels[2].path, DocumentPath::new(2).child(1));
+        assert_eq!(labels[4].path, DocumentPath::header(0, 0));
+        assert_eq!(labels[5].path, DocumentPath::footer(0, 0));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::documents::*;
+use crate::types::*;
+
+const LEVELS: usize = 9;
+
+// Ready made list schemes for `Docx::create_list`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListPreset {
+    // •, ◦ and ▪ repeated
+    Bullet,
+    // 1., a., i. repeated
+    Decimal,
+    // 1., 1.1., 1.1.1.
+    Outline,
+    // I., 1.1, 1.1.1 where nested numbers are always arabic
+    Legal,
+    // A., a., i. repeated
+    UpperLetter,
+    // I., A., 1., a., i.
+    UpperRoman,
+}
+
+impl ListPreset {
+    // Nine levels of the scheme.
+    pub fn abstract_numbering(&self, id: usize) -> AbstractNumbering {
+        (0..LEVELS).fold(AbstractNumbering::new(id), |a, l| {
+            a.add_level(self.level(l))
+        })
+    }
+
+    fn level(&self, l: usize) -> Level {
+        let (format, text) = match self {
+            ListPreset::Bullet => ("bullet", ["•", "◦", "▪"][l % 3].to_owned()),
+            ListPreset::Decimal => (
+                ["decimal", "lowerLetter", "lowerRoman"][l % 3],
+                format!("%{}.", l + 1),
+            ),
+            ListPreset::Outline => ("decimal", outline_text(l, ".")),
+            ListPreset::Legal if l == 0 => ("upperRoman", "%1.".to_owned()),
+            ListPreset::Legal => ("decimal", outline_text(l, "")),
+            ListPreset::UpperLetter => (
+                ["upperLetter", "lowerLetter", "lowerRoman"][l % 3],
+                format!("%{}.", l + 1),
+            ),
+            ListPreset::UpperRoman => (
+                [
+                    "upperRoman",
+                    "upperLetter",
+                    "decimal",
+                    "lowerLetter",
+                    "lowerRoman",
+                ][l % 5],
+                format!("%{}.", l + 1),
+            ),
+        };
+        let (start, hanging) = match self {
+            // Nested numbers get longer, so does their hanging indent.
+            ListPreset::Outline | ListPreset::Legal => (
+                [360, 792, 1224, 1728, 2232, 2736, 3240, 3744, 4320][l],
+                [360, 432, 504, 648, 792, 936, 1080, 1224, 1440][l],
+            ),
+            _ => (720 * (l as i32 + 1), 360),
+        };
+        let level = Level::new(
+            l,
+            Start::new(1),
+            NumberFormat::new(format),
+            LevelText::new(text),
+            LevelJc::new("left"),
+        )
+        .indent(
+            Some(start),
+            Some(SpecialIndentType::Hanging(hanging)),
+            None,
+            None,
+        );
+        if *self == ListPreset::Legal && l > 0 {
+            level.is_lgl()
+        } else {
+            level
+        }
+    }
+}
+
+// "%1.%2.%3" followed by `suffix`.
+fn outline_text(l: usize, suffix: &str) -> String {
+    let numbers: Vec<String> = (1..=l + 1).map(|n| format!("%{}", n)).collect();
+    format!("{}{}", numbers.join("."), suffix)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_legal_levels() {
+        let a = ListPreset::Legal.abstract_numbering(3);
+        assert_eq!(a.id, 3);
+        assert_eq!(a.levels.len(), 9);
+        assert_eq!(a.levels[0].format, NumberFormat::new("upperRoman"));
+        assert_eq!(a.levels[0].is_lgl, None);
+        assert_eq!(a.levels[2].text, LevelText::new("%1.%2.%3"));
+        assert_eq!(a.levels[2].is_lgl, Some(IsLgl::new()));
+    }
+}
@@ -2,17 +2,26 @@ use std::{collections::HashMap, str::FromStr};
 
 mod bookmark_id;
 mod build_xml;
+mod comment_collector;
 mod comments;
 mod comments_extended;
+mod comments_extensible;
+mod comments_ids;
 mod content_types;
 mod custom_item;
 mod custom_item_property;
 mod custom_item_rels;
+mod data_binding_resolver;
 mod doc_props;
 mod document;
+mod document_comparer;
+mod document_path;
 mod document_rels;
+mod effective_formatting;
 mod elements;
+mod font_collector;
 mod font_table;
+mod font_table_rels;
 mod footer;
 mod footer_id;
 mod footer_rels;
@@ -24,12 +33,19 @@ mod header_rels;
 mod history_id;
 mod hyperlink_id;
 mod image_collector;
+mod list_label_resolver;
+mod list_preset;
+mod numbering_rels;
 mod numberings;
+mod package_signer;
 mod paragraph_id;
 mod paragraph_property_change_id;
+mod people;
 mod pic_id;
 mod preset_styles;
 mod rels;
+mod revision_filter;
+mod revision_resolver;
 mod settings;
 mod styles;
 mod taskpanes;
@@ -39,6 +55,7 @@ mod toc_key;
 mod web_settings;
 mod webextension;
 mod xml_docx;
+mod xml_signature;
 
 pub(crate) use build_xml::BuildXML;
 pub(crate) use history_id::HistoryId;
@@ -51,15 +68,22 @@ pub(crate) use pic_id::*;
 pub use bookmark_id::*;
 pub use comments::*;
 pub use comments_extended::*;
+pub use comments_extensible::*;
+pub use comments_ids::*;
 pub use content_types::*;
 pub use custom_item::*;
 pub use custom_item_property::*;
 pub use custom_item_rels::*;
 pub use doc_props::*;
 pub use document::*;
+pub use document_path::*;
 pub use document_rels::*;
+pub use effective_formatting::{
+    EffectiveParagraphProperty, EffectiveRunProperty, FormattingSource,
+};
 pub use elements::*;
 pub use font_table::*;
+pub use font_table_rels::*;
 pub use footer::*;
 pub use footer_id::*;
 pub use footer_rels::*;
@@ -67,8 +91,15 @@ pub use footnotes::*;
 pub use header::*;
 pub use header_id::*;
 pub use header_rels::*;
+pub use list_label_resolver::ListLabel;
+pub use list_preset::*;
+pub use numbering_rels::*;
 pub use numberings::*;
+pub use package_signer::*;
+pub use people::*;
+pub use preset_styles::{builtin_latent_styles, builtin_styles, StylePalette};
 pub use rels::*;
+pub use revision_filter::*;
 pub use settings::*;
 pub use styles::*;
 pub use taskpanes::*;
@@ -81,7 +112,17 @@ pub use xml_docx::*;
 
 use serde::{ser, Serialize};
 
-use self::image_collector::{collect_images_from_paragraph, collect_images_from_table};
+use self::comment_collector::visit_comments_in_document;
+use self::data_binding_resolver::*;
+use self::document_comparer::DocumentComparer;
+use self::effective_formatting::FormattingResolver;
+use self::font_collector::FontCollector;
+use self::image_collector::{
+    collect_images_from_num_pic_bullets, collect_images_from_paragraph, collect_images_from_table,
+    image_type,
+};
+use self::list_label_resolver::ListLabelResolver;
+use self::revision_resolver::RevisionResolver;
 
 #[derive(Debug, Clone)]
 pub struct Image(pub Vec<u8>);
@@ -92,6 +133,9 @@ pub struct Png(pub Vec<u8>);
 pub type ImageIdAndPath = (String, String);
 pub type ImageIdAndBuf = (String, Vec<u8>);
 
+// Runs are 10pt when no size is given in the document defaults.
+pub(crate) const DEFAULT_FONT_SIZE: usize = 20;
+
 impl ser::Serialize for Image {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -127,6 +171,9 @@ pub struct Docx {
     pub font_table: FontTable,
     pub media: Vec<(String, Vec<u8>)>,
     pub comments_extended: CommentsExtended,
+    pub comments_ids: CommentsIds,
+    pub comments_extensible: CommentsExtensible,
+    pub people: People,
     pub web_settings: WebSettings,
     pub taskpanes: Option<Taskpanes>,
     pub taskpanes_rels: TaskpanesRels,
@@ -134,10 +181,18 @@ pub struct Docx {
     pub custom_items: Vec<CustomItem>,
     pub custom_item_props: Vec<CustomItemProperty>,
     pub custom_item_rels: Vec<CustomItemRels>,
+    #[serde(skip)]
+    pub embedded_fonts: Vec<EmbeddedFont>,
+    // Written as theme1.xml. The reader sets the first of `themes`.
+    #[serde(skip)]
+    pub theme: Option<Theme>,
     // reader only
     pub themes: Vec<Theme>,
     // reader only
     pub images: Vec<(String, String, Image, Png)>,
+    // reader only. Images of numbering.xml.rels, whose ids may equal those of document.xml.rels.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub numbering_images: Vec<(String, String, Image, Png)>,
     // reader only
     pub hyperlinks: Vec<(String, String, String)>,
     pub footnotes: Footnotes,
@@ -173,6 +228,9 @@ impl Default for Docx {
             font_table,
             media,
             comments_extended,
+            comments_ids: CommentsIds::new(),
+            comments_extensible: CommentsExtensible::new(),
+            people: People::new(),
             web_settings,
             taskpanes: None,
             taskpanes_rels: TaskpanesRels::new(),
@@ -180,8 +238,11 @@ impl Default for Docx {
             custom_items: vec![],
             custom_item_props: vec![],
             custom_item_rels: vec![],
+            embedded_fonts: vec![],
+            theme: None,
             themes: vec![],
             images: vec![],
+            numbering_images: vec![],
             hyperlinks: vec![],
             footnotes,
         }
@@ -223,6 +284,13 @@ impl Docx {
         self
     }
 
+    /// Adds Word's built-in styles (Heading 1-9, Title, Quote, Hyperlink, Table Grid...)
+    /// which are not defined yet, together with Word's latentStyles.
+    pub fn add_builtin_styles(mut self, palette: StylePalette) -> Self {
+        self.styles = self.styles.add_builtin_styles(&palette);
+        self
+    }
+
     pub fn numberings(mut self, n: Numberings) -> Self {
         self.numberings = n;
         self
@@ -246,15 +314,19 @@ impl Docx {
         path: impl Into<String>,
         buf: Vec<u8>,
     ) -> Self {
-        if let Ok(dimg) = image::load_from_memory(&buf) {
-            let mut png = std::io::Cursor::new(vec![]);
-            // For now only png supported
-            dimg.write_to(&mut png, ImageFormat::Png)
-                .expect("Unable to write dynamic image");
-
-            self.images
-                .push((id.into(), path.into(), Image(buf), Png(png.into_inner())));
-        }
+        self.images.extend(read_image(id.into(), path.into(), buf));
+        self
+    }
+
+    // reader only
+    pub(crate) fn add_numbering_image(
+        mut self,
+        id: impl Into<String>,
+        path: impl Into<String>,
+        buf: Vec<u8>,
+    ) -> Self {
+        self.numbering_images
+            .extend(read_image(id.into(), path.into(), buf));
         self
     }
 
@@ -280,6 +352,26 @@ impl Docx {
         self
     }
 
+    pub fn comments_ids(mut self, c: CommentsIds) -> Self {
+        self.comments_ids = c;
+        self
+    }
+
+    pub fn comments_extensible(mut self, c: CommentsExtensible) -> Self {
+        self.comments_extensible = c;
+        self
+    }
+
+    pub fn people(mut self, p: People) -> Self {
+        self.people = p;
+        self
+    }
+
+    pub fn add_person(mut self, p: Person) -> Self {
+        self.people = self.people.add_person(p);
+        self
+    }
+
     pub fn add_paragraph(mut self, p: Paragraph) -> Docx {
         if p.has_numbering {
             // If this document has numbering, set numberings.xml to document_rels.
@@ -411,6 +503,12 @@ impl Docx {
         self
     }
 
+    /// Registers an image bullet for `Level::pic_bullet`.
+    pub fn add_num_pic_bullet(mut self, bullet: NumPicBullet) -> Docx {
+        self.numberings = self.numberings.add_num_pic_bullet(bullet);
+        self
+    }
+
     pub fn add_abstract_numbering(mut self, num: AbstractNumbering) -> Docx {
         self.numberings = self.numberings.add_abstract_numbering(num);
         self
@@ -421,6 +519,18 @@ impl Docx {
         self
     }
 
+    /// Registers a list of the preset scheme with newly allocated ids.
+    /// Returns the numbering id to give to `Paragraph::numbering`.
+    pub fn create_list(&mut self, preset: ListPreset) -> usize {
+        self.numberings.add_list(preset)
+    }
+
+    /// Adds a new instance of the list of `numbering_id` which numbers from its start again.
+    /// Returns the numbering id of the new instance, None if `numbering_id` is not registered.
+    pub fn restart_list(&mut self, numbering_id: usize) -> Option<usize> {
+        self.numberings.restart_list(numbering_id)
+    }
+
     pub fn created_at(mut self, date: &str) -> Self {
         self.doc_props = self.doc_props.created_at(date);
         self
@@ -431,11 +541,35 @@ impl Docx {
         self
     }
 
+    /// Replaces the core properties (docProps/core.xml), including the dates set by `created_at` / `updated_at`.
+    pub fn core_props(mut self, config: CorePropsConfig) -> Self {
+        self.doc_props = self.doc_props.core_props(config);
+        self
+    }
+
+    /// Sets the extended properties (docProps/app.xml).
+    pub fn app_props(mut self, app: AppProps) -> Self {
+        self.doc_props = self.doc_props.app_props(app);
+        self
+    }
+
     pub fn custom_property(mut self, name: impl Into<String>, item: impl Into<String>) -> Self {
         self.doc_props = self.doc_props.custom_property(name, item);
         self
     }
 
+    /// Adds a typed custom property. The properties are written in the order they are added.
+    pub fn add_custom_property(mut self, prop: CustomProperty) -> Self {
+        self.doc_props = self.doc_props.add_custom_property(prop);
+        self
+    }
+
+    /// Asks Word to update fields such as a table of contents when the document is opened.
+    pub fn update_fields(mut self) -> Self {
+        self.settings = self.settings.update_fields();
+        self
+    }
+
     pub fn doc_id(mut self, id: &str) -> Self {
         self.settings = self.settings.doc_id(id);
         self
@@ -451,6 +585,85 @@ impl Docx {
         self
     }
 
+    pub fn document_protection(mut self, p: DocumentProtection) -> Self {
+        self.settings = self.settings.document_protection(p);
+        self
+    }
+
+    pub fn write_protection(mut self, p: WriteProtection) -> Self {
+        self.settings = self.settings.write_protection(p);
+        self
+    }
+
+    pub fn track_revisions(mut self) -> Self {
+        self.settings = self.settings.track_revisions();
+        self
+    }
+
+    /// Accept every tracked revision in the document body, headers, footers and footnotes.
+    pub fn accept_all_revisions(self) -> Self {
+        self.accept_revisions(RevisionFilter::new())
+    }
+
+    /// Reject every tracked revision in the document body, headers, footers and footnotes.
+    pub fn reject_all_revisions(self) -> Self {
+        self.reject_revisions(RevisionFilter::new())
+    }
+
+    /// Accept the tracked revisions matching the filter.
+    pub fn accept_revisions(mut self, filter: RevisionFilter) -> Self {
+        RevisionResolver::new(true, &filter).resolve_document(&mut self.document);
+        self
+    }
+
+    /// Reject the tracked revisions matching the filter.
+    pub fn reject_revisions(mut self, filter: RevisionFilter) -> Self {
+        RevisionResolver::new(false, &filter).resolve_document(&mut self.document);
+        self
+    }
+
+    /// Compare with a revised version of the document like Word's Compare feature.
+    /// Returns the revised document with the differences tracked as revisions of the given author and date.
+    /// Revisions already in either document are accepted before comparing.
+    pub fn compare(
+        &self,
+        revised: &Docx,
+        author: impl Into<String>,
+        date: impl Into<String>,
+    ) -> Docx {
+        let original = self.clone().accept_all_revisions();
+        let mut docx = revised.clone().accept_all_revisions();
+        docx.document =
+            DocumentComparer::new(author, date).compare(&original.document, &docx.document);
+        docx
+    }
+
+    /// Computed formatting of the paragraph at `path` with the source of each attribute.
+    /// Document defaults, table style, numbering level, paragraph style and direct formatting
+    /// are applied in this order. Returns None if `path` does not point to a paragraph.
+    pub fn effective_paragraph_property(
+        &self,
+        path: &DocumentPath,
+    ) -> Option<EffectiveParagraphProperty> {
+        let location = path.find_paragraph(&self.document)?;
+        Some(FormattingResolver::new(&self.styles, &self.numberings).paragraph(&location))
+    }
+
+    /// Computed formatting of the run at `path` with the source of each attribute.
+    /// Document defaults, table style, paragraph style, character style and direct formatting
+    /// are applied in this order. Returns None if `path` does not point to a run.
+    pub fn effective_run_property(&self, path: &DocumentPath) -> Option<EffectiveRunProperty> {
+        let location = path.find_paragraph(&self.document)?;
+        let run = path.find_run(location.paragraph)?;
+        Some(FormattingResolver::new(&self.styles, &self.numberings).run(&location, run))
+    }
+
+    /// Rendered list numbers of the numbered paragraphs in the body, its tables, content controls and
+    /// tables of contents in document order, followed by those of the headers and footers.
+    pub fn list_labels(&self) -> Vec<ListLabel> {
+        ListLabelResolver::new(&self.styles, &self.numberings).resolve(&self.document)
+    }
+
     pub fn page_size(mut self, w: u32, h: u32) -> Self {
         self.document = self.document.page_size(PageSize::new().size(w, h));
         self
@@ -486,6 +699,34 @@ impl Docx {
         self
     }
 
+    /// Overrides the metadata of a font in fontTable.xml.
+    pub fn add_font(mut self, font: Font) -> Self {
+        self.font_table = self.font_table.add_font(font);
+        self
+    }
+
+    /// Embeds a TrueType font so the document renders where it is not installed.
+    /// A font embedded again with the same name and style replaces the previous one.
+    pub fn embed_font(
+        mut self,
+        name: impl Into<String>,
+        embed_type: crate::types::FontEmbedType,
+        data: Vec<u8>,
+    ) -> Self {
+        let name = name.into();
+        self.embedded_fonts
+            .retain(|f| f.name != name || f.embed_type != embed_type);
+        self.embedded_fonts
+            .push(EmbeddedFont::new(name, embed_type, data));
+        self.settings = self.settings.embed_true_type_fonts();
+        self
+    }
+
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
     pub fn taskpanes(mut self) -> Self {
         self.taskpanes = Some(Taskpanes::new());
         self.rels = self.rels.add_taskpanes_rel();
@@ -500,8 +741,12 @@ impl Docx {
         self
     }
 
-    pub fn add_custom_item(mut self, id: &str, xml: &str) -> Self {
+    pub fn add_custom_item(self, id: &str, xml: &str) -> Self {
         let x = CustomItem::from_str(xml).expect("should parse xml string");
+        self.custom_item(id, x)
+    }
+
+    pub(crate) fn custom_item(mut self, id: &str, x: CustomItem) -> Self {
         self.content_type = self.content_type.add_custom_xml();
         let rel = CustomItemRels::new().add_item();
         self.custom_item_props.push(CustomItemProperty::new(id));
@@ -521,6 +766,10 @@ impl Docx {
 
         self.update_dependencies();
 
+        self.update_data_bindings();
+
+        self.update_table_grids();
+
         let tocs: Vec<(usize, Box<TableOfContents>)> = self
             .document
             .children
@@ -558,6 +807,27 @@ impl Docx {
         images_bufs.extend(header_images_bufs);
         images_bufs.extend(footer_images_bufs);
 
+        let mut numbering_images = vec![];
+        let mut numbering_media = vec![];
+        collect_images_from_num_pic_bullets(
+            &mut self.numberings.num_pic_bullets,
+            &mut numbering_images,
+            &mut images_bufs,
+            &mut numbering_media,
+        );
+        for (_, buf) in &numbering_media {
+            let (ext, content_type) = image_type(buf);
+            self.content_type =
+                std::mem::take(&mut self.content_type).add_default(ext, content_type);
+        }
+        let numbering_rels = if numbering_images.is_empty() {
+            None
+        } else {
+            let mut rels = NumberingRels::new();
+            rels.set_images(numbering_images);
+            Some(rels.build())
+        };
+
         let mut header_rels = vec![HeaderRels::new(); 3];
         for (i, images) in header_images.iter().enumerate() {
             if let Some(h) = header_rels.get_mut(i) {
@@ -598,6 +868,14 @@ impl Docx {
             .map(|h| h.build())
             .collect();
 
+        let (fonts, font_table_rels) = self.update_font_table();
+
+        let theme = self.theme.as_ref().map(|t| t.build());
+        if theme.is_some() {
+            self.content_type = self.content_type.add_theme();
+            self.document_rels.has_theme = true;
+        }
+
         // Collect footnotes
         if self.collect_footnotes() {
             // Relationship entry for footnotes
@@ -617,11 +895,23 @@ impl Docx {
             footer_rels: footer_rels.into_iter().map(|r| r.build()).collect(),
             settings: self.settings.build(),
             font_table: self.font_table.build(),
+            font_table_rels,
+            fonts,
             numberings: self.numberings.build(),
-            media: images_bufs,
+            numbering_rels,
+            theme,
+            // For now only png supported, except for picture bullets.
+            media: images_bufs
+                .into_iter()
+                .map(|(id, buf)| (format!("{}.png", id), buf))
+                .chain(numbering_media)
+                .collect(),
             headers,
             footers,
             comments_extended: self.comments_extended.build(),
+            comments_ids: self.comments_ids.build(),
+            comments_extensible: self.comments_extensible.build(),
+            people: self.people.build(),
             taskpanes: self.taskpanes.map(|taskpanes| taskpanes.build()),
             taskpanes_rels: self.taskpanes_rels.build(),
             web_extensions,
@@ -646,194 +936,137 @@ impl Docx {
         serde_json::to_string_pretty(&self).unwrap()
     }
 
-    fn reset(&self) {
-        crate::reset_para_id();
-    }
+    // Add every font used in styles, numberings and content to fontTable.xml,
+    // and returns the obfuscated embedded fonts with fontTable.xml.rels.
+    fn update_font_table(&mut self) -> (Vec<Vec<u8>>, Option<Vec<u8>>) {
+        let mut collector = FontCollector::new();
+        collector.visit_styles(&self.styles);
+        collector.visit_numberings(&self.numberings);
+        collector.visit_document(&self.document);
+        // Theme fonts are used through w:asciiTheme and the like.
+        if let Some(theme) = &self.theme {
+            for group in [&theme.font_schema.major_font, &theme.font_schema.minor_font] {
+                collector.add_name(&group.latin);
+                collector.add_name(&group.ea);
+                collector.add_name(&group.cs);
+            }
+        }
+        for f in &self.embedded_fonts {
+            collector.add_name(&f.name);
+        }
+        for name in collector.into_names() {
+            if self.font_table.find_font(&name).is_none() {
+                self.font_table =
+                    std::mem::take(&mut self.font_table).add_font(Font::from_name(name));
+            }
+        }
 
-    fn insert_comment_to_map(
-        &self,
-        comment_map: &mut HashMap<usize, String>,
-        c: &CommentRangeStart,
-    ) {
-        let comment = c.get_comment();
-        let comment_id = comment.id();
-        for child in comment.children {
-            if let CommentChild::Paragraph(child) = child {
-                let para_id = child.id.clone();
-                comment_map.insert(comment_id, para_id.clone());
+        if self.embedded_fonts.is_empty() {
+            return (vec![], None);
+        }
+        let mut rels = FontTableRels::new();
+        let mut fonts = vec![];
+        for (i, f) in self.embedded_fonts.iter().enumerate() {
+            let id = format!("rIdFont{}", i + 1);
+            let key = f.font_key();
+            if let Some(font) = self.font_table.find_font_mut(&f.name) {
+                font.add_embed(f.embed_type, &id, &key);
             }
-            // TODO: Support table in comment
+            rels = rels.add_font(&id, format!("fonts/font{}.odttf", i + 1));
+            fonts.push(f.obfuscate(&key));
         }
+        self.content_type = std::mem::take(&mut self.content_type).add_default(
+            "odttf",
+            "application/vnd.openxmlformats-officedocument.obfuscatedFont",
+        );
+        (fonts, Some(rels.build()))
     }
 
-    // Traverse and clone comments from document and add to comments node.
+    fn reset(&self) {
+        crate::reset_para_id();
+    }
+
+    // Traverse and clone comments from all stories and add to comments node.
     fn update_dependencies(&mut self) {
         let mut comments: Vec<Comment> = vec![];
-        let mut comments_extended: Vec<CommentExtended> = vec![];
-        let mut comment_map: HashMap<usize, String> = HashMap::new();
-
-        let mut hyperlink_map: HashMap<String, String> = HashMap::new();
-
-        for child in &self.document.children {
-            match child {
-                DocumentChild::Paragraph(paragraph) => {
-                    for child in &paragraph.children {
-                        if let ParagraphChild::CommentStart(c) = child {
-                            self.insert_comment_to_map(&mut comment_map, c);
-                        }
-                        if let ParagraphChild::Hyperlink(h) = child {
-                            if let HyperlinkData::External { rid, path } = h.link.clone() {
-                                hyperlink_map.insert(rid, path);
-                            };
-                            for child in &h.children {
-                                if let ParagraphChild::CommentStart(c) = child {
-                                    self.insert_comment_to_map(&mut comment_map, c);
-                                }
-                            }
-                        }
-                    }
-                }
-                DocumentChild::Table(table) => {
-                    collect_dependencies_in_table(
-                        table,
-                        &mut comments,
-                        &mut comments_extended,
-                        &mut comment_map,
-                        &mut hyperlink_map,
-                    );
-                }
-                _ => {}
+        visit_comments_in_document(&mut self.document, &mut |c| {
+            if comments.iter().all(|comment| comment.id() != c.get_id()) {
+                comments.push(c.get_comment());
             }
-        }
+        });
 
+        let comment_map: HashMap<usize, String> = comments
+            .iter()
+            .filter_map(|c| c.paragraph_id().map(|para_id| (c.id(), para_id)))
+            .collect();
+        let comments_extended: Vec<CommentExtended> = comments
+            .iter()
+            .filter_map(|c| create_comment_extended(c, &comment_map))
+            .collect();
+
+        let mut hyperlink_map: HashMap<String, String> = HashMap::new();
         for child in &self.document.children {
             match child {
                 DocumentChild::Paragraph(paragraph) => {
-                    for child in &paragraph.children {
-                        if let ParagraphChild::CommentStart(c) = child {
-                            push_comment_and_comment_extended(
-                                &mut comments,
-                                &mut comments_extended,
-                                &comment_map,
-                                c,
-                            );
-                        }
-                        if let ParagraphChild::Hyperlink(h) = child {
-                            if let HyperlinkData::External { rid, path } = h.link.clone() {
-                                hyperlink_map.insert(rid, path);
-                            };
-                            for child in &h.children {
-                                if let ParagraphChild::CommentStart(c) = child {
-                                    push_comment_and_comment_extended(
-                                        &mut comments,
-                                        &mut comments_extended,
-                                        &comment_map,
-                                        c,
-                                    );
-                                }
-                            }
-                        }
-                    }
+                    collect_hyperlinks_in_paragraph(paragraph, &mut hyperlink_map);
                 }
                 DocumentChild::Table(table) => {
-                    collect_dependencies_in_table(
-                        table,
-                        &mut comments,
-                        &mut comments_extended,
-                        &mut comment_map,
-                        &mut hyperlink_map,
-                    );
+                    collect_hyperlinks_in_table(table, &mut hyperlink_map);
                 }
                 DocumentChild::TableOfContents(toc) => {
                     // TODO:refine later
-                    for child in &toc.before_contents {
-                        if let TocContent::Paragraph(paragraph) = child {
-                            for child in &paragraph.children {
-                                if let ParagraphChild::CommentStart(c) = child {
-                                    push_comment_and_comment_extended(
-                                        &mut comments,
-                                        &mut comments_extended,
-                                        &comment_map,
-                                        c,
-                                    );
-                                }
-                                if let ParagraphChild::Hyperlink(h) = child {
-                                    if let HyperlinkData::External { rid, path } = h.link.clone() {
-                                        hyperlink_map.insert(rid, path);
-                                    };
-                                    for child in &h.children {
-                                        if let ParagraphChild::CommentStart(c) = child {
-                                            push_comment_and_comment_extended(
-                                                &mut comments,
-                                                &mut comments_extended,
-                                                &comment_map,
-                                                c,
-                                            );
-                                        }
-                                    }
-                                }
+                    for child in toc.before_contents.iter().chain(toc.after_contents.iter()) {
+                        match child {
+                            TocContent::Paragraph(paragraph) => {
+                                collect_hyperlinks_in_paragraph(paragraph, &mut hyperlink_map);
                             }
-                        }
-                        if let TocContent::Table(table) = child {
-                            collect_dependencies_in_table(
-                                table,
-                                &mut comments,
-                                &mut comments_extended,
-                                &mut comment_map,
-                                &mut hyperlink_map,
-                            );
-                        }
-                    }
-                    for child in &toc.after_contents {
-                        if let TocContent::Paragraph(paragraph) = child {
-                            for child in &paragraph.children {
-                                if let ParagraphChild::CommentStart(c) = child {
-                                    push_comment_and_comment_extended(
-                                        &mut comments,
-                                        &mut comments_extended,
-                                        &comment_map,
-                                        c,
-                                    );
-                                }
-                                if let ParagraphChild::Hyperlink(h) = child {
-                                    if let HyperlinkData::External { rid, path } = h.link.clone() {
-                                        hyperlink_map.insert(rid, path);
-                                    };
-                                    for child in &h.children {
-                                        if let ParagraphChild::CommentStart(c) = child {
-                                            push_comment_and_comment_extended(
-                                                &mut comments,
-                                                &mut comments_extended,
-                                                &comment_map,
-                                                c,
-                                            );
-                                        }
-                                    }
-                                }
+                            TocContent::Table(table) => {
+                                collect_hyperlinks_in_table(table, &mut hyperlink_map);
                             }
                         }
-                        if let TocContent::Table(table) = child {
-                            collect_dependencies_in_table(
-                                table,
-                                &mut comments,
-                                &mut comments_extended,
-                                &mut comment_map,
-                                &mut hyperlink_map,
-                            );
-                        }
                     }
                 }
                 _ => {}
             }
         }
+
         // If this document has comments, set comments.xml to document_rels.
         // This is because comments.xml without comment cause an error on word online.
         if !comments.is_empty() {
             self.document_rels.has_comments = true;
         }
 
+        let mut comments_ids: Vec<CommentId> = vec![];
+        let mut comments_extensible: Vec<CommentExtensible> = vec![];
+        for c in &comments {
+            if let Some(para_id) = c.paragraph_id() {
+                let durable_id = c.durable_id.clone().unwrap_or_else(|| para_id.clone());
+                comments_ids.push(CommentId::new(para_id, durable_id.clone()));
+                // w:date is local time, so dateUtc is only kept from a read commentsExtensible.
+                let read = self
+                    .comments_extensible
+                    .children
+                    .iter()
+                    .find(|e| e.durable_id == durable_id)
+                    .and_then(|e| e.date_utc.clone());
+                let mut extensible = CommentExtensible::new(durable_id);
+                if let Some(date_utc) = read {
+                    extensible = extensible.date_utc(date_utc);
+                }
+                comments_extensible.push(extensible);
+            }
+            if !self.people.contains(&c.author) {
+                self.people
+                    .people
+                    .push(Person::new(c.author.clone()).presence_info("None", c.author.clone()));
+            }
+        }
+
         self.comments_extended
             .add_comments_extended(comments_extended);
+        self.comments_ids.add_comment_ids(comments_ids);
+        self.comments_extensible
+            .add_comments_extensible(comments_extensible);
 
         self.comments.add_comments(comments);
 
@@ -844,56 +1077,121 @@ impl Docx {
         }
     }
 
-    // Traverse and clone comments from document and add to comments node.
+    // Attach read comments to their range starts in every story.
     // reader only
     pub(crate) fn store_comments(&mut self, comments: &[Comment]) {
+        visit_comments_in_document(&mut self.document, &mut |c| {
+            let comment_id = c.get_id();
+            if let Some(comment) = comments.iter().find(|c| c.id() == comment_id) {
+                c.comment(comment.clone());
+            }
+        });
+
+        if !comments.is_empty() {
+            self.document_rels.has_comments = true;
+        }
+    }
+
+    // Fill data bound structured data tags with values from custom xml items.
+    fn update_data_bindings(&mut self) {
+        if self.custom_items.is_empty() {
+            return;
+        }
+        let items = &self.custom_items;
+        let props = &self.custom_item_props;
+        let mut update = |tag: &mut StructuredDataTag| {
+            if let Some(v) = resolve_data_binding(tag, items, props) {
+                tag.set_bound_text(&v);
+            }
+        };
+        visit_tags_in_document(&mut self.document, &mut update);
+    }
+
+    // Without a tblGrid LibreOffice and Google Docs collapse the columns of auto width tables,
+    // so estimate one from the content and the text width of the section.
+    fn update_table_grids(&mut self) {
+        let section = &mut self.document.section_property;
+        let text_width = (section.page_size.get_width() as i32
+            - section.page_margin.left
+            - section.page_margin.right)
+            .max(0) as usize;
+        let default_size = self
+            .styles
+            .get_default_run_property()
+            .sz
+            .as_ref()
+            .map(|s| s.get_val())
+            .unwrap_or(DEFAULT_FONT_SIZE);
         for child in &mut self.document.children {
+            if let DocumentChild::Table(t) = child {
+                t.fill_grid(text_width, default_size);
+            }
+        }
+        for header in vec![
+            section.header.as_mut(),
+            section.first_header.as_mut(),
+            section.even_header.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for child in &mut header.children {
+                if let HeaderChild::Table(t) = child {
+                    t.fill_grid(text_width, default_size);
+                }
+            }
+        }
+        for footer in vec![
+            section.footer.as_mut(),
+            section.first_footer.as_mut(),
+            section.even_footer.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for child in &mut footer.children {
+                if let FooterChild::Table(t) = child {
+                    t.fill_grid(text_width, default_size);
+                }
+            }
+        }
+    }
+
+    /// Collect the value of each data bound structured data tag.
+    /// The value is taken from the bound custom xml item and falls back to the tag's text.
+    pub fn bound_values(&self) -> Vec<(DataBinding, String)> {
+        let mut values = vec![];
+        let items = &self.custom_items;
+        let props = &self.custom_item_props;
+        let mut collect = |tag: &StructuredDataTag| {
+            if let Some(binding) = tag.property.data_binding.clone() {
+                let v = resolve_data_binding(tag, items, props)
+                    .unwrap_or_else(|| crate::escape::replace_escaped(&tag.raw_text()));
+                values.push((binding, v));
+            }
+        };
+        walk_tags_in_document(&self.document, &mut collect);
+        values
+    }
+
+    /// Collect legacy form fields in the document body with their current values.
+    pub fn form_field_values(&self) -> Vec<(FormFieldData, FormFieldValue)> {
+        let mut values = vec![];
+        for child in &self.document.children {
             match child {
                 DocumentChild::Paragraph(paragraph) => {
-                    for child in &mut paragraph.children {
-                        if let ParagraphChild::CommentStart(ref mut c) = child {
-                            let comment_id = c.get_id();
-                            if let Some(comment) = comments.iter().find(|c| c.id() == comment_id) {
-                                let comment = comment.clone();
-                                c.as_mut().comment(comment);
-                            }
-                        }
-                        if let ParagraphChild::Insert(ref mut insert) = child {
-                            for child in &mut insert.children {
-                                if let InsertChild::CommentStart(ref mut c) = child {
-                                    let comment_id = c.get_id();
-                                    if let Some(comment) =
-                                        comments.iter().find(|c| c.id() == comment_id)
-                                    {
-                                        let comment = comment.clone();
-                                        c.as_mut().comment(comment);
-                                    }
-                                }
-                            }
-                        }
-                        if let ParagraphChild::Delete(ref mut delete) = child {
-                            for child in &mut delete.children {
-                                if let DeleteChild::CommentStart(ref mut c) = child {
-                                    let comment_id = c.get_id();
-                                    if let Some(comment) =
-                                        comments.iter().find(|c| c.id() == comment_id)
-                                    {
-                                        let comment = comment.clone();
-                                        c.as_mut().comment(comment);
-                                    }
-                                }
-                            }
-                        }
-                    }
+                    values.extend(paragraph.form_field_values());
+                }
+                DocumentChild::Table(table) => {
+                    collect_form_field_values_from_table(table, &mut values);
+                }
+                DocumentChild::StructuredDataTag(tag) => {
+                    collect_form_field_values_from_tag(tag, &mut values);
                 }
-                DocumentChild::Table(table) => store_comments_in_table(table, comments),
                 _ => {}
             }
         }
-
-        if !comments.is_empty() {
-            self.document_rels.has_comments = true;
-        }
+        values
     }
 
     // Traverse and collect images from document.
@@ -1224,121 +1522,79 @@ impl Docx {
         self.footnotes.add(footnotes);
         is_footnotes
     }
+
+    // Move read footnote content to the references which collect_footnotes writes.
+    pub(crate) fn store_footnotes(&mut self, footnotes: &Footnotes) {
+        for child in &mut self.document.children {
+            if let DocumentChild::Paragraph(paragraph) = child {
+                for child in &mut paragraph.children {
+                    if let ParagraphChild::Run(run) = child {
+                        for child in &mut run.children {
+                            if let RunChild::FootnoteReference(r) = child {
+                                if let Some(f) = footnotes.footnotes.iter().find(|f| f.id == r.id) {
+                                    r.content = f.content.clone();
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn read_image(id: String, path: String, buf: Vec<u8>) -> Option<(String, String, Image, Png)> {
+    let dimg = image::load_from_memory(&buf).ok()?;
+    let mut png = std::io::Cursor::new(vec![]);
+    // For now only png supported
+    dimg.write_to(&mut png, ImageFormat::Png)
+        .expect("Unable to write dynamic image");
+    Some((id, path, Image(buf), Png(png.into_inner())))
 }
 
-fn collect_dependencies_in_paragraph(
+fn collect_hyperlinks_in_paragraph(
     paragraph: &Paragraph,
-    comments: &mut Vec<Comment>,
-    comments_extended: &mut Vec<CommentExtended>,
-    comment_map: &mut HashMap<usize, String>,
     hyperlink_map: &mut HashMap<String, String>,
 ) {
     for child in &paragraph.children {
-        if let ParagraphChild::CommentStart(c) = child {
-            push_comment_and_comment_extended(comments, comments_extended, comment_map, c);
-        }
         if let ParagraphChild::Hyperlink(h) = child {
             if let HyperlinkData::External { rid, path } = h.link.clone() {
                 hyperlink_map.insert(rid, path);
             };
-            for child in &h.children {
-                if let ParagraphChild::CommentStart(c) = child {
-                    push_comment_and_comment_extended(comments, comments_extended, comment_map, c);
-                }
-            }
         }
     }
 }
 
-fn collect_dependencies_in_table(
-    table: &Table,
-    comments: &mut Vec<Comment>,
-    comments_extended: &mut Vec<CommentExtended>,
-    comment_map: &mut HashMap<usize, String>,
-    hyperlink_map: &mut HashMap<String, String>,
-) {
+fn collect_hyperlinks_in_table(table: &Table, hyperlink_map: &mut HashMap<String, String>) {
     for TableChild::TableRow(row) in &table.rows {
         for TableRowChild::TableCell(cell) in &row.cells {
             for content in &cell.children {
                 match content {
                     TableCellContent::Paragraph(paragraph) => {
-                        collect_dependencies_in_paragraph(
-                            paragraph,
-                            comments,
-                            comments_extended,
-                            comment_map,
-                            hyperlink_map,
-                        );
+                        collect_hyperlinks_in_paragraph(paragraph, hyperlink_map);
+                    }
+                    TableCellContent::Table(table) => {
+                        collect_hyperlinks_in_table(table, hyperlink_map)
                     }
-                    TableCellContent::Table(table) => collect_dependencies_in_table(
-                        table,
-                        comments,
-                        comments_extended,
-                        comment_map,
-                        hyperlink_map,
-                    ),
                     TableCellContent::StructuredDataTag(tag) => {
                         for child in &tag.children {
                             if let StructuredDataTagChild::Paragraph(paragraph) = child {
-                                collect_dependencies_in_paragraph(
-                                    paragraph,
-                                    comments,
-                                    comments_extended,
-                                    comment_map,
-                                    hyperlink_map,
-                                );
+                                collect_hyperlinks_in_paragraph(paragraph, hyperlink_map);
                             }
                             if let StructuredDataTagChild::Table(table) = child {
-                                collect_dependencies_in_table(
-                                    table,
-                                    comments,
-                                    comments_extended,
-                                    comment_map,
-                                    hyperlink_map,
-                                );
+                                collect_hyperlinks_in_table(table, hyperlink_map);
                             }
                         }
                     }
                     TableCellContent::TableOfContents(t) => {
-                        for child in &t.before_contents {
-                            if let TocContent::Paragraph(paragraph) = child {
-                                collect_dependencies_in_paragraph(
-                                    paragraph,
-                                    comments,
-                                    comments_extended,
-                                    comment_map,
-                                    hyperlink_map,
-                                );
-                            }
-                            if let TocContent::Table(table) = child {
-                                collect_dependencies_in_table(
-                                    table,
-                                    comments,
-                                    comments_extended,
-                                    comment_map,
-                                    hyperlink_map,
-                                );
-                            }
-                        }
-
-                        for child in &t.after_contents {
-                            if let TocContent::Paragraph(paragraph) = child {
-                                collect_dependencies_in_paragraph(
-                                    paragraph,
-                                    comments,
-                                    comments_extended,
-                                    comment_map,
-                                    hyperlink_map,
-                                );
-                            }
-                            if let TocContent::Table(table) = child {
-                                collect_dependencies_in_table(
-                                    table,
-                                    comments,
-                                    comments_extended,
-                                    comment_map,
-                                    hyperlink_map,
-                                );
+                        for child in t.before_contents.iter().chain(t.after_contents.iter()) {
+                            match child {
+                                TocContent::Paragraph(paragraph) => {
+                                    collect_hyperlinks_in_paragraph(paragraph, hyperlink_map);
+                                }
+                                TocContent::Table(table) => {
+                                    collect_hyperlinks_in_table(table, hyperlink_map);
+                                }
                             }
                         }
                     }
@@ -1348,111 +1604,67 @@ fn collect_dependencies_in_table(
     }
 }
 
-fn store_comments_in_paragraph(paragraph: &mut Paragraph, comments: &[Comment]) {
-    for child in &mut paragraph.children {
-        if let ParagraphChild::CommentStart(ref mut c) = child {
-            let comment_id = c.get_id();
-            if let Some(comment) = comments.iter().find(|c| c.id() == comment_id) {
-                let comment = comment.clone();
-                c.as_mut().comment(comment);
-            }
-        }
-        if let ParagraphChild::Insert(ref mut insert) = child {
-            for child in &mut insert.children {
-                if let InsertChild::CommentStart(ref mut c) = child {
-                    let comment_id = c.get_id();
-                    if let Some(comment) = comments.iter().find(|c| c.id() == comment_id) {
-                        let comment = comment.clone();
-                        c.as_mut().comment(comment);
-                    }
-                }
-            }
-        }
-        if let ParagraphChild::Delete(ref mut delete) = child {
-            for child in &mut delete.children {
-                if let DeleteChild::CommentStart(ref mut c) = child {
-                    let comment_id = c.get_id();
-                    if let Some(comment) = comments.iter().find(|c| c.id() == comment_id) {
-                        let comment = comment.clone();
-                        c.as_mut().comment(comment);
-                    }
-                }
-            }
-        }
-    }
-}
-
-fn store_comments_in_table(table: &mut Table, comments: &[Comment]) {
-    for TableChild::TableRow(row) in &mut table.rows {
-        for TableRowChild::TableCell(cell) in &mut row.cells {
-            for content in &mut cell.children {
+fn collect_form_field_values_from_table(
+    table: &Table,
+    values: &mut Vec<(FormFieldData, FormFieldValue)>,
+) {
+    for TableChild::TableRow(row) in &table.rows {
+        for TableRowChild::TableCell(cell) in &row.cells {
+            for content in &cell.children {
                 match content {
                     TableCellContent::Paragraph(paragraph) => {
-                        store_comments_in_paragraph(paragraph, comments)
+                        values.extend(paragraph.form_field_values());
                     }
-                    TableCellContent::Table(ref mut table) => {
-                        store_comments_in_table(table, comments);
+                    TableCellContent::Table(table) => {
+                        collect_form_field_values_from_table(table, values);
                     }
-                    TableCellContent::StructuredDataTag(ref mut tag) => {
-                        for child in &mut tag.children {
-                            if let StructuredDataTagChild::Paragraph(paragraph) = child {
-                                store_comments_in_paragraph(paragraph, comments);
-                            }
-                            if let StructuredDataTagChild::Table(table) = child {
-                                store_comments_in_table(table, comments);
-                            }
-                        }
-                    }
-                    TableCellContent::TableOfContents(ref mut t) => {
-                        for child in &mut t.before_contents {
-                            if let TocContent::Paragraph(paragraph) = child {
-                                store_comments_in_paragraph(paragraph, comments);
-                            }
-                            if let TocContent::Table(table) = child {
-                                store_comments_in_table(table, comments);
-                            }
-                        }
-
-                        for child in &mut t.after_contents {
-                            if let TocContent::Paragraph(paragraph) = child {
-                                store_comments_in_paragraph(paragraph, comments);
-                            }
-                            if let TocContent::Table(table) = child {
-                                store_comments_in_table(table, comments);
-                            }
-                        }
+                    TableCellContent::StructuredDataTag(tag) => {
+                        collect_form_field_values_from_tag(tag, values);
                     }
+                    TableCellContent::TableOfContents(_) => {}
                 }
             }
         }
     }
 }
 
-fn push_comment_and_comment_extended(
-    comments: &mut Vec<Comment>,
-    comments_extended: &mut Vec<CommentExtended>,
-    comment_map: &HashMap<usize, String>,
-    c: &CommentRangeStart,
+fn collect_form_field_values_from_tag(
+    tag: &StructuredDataTag,
+    values: &mut Vec<(FormFieldData, FormFieldValue)>,
 ) {
-    let comment = c.get_comment();
-    for child in comment.children {
-        if let CommentChild::Paragraph(child) = child {
-            let para_id = child.id.clone();
-            comments.push(c.get_comment());
-            let comment_extended = CommentExtended::new(para_id);
-            if let Some(parent_comment_id) = comment.parent_comment_id {
-                if let Some(parent_para_id) = comment_map.get(&parent_comment_id) {
-                    comments_extended
-                        .push(comment_extended.parent_paragraph_id(parent_para_id.clone()));
-                }
-            } else {
-                comments_extended.push(comment_extended);
+    for child in &tag.children {
+        match child {
+            StructuredDataTagChild::Paragraph(paragraph) => {
+                values.extend(paragraph.form_field_values());
             }
+            StructuredDataTagChild::Table(table) => {
+                collect_form_field_values_from_table(table, values);
+            }
+            StructuredDataTagChild::StructuredDataTag(tag) => {
+                collect_form_field_values_from_tag(tag, values);
+            }
+            _ => {}
         }
-        // TODO: Support table in comment
     }
 }
 
+fn create_comment_extended(
+    comment: &Comment,
+    comment_map: &HashMap<usize, String>,
+) -> Option<CommentExtended> {
+    let mut comment_extended = CommentExtended::new(comment.paragraph_id()?);
+    if comment.done {
+        comment_extended = comment_extended.done();
+    }
+    if let Some(parent_para_id) = comment
+        .parent_comment_id
+        .and_then(|id| comment_map.get(&id))
+    {
+        comment_extended = comment_extended.parent_paragraph_id(parent_para_id.clone());
+    }
+    Some(comment_extended)
+}
+
 fn update_document_by_toc(
     document_children: Vec<DocumentChild>,
     styles: &Styles,
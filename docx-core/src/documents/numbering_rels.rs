@@ -0,0 +1,67 @@
+use crate::documents::BuildXML;
+use crate::{xml_builder::*, ImageIdAndPath};
+use serde::Serialize;
+
+// Relationships of numbering.xml, i.e. images of picture bullets.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NumberingRels {
+    pub images: Vec<(String, String)>,
+}
+
+impl NumberingRels {
+    pub fn new() -> NumberingRels {
+        Default::default()
+    }
+
+    pub fn add_image(mut self, id: impl Into<String>, path: impl Into<String>) -> Self {
+        self.images.push((id.into(), path.into()));
+        self
+    }
+
+    pub(crate) fn set_images(&mut self, images: Vec<ImageIdAndPath>) {
+        self.images = images;
+    }
+}
+
+impl BuildXML for NumberingRels {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new();
+        b = b
+            .declaration(None)
+            .open_relationships("http://schemas.openxmlformats.org/package/2006/relationships");
+
+        for (id, path) in self.images.iter() {
+            b = b.relationship(
+                id,
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
+                path,
+            )
+        }
+
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let b = NumberingRels::new()
+            .add_image("numberingrIdImage1", "media/numberingrIdImage1.png")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="numberingrIdImage1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="media/numberingrIdImage1.png" />
+</Relationships>"#
+        );
+    }
+}
@@ -8,6 +8,8 @@ use serde::Serialize;
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Numberings {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub num_pic_bullets: Vec<NumPicBullet>,
     pub abstract_nums: Vec<AbstractNumbering>,
     pub numberings: Vec<Numbering>,
 }
@@ -17,6 +19,11 @@ impl Numberings {
         Default::default()
     }
 
+    pub fn add_num_pic_bullet(mut self, b: NumPicBullet) -> Self {
+        self.num_pic_bullets.push(b);
+        self
+    }
+
     pub fn add_abstract_numbering(mut self, n: AbstractNumbering) -> Self {
         self.abstract_nums.push(n);
         self
@@ -26,11 +33,97 @@ impl Numberings {
         self.numberings.push(n);
         self
     }
+
+    // Registers a list of the preset scheme and returns its numbering id for `Paragraph::numbering`.
+    pub fn add_list(&mut self, preset: ListPreset) -> usize {
+        let abstract_id = next_id(self.abstract_nums.iter().map(|a| a.id));
+        self.abstract_nums
+            .push(preset.abstract_numbering(abstract_id));
+        let id = next_id(self.numberings.iter().map(|n| n.id));
+        self.numberings.push(Numbering::new(id, abstract_id));
+        id
+    }
+
+    // Adds a numbering of the same scheme as numbering `id` which starts over,
+    // and returns its id. None if there is no numbering `id`.
+    pub fn restart_list(&mut self, id: usize) -> Option<usize> {
+        let abstract_id = self.find_numbering(id)?.abstract_num_id;
+        let start = self
+            .find_level(id, 0)
+            .map(|l| l.start.get_val())
+            .unwrap_or(1);
+        let new_id = next_id(self.numberings.iter().map(|n| n.id));
+        self.numberings.push(
+            Numbering::new(new_id, abstract_id).add_override(LevelOverride::new(0).start(start)),
+        );
+        Some(new_id)
+    }
+
+    // Abstract numberings defined through a numbering style (numStyleLink) have no levels
+    // of their own. Copies them from the abstract numbering the style's numPr refers to,
+    // or failing that, from the one linked back with styleLink.
+    pub(crate) fn resolve_num_style_links(&mut self, styles: &Styles) {
+        let resolved: Vec<(usize, Vec<Level>)> = self
+            .abstract_nums
+            .iter()
+            .filter(|a| a.levels.is_empty())
+            .filter_map(|a| {
+                let link = a.num_style_link.as_ref()?;
+                let by_style = styles
+                    .find_style_by_id(link)
+                    .and_then(|s| s.paragraph_property.numbering_property.as_ref())
+                    .and_then(|n| n.id.as_ref())
+                    .and_then(|id| self.find_numbering(id.id))
+                    .and_then(|n| {
+                        self.abstract_nums
+                            .iter()
+                            .find(|l| l.id == n.abstract_num_id)
+                    })
+                    .filter(|l| !l.levels.is_empty());
+                let linked = by_style.or_else(|| {
+                    self.abstract_nums
+                        .iter()
+                        .find(|l| l.style_link.as_ref() == Some(link) && !l.levels.is_empty())
+                })?;
+                Some((a.id, linked.levels.clone()))
+            })
+            .collect();
+        for (id, levels) in resolved {
+            if let Some(a) = self.abstract_nums.iter_mut().find(|a| a.id == id) {
+                a.levels = levels;
+            }
+        }
+    }
+
+    pub(crate) fn find_numbering(&self, id: usize) -> Option<&Numbering> {
+        self.numberings.iter().find(|n| n.id == id)
+    }
+
+    // The level of numbering `id`, taking its lvlOverride into account.
+    pub(crate) fn find_level(&self, id: usize, level: usize) -> Option<&Level> {
+        let num = self.find_numbering(id)?;
+        let overridden = num
+            .level_overrides
+            .iter()
+            .find(|o| o.level == level)
+            .and_then(|o| o.override_level.as_ref());
+        match overridden {
+            Some(l) => Some(l),
+            None => self
+                .abstract_nums
+                .iter()
+                .find(|a| a.id == num.abstract_num_id)?
+                .levels
+                .iter()
+                .find(|l| l.level == level),
+        }
+    }
 }
 
 impl Default for Numberings {
     fn default() -> Self {
         Self {
+            num_pic_bullets: vec![],
             abstract_nums: vec![],
             numberings: vec![],
         }
@@ -40,6 +133,9 @@ impl Default for Numberings {
 impl BuildXML for Numberings {
     fn build(&self) -> Vec<u8> {
         let mut b = XMLBuilder::new().declaration(Some(true)).open_numbering();
+        for p in &self.num_pic_bullets {
+            b = b.add_child(p);
+        }
         b = b.add_child(&create_default_numbering());
         for n in &self.abstract_nums {
             b = b.add_child(n);
@@ -52,6 +148,11 @@ impl BuildXML for Numberings {
     }
 }
 
+// Id 1 is taken by the default numbering written with every document.
+fn next_id(ids: impl Iterator<Item = usize>) -> usize {
+    ids.max().unwrap_or_default().max(1) + 1
+}
+
 fn create_default_numbering() -> AbstractNumbering {
     AbstractNumbering::new(1)
         .add_level(
@@ -0,0 +1,178 @@
+use std::io::{Cursor, Read, Write};
+
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use x509_cert::der::{DecodePem, Encode};
+use zip::result::{ZipError, ZipResult};
+use zip::write::FileOptions;
+
+use super::xml_signature::*;
+use crate::crypto;
+use crate::documents::{BuildXML, ContentTypes, Rels};
+use crate::reader::FromXML;
+use crate::DocxError;
+
+pub(crate) const DIGITAL_SIGNATURE_ORIGIN_TYPE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/origin";
+pub(crate) const DIGITAL_SIGNATURE_TYPE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/signature";
+const ORIGIN_PATH: &str = "_xmlsignatures/origin.sigs";
+const ORIGIN_RELS_PATH: &str = "_xmlsignatures/_rels/origin.sigs.rels";
+const SIGNATURE_PATH: &str = "_xmlsignatures/sig1.xml";
+
+// Signs packages with an XML-DSig package signature.
+// The key is an RSA private key and the certificate must carry its public key.
+#[derive(Debug, Clone)]
+pub struct PackageSigner {
+    private_key: RsaPrivateKey,
+    certificate: Vec<u8>,
+    signing_time: Option<String>,
+}
+
+impl PackageSigner {
+    // `private_key` is PKCS#8 or PKCS#1 DER and `certificate` is X.509 DER.
+    pub fn from_der(private_key: &[u8], certificate: &[u8]) -> Result<Self, DocxError> {
+        let private_key = RsaPrivateKey::from_pkcs8_der(private_key)
+            .or_else(|_| RsaPrivateKey::from_pkcs1_der(private_key))
+            .map_err(|_| DocxError::SigningKeyError)?;
+        Self::new(private_key, certificate.to_vec())
+    }
+
+    // `private_key` is a PKCS#8 or PKCS#1 PEM and `certificate` is an X.509 PEM.
+    pub fn from_pem(private_key: &str, certificate: &str) -> Result<Self, DocxError> {
+        let private_key = RsaPrivateKey::from_pkcs8_pem(private_key)
+            .or_else(|_| RsaPrivateKey::from_pkcs1_pem(private_key))
+            .map_err(|_| DocxError::SigningKeyError)?;
+        let certificate = x509_cert::Certificate::from_pem(certificate)
+            .ok()
+            .and_then(|c| c.to_der().ok())
+            .ok_or(DocxError::SigningKeyError)?;
+        Self::new(private_key, certificate)
+    }
+
+    fn new(private_key: RsaPrivateKey, certificate: Vec<u8>) -> Result<Self, DocxError> {
+        if crypto::public_key(&certificate) != Some(RsaPublicKey::from(&private_key)) {
+            return Err(DocxError::SigningKeyError);
+        }
+        Ok(Self {
+            private_key,
+            certificate,
+            signing_time: None,
+        })
+    }
+
+    // i.e. 2024-01-01T00:00:00Z. Signing fails unless the time is set.
+    pub fn signing_time(mut self, t: impl Into<String>) -> Self {
+        self.signing_time = Some(t.into());
+        self
+    }
+
+    // Add a signature covering every part of the package.
+    pub(crate) fn sign(&self, package: &[u8]) -> ZipResult<Vec<u8>> {
+        let signing_time = self.signing_time.clone().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "the signing time of the package signature is not set",
+            )
+        })?;
+        let mut archive = zip::ZipArchive::new(Cursor::new(package))?;
+        let mut parts = vec![];
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            if file.is_dir() {
+                continue;
+            }
+            let mut buf = vec![];
+            file.read_to_end(&mut buf)?;
+            parts.push((file.name().to_owned(), buf));
+        }
+
+        let invalid = |_| ZipError::InvalidArchive("Failed to read package relationships");
+        let mut content_types = None;
+        for (name, data) in parts.iter_mut() {
+            if name == "[Content_Types].xml" {
+                let c = ContentTypes::from_xml(&data[..])
+                    .map_err(invalid)?
+                    .add_default(
+                        "sigs",
+                        "application/vnd.openxmlformats-package.digital-signature-origin",
+                    )
+                    .add_content(
+                        format!("/{}", SIGNATURE_PATH),
+                        "application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml",
+                    );
+                *data = c.build();
+                content_types = Some(c);
+            } else if name == "_rels/.rels" {
+                *data = Rels::from_xml(&data[..])
+                    .map_err(invalid)?
+                    .add_rel(DIGITAL_SIGNATURE_ORIGIN_TYPE, ORIGIN_PATH)
+                    .build();
+            }
+        }
+        let content_types = content_types.ok_or(ZipError::FileNotFound)?;
+
+        let digest = crypto::DigestMethod::Sha256;
+        let mut signature = XmlSignature {
+            certificate: base64::encode(&self.certificate),
+            signing_time,
+            ..Default::default()
+        };
+        for (name, data) in parts.iter() {
+            let part_name = format!("/{}", name);
+            let content_type = match content_types.content_type(&part_name) {
+                Some(t) if name != "[Content_Types].xml" => t,
+                _ => continue,
+            };
+            let mut reference = SignatureReference {
+                uri: format!("{}?ContentType={}", part_name, content_type),
+                relationship_ids: None,
+                digest_value: base64::encode(digest.digest(data)),
+            };
+            if name.ends_with(".rels") {
+                // The signature origin relationship is not signed.
+                let ids: Vec<String> = crypto::read_relationships(data)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|(_, _, _, t)| t != DIGITAL_SIGNATURE_ORIGIN_TYPE)
+                    .map(|(id, ..)| id)
+                    .collect();
+                let transformed = crypto::transform_relationships(data, &ids, &[]).ok_or(
+                    ZipError::InvalidArchive("Failed to read package relationships"),
+                )?;
+                reference.digest_value = base64::encode(digest.digest(&transformed));
+                reference.relationship_ids = Some(ids);
+            }
+            signature.references.push(reference);
+        }
+
+        let object =
+            crypto::canonicalize(&signature.build(), Some("Object"), Some(PACKAGE_OBJECT_ID))
+                .expect("should canonicalize signature object");
+        signature.object_digest_value = base64::encode(digest.digest(&object));
+        let signed_info = crypto::canonicalize(&signature.build(), Some("SignedInfo"), None)
+            .expect("should canonicalize signed info");
+        signature.signature_value =
+            base64::encode(crypto::sign_rsa_sha256(&self.private_key, &signed_info));
+
+        parts.push((ORIGIN_PATH.to_owned(), vec![]));
+        parts.push((
+            ORIGIN_RELS_PATH.to_owned(),
+            Rels::new()
+                .add_rel(DIGITAL_SIGNATURE_TYPE, "sig1.xml")
+                .build(),
+        ));
+        parts.push((SIGNATURE_PATH.to_owned(), signature.build()));
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(vec![]));
+        let options = FileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .unix_permissions(0o755);
+        for (name, data) in parts.iter() {
+            zip.start_file(name, options)?;
+            zip.write_all(data)?;
+        }
+        Ok(zip.finish()?.into_inner())
+    }
+}
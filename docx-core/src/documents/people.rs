@@ -0,0 +1,60 @@
+use serde::Serialize;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct People {
+    pub people: Vec<Person>,
+}
+
+impl People {
+    pub fn new() -> People {
+        Default::default()
+    }
+
+    pub fn add_person(mut self, p: Person) -> Self {
+        self.people.push(p);
+        self
+    }
+
+    pub fn contains(&self, author: &str) -> bool {
+        self.people.iter().any(|p| p.author == author)
+    }
+}
+
+impl BuildXML for People {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .declaration(Some(true))
+            .open_people()
+            .add_children(&self.people)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_people() {
+        let b = People::new()
+            .add_person(Person::new("bokuweb").presence_info("None", "bokuweb"))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w15:people xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml"><w15:person w15:author="bokuweb">
+  <w15:presenceInfo w15:providerId="None" w15:userId="bokuweb" />
+</w15:person></w15:people>"#
+        );
+    }
+}
@@ -0,0 +1,438 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::types::*;
+
+// Fonts and colors of the built-in style library. Defaults are those of Word's
+// Normal template, where headings use the theme's major fonts.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StylePalette {
+    pub heading_fonts: RunFonts,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_fonts: Option<RunFonts>,
+    // Heading 1, 2, 4 and 5
+    pub heading_color: String,
+    // Heading 3, 6 and 7
+    pub subheading_color: String,
+    // Intense Quote
+    pub accent_color: String,
+    pub hyperlink_color: String,
+}
+
+impl StylePalette {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn heading_fonts(mut self, f: RunFonts) -> Self {
+        self.heading_fonts = f;
+        self
+    }
+
+    pub fn body_fonts(mut self, f: RunFonts) -> Self {
+        self.body_fonts = Some(f);
+        self
+    }
+
+    pub fn heading_color(mut self, color: impl Into<String>) -> Self {
+        self.heading_color = color.into();
+        self
+    }
+
+    pub fn subheading_color(mut self, color: impl Into<String>) -> Self {
+        self.subheading_color = color.into();
+        self
+    }
+
+    pub fn accent_color(mut self, color: impl Into<String>) -> Self {
+        self.accent_color = color.into();
+        self
+    }
+
+    pub fn hyperlink_color(mut self, color: impl Into<String>) -> Self {
+        self.hyperlink_color = color.into();
+        self
+    }
+}
+
+impl Default for StylePalette {
+    fn default() -> Self {
+        Self {
+            heading_fonts: RunFonts::new()
+                .ascii_theme("majorHAnsi")
+                .hi_ansi_theme("majorHAnsi")
+                .east_asia_theme("majorEastAsia")
+                .cs_theme("majorBidi"),
+            body_fonts: None,
+            heading_color: "2F5496".to_owned(),
+            subheading_color: "1F3763".to_owned(),
+            accent_color: "4472C4".to_owned(),
+            hyperlink_color: "0563C1".to_owned(),
+        }
+    }
+}
+
+// Word's built-in styles with their built-in ids and names, so that Word's navigation
+// pane and TOC recognize them. TOC styles are added by `Docx::build` when needed.
+pub fn builtin_styles(palette: &StylePalette) -> Vec<Style> {
+    let mut normal = Style::new("Normal", StyleType::Paragraph)
+        .name("Normal")
+        .default_style();
+    if let Some(ref f) = palette.body_fonts {
+        normal = normal.fonts(f.clone());
+    }
+    let mut styles = vec![normal];
+
+    for level in 1..=9 {
+        let (color, size, italic) = match level {
+            1 => (palette.heading_color.as_str(), Some(32), false),
+            2 => (palette.heading_color.as_str(), Some(26), false),
+            3 => (palette.subheading_color.as_str(), Some(24), false),
+            4 => (palette.heading_color.as_str(), None, true),
+            5 => (palette.heading_color.as_str(), None, false),
+            6 => (palette.subheading_color.as_str(), None, false),
+            7 => (palette.subheading_color.as_str(), None, true),
+            8 => ("272727", Some(21), false),
+            _ => ("272727", Some(21), true),
+        };
+        let mut run = RunProperty::new()
+            .fonts(palette.heading_fonts.clone())
+            .color(color);
+        if let Some(size) = size {
+            run = run.size(size);
+        }
+        if italic {
+            run = run.italic();
+        }
+        let mut heading = paragraph(&format!("Heading{}", level), &format!("heading {}", level))
+            .link(format!("Heading{}Char", level))
+            .ui_priority(9)
+            .keep_next(true)
+            .keep_lines(true)
+            .line_spacing(
+                LineSpacing::new()
+                    .before(if level == 1 { 240 } else { 40 })
+                    .after(0),
+            )
+            .outline_lvl(level - 1);
+        if level > 1 {
+            heading = heading.semi_hidden().unhide_when_used();
+        }
+        heading.run_property = run.clone();
+        styles.push(heading);
+        styles.push(linked_character(
+            &format!("Heading{}", level),
+            &format!("Heading {} Char", level),
+            9,
+            run,
+        ));
+    }
+
+    let mut title = paragraph("Title", "Title")
+        .link("TitleChar")
+        .ui_priority(10)
+        .line_spacing(single_spacing());
+    title.run_property = RunProperty::new()
+        .fonts(palette.heading_fonts.clone())
+        .spacing(-10)
+        .size(56);
+    let title_char = linked_character("Title", "Title Char", 10, title.run_property.clone());
+
+    let mut subtitle = paragraph("Subtitle", "Subtitle")
+        .link("SubtitleChar")
+        .ui_priority(11);
+    subtitle.run_property = RunProperty::new().color("5A5A5A").spacing(15).size(22);
+    let subtitle_char = linked_character(
+        "Subtitle",
+        "Subtitle Char",
+        11,
+        subtitle.run_property.clone(),
+    );
+
+    let mut quote = paragraph("Quote", "Quote")
+        .link("QuoteChar")
+        .ui_priority(29)
+        .line_spacing(LineSpacing::new().before(200).after(160))
+        .indent(Some(864), None, Some(864), None)
+        .align(AlignmentType::Center);
+    quote.run_property = RunProperty::new().italic().color("404040");
+    let quote_char = linked_character("Quote", "Quote Char", 29, quote.run_property.clone());
+
+    let mut intense_quote = paragraph("IntenseQuote", "Intense Quote")
+        .link("IntenseQuoteChar")
+        .ui_priority(30)
+        .line_spacing(LineSpacing::new().before(360).after(360))
+        .indent(Some(864), None, Some(864), None)
+        .align(AlignmentType::Center);
+    intense_quote.paragraph_property = intense_quote.paragraph_property.set_borders(
+        ParagraphBorders::with_empty()
+            .set(
+                ParagraphBorder::new(ParagraphBorderPosition::Top)
+                    .size(4)
+                    .space(10)
+                    .color(&palette.accent_color),
+            )
+            .set(
+                ParagraphBorder::new(ParagraphBorderPosition::Bottom)
+                    .size(4)
+                    .space(10)
+                    .color(&palette.accent_color),
+            ),
+    );
+    intense_quote.run_property = RunProperty::new().italic().color(&palette.accent_color);
+    let intense_quote_char = linked_character(
+        "IntenseQuote",
+        "Intense Quote Char",
+        30,
+        intense_quote.run_property.clone(),
+    );
+
+    let caption = paragraph("Caption", "caption")
+        .ui_priority(35)
+        .semi_hidden()
+        .unhide_when_used()
+        .line_spacing(LineSpacing::new().after(200).line(240))
+        .italic()
+        .color("44546A")
+        .size(18);
+
+    let list_paragraph = paragraph("ListParagraph", "List Paragraph")
+        .ui_priority(34)
+        .indent(Some(720), None, None, None);
+
+    let no_spacing = Style::new("NoSpacing", StyleType::Paragraph)
+        .name("No Spacing")
+        .ui_priority(1)
+        .line_spacing(single_spacing());
+
+    let mut header_footer = vec![];
+    for (id, name) in &[("Header", "header"), ("Footer", "footer")] {
+        let mut s = paragraph(id, name)
+            .link(format!("{}Char", id))
+            .ui_priority(99)
+            .unhide_when_used()
+            .q_format(false)
+            .line_spacing(single_spacing());
+        s.paragraph_property = s
+            .paragraph_property
+            .add_tab(Tab::new().val(TabValueType::Center).pos(4680))
+            .add_tab(Tab::new().val(TabValueType::Right).pos(9360));
+        header_footer.push(s);
+        header_footer.push(linked_character(
+            id,
+            &format!("{} Char", id),
+            99,
+            RunProperty::new(),
+        ));
+    }
+
+    let footnote_text = paragraph("FootnoteText", "footnote text")
+        .link("FootnoteTextChar")
+        .ui_priority(99)
+        .semi_hidden()
+        .unhide_when_used()
+        .q_format(false)
+        .line_spacing(single_spacing())
+        .size(20);
+    let footnote_text_char = linked_character(
+        "FootnoteText",
+        "Footnote Text Char",
+        99,
+        footnote_text.run_property.clone(),
+    )
+    .semi_hidden();
+    let mut footnote_reference = Style::new("FootnoteReference", StyleType::Character)
+        .name("footnote reference")
+        .ui_priority(99)
+        .semi_hidden()
+        .unhide_when_used()
+        .q_format(false);
+    footnote_reference.run_property = RunProperty::new().vert_align(VertAlignType::SuperScript);
+
+    let hyperlink = Style::new("Hyperlink", StyleType::Character)
+        .name("Hyperlink")
+        .ui_priority(99)
+        .unhide_when_used()
+        .q_format(false)
+        .color(&palette.hyperlink_color)
+        .underline("single");
+    let strong = Style::new("Strong", StyleType::Character)
+        .name("Strong")
+        .ui_priority(22)
+        .bold();
+    let emphasis = Style::new("Emphasis", StyleType::Character)
+        .name("Emphasis")
+        .ui_priority(20)
+        .italic();
+
+    let border = |position| TableBorder::new(position).size(4).color("auto");
+    let table_grid = Style::new("TableGrid", StyleType::Table)
+        .name("Table Grid")
+        .ui_priority(39)
+        .q_format(false)
+        .line_spacing(single_spacing())
+        .set_borders(
+            TableBorders::with_empty()
+                .set(border(TableBorderPosition::Top))
+                .set(border(TableBorderPosition::Left))
+                .set(border(TableBorderPosition::Bottom))
+                .set(border(TableBorderPosition::Right))
+                .set(border(TableBorderPosition::InsideH))
+                .set(border(TableBorderPosition::InsideV)),
+        );
+
+    styles.extend(vec![
+        title,
+        title_char,
+        subtitle,
+        subtitle_char,
+        quote,
+        quote_char,
+        intense_quote,
+        intense_quote_char,
+        caption,
+        list_paragraph,
+        no_spacing,
+    ]);
+    styles.extend(header_footer);
+    styles.extend(vec![
+        footnote_text,
+        footnote_text_char,
+        footnote_reference,
+        hyperlink,
+        strong,
+        emphasis,
+        table_grid,
+    ]);
+    styles
+}
+
+// Word's latent style exceptions for the built-in styles shown in the style gallery.
+pub fn builtin_latent_styles() -> LatentStyles {
+    let quick = |name: &str, priority: usize| {
+        LatentStyleException::new(name)
+            .ui_priority(priority)
+            .q_format()
+    };
+    let hidden = |name: &str| {
+        LatentStyleException::new(name)
+            .semi_hidden()
+            .unhide_when_used()
+    };
+    let mut exceptions = vec![quick("Normal", 0), quick("heading 1", 9)];
+    for level in 2..=9 {
+        exceptions.push(
+            quick(&format!("heading {}", level), 9)
+                .semi_hidden()
+                .unhide_when_used(),
+        );
+    }
+    for level in 1..=9 {
+        exceptions.push(hidden(&format!("toc {}", level)).ui_priority(39));
+    }
+    exceptions.extend(vec![
+        hidden("footnote text"),
+        hidden("header"),
+        hidden("footer"),
+        quick("caption", 35).semi_hidden().unhide_when_used(),
+        hidden("footnote reference"),
+        quick("Title", 10),
+        hidden("Default Paragraph Font").ui_priority(1),
+        quick("Subtitle", 11),
+        hidden("Hyperlink"),
+        quick("Strong", 22),
+        quick("Emphasis", 20),
+        LatentStyleException::new("Table Grid").ui_priority(39),
+        LatentStyleException::new("Placeholder Text").semi_hidden(),
+        quick("No Spacing", 1),
+        quick("List Paragraph", 34),
+        quick("Quote", 29),
+        quick("Intense Quote", 30),
+        quick("Subtle Emphasis", 19),
+        quick("Intense Emphasis", 21),
+        quick("Subtle Reference", 31),
+        quick("Intense Reference", 32),
+        quick("Book Title", 33),
+        hidden("Bibliography").ui_priority(37),
+        quick("TOC Heading", 39).semi_hidden().unhide_when_used(),
+    ]);
+    exceptions
+        .into_iter()
+        .fold(LatentStyles::new(), |l, e| l.add_exception(e))
+}
+
+fn paragraph(id: &str, name: &str) -> Style {
+    Style::new(id, StyleType::Paragraph)
+        .name(name)
+        .based_on("Normal")
+        .next("Normal")
+}
+
+// Character style linked to paragraph style `paragraph_id` sharing its run properties.
+fn linked_character(paragraph_id: &str, name: &str, ui_priority: usize, run: RunProperty) -> Style {
+    let mut s = Style::new(format!("{}Char", paragraph_id), StyleType::Character)
+        .name(name)
+        .link(paragraph_id)
+        .ui_priority(ui_priority)
+        .q_format(false);
+    s.run_property = run;
+    s
+}
+
+fn single_spacing() -> LineSpacing {
+    LineSpacing::new()
+        .after(0)
+        .line(240)
+        .line_rule(LineSpacingType::Auto)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_builtin_styles() {
+        let styles = builtin_styles(&StylePalette::new().heading_color("C00000"));
+        let heading = styles.iter().find(|s| s.style_id == "Heading2").unwrap();
+        assert_eq!(heading.name, Name::new("heading 2"));
+        assert_eq!(heading.link, Some(Link::new("Heading2Char")));
+        assert_eq!(heading.run_property.color, Some(Color::new("C00000")));
+        assert_eq!(heading.semi_hidden, Some(true));
+        // Only the styles of Word's style gallery are marked with qFormat.
+        let gallery: Vec<&str> = styles
+            .iter()
+            .filter(|s| s.q_format)
+            .map(|s| s.style_id.as_str())
+            .collect();
+        assert!(gallery.contains(&"Heading2"));
+        for id in &[
+            "Heading2Char",
+            "Header",
+            "Footer",
+            "Hyperlink",
+            "FootnoteText",
+        ] {
+            assert!(!gallery.contains(id), "{} is in the gallery", id);
+        }
+        let ids: Vec<&str> = styles.iter().map(|s| s.style_id.as_str()).collect();
+        for id in &[
+            "Normal",
+            "Heading9Char",
+            "Title",
+            "Subtitle",
+            "Quote",
+            "Caption",
+            "ListParagraph",
+            "Hyperlink",
+            "FootnoteText",
+            "FootnoteReference",
+            "TableGrid",
+        ] {
+            assert!(ids.contains(id), "{} is missing", id);
+        }
+    }
+}
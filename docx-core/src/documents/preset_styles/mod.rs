@@ -1,3 +1,5 @@
+mod builtin;
 mod toc;
 
+pub use builtin::*;
 pub use toc::*;
@@ -0,0 +1,83 @@
+use crate::escape;
+
+/// Selects the revisions handled by `Docx::accept_revisions` and `Docx::reject_revisions`.
+/// An empty filter selects every revision.
+/// Dates are compared as ISO 8601 strings, so a bound like "2023-04-01" covers the whole day.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RevisionFilter {
+    author: Option<String>,
+    date_from: Option<String>,
+    date_to: Option<String>,
+}
+
+impl RevisionFilter {
+    pub fn new() -> RevisionFilter {
+        Default::default()
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    pub fn date_from(mut self, date: impl Into<String>) -> Self {
+        self.date_from = Some(date.into());
+        self
+    }
+
+    pub fn date_to(mut self, date: impl Into<String>) -> Self {
+        self.date_to = Some(date.into());
+        self
+    }
+
+    pub fn matches(&self, author: &str, date: &str) -> bool {
+        if let Some(ref a) = self.author {
+            // Authors set through builders are stored escaped.
+            if a != author && escape::escape(a) != author {
+                return false;
+            }
+        }
+        if let Some(ref from) = self.date_from {
+            if date < from.as_str() {
+                return false;
+            }
+        }
+        if let Some(ref to) = self.date_to {
+            let len = to.len().min(date.len());
+            if date.get(..len).unwrap_or(date) > to.as_str() {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_match_all() {
+        let f = RevisionFilter::new();
+        assert!(f.matches("bokuweb", "2023-04-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_match_author() {
+        let f = RevisionFilter::new().author("bokuweb");
+        assert!(f.matches("bokuweb", "2023-04-01T00:00:00Z"));
+        assert!(!f.matches("unnamed", "2023-04-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_match_date_range() {
+        let f = RevisionFilter::new()
+            .date_from("2023-04-01")
+            .date_to("2023-04-02");
+        assert!(!f.matches("bokuweb", "2023-03-31T23:59:59Z"));
+        assert!(f.matches("bokuweb", "2023-04-01T00:00:00Z"));
+        assert!(f.matches("bokuweb", "2023-04-02T23:59:59Z"));
+        assert!(!f.matches("bokuweb", "2023-04-03T00:00:00Z"));
+    }
+}
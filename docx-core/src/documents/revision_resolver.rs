@@ -0,0 +1,858 @@
+use std::collections::HashSet;
+
+use crate::documents::*;
+use crate::types::*;
+
+// Block level children which may hold a paragraph.
+// A paragraph whose mark is removed is joined with the following paragraph.
+trait BlockChild {
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph>;
+}
+
+impl BlockChild for DocumentChild {
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            DocumentChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+impl BlockChild for TableCellContent {
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            TableCellContent::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+impl BlockChild for HeaderChild {
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            HeaderChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+impl BlockChild for FooterChild {
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            FooterChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+impl BlockChild for StructuredDataTagChild {
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self {
+            StructuredDataTagChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+impl BlockChild for Paragraph {
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        Some(self)
+    }
+}
+
+// Without a following paragraph the removed mark joins the paragraph with the preceding one.
+fn join_backward<C: BlockChild>(joined: &mut Vec<C>, mut pending: C) {
+    if let (Some(prev), Some(p)) = (
+        joined.last_mut().and_then(|c| c.paragraph_mut()),
+        pending.paragraph_mut(),
+    ) {
+        prev.children.append(&mut p.children);
+    } else {
+        joined.push(pending);
+    }
+}
+
+// The flag tells whether the paragraph mark of the child has been removed.
+fn join_paragraphs<C: BlockChild>(children: Vec<(C, bool)>) -> Vec<C> {
+    let mut joined = Vec::with_capacity(children.len());
+    let mut pending: Option<C> = None;
+    for (mut child, mark_removed) in children {
+        match child.paragraph_mut() {
+            Some(p) => {
+                if let Some(mut prev) = pending.take() {
+                    if let Some(prev) = prev.paragraph_mut() {
+                        let mut c = std::mem::take(&mut prev.children);
+                        c.append(&mut p.children);
+                        p.children = c;
+                    }
+                }
+                if mark_removed {
+                    pending = Some(child);
+                } else {
+                    joined.push(child);
+                }
+            }
+            None => {
+                if let Some(pending) = pending.take() {
+                    join_backward(&mut joined, pending);
+                }
+                joined.push(child);
+            }
+        }
+    }
+    if let Some(pending) = pending.take() {
+        join_backward(&mut joined, pending);
+    }
+    joined
+}
+
+/// Resolves tracked revisions into clean content.
+/// Accepting keeps inserted content and drops deleted content, rejecting does the opposite.
+/// Revisions not matching the filter are kept as they are.
+pub(crate) struct RevisionResolver<'a> {
+    accept: bool,
+    filter: &'a RevisionFilter,
+    // Ids of resolved move ranges. The matching range ends are dropped with them.
+    move_from_ranges: HashSet<usize>,
+    move_to_ranges: HashSet<usize>,
+}
+
+impl<'a> RevisionResolver<'a> {
+    pub(crate) fn new(accept: bool, filter: &'a RevisionFilter) -> Self {
+        Self {
+            accept,
+            filter,
+            move_from_ranges: HashSet::new(),
+            move_to_ranges: HashSet::new(),
+        }
+    }
+
+    fn matches(&self, author: &str, date: &str) -> bool {
+        self.filter.matches(author, date)
+    }
+
+    pub(crate) fn resolve_document(&mut self, document: &mut Document) {
+        let children = std::mem::take(&mut document.children)
+            .into_iter()
+            .filter_map(|mut child| {
+                let mark_removed = match child {
+                    DocumentChild::Paragraph(ref mut p) => self.resolve_paragraph(p),
+                    DocumentChild::Table(ref mut t) => {
+                        if !self.resolve_table(t) {
+                            return None;
+                        }
+                        false
+                    }
+                    DocumentChild::StructuredDataTag(ref mut tag) => {
+                        self.resolve_tag(tag);
+                        false
+                    }
+                    _ => false,
+                };
+                Some((child, mark_removed))
+            })
+            .collect();
+        document.children = join_paragraphs(children);
+        self.resolve_section_property(&mut document.section_property);
+    }
+
+    fn resolve_section_property(&mut self, section: &mut SectionProperty) {
+        if let Some(change) = section.section_property_change.take() {
+            if !self.matches(&change.author, &change.date) {
+                section.section_property_change = Some(change);
+            } else if !self.accept {
+                // Header and footer references are not part of the previous properties.
+                let mut prev = *change.property;
+                prev.header_reference = section.header_reference.take();
+                prev.header = section.header.take();
+                prev.first_header_reference = section.first_header_reference.take();
+                prev.first_header = section.first_header.take();
+                prev.even_header_reference = section.even_header_reference.take();
+                prev.even_header = section.even_header.take();
+                prev.footer_reference = section.footer_reference.take();
+                prev.footer = section.footer.take();
+                prev.first_footer_reference = section.first_footer_reference.take();
+                prev.first_footer = section.first_footer.take();
+                prev.even_footer_reference = section.even_footer_reference.take();
+                prev.even_footer = section.even_footer.take();
+                prev.section_property_change = None;
+                *section = prev;
+            }
+        }
+        for header in vec![
+            section.header.as_mut(),
+            section.first_header.as_mut(),
+            section.even_header.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            self.resolve_header(header);
+        }
+        for footer in vec![
+            section.footer.as_mut(),
+            section.first_footer.as_mut(),
+            section.even_footer.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            self.resolve_footer(footer);
+        }
+    }
+
+    fn resolve_header(&mut self, header: &mut Header) {
+        let children = std::mem::take(&mut header.children)
+            .into_iter()
+            .filter_map(|mut child| {
+                let mark_removed = match child {
+                    HeaderChild::Paragraph(ref mut p) => self.resolve_paragraph(p),
+                    HeaderChild::Table(ref mut t) => {
+                        if !self.resolve_table(t) {
+                            return None;
+                        }
+                        false
+                    }
+                    HeaderChild::StructuredDataTag(ref mut tag) => {
+                        self.resolve_tag(tag);
+                        false
+                    }
+                };
+                Some((child, mark_removed))
+            })
+            .collect();
+        header.children = join_paragraphs(children);
+    }
+
+    fn resolve_footer(&mut self, footer: &mut Footer) {
+        let children = std::mem::take(&mut footer.children)
+            .into_iter()
+            .filter_map(|mut child| {
+                let mark_removed = match child {
+                    FooterChild::Paragraph(ref mut p) => self.resolve_paragraph(p),
+                    FooterChild::Table(ref mut t) => {
+                        if !self.resolve_table(t) {
+                            return None;
+                        }
+                        false
+                    }
+                    FooterChild::StructuredDataTag(ref mut tag) => {
+                        self.resolve_tag(tag);
+                        false
+                    }
+                };
+                Some((child, mark_removed))
+            })
+            .collect();
+        footer.children = join_paragraphs(children);
+    }
+
+    fn resolve_tag(&mut self, tag: &mut StructuredDataTag) {
+        let children = std::mem::take(&mut tag.children)
+            .into_iter()
+            .flat_map(|child| match child {
+                StructuredDataTagChild::Insert(ins) => {
+                    self.resolve_tag_revision(ParagraphChild::Insert(ins))
+                }
+                StructuredDataTagChild::Delete(del) => {
+                    self.resolve_tag_revision(ParagraphChild::Delete(del))
+                }
+                _ => vec![child],
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .filter_map(|mut child| {
+                let mark_removed = match child {
+                    StructuredDataTagChild::Run(ref mut run) => {
+                        self.resolve_run(run);
+                        false
+                    }
+                    StructuredDataTagChild::Paragraph(ref mut p) => self.resolve_paragraph(p),
+                    StructuredDataTagChild::Table(ref mut t) => {
+                        if !self.resolve_table(t) {
+                            return None;
+                        }
+                        false
+                    }
+                    StructuredDataTagChild::StructuredDataTag(ref mut tag) => {
+                        self.resolve_tag(tag);
+                        false
+                    }
+                    _ => false,
+                };
+                Some((child, mark_removed))
+            })
+            .collect();
+        tag.children = join_paragraphs(children);
+    }
+
+    // Resolves an insertion or a deletion of runs in a tag as the same revision in a paragraph.
+    fn resolve_tag_revision(&mut self, child: ParagraphChild) -> Vec<StructuredDataTagChild> {
+        self.resolve_paragraph_children(vec![child])
+            .into_iter()
+            .filter_map(|child| match child {
+                ParagraphChild::Run(run) => Some(StructuredDataTagChild::Run(run)),
+                ParagraphChild::Insert(ins) => Some(StructuredDataTagChild::Insert(ins)),
+                ParagraphChild::Delete(del) => Some(StructuredDataTagChild::Delete(del)),
+                ParagraphChild::CommentStart(c) => Some(StructuredDataTagChild::CommentStart(c)),
+                ParagraphChild::CommentEnd(c) => Some(StructuredDataTagChild::CommentEnd(c)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn resolve_paragraphs(&mut self, paragraphs: &mut Vec<Paragraph>) {
+        let children = std::mem::take(paragraphs)
+            .into_iter()
+            .map(|mut p| {
+                let mark_removed = self.resolve_paragraph(&mut p);
+                (p, mark_removed)
+            })
+            .collect();
+        *paragraphs = join_paragraphs(children);
+    }
+
+    // Returns true if the paragraph mark has been removed.
+    fn resolve_paragraph(&mut self, paragraph: &mut Paragraph) -> bool {
+        let children = std::mem::take(&mut paragraph.children);
+        paragraph.children = self.resolve_paragraph_children(children);
+
+        let property = &mut paragraph.property;
+        if let Some(change) = property.paragraph_property_change.take() {
+            if !self.matches(&change.author, &change.date) {
+                property.paragraph_property_change = Some(change);
+            } else if !self.accept {
+                // The paragraph mark properties and the section are not part of the previous properties.
+                let mut prev = *change.property;
+                prev.run_property = std::mem::take(&mut property.run_property);
+                prev.section_property = property.section_property.take();
+                prev.paragraph_property_change = None;
+                *property = prev;
+                paragraph.has_numbering = paragraph.property.numbering_property.is_some();
+            }
+        }
+
+        let property = &mut paragraph.property;
+        if let Some(ref mut section) = property.section_property {
+            self.resolve_section_property(section);
+        }
+        self.resolve_run_property(&mut property.run_property);
+
+        let mark = &mut property.run_property;
+        let mut mark_removed = false;
+        if let Some(del) = mark.del.take() {
+            if self.matches(&del.author, &del.date) {
+                mark_removed = self.accept;
+            } else {
+                mark.del = Some(del);
+            }
+        }
+        if let Some(ins) = mark.ins.take() {
+            if self.matches(&ins.author, &ins.date) {
+                mark_removed = mark_removed || !self.accept;
+            } else {
+                mark.ins = Some(ins);
+            }
+        }
+        mark_removed
+    }
+
+    fn resolve_paragraph_children(&mut self, children: Vec<ParagraphChild>) -> Vec<ParagraphChild> {
+        let mut resolved = Vec::with_capacity(children.len());
+        for child in children {
+            match child {
+                ParagraphChild::Run(mut run) => {
+                    self.resolve_run(&mut run);
+                    resolved.push(ParagraphChild::Run(run));
+                }
+                ParagraphChild::Insert(mut ins) => {
+                    if self.matches(&ins.author, &ins.date) {
+                        resolved.extend(self.resolve_inserted(ins.children));
+                    } else {
+                        ins.children = self.resolve_insert_children(ins.children);
+                        resolved.push(ParagraphChild::Insert(ins));
+                    }
+                }
+                ParagraphChild::Delete(mut del) => {
+                    if self.matches(&del.author, &del.date) {
+                        resolved.extend(self.resolve_deleted(del.children));
+                    } else {
+                        self.resolve_delete_children(&mut del.children);
+                        resolved.push(ParagraphChild::Delete(del));
+                    }
+                }
+                ParagraphChild::MoveTo(mut m) => {
+                    if self.matches(&m.author, &m.date) {
+                        resolved.extend(self.resolve_inserted(m.children));
+                    } else {
+                        m.children = self.resolve_insert_children(m.children);
+                        resolved.push(ParagraphChild::MoveTo(m));
+                    }
+                }
+                ParagraphChild::MoveFrom(mut m) => {
+                    if self.matches(&m.author, &m.date) {
+                        resolved.extend(self.resolve_deleted(m.children));
+                    } else {
+                        self.resolve_delete_children(&mut m.children);
+                        resolved.push(ParagraphChild::MoveFrom(m));
+                    }
+                }
+                ParagraphChild::MoveFromRangeStart(s) => {
+                    if self.matches(&s.author, &s.date) {
+                        self.move_from_ranges.insert(s.id);
+                    } else {
+                        resolved.push(ParagraphChild::MoveFromRangeStart(s));
+                    }
+                }
+                ParagraphChild::MoveFromRangeEnd(e) => {
+                    if !self.move_from_ranges.contains(&e.id) {
+                        resolved.push(ParagraphChild::MoveFromRangeEnd(e));
+                    }
+                }
+                ParagraphChild::MoveToRangeStart(s) => {
+                    if self.matches(&s.author, &s.date) {
+                        self.move_to_ranges.insert(s.id);
+                    } else {
+                        resolved.push(ParagraphChild::MoveToRangeStart(s));
+                    }
+                }
+                ParagraphChild::MoveToRangeEnd(e) => {
+                    if !self.move_to_ranges.contains(&e.id) {
+                        resolved.push(ParagraphChild::MoveToRangeEnd(e));
+                    }
+                }
+                ParagraphChild::Hyperlink(mut link) => {
+                    // A link whose whole content is removed is removed with it.
+                    let had_children = !link.children.is_empty();
+                    link.children = self.resolve_paragraph_children(link.children);
+                    if !had_children || !link.children.is_empty() {
+                        resolved.push(ParagraphChild::Hyperlink(link));
+                    }
+                }
+                ParagraphChild::StructuredDataTag(mut tag) => {
+                    self.resolve_tag(&mut tag);
+                    resolved.push(ParagraphChild::StructuredDataTag(tag));
+                }
+                _ => resolved.push(child),
+            }
+        }
+        resolved
+    }
+
+    // Resolves the children of a matched insertion.
+    fn resolve_inserted(&mut self, children: Vec<InsertChild>) -> Vec<ParagraphChild> {
+        let mut resolved = vec![];
+        for child in children {
+            match child {
+                InsertChild::Run(mut run) => {
+                    if self.accept {
+                        self.resolve_run(&mut run);
+                        resolved.push(ParagraphChild::Run(run));
+                    }
+                }
+                InsertChild::Delete(del) => {
+                    if self.accept {
+                        resolved.extend(
+                            self.resolve_paragraph_children(vec![ParagraphChild::Delete(del)]),
+                        );
+                    } else {
+                        resolved.extend(self.comment_ranges(del.children));
+                    }
+                }
+                // Comment ranges are kept so that the comments stay anchored.
+                InsertChild::CommentStart(c) => resolved.push(ParagraphChild::CommentStart(c)),
+                InsertChild::CommentEnd(c) => resolved.push(ParagraphChild::CommentEnd(c)),
+            }
+        }
+        resolved
+    }
+
+    // Resolves the children of a matched deletion.
+    fn resolve_deleted(&mut self, children: Vec<DeleteChild>) -> Vec<ParagraphChild> {
+        if self.accept {
+            return self.comment_ranges(children);
+        }
+        children
+            .into_iter()
+            .map(|child| match child {
+                DeleteChild::Run(mut run) => {
+                    restore_deleted_run(&mut run);
+                    self.resolve_run(&mut run);
+                    ParagraphChild::Run(Box::new(run))
+                }
+                DeleteChild::CommentStart(c) => ParagraphChild::CommentStart(c),
+                DeleteChild::CommentEnd(c) => ParagraphChild::CommentEnd(c),
+            })
+            .collect()
+    }
+
+    fn comment_ranges(&self, children: Vec<DeleteChild>) -> Vec<ParagraphChild> {
+        children
+            .into_iter()
+            .filter_map(|child| match child {
+                DeleteChild::CommentStart(c) => Some(ParagraphChild::CommentStart(c)),
+                DeleteChild::CommentEnd(c) => Some(ParagraphChild::CommentEnd(c)),
+                DeleteChild::Run(_) => None,
+            })
+            .collect()
+    }
+
+    // Resolves deletions nested in an insertion which is kept.
+    fn resolve_insert_children(&mut self, children: Vec<InsertChild>) -> Vec<InsertChild> {
+        let mut resolved = vec![];
+        for child in children {
+            match child {
+                InsertChild::Run(mut run) => {
+                    self.resolve_run(&mut run);
+                    resolved.push(InsertChild::Run(run));
+                }
+                InsertChild::Delete(mut del) => {
+                    if self.matches(&del.author, &del.date) {
+                        resolved.extend(self.resolve_deleted(del.children).into_iter().filter_map(
+                            |child| match child {
+                                ParagraphChild::Run(run) => Some(InsertChild::Run(run)),
+                                ParagraphChild::CommentStart(c) => {
+                                    Some(InsertChild::CommentStart(c))
+                                }
+                                ParagraphChild::CommentEnd(c) => Some(InsertChild::CommentEnd(c)),
+                                _ => None,
+                            },
+                        ));
+                    } else {
+                        self.resolve_delete_children(&mut del.children);
+                        resolved.push(InsertChild::Delete(del));
+                    }
+                }
+                _ => resolved.push(child),
+            }
+        }
+        resolved
+    }
+
+    fn resolve_delete_children(&mut self, children: &mut [DeleteChild]) {
+        for child in children {
+            if let DeleteChild::Run(run) = child {
+                self.resolve_run(run);
+            }
+        }
+    }
+
+    fn resolve_run(&mut self, run: &mut Run) {
+        self.resolve_run_property(&mut run.run_property);
+        for child in &mut run.children {
+            if let RunChild::FootnoteReference(footnote) = child {
+                self.resolve_paragraphs(&mut footnote.content);
+            }
+        }
+    }
+
+    fn resolve_run_property(&mut self, property: &mut RunProperty) {
+        if let Some(change) = property.run_property_change.take() {
+            if !self.matches(&change.author, &change.date) {
+                property.run_property_change = Some(change);
+            } else if !self.accept {
+                // Paragraph mark revisions are not part of the previous properties.
+                let mut prev = *change.property;
+                prev.ins = property.ins.take();
+                prev.del = property.del.take();
+                prev.run_property_change = None;
+                *property = prev;
+            }
+        }
+    }
+
+    // Returns false if every row of the table has been removed.
+    fn resolve_table(&mut self, table: &mut Table) -> bool {
+        if let Some(change) = table.property.table_property_change.take() {
+            if !self.matches(&change.author, &change.date) {
+                table.property.table_property_change = Some(change);
+            } else if !self.accept {
+                table.property = *change.property;
+                table.property.table_property_change = None;
+            }
+        }
+        let rows = std::mem::take(&mut table.rows);
+        for TableChild::TableRow(mut row) in rows {
+            if self.resolve_row(&mut row) {
+                table.rows.push(TableChild::TableRow(row));
+            }
+        }
+        !table.rows.is_empty()
+    }
+
+    // Returns false if the row has been removed.
+    fn resolve_row(&mut self, row: &mut TableRow) -> bool {
+        let property = &mut row.property;
+        if let Some(del) = property.del.take() {
+            if !self.matches(&del.author, &del.date) {
+                property.del = Some(del);
+            } else if self.accept {
+                return false;
+            }
+        }
+        if let Some(ins) = property.ins.take() {
+            if !self.matches(&ins.author, &ins.date) {
+                property.ins = Some(ins);
+            } else if !self.accept {
+                return false;
+            }
+        }
+        if let Some(change) = property.table_row_property_change.take() {
+            if !self.matches(&change.author, &change.date) {
+                property.table_row_property_change = Some(change);
+            } else if !self.accept {
+                let mut prev = *change.property;
+                prev.ins = property.ins.take();
+                prev.del = property.del.take();
+                prev.table_row_property_change = None;
+                *property = prev;
+            }
+        }
+        let cells = std::mem::take(&mut row.cells);
+        for TableRowChild::TableCell(mut cell) in cells {
+            if self.resolve_cell(&mut cell) {
+                row.cells.push(TableRowChild::TableCell(cell));
+            }
+        }
+        !row.cells.is_empty()
+    }
+
+    // Returns false if the cell has been removed.
+    fn resolve_cell(&mut self, cell: &mut TableCell) -> bool {
+        let property = &mut cell.property;
+        if let Some(del) = property.cell_delete.take() {
+            if !self.matches(&del.author, &del.date) {
+                property.cell_delete = Some(del);
+            } else if self.accept {
+                return false;
+            }
+        }
+        if let Some(ins) = property.cell_insert.take() {
+            if !self.matches(&ins.author, &ins.date) {
+                property.cell_insert = Some(ins);
+            } else if !self.accept {
+                return false;
+            }
+        }
+        if let Some(merge) = property.cell_merge.take() {
+            if !self.matches(&merge.author, &merge.date) {
+                property.cell_merge = Some(merge);
+            } else {
+                // Rejecting restores the merge before the revision, which may be none.
+                let v_merge = if self.accept {
+                    merge.v_merge
+                } else {
+                    merge.v_merge_orig
+                };
+                property.vertical_merge = v_merge.map(|m| match m {
+                    CellMergeType::Continue => VMerge::new(VMergeType::Continue),
+                    CellMergeType::Restart => VMerge::new(VMergeType::Restart),
+                });
+            }
+        }
+        if let Some(change) = property.table_cell_property_change.take() {
+            if !self.matches(&change.author, &change.date) {
+                property.table_cell_property_change = Some(change);
+            } else if !self.accept {
+                let mut prev = *change.property;
+                prev.cell_insert = property.cell_insert.take();
+                prev.cell_delete = property.cell_delete.take();
+                prev.cell_merge = property.cell_merge.take();
+                prev.table_cell_property_change = None;
+                *property = prev;
+            }
+        }
+
+        let children = std::mem::take(&mut cell.children)
+            .into_iter()
+            .filter_map(|mut child| {
+                let mark_removed = match child {
+                    TableCellContent::Paragraph(ref mut p) => self.resolve_paragraph(p),
+                    TableCellContent::Table(ref mut t) => {
+                        if !self.resolve_table(t) {
+                            return None;
+                        }
+                        false
+                    }
+                    TableCellContent::StructuredDataTag(ref mut tag) => {
+                        self.resolve_tag(tag);
+                        false
+                    }
+                    TableCellContent::TableOfContents(_) => false,
+                };
+                Some((child, mark_removed))
+            })
+            .collect();
+        cell.children = join_paragraphs(children);
+        true
+    }
+}
+
+// Turns deleted text back into text.
+fn restore_deleted_run(run: &mut Run) {
+    for child in &mut run.children {
+        match child {
+            RunChild::DeleteText(t) => *child = RunChild::Text(t.clone().into()),
+            RunChild::DeleteInstrText(i) => {
+                *child = RunChild::InstrText(Box::new(i.as_ref().clone().into()))
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn accept(mut document: Document) -> Document {
+        RevisionResolver::new(true, &RevisionFilter::new()).resolve_document(&mut document);
+        document
+    }
+
+    fn reject(mut document: Document) -> Document {
+        RevisionResolver::new(false, &RevisionFilter::new()).resolve_document(&mut document);
+        document
+    }
+
+    fn redline() -> Document {
+        Document::new().add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Hello "))
+                .add_insert(Insert::new(Run::new().add_text("new ")))
+                .add_delete(Delete::new().add_run(Run::new().add_delete_text("old ")))
+                .add_run(Run::new().add_text("world")),
+        )
+    }
+
+    fn texts(document: &Document) -> Vec<String> {
+        document
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                DocumentChild::Paragraph(p) => Some(p.raw_text()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_accept_insert_and_delete() {
+        let document = accept(redline());
+        assert_eq!(texts(&document), vec!["Hello new world".to_owned()]);
+    }
+
+    #[test]
+    fn test_reject_insert_and_delete() {
+        let document = reject(redline());
+        assert_eq!(texts(&document), vec!["Hello old world".to_owned()]);
+    }
+
+    #[test]
+    fn test_filter_by_author() {
+        let mut document = Document::new().add_paragraph(
+            Paragraph::new()
+                .add_insert(Insert::new(Run::new().add_text("a")).author("bokuweb"))
+                .add_insert(Insert::new(Run::new().add_text("b")).author("someone")),
+        );
+        RevisionResolver::new(false, &RevisionFilter::new().author("bokuweb"))
+            .resolve_document(&mut document);
+        let p = match &document.children[0] {
+            DocumentChild::Paragraph(p) => p,
+            _ => unreachable!(),
+        };
+        assert_eq!(p.children.len(), 1);
+        assert!(matches!(&p.children[0], ParagraphChild::Insert(ins) if ins.author == "someone"));
+    }
+
+    #[test]
+    fn test_accept_deleted_paragraph_mark() {
+        let document = Document::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Hello "))
+                    .delete("bokuweb", "2023-04-01T00:00:00Z"),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("world")));
+        assert_eq!(
+            texts(&accept(document.clone())),
+            vec!["Hello world".to_owned()]
+        );
+        assert_eq!(
+            texts(&reject(document)),
+            vec!["Hello ".to_owned(), "world".to_owned()]
+        );
+    }
+
+    #[test]
+    fn test_reject_inserted_last_paragraph() {
+        let document = Document::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_insert(Insert::new(Run::new().add_text("world")))
+                    .insert("bokuweb", "2023-04-01T00:00:00Z"),
+            );
+        assert_eq!(texts(&reject(document)), vec!["Hello".to_owned()]);
+    }
+
+    #[test]
+    fn test_reject_inserted_row() {
+        let document = Document::new().add_table(Table::new(vec![
+            TableRow::new(vec![TableCell::new()]),
+            TableRow::new(vec![TableCell::new()]).insert(Insert::new_with_empty()),
+        ]));
+        match &reject(document.clone()).children[0] {
+            DocumentChild::Table(t) => assert_eq!(t.rows.len(), 1),
+            _ => unreachable!(),
+        };
+        match &accept(document).children[0] {
+            DocumentChild::Table(t) => {
+                let TableChild::TableRow(row) = &t.rows[1];
+                assert_eq!(t.rows.len(), 2);
+                assert_eq!(row.property.ins, None);
+            }
+            _ => unreachable!(),
+        };
+    }
+
+    #[test]
+    fn test_resolve_cell_merge() {
+        let document = Document::new().add_table(Table::new(vec![TableRow::new(vec![
+            TableCell::new()
+                .vertical_merge(VMergeType::Continue)
+                .cell_merge(
+                    CellMerge::new()
+                        .v_merge(CellMergeType::Continue)
+                        .v_merge_orig(CellMergeType::Restart),
+                ),
+            TableCell::new()
+                .vertical_merge(VMergeType::Continue)
+                .cell_merge(CellMerge::new().v_merge(CellMergeType::Continue)),
+        ])]));
+        let merges = |document: &Document| match &document.children[0] {
+            DocumentChild::Table(t) => {
+                let TableChild::TableRow(row) = &t.rows[0];
+                row.cells
+                    .iter()
+                    .map(|TableRowChild::TableCell(c)| {
+                        assert_eq!(c.property.cell_merge, None);
+                        c.property.get_vertical_merge()
+                    })
+                    .collect::<Vec<_>>()
+            }
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            merges(&reject(document.clone())),
+            vec![Some(VMergeType::Restart), None]
+        );
+        assert_eq!(
+            merges(&accept(document)),
+            vec![Some(VMergeType::Continue), Some(VMergeType::Continue)]
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use super::*;
 
 use crate::documents::BuildXML;
+use crate::escape::escape;
 use crate::types::CharacterSpacingValues;
 use crate::xml_builder::*;
 
@@ -15,8 +16,36 @@ pub struct Settings {
     doc_vars: Vec<DocVar>,
     even_and_odd_headers: bool,
     adjust_line_height_in_table: bool,
+    track_revisions: bool,
+    embed_true_type_fonts: bool,
+    save_subset_fonts: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     character_spacing_control: Option<CharacterSpacingValues>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_protection: Option<DocumentProtection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_protection: Option<WriteProtection>,
+    update_fields: bool,
+    mirror_margins: bool,
+    gutter_at_top: bool,
+    book_fold_printing: bool,
+    display_background_shape: bool,
+    auto_hyphenation: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hyphenation_zone: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    consecutive_hyphen_limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compatibility_mode: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme_font_lang: Option<ThemeFontLang>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof_state: Option<ProofState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rsid_root: Option<String>,
+    rsids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_table_style: Option<String>,
 }
 
 impl Settings {
@@ -49,10 +78,123 @@ impl Settings {
         self
     }
 
+    pub fn track_revisions(mut self) -> Self {
+        self.track_revisions = true;
+        self
+    }
+
+    pub fn embed_true_type_fonts(mut self) -> Self {
+        self.embed_true_type_fonts = true;
+        self
+    }
+
+    // Embed only the characters used, when Word saves the document again.
+    pub fn save_subset_fonts(mut self) -> Self {
+        self.save_subset_fonts = true;
+        self
+    }
+
     pub fn character_spacing_control(mut self, val: CharacterSpacingValues) -> Self {
         self.character_spacing_control = Some(val);
         self
     }
+
+    pub fn document_protection(mut self, p: DocumentProtection) -> Self {
+        self.document_protection = Some(p);
+        self
+    }
+
+    pub fn write_protection(mut self, p: WriteProtection) -> Self {
+        self.write_protection = Some(p);
+        self
+    }
+
+    pub fn zoom(mut self, percent: usize) -> Self {
+        self.zoom = Zoom::new(percent);
+        self
+    }
+
+    // Ask the application to refresh fields such as TOC when the document is opened.
+    pub fn update_fields(mut self) -> Self {
+        self.update_fields = true;
+        self
+    }
+
+    pub fn mirror_margins(mut self) -> Self {
+        self.mirror_margins = true;
+        self
+    }
+
+    pub fn gutter_at_top(mut self) -> Self {
+        self.gutter_at_top = true;
+        self
+    }
+
+    pub fn book_fold_printing(mut self) -> Self {
+        self.book_fold_printing = true;
+        self
+    }
+
+    pub fn display_background_shape(mut self) -> Self {
+        self.display_background_shape = true;
+        self
+    }
+
+    pub fn auto_hyphenation(mut self) -> Self {
+        self.auto_hyphenation = true;
+        self
+    }
+
+    // Distance from the right margin in twips within which words are hyphenated.
+    pub fn hyphenation_zone(mut self, zone: usize) -> Self {
+        self.hyphenation_zone = Some(zone);
+        self
+    }
+
+    pub fn consecutive_hyphen_limit(mut self, limit: usize) -> Self {
+        self.consecutive_hyphen_limit = Some(limit);
+        self
+    }
+
+    // e.g. 14 for Word 2010, 15 for Word 2013 and later.
+    pub fn compatibility_mode(mut self, mode: usize) -> Self {
+        self.compatibility_mode = Some(mode);
+        self
+    }
+
+    // Documents without a compatibilityMode setting are kept in the mode of older versions.
+    pub(crate) fn clear_compatibility_mode(mut self) -> Self {
+        self.compatibility_mode = None;
+        self
+    }
+
+    pub fn theme_font_lang(mut self, lang: ThemeFontLang) -> Self {
+        self.theme_font_lang = Some(lang);
+        self
+    }
+
+    pub fn proof_state(mut self, p: ProofState) -> Self {
+        self.proof_state = Some(p);
+        self
+    }
+
+    pub fn rsid_root(mut self, rsid: impl Into<String>) -> Self {
+        self.rsid_root = Some(rsid.into());
+        self
+    }
+
+    pub fn add_rsid(mut self, rsid: impl Into<String>) -> Self {
+        let rsid = rsid.into();
+        if !self.rsids.contains(&rsid) {
+            self.rsids.push(rsid);
+        }
+        self
+    }
+
+    pub fn default_table_style(mut self, style_id: impl Into<String>) -> Self {
+        self.default_table_style = Some(style_id.into());
+        self
+    }
 }
 
 impl Default for Settings {
@@ -64,7 +206,26 @@ impl Default for Settings {
             doc_vars: vec![],
             even_and_odd_headers: false,
             adjust_line_height_in_table: false,
+            track_revisions: false,
+            embed_true_type_fonts: false,
+            save_subset_fonts: false,
             character_spacing_control: None,
+            document_protection: None,
+            write_protection: None,
+            update_fields: false,
+            mirror_margins: false,
+            gutter_at_top: false,
+            book_fold_printing: false,
+            display_background_shape: false,
+            auto_hyphenation: false,
+            hyphenation_zone: None,
+            consecutive_hyphen_limit: None,
+            compatibility_mode: Some(15),
+            theme_font_lang: None,
+            proof_state: None,
+            rsid_root: None,
+            rsids: vec![],
+            default_table_style: None,
         }
     }
 }
@@ -75,8 +236,68 @@ impl BuildXML for Settings {
         let mut b = b
             .declaration(Some(true))
             .open_settings()
-            .add_child(&self.default_tab_stop)
-            .add_child(&self.zoom)
+            .add_optional_child(&self.write_protection)
+            .add_child(&self.zoom);
+
+        if self.display_background_shape {
+            b = b.display_background_shape();
+        }
+
+        if self.embed_true_type_fonts {
+            b = b.embed_true_type_fonts();
+        }
+
+        if self.save_subset_fonts {
+            b = b.save_subset_fonts();
+        }
+
+        if self.mirror_margins {
+            b = b.mirror_margins();
+        }
+
+        if self.gutter_at_top {
+            b = b.gutter_at_top();
+        }
+
+        b = b.add_optional_child(&self.proof_state);
+
+        if self.track_revisions {
+            b = b.track_revisions();
+        }
+
+        b = b
+            .add_optional_child(&self.document_protection)
+            .add_child(&self.default_tab_stop);
+
+        if self.auto_hyphenation {
+            b = b.auto_hyphenation();
+        }
+
+        if let Some(v) = self.consecutive_hyphen_limit {
+            b = b.consecutive_hyphen_limit(v);
+        }
+
+        if let Some(v) = self.hyphenation_zone {
+            b = b.hyphenation_zone(v);
+        }
+
+        if let Some(v) = self.default_table_style.as_ref() {
+            b = b.default_table_style(&escape(v));
+        }
+
+        if self.even_and_odd_headers {
+            b = b.even_and_odd_headers();
+        }
+
+        if self.book_fold_printing {
+            b = b.book_fold_printing();
+        }
+
+        if self.update_fields {
+            b = b.update_fields("true");
+        }
+
+        b = b
             .open_compat()
             .space_for_ul()
             .balance_single_byte_double_byte_width()
@@ -92,13 +313,17 @@ impl BuildXML for Settings {
             b = b.adjust_line_height_table();
         }
 
-        b = b
-            .use_fe_layout()
-            .compat_setting(
+        b = b.use_fe_layout();
+
+        if let Some(v) = self.compatibility_mode {
+            b = b.compat_setting(
                 "compatibilityMode",
                 "http://schemas.microsoft.com/office/word",
-                "15",
-            )
+                &format!("{}", v),
+            );
+        }
+
+        b = b
             .compat_setting(
                 "overrideTableStyleFontSizeAndJustification",
                 "http://schemas.microsoft.com/office/word",
@@ -124,8 +349,7 @@ impl BuildXML for Settings {
                 "http://schemas.microsoft.com/office/word",
                 "0",
             )
-            .close()
-            .add_optional_child(&self.doc_id);
+            .close();
 
         if !self.doc_vars.is_empty() {
             b = b.open_doc_vars();
@@ -135,10 +359,21 @@ impl BuildXML for Settings {
             b = b.close();
         }
 
-        if self.even_and_odd_headers {
-            b = b.even_and_odd_headers();
+        if self.rsid_root.is_some() || !self.rsids.is_empty() {
+            b = b.open_rsids();
+            if let Some(v) = self.rsid_root.as_ref() {
+                b = b.rsid_root(v);
+            }
+            for v in self.rsids.iter() {
+                b = b.rsid(v);
+            }
+            b = b.close();
         }
-        b.close().build()
+
+        b.add_optional_child(&self.theme_font_lang)
+            .add_optional_child(&self.doc_id)
+            .close()
+            .build()
     }
 }
 
@@ -156,7 +391,7 @@ mod tests {
         assert_eq!(
             str::from_utf8(&b).unwrap(),
             r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
-<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml"><w:defaultTabStop w:val="840" /><w:zoom w:percent="100" /><w:compat>
+<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml"><w:zoom w:percent="100" /><w:defaultTabStop w:val="840" /><w:compat>
     <w:spaceForUL />
     <w:balanceSingleByteDoubleByteWidth />
     <w:doNotLeaveBackslashAlone />
@@ -173,4 +408,67 @@ mod tests {
 </w:settings>"#
         );
     }
+
+    #[test]
+    fn test_settings_with_document_protection() {
+        use crate::types::DocumentProtectionType;
+        let c = Settings::new()
+            .document_protection(DocumentProtection::new(DocumentProtectionType::Forms));
+        let b = c.build();
+        assert!(str::from_utf8(&b).unwrap().contains(
+            r#"<w:documentProtection w:edit="forms" w:enforcement="1" /><w:defaultTabStop w:val="840" />"#
+        ));
+    }
+
+    #[test]
+    fn test_settings_with_track_revisions() {
+        let c = Settings::new().track_revisions();
+        let b = c.build();
+        assert!(str::from_utf8(&b)
+            .unwrap()
+            .contains(r#"<w:trackRevisions /><w:defaultTabStop w:val="840" />"#));
+    }
+
+    #[test]
+    fn test_rich_settings() {
+        let c = Settings::new()
+            .update_fields()
+            .mirror_margins()
+            .proof_state(ProofState::new().spelling(crate::types::ProofStateType::Clean))
+            .auto_hyphenation()
+            .hyphenation_zone(357)
+            .default_table_style("TableGrid")
+            .compatibility_mode(14)
+            .rsid_root("00A1B2C3")
+            .add_rsid("00A1B2C3")
+            .theme_font_lang(ThemeFontLang::new("en-US"))
+            .doc_id("A1B2C3D4-E5F6-4A7B-8C9D-0E1F2A3B4C5D");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml"><w:zoom w:percent="100" /><w:mirrorMargins /><w:proofState w:spelling="clean" /><w:defaultTabStop w:val="840" /><w:autoHyphenation />
+  <w:hyphenationZone w:val="357" />
+  <w:defaultTableStyle w:val="TableGrid" />
+  <w:updateFields w:val="true" />
+  <w:compat>
+    <w:spaceForUL />
+    <w:balanceSingleByteDoubleByteWidth />
+    <w:doNotLeaveBackslashAlone />
+    <w:ulTrailSpace />
+    <w:doNotExpandShiftReturn />
+    <w:useFELayout />
+    <w:compatSetting w:name="compatibilityMode" w:uri="http://schemas.microsoft.com/office/word" w:val="14" />
+    <w:compatSetting w:name="overrideTableStyleFontSizeAndJustification" w:uri="http://schemas.microsoft.com/office/word" w:val="1" />
+    <w:compatSetting w:name="enableOpenTypeFeatures" w:uri="http://schemas.microsoft.com/office/word" w:val="1" />
+    <w:compatSetting w:name="doNotFlipMirrorIndents" w:uri="http://schemas.microsoft.com/office/word" w:val="1" />
+    <w:compatSetting w:name="differentiateMultirowTableHeaders" w:uri="http://schemas.microsoft.com/office/word" w:val="1" />
+    <w:compatSetting w:name="useWord2013TrackBottomHyphenation" w:uri="http://schemas.microsoft.com/office/word" w:val="0" />
+  </w:compat>
+  <w:rsids>
+    <w:rsidRoot w:val="00A1B2C3" />
+    <w:rsid w:val="00A1B2C3" />
+  </w:rsids><w:themeFontLang w:val="en-US" /><w15:docId w15:val="{A1B2C3D4-E5F6-4A7B-8C9D-0E1F2A3B4C5D}" /></w:settings>"#
+        );
+    }
 }
@@ -9,6 +9,8 @@ use crate::xml_builder::*;
 #[serde(rename_all = "camelCase")]
 pub struct Styles {
     doc_defaults: DocDefaults,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latent_styles: Option<LatentStyles>,
     styles: Vec<Style>,
 }
 
@@ -42,15 +44,52 @@ impl Styles {
         self
     }
 
+    pub fn latent_styles(mut self, latent_styles: LatentStyles) -> Self {
+        self.latent_styles = Some(latent_styles);
+        self
+    }
+
+    // Adds Word's built-in styles which are not defined yet, see `StylePalette`.
+    pub fn add_builtin_styles(mut self, palette: &StylePalette) -> Self {
+        for style in preset_styles::builtin_styles(palette) {
+            if self.find_style_by_id(&style.style_id).is_none() {
+                self.styles.push(style);
+            }
+        }
+        if self.latent_styles.is_none() {
+            self.latent_styles = Some(preset_styles::builtin_latent_styles());
+        }
+        self
+    }
+
     pub(crate) fn doc_defaults(mut self, doc_defaults: DocDefaults) -> Self {
         self.doc_defaults = doc_defaults;
         self
     }
 
+    pub(crate) fn get_default_run_property(&self) -> &RunProperty {
+        self.doc_defaults.get_run_property()
+    }
+
+    pub(crate) fn get_default_paragraph_property(&self) -> &ParagraphProperty {
+        self.doc_defaults.get_paragraph_property()
+    }
+
+    pub(crate) fn get_styles(&self) -> &[Style] {
+        &self.styles
+    }
+
     pub fn find_style_by_id(&self, id: &str) -> Option<&Style> {
         self.styles.iter().find(|s| s.style_id == id)
     }
 
+    // The style marked as default for `style_type`.
+    pub fn find_default_style(&self, style_type: StyleType) -> Option<&Style> {
+        self.styles
+            .iter()
+            .find(|s| s.style_type == style_type && s.default == Some(true))
+    }
+
     pub fn create_heading_style_map(&self) -> std::collections::HashMap<String, usize> {
         self.styles
             .iter()
@@ -70,6 +109,7 @@ impl Default for Styles {
     fn default() -> Self {
         Self {
             doc_defaults: DocDefaults::new(),
+            latent_styles: None,
             styles: vec![],
         }
     }
@@ -77,14 +117,15 @@ impl Default for Styles {
 
 impl BuildXML for Styles {
     fn build(&self) -> Vec<u8> {
-        let b = XMLBuilder::new();
-        let normal = Style::new("Normal", StyleType::Paragraph).name("Normal");
-        b.open_styles()
+        let mut b = XMLBuilder::new()
+            .open_styles()
             .add_child(&self.doc_defaults)
-            .add_child(&normal)
-            .add_children(&self.styles)
-            .close()
-            .build()
+            .add_optional_child(&self.latent_styles);
+        // Word needs a Normal style, write the default one unless it is defined.
+        if self.find_style_by_id("Normal").is_none() {
+            b = b.add_child(&Style::new("Normal", StyleType::Paragraph).name("Normal"));
+        }
+        b.add_children(&self.styles).close().build()
     }
 }
 
@@ -1,11 +1,159 @@
 use serde::Serialize;
 
 use super::*;
+use crate::xml_builder::*;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
 #[cfg_attr(feature = "wasm", ts(export))]
 #[serde(rename_all = "camelCase")]
 pub struct Theme {
+    pub name: String,
+    pub color_scheme: ColorScheme,
     pub font_schema: FontScheme,
+    pub format_scheme: FormatScheme,
+}
+
+impl Theme {
+    // The default Office theme.
+    pub fn new() -> Self {
+        Self {
+            name: "Office Theme".to_owned(),
+            color_scheme: ColorScheme::new(),
+            font_schema: FontScheme::new()
+                .name("Office")
+                .major_font(FontGroup::new("Calibri Light"))
+                .minor_font(FontGroup::new("Calibri")),
+            format_scheme: FormatScheme::new(),
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn color_scheme(mut self, s: ColorScheme) -> Self {
+        self.color_scheme = s;
+        self
+    }
+
+    pub fn font_scheme(mut self, s: FontScheme) -> Self {
+        self.font_schema = s;
+        self
+    }
+
+    pub fn format_scheme(mut self, s: FormatScheme) -> Self {
+        self.format_scheme = s;
+        self
+    }
+
+    // RGB hex of a theme color reference with its tint or shade applied.
+    pub fn resolve_color(&self, c: &ThemeColor) -> Option<String> {
+        let base = self.color_scheme.get_color(c.color)?;
+        let rgb = u32::from_str_radix(base, 16).ok()?;
+        let ratio = |v: &Option<String>| {
+            v.as_ref()
+                .and_then(|v| u8::from_str_radix(v, 16).ok())
+                .map(|v| v as f64 / 255.0)
+        };
+        let tint = ratio(&c.tint);
+        let shade = ratio(&c.shade);
+        let channels = [(rgb >> 16) & 0xFF, (rgb >> 8) & 0xFF, rgb & 0xFF].map(|ch| {
+            let mut ch = ch as f64;
+            if let Some(t) = tint {
+                ch = 255.0 - (255.0 - ch) * t;
+            }
+            if let Some(s) = shade {
+                ch *= s;
+            }
+            ch.round() as u32
+        });
+        Some(format!(
+            "{:02X}{:02X}{:02X}",
+            channels[0], channels[1], channels[2]
+        ))
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildXML for Theme {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .declaration(Some(true))
+            .open_theme(&self.name)
+            .open_theme_elements()
+            .add_child(&self.color_scheme)
+            .add_child(&self.font_schema)
+            .add_child(&self.format_scheme)
+            .close()
+            .object_defaults()
+            .extra_clr_scheme_lst()
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::ThemeColorType;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_resolve_color() {
+        let t = Theme::new();
+        let accent1 = ThemeColor::new(ThemeColorType::Accent1);
+        assert_eq!(t.resolve_color(&accent1), Some("4472C4".to_owned()));
+        assert_eq!(
+            t.resolve_color(&accent1.clone().shade("BF")),
+            Some("335593".to_owned())
+        );
+        assert_eq!(
+            t.resolve_color(&accent1.tint("66")),
+            Some("B4C7E7".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Theme::default(), Theme::new());
+        let b = Theme::default().build();
+        assert!(!str::from_utf8(&b).unwrap().contains(r#"val="""#));
+    }
+
+    #[test]
+    fn test_build() {
+        let t = Theme::new().color_scheme(
+            ColorScheme::new()
+                .name("Corporate")
+                .color(ThemeColorType::Accent1, "C00000"),
+        );
+        let b = t.build();
+        let xml = str::from_utf8(&b).unwrap();
+        assert!(xml.starts_with(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Office Theme">
+  <a:themeElements><a:clrScheme name="Corporate">"#
+        ));
+        assert!(xml.contains(
+            r#"<a:accent1>
+    <a:srgbClr val="C00000" />
+  </a:accent1>"#
+        ));
+        assert!(xml.ends_with(
+            r#"</a:fmtScheme></a:themeElements>
+  <a:objectDefaults />
+  <a:extraClrSchemeLst />
+</a:theme>"#
+        ));
+    }
 }
@@ -1,5 +1,6 @@
-use super::XMLDocProps;
+use super::{PackageSigner, XMLDocProps};
 
+use crate::crypto;
 use crate::zipper;
 use std::io::prelude::*;
 use std::io::Seek;
@@ -15,13 +16,21 @@ pub struct XMLDocx {
     pub document_rels: Vec<u8>,
     pub settings: Vec<u8>,
     pub font_table: Vec<u8>,
+    pub font_table_rels: Option<Vec<u8>>,
+    pub fonts: Vec<Vec<u8>>,
     pub numberings: Vec<u8>,
+    pub numbering_rels: Option<Vec<u8>>,
+    pub theme: Option<Vec<u8>>,
+    // File names in word/media with their buffers, i.e. image1.png.
     pub media: Vec<(String, Vec<u8>)>,
     pub headers: Vec<Vec<u8>>,
     pub header_rels: Vec<Vec<u8>>,
     pub footers: Vec<Vec<u8>>,
     pub footer_rels: Vec<Vec<u8>>,
     pub comments_extended: Vec<u8>,
+    pub comments_ids: Vec<u8>,
+    pub comments_extensible: Vec<u8>,
+    pub people: Vec<u8>,
     pub taskpanes: Option<Vec<u8>>,
     pub taskpanes_rels: Vec<u8>,
     pub web_extensions: Vec<Vec<u8>>,
@@ -38,4 +47,26 @@ impl XMLDocx {
     {
         zipper::zip(w, self)
     }
+
+    // Pack the document and add a package signature made with `signer`.
+    pub fn pack_signed<W>(self, mut w: W, signer: &PackageSigner) -> zip::result::ZipResult<()>
+    where
+        W: Write + Seek,
+    {
+        let mut buf = std::io::Cursor::new(vec![]);
+        zipper::zip(&mut buf, self)?;
+        w.write_all(&signer.sign(buf.get_ref())?)?;
+        Ok(())
+    }
+
+    // Pack the document and encrypt it with ECMA-376 Agile Encryption.
+    pub fn pack_encrypted<W>(self, mut w: W, password: &str) -> zip::result::ZipResult<()>
+    where
+        W: Write + Seek,
+    {
+        let mut buf = std::io::Cursor::new(vec![]);
+        zipper::zip(&mut buf, self)?;
+        w.write_all(&crypto::encrypt_package(buf.get_ref(), password)?)?;
+        Ok(())
+    }
 }
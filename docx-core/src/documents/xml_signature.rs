@@ -0,0 +1,163 @@
+use crate::crypto::{DigestMethod, C14N_URI, RELATIONSHIP_TRANSFORM_URI, RSA_SHA256_URI};
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+pub(crate) const PACKAGE_OBJECT_ID: &str = "idPackageObject";
+const PACKAGE_SIGNATURE_ID: &str = "idPackageSignature";
+const XML_DSIG_NAMESPACE: &str = "http://www.w3.org/2000/09/xmldsig#";
+const DIGITAL_SIGNATURE_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/package/2006/digital-signature";
+
+// A part covered by a package signature.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SignatureReference {
+    // i.e. /word/document.xml?ContentType=application/vnd...main+xml
+    pub uri: String,
+    // Relationship parts are signed through the relationship transform.
+    pub relationship_ids: Option<Vec<String>>,
+    pub digest_value: String,
+}
+
+// The XML-DSig signature part of a package, i.e. /_xmlsignatures/sig1.xml
+// Please see ECMA-376 Part 2 13.2.4 Signature Markup.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct XmlSignature {
+    pub references: Vec<SignatureReference>,
+    pub object_digest_value: String,
+    pub signature_value: String,
+    pub certificate: String,
+    pub signing_time: String,
+}
+
+impl BuildXML for XmlSignature {
+    fn build(&self) -> Vec<u8> {
+        let digest_method = DigestMethod::Sha256.uri();
+        let mut b = XMLBuilder::new()
+            .declaration(None)
+            .open_signature(XML_DSIG_NAMESPACE, PACKAGE_SIGNATURE_ID)
+            .open_signed_info()
+            .canonicalization_method(C14N_URI)
+            .signature_method(RSA_SHA256_URI)
+            .open_object_reference(
+                "http://www.w3.org/2000/09/xmldsig#Object",
+                &format!("#{}", PACKAGE_OBJECT_ID),
+            )
+            .digest_method(digest_method)
+            .digest_value(&self.object_digest_value)
+            .close()
+            .close()
+            .signature_value(&self.signature_value)
+            .open_key_info()
+            .open_x509_data()
+            .x509_certificate(&self.certificate)
+            .close()
+            .close()
+            .open_object(PACKAGE_OBJECT_ID)
+            .open_manifest();
+        for r in self.references.iter() {
+            b = b.open_part_reference(&r.uri);
+            if let Some(ref ids) = r.relationship_ids {
+                b = b
+                    .open_transforms()
+                    .open_transform(RELATIONSHIP_TRANSFORM_URI);
+                for id in ids {
+                    b = b.relationship_reference(DIGITAL_SIGNATURE_NAMESPACE, id);
+                }
+                b = b.close().transform(C14N_URI).close();
+            }
+            b = b
+                .digest_method(digest_method)
+                .digest_value(&r.digest_value)
+                .close();
+        }
+        b.close()
+            .open_signature_properties()
+            .open_signature_property("idSignatureTime", &format!("#{}", PACKAGE_SIGNATURE_ID))
+            .open_signature_time(DIGITAL_SIGNATURE_NAMESPACE)
+            .signature_time_format("YYYY-MM-DDThh:mm:ssTZD")
+            .signature_time_value(&self.signing_time)
+            .close()
+            .close()
+            .close()
+            .close()
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let s = XmlSignature {
+            references: vec![
+                SignatureReference {
+                    uri: "/_rels/.rels?ContentType=application/vnd.openxmlformats-package.relationships+xml".to_owned(),
+                    relationship_ids: Some(vec!["rId1".to_owned()]),
+                    digest_value: "REL=".to_owned(),
+                },
+                SignatureReference {
+                    uri: "/word/document.xml?ContentType=application/xml".to_owned(),
+                    relationship_ids: None,
+                    digest_value: "DOC=".to_owned(),
+                },
+            ],
+            object_digest_value: "OBJ=".to_owned(),
+            signature_value: "SIG=".to_owned(),
+            certificate: "CERT=".to_owned(),
+            signing_time: "2024-01-01T00:00:00Z".to_owned(),
+        };
+        assert_eq!(
+            str::from_utf8(&s.build()).unwrap(),
+            r##"<?xml version="1.0" encoding="UTF-8"?>
+<Signature xmlns="http://www.w3.org/2000/09/xmldsig#" Id="idPackageSignature">
+  <SignedInfo>
+    <CanonicalizationMethod Algorithm="http://www.w3.org/TR/2001/REC-xml-c14n-20010315" />
+    <SignatureMethod Algorithm="http://www.w3.org/2001/04/xmldsig-more#rsa-sha256" />
+    <Reference Type="http://www.w3.org/2000/09/xmldsig#Object" URI="#idPackageObject">
+      <DigestMethod Algorithm="http://www.w3.org/2001/04/xmlenc#sha256" />
+      <DigestValue>OBJ=</DigestValue>
+    </Reference>
+  </SignedInfo>
+  <SignatureValue>SIG=</SignatureValue>
+  <KeyInfo>
+    <X509Data>
+      <X509Certificate>CERT=</X509Certificate>
+    </X509Data>
+  </KeyInfo>
+  <Object Id="idPackageObject">
+    <Manifest>
+      <Reference URI="/_rels/.rels?ContentType=application/vnd.openxmlformats-package.relationships+xml">
+        <Transforms>
+          <Transform Algorithm="http://schemas.openxmlformats.org/package/2006/RelationshipTransform">
+            <mdssi:RelationshipReference xmlns:mdssi="http://schemas.openxmlformats.org/package/2006/digital-signature" SourceId="rId1" />
+          </Transform>
+          <Transform Algorithm="http://www.w3.org/TR/2001/REC-xml-c14n-20010315" />
+        </Transforms>
+        <DigestMethod Algorithm="http://www.w3.org/2001/04/xmlenc#sha256" />
+        <DigestValue>REL=</DigestValue>
+      </Reference>
+      <Reference URI="/word/document.xml?ContentType=application/xml">
+        <DigestMethod Algorithm="http://www.w3.org/2001/04/xmlenc#sha256" />
+        <DigestValue>DOC=</DigestValue>
+      </Reference>
+    </Manifest>
+    <SignatureProperties>
+      <SignatureProperty Id="idSignatureTime" Target="#idPackageSignature">
+        <mdssi:SignatureTime xmlns:mdssi="http://schemas.openxmlformats.org/package/2006/digital-signature">
+          <mdssi:Format>YYYY-MM-DDThh:mm:ssTZD</mdssi:Format>
+          <mdssi:Value>2024-01-01T00:00:00Z</mdssi:Value>
+        </mdssi:SignatureTime>
+      </SignatureProperty>
+    </SignatureProperties>
+  </Object>
+</Signature>"##
+        );
+    }
+}
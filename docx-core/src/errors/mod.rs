@@ -8,6 +8,10 @@ pub enum DocxError {
     EmitterError(#[from] xml::writer::Error),
     #[error("Failed to zip XML documents.")]
     ZipError(#[from] zip::result::ZipError),
+    #[error("Failed to load signing key or certificate.")]
+    SigningKeyError,
+    #[error("Invalid table: {0}")]
+    InvalidTable(String),
     #[error("Unknown error")]
     Unknown,
 }
@@ -1,3 +1,4 @@
+mod crypto;
 mod documents;
 mod errors;
 mod escape;
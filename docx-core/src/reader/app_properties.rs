@@ -0,0 +1,29 @@
+use std::io::Read;
+
+use super::core_properties::read_property_texts;
+use super::*;
+
+impl FromXML for AppProps {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut props = AppProps::new();
+        let count = |t: &str| t.trim().parse::<usize>().ok();
+        for (name, text) in read_property_texts(reader)? {
+            match name.as_str() {
+                "Template" => props.template = Some(text),
+                "TotalTime" => props.total_time = count(&text),
+                "Pages" => props.pages = count(&text),
+                "Words" => props.words = count(&text),
+                "Characters" => props.characters = count(&text),
+                "Application" => props.application = Some(text),
+                "Lines" => props.lines = count(&text),
+                "Paragraphs" => props.paragraphs = count(&text),
+                "Manager" => props.manager = Some(text),
+                "Company" => props.company = Some(text),
+                "CharactersWithSpaces" => props.characters_with_spaces = count(&text),
+                "AppVersion" => props.app_version = Some(text),
+                _ => {}
+            }
+        }
+        Ok(props)
+    }
+}
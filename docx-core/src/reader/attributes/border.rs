@@ -2,8 +2,11 @@ use std::str::FromStr;
 
 use xml::attribute::OwnedAttribute;
 
+use crate::documents::ThemeColor;
 use crate::types::*;
 
+use super::read_theme_color;
+
 use super::super::errors::*;
 
 pub struct BorderAttrs {
@@ -11,6 +14,7 @@ pub struct BorderAttrs {
     pub color: String,
     pub size: Option<u32>,
     pub space: Option<u32>,
+    pub theme_color: Option<ThemeColor>,
 }
 
 pub fn read_border(attrs: &[OwnedAttribute]) -> Result<BorderAttrs, ReaderError> {
@@ -35,5 +39,6 @@ pub fn read_border(attrs: &[OwnedAttribute]) -> Result<BorderAttrs, ReaderError>
         color,
         size,
         space,
+        theme_color: read_theme_color(attrs, ["themeColor", "themeTint", "themeShade"]),
     })
 }
@@ -5,6 +5,7 @@ mod indent;
 mod indent_level;
 pub(crate) mod line_spacing;
 mod name;
+mod theme_color;
 mod val;
 mod width;
 
@@ -14,6 +15,7 @@ pub use id::*;
 pub use indent::*;
 pub use indent_level::*;
 pub use name::*;
+pub use theme_color::*;
 pub use val::*;
 pub use width::*;
 
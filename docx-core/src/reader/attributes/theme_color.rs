@@ -0,0 +1,27 @@
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+
+use crate::documents::ThemeColor;
+use crate::types::*;
+
+// Reads a theme reference written as `names`, i.e. themeColor, themeTint and themeShade.
+pub fn read_theme_color(attrs: &[OwnedAttribute], names: [&str; 3]) -> Option<ThemeColor> {
+    let mut theme: Option<ThemeColor> = None;
+    let mut tint = None;
+    let mut shade = None;
+    for a in attrs {
+        let local_name = a.name.local_name.as_str();
+        if local_name == names[0] {
+            theme = ThemeColorType::from_str(&a.value).ok().map(ThemeColor::new);
+        } else if local_name == names[1] {
+            tint = Some(a.value.to_owned());
+        } else if local_name == names[2] {
+            shade = Some(a.value.to_owned());
+        }
+    }
+    let mut theme = theme?;
+    theme.tint = tint;
+    theme.shade = shade;
+    Some(theme)
+}
@@ -0,0 +1,62 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::EventReader;
+
+use super::*;
+use crate::types::CellMergeType;
+
+impl ElementReader for CellInsert {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut c = CellInsert::new();
+        if let Some(author) = read(attrs, "author") {
+            c = c.author(author);
+        }
+        if let Some(date) = read(attrs, "date") {
+            c = c.date(date);
+        }
+        Ok(c)
+    }
+}
+
+impl ElementReader for CellDelete {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut c = CellDelete::new();
+        if let Some(author) = read(attrs, "author") {
+            c = c.author(author);
+        }
+        if let Some(date) = read(attrs, "date") {
+            c = c.date(date);
+        }
+        Ok(c)
+    }
+}
+
+impl ElementReader for CellMerge {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut c = CellMerge::new();
+        if let Some(author) = read(attrs, "author") {
+            c = c.author(author);
+        }
+        if let Some(date) = read(attrs, "date") {
+            c = c.date(date);
+        }
+        if let Some(Ok(v)) = read(attrs, "vMerge").map(|v| CellMergeType::from_str(&v)) {
+            c = c.v_merge(v);
+        }
+        if let Some(Ok(v)) = read(attrs, "vMergeOrig").map(|v| CellMergeType::from_str(&v)) {
+            c = c.v_merge_orig(v);
+        }
+        Ok(c)
+    }
+}
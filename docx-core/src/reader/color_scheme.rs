@@ -0,0 +1,75 @@
+#![allow(clippy::single_match)]
+
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+use crate::types::*;
+
+fn slot_type(name: &str) -> Option<ThemeColorType> {
+    match name {
+        "dk1" => Some(ThemeColorType::Dark1),
+        "lt1" => Some(ThemeColorType::Light1),
+        "dk2" => Some(ThemeColorType::Dark2),
+        "lt2" => Some(ThemeColorType::Light2),
+        "accent1" => Some(ThemeColorType::Accent1),
+        "accent2" => Some(ThemeColorType::Accent2),
+        "accent3" => Some(ThemeColorType::Accent3),
+        "accent4" => Some(ThemeColorType::Accent4),
+        "accent5" => Some(ThemeColorType::Accent5),
+        "accent6" => Some(ThemeColorType::Accent6),
+        "hlink" => Some(ThemeColorType::Hyperlink),
+        "folHlink" => Some(ThemeColorType::FollowedHyperlink),
+        _ => None,
+    }
+}
+
+impl ElementReader for ColorScheme {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut s = ColorScheme::default();
+        if let Some(name) = read(attrs, "name") {
+            s = s.name(name);
+        }
+        let mut slot = None;
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    if let Some(t) = slot_type(&name.local_name) {
+                        slot = Some(t);
+                        continue;
+                    }
+                    let e = AXMLElement::from_str(&name.local_name).unwrap();
+                    // System colors, i.e. windowText, keep their last computed value.
+                    let val = match e {
+                        AXMLElement::SrgbClr => read(&attributes, "val"),
+                        AXMLElement::SysClr => read(&attributes, "lastClr"),
+                        _ => None,
+                    };
+                    if let (Some(t), Some(val)) = (slot, val) {
+                        s = s.color(t, val);
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = AXMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        AXMLElement::ClrScheme => {
+                            return Ok(s);
+                        }
+                        _ => {}
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
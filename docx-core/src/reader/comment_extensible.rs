@@ -0,0 +1,20 @@
+use std::io::Read;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::EventReader;
+
+use super::*;
+
+impl ElementReader for CommentExtensible {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let durable_id = read(attrs, "durableId").ok_or(ReaderError::XMLReadError)?;
+        let mut c = CommentExtensible::new(durable_id);
+        if let Some(date_utc) = read(attrs, "dateUtc") {
+            c = c.date_utc(date_utc);
+        }
+        Ok(c)
+    }
+}
@@ -0,0 +1,17 @@
+use std::io::Read;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::EventReader;
+
+use super::*;
+
+impl ElementReader for CommentId {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let para_id = read(attrs, "paraId").ok_or(ReaderError::XMLReadError)?;
+        let durable_id = read(attrs, "durableId").ok_or(ReaderError::XMLReadError)?;
+        Ok(CommentId::new(para_id, durable_id))
+    }
+}
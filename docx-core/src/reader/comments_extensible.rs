@@ -0,0 +1,38 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl FromXML for CommentsExtensible {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut children: Vec<CommentExtensible> = vec![];
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name)
+                        .expect("should convert to XMLElement");
+                    if let XMLElement::CommentExtensible = e {
+                        if let Ok(ex) = CommentExtensible::read(&mut r, &attributes) {
+                            children.push(ex);
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::CommentsExtensible {
+                        return Ok(CommentsExtensible { children });
+                    }
+                }
+                Ok(XmlEvent::EndDocument { .. }) => return Ok(CommentsExtensible { children }),
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::io::Read;
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl FromXML for CoreProps {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut config = CorePropsConfig::new();
+        for (name, text) in read_property_texts(reader)? {
+            match name.as_str() {
+                "created" => config.created = Some(text),
+                "creator" => config.creator = Some(text),
+                "description" => config.description = Some(text),
+                "language" => config.language = Some(text),
+                "lastModifiedBy" => config.last_modified_by = Some(text),
+                "modified" => config.modified = Some(text),
+                "revision" => config.revision = text.trim().parse().ok(),
+                "subject" => config.subject = Some(text),
+                "title" => config.title = Some(text),
+                "keywords" => config.keywords = Some(text),
+                "category" => config.category = Some(text),
+                "contentStatus" => config.content_status = Some(text),
+                _ => {}
+            }
+        }
+        Ok(CoreProps::new(config))
+    }
+}
+
+// Collects the text of the direct children of the root element by their local name.
+pub(crate) fn read_property_texts<R: Read>(
+    reader: R,
+) -> Result<Vec<(String, String)>, ReaderError> {
+    let mut r = EventReader::new(reader);
+    let mut props = vec![];
+    let mut depth = 0;
+    let mut current: Option<(String, String)> = None;
+    loop {
+        match r.next() {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                depth += 1;
+                if depth == 2 {
+                    current = Some((name.local_name, String::new()));
+                }
+            }
+            Ok(XmlEvent::Characters(c)) => {
+                if depth == 2 {
+                    if let Some((_, text)) = current.as_mut() {
+                        text.push_str(&c);
+                    }
+                }
+            }
+            Ok(XmlEvent::EndElement { .. }) => {
+                if depth == 2 {
+                    if let Some((name, text)) = current.take() {
+                        props.push((name, text));
+                    }
+                }
+                depth -= 1;
+            }
+            Ok(XmlEvent::EndDocument { .. }) => return Ok(props),
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
@@ -1,14 +1,42 @@
 use std::io::Read;
 use std::str::FromStr;
 
+use xml::attribute::OwnedAttribute;
 use xml::reader::{EventReader, XmlEvent};
 
 use super::*;
 
+fn read_value(el: &VtXMLElement, text: &str) -> Option<CustomPropertyValue> {
+    match el {
+        VtXMLElement::Lpwstr | VtXMLElement::Lpstr => {
+            Some(CustomPropertyValue::Text(text.to_owned()))
+        }
+        VtXMLElement::I4 | VtXMLElement::Int => {
+            text.trim().parse().ok().map(CustomPropertyValue::Integer)
+        }
+        VtXMLElement::R8 | VtXMLElement::R4 => {
+            text.trim().parse().ok().map(CustomPropertyValue::Float)
+        }
+        VtXMLElement::Bool => match text.trim() {
+            "true" | "1" => Some(CustomPropertyValue::Bool(true)),
+            "false" | "0" => Some(CustomPropertyValue::Bool(false)),
+            _ => None,
+        },
+        VtXMLElement::Filetime => Some(CustomPropertyValue::Date(text.trim().to_owned())),
+        VtXMLElement::Unsupported => None,
+    }
+}
+
+fn read_link_target(attrs: &[OwnedAttribute]) -> Option<String> {
+    attrs
+        .iter()
+        .find(|a| a.name.local_name == "linkTarget")
+        .map(|a| a.value.clone())
+}
+
 impl FromXML for CustomProps {
     fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
         let mut r = EventReader::new(reader);
-        // TODO: Fow now, support only string.
         let mut props = CustomProps::new();
         loop {
             let e = r.next();
@@ -18,20 +46,17 @@ impl FromXML for CustomProps {
                 }) => {
                     if let Ok(XMLElement::Property) = XMLElement::from_str(&name.local_name) {
                         if let Some(key) = read_name(&attributes) {
+                            let link_target = read_link_target(&attributes);
+                            let mut value = None;
+                            let mut text = String::new();
                             loop {
                                 let e = r.next();
                                 match e {
                                     Ok(XmlEvent::StartElement { name, .. }) => {
-                                        // TODO: Fow now, support only string.
-                                        if let Ok(VtXMLElement::Lpwstr) =
-                                            VtXMLElement::from_str(&name.local_name)
-                                        {
-                                            let e = r.next();
-                                            if let Ok(XmlEvent::Characters(c)) = e {
-                                                props = props.add_custom_property(&key, c)
-                                            }
-                                        }
+                                        value = VtXMLElement::from_str(&name.local_name).ok();
+                                        text.clear();
                                     }
+                                    Ok(XmlEvent::Characters(c)) => text.push_str(&c),
                                     Ok(XmlEvent::EndElement { name, .. }) => {
                                         if let Ok(XMLElement::Property) =
                                             XMLElement::from_str(&name.local_name)
@@ -39,9 +64,15 @@ impl FromXML for CustomProps {
                                             break;
                                         }
                                     }
+                                    Err(_) => return Err(ReaderError::XMLReadError),
                                     _ => {}
                                 }
                             }
+                            if let Some(v) = value.and_then(|el| read_value(&el, &text)) {
+                                let mut prop = CustomProperty::new(key, v);
+                                prop.link_target = link_target;
+                                props = props.add_property(prop);
+                            }
                         }
                     }
                 }
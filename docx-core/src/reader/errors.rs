@@ -22,6 +22,12 @@ pub enum ReaderError {
     DocumentNumberingsNotFoundError,
     #[error("Failed to find header or footer rels.")]
     HeaderOrFooterRelsNotFoundError,
+    #[error("The document is encrypted.")]
+    PasswordRequiredError,
+    #[error("The password is incorrect.")]
+    InvalidPasswordError,
+    #[error("Failed to decrypt document.")]
+    DecryptionError,
     #[error("Unknown error")]
     Unknown,
 }
@@ -11,9 +11,12 @@ use crate::reader::*;
 impl ElementReader for FontScheme {
     fn read<R: Read>(
         r: &mut EventReader<R>,
-        _attrs: &[OwnedAttribute],
+        attrs: &[OwnedAttribute],
     ) -> Result<Self, ReaderError> {
         let mut fs = FontScheme::new();
+        if let Some(name) = read(attrs, "name") {
+            fs = fs.name(name);
+        }
         loop {
             let e = r.next();
             match e {
@@ -0,0 +1,124 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+use super::attributes::*;
+use crate::types::FontPitchType;
+
+impl FromXML for FontTable {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut font_table = FontTable::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name)
+                        .expect("should convert to XMLElement");
+                    if let XMLElement::Font = e {
+                        if let Some(font) = read_font(&mut r, &attributes)? {
+                            font_table = font_table.add_font(font);
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::Fonts {
+                        return Ok(font_table);
+                    }
+                }
+                Ok(XmlEvent::EndDocument { .. }) => {
+                    return Ok(font_table);
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+// Embedded fonts are not read, their parts are not kept.
+fn read_font<R: Read>(
+    r: &mut EventReader<R>,
+    attrs: &[OwnedAttribute],
+) -> Result<Option<Font>, ReaderError> {
+    let font_name = read_name(attrs);
+    let mut font = Font::new(
+        font_name.clone().unwrap_or_default(),
+        "00",
+        "auto",
+        FontPitchType::Default,
+    );
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                let val = read_val(&attributes);
+                match (e, val) {
+                    (XMLElement::Panose1, Some(v)) => font = font.panose(v),
+                    (XMLElement::Charset, Some(v)) => font.charset = v,
+                    (XMLElement::Family, Some(v)) => font.family = v,
+                    (XMLElement::Pitch, Some(v)) => {
+                        if let Ok(pitch) = FontPitchType::from_str(&v) {
+                            font.pitch = pitch;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                if e == XMLElement::Font {
+                    return Ok(font_name.map(|_| font));
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_font_table() {
+        let xml = r#"<w:fonts xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:font w:name="Corporate Sans">
+        <w:altName w:val="Arial"/>
+        <w:panose1 w:val="020B0604020202020204"/>
+        <w:charset w:val="80"/>
+        <w:family w:val="swiss"/>
+        <w:pitch w:val="variable"/>
+        <w:sig w:usb0="E0002AFF" w:usb1="C0007843" w:usb2="00000009" w:usb3="00000000" w:csb0="000001FF" w:csb1="00000000"/>
+    </w:font>
+    <w:font w:name="Courier New">
+        <w:charset w:val="00"/>
+        <w:pitch w:val="fixed"/>
+    </w:font>
+</w:fonts>"#;
+        let f = FontTable::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            f,
+            FontTable::new()
+                .add_font(
+                    Font::new("Corporate Sans", "80", "swiss", FontPitchType::Variable)
+                        .panose("020B0604020202020204")
+                )
+                .add_font(Font::new("Courier New", "00", "auto", FontPitchType::Fixed))
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl FromXML for Footnotes {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut footnotes = Footnotes::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name)
+                        .expect("should convert to XMLElement");
+                    if let XMLElement::Footnote = e {
+                        let footnote = read_footnote(&mut r, &attributes)?;
+                        // Separators have a type and are written by Word itself.
+                        if let (Some(f), None) = (footnote, read(&attributes, "type")) {
+                            footnotes.add(vec![f]);
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::Footnotes {
+                        return Ok(footnotes);
+                    }
+                }
+                Ok(XmlEvent::EndDocument { .. }) => {
+                    return Ok(footnotes);
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn read_footnote<R: Read>(
+    r: &mut EventReader<R>,
+    attrs: &[OwnedAttribute],
+) -> Result<Option<Footnote>, ReaderError> {
+    let id = read(attrs, "id").and_then(|id| usize::from_str(&id).ok());
+    let mut content = vec![];
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                let e =
+                    XMLElement::from_str(&name.local_name).expect("should convert to XMLElement");
+                if let XMLElement::Paragraph = e {
+                    content.push(Paragraph::read(r, &attributes)?);
+                }
+            }
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                if e == XMLElement::Footnote {
+                    return Ok(id.map(|id| Footnote { id, content }));
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_footnotes() {
+        let xml = r#"<w:footnotes xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:footnote w:type="separator" w:id="-1"><w:p><w:r><w:separator/></w:r></w:p></w:footnote>
+    <w:footnote w:id="1"><w:p><w:r><w:t>Note</w:t></w:r></w:p></w:footnote>
+</w:footnotes>"#;
+        let f = Footnotes::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(f.footnotes.len(), 1);
+        assert_eq!(f.footnotes[0].id, 1);
+        assert_eq!(f.footnotes[0].content[0].raw_text(), "Note");
+    }
+}
@@ -0,0 +1,216 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+use crate::types::TextInputType;
+
+impl ElementReader for FormFieldData {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        _attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut data = FormFieldData::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        XMLElement::Name => {
+                            if let Some(v) = read_val(&attributes) {
+                                data = data.name(v);
+                            }
+                        }
+                        XMLElement::Enabled => {
+                            data.enabled = read_bool(&attributes);
+                        }
+                        XMLElement::CalcOnExit => {
+                            data.calc_on_exit = read_bool(&attributes);
+                        }
+                        XMLElement::HelpText => {
+                            if let Some(v) = read_val(&attributes) {
+                                data = data.help_text(v);
+                            }
+                        }
+                        XMLElement::StatusText => {
+                            if let Some(v) = read_val(&attributes) {
+                                data = data.status_text(v);
+                            }
+                        }
+                        XMLElement::TextInput => {
+                            data = data.text_input(read_text_input(r)?);
+                        }
+                        XMLElement::CheckBox => {
+                            data = data.check_box(read_check_box(r)?);
+                        }
+                        XMLElement::DropDownList => {
+                            data = data.drop_down_list(read_drop_down_list(r)?);
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::FormFieldData {
+                        return Ok(data);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn read_text_input<R: Read>(r: &mut EventReader<R>) -> Result<TextInput, ReaderError> {
+    let mut t = TextInput::new();
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                attributes, name, ..
+            }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                let val = read_val(&attributes);
+                match (e, val) {
+                    (XMLElement::Type, Some(v)) => {
+                        if let Ok(ty) = TextInputType::from_str(&v) {
+                            t = t.input_type(ty);
+                        }
+                    }
+                    (XMLElement::Default, Some(v)) => t = t.default_value(v),
+                    (XMLElement::MaxLength, Some(v)) => {
+                        if let Ok(l) = usize::from_str(&v) {
+                            t = t.max_length(l);
+                        }
+                    }
+                    (XMLElement::Format, Some(v)) => t = t.format(v),
+                    _ => {}
+                }
+            }
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                if e == XMLElement::TextInput {
+                    return Ok(t);
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+fn read_check_box<R: Read>(r: &mut EventReader<R>) -> Result<CheckBox, ReaderError> {
+    let mut c = CheckBox::new();
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                attributes, name, ..
+            }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                match e {
+                    XMLElement::CheckBoxSize => {
+                        if let Some(Ok(s)) = read_val(&attributes).map(|v| usize::from_str(&v)) {
+                            c = c.size(s);
+                        }
+                    }
+                    XMLElement::Default => c = c.default_checked(read_bool(&attributes)),
+                    XMLElement::Checked => c = c.checked(read_bool(&attributes)),
+                    _ => {}
+                }
+            }
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                if e == XMLElement::CheckBox {
+                    return Ok(c);
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+fn read_drop_down_list<R: Read>(r: &mut EventReader<R>) -> Result<DropDownList, ReaderError> {
+    let mut d = DropDownList::new();
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                attributes, name, ..
+            }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                let val = read_val(&attributes);
+                match (e, val) {
+                    (XMLElement::DropDownResult, Some(v)) => {
+                        if let Ok(i) = usize::from_str(&v) {
+                            d = d.result(i);
+                        }
+                    }
+                    (XMLElement::Default, Some(v)) => {
+                        if let Ok(i) = usize::from_str(&v) {
+                            d = d.default_index(i);
+                        }
+                    }
+                    (XMLElement::ListEntry, Some(v)) => d = d.add_entry(v),
+                    _ => {}
+                }
+            }
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                if e == XMLElement::DropDownList {
+                    return Ok(d);
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_check_box() {
+        let c = r#"<w:ffData xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:name w:val="Check1"/><w:enabled/><w:calcOnExit w:val="0"/>
+  <w:checkBox><w:sizeAuto/><w:default w:val="0"/><w:checked/></w:checkBox>
+</w:ffData>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let data = FormFieldData::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            data,
+            FormFieldData::new()
+                .name("Check1")
+                .check_box(CheckBox::new().default_checked(false).checked(true))
+        );
+    }
+
+    #[test]
+    fn test_read_drop_down_list() {
+        let c = r#"<w:ffData xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:name w:val="Dropdown1"/><w:enabled w:val="0"/>
+  <w:ddList><w:result w:val="1"/><w:listEntry w:val="A"/><w:listEntry w:val="B"/></w:ddList>
+</w:ffData>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let data = FormFieldData::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            data,
+            FormFieldData::new()
+                .name("Dropdown1")
+                .disable()
+                .drop_down_list(DropDownList::new().result(1).add_entry("A").add_entry("B"))
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+use super::attributes::*;
+
+impl ElementReader for LatentStyles {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut latent_styles = LatentStyles::new();
+        for a in attrs {
+            let v = &a.value;
+            match a.name.local_name.as_str() {
+                "defLockedState" => latent_styles.def_locked_state = !is_false(v),
+                "defUIPriority" => {
+                    if let Ok(v) = v.parse() {
+                        latent_styles = latent_styles.def_ui_priority(v);
+                    }
+                }
+                "defSemiHidden" => latent_styles = latent_styles.def_semi_hidden(!is_false(v)),
+                "defUnhideWhenUsed" => {
+                    latent_styles = latent_styles.def_unhide_when_used(!is_false(v))
+                }
+                "defQFormat" => latent_styles = latent_styles.def_q_format(!is_false(v)),
+                "count" => {
+                    if let Ok(v) = v.parse() {
+                        latent_styles = latent_styles.count(v);
+                    }
+                }
+                _ => {}
+            }
+        }
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if let XMLElement::LatentStyleException = e {
+                        latent_styles = latent_styles.add_exception(read_exception(&attributes));
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if let XMLElement::LatentStyles = e {
+                        return Ok(latent_styles);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn read_exception(attrs: &[OwnedAttribute]) -> LatentStyleException {
+    let mut e = LatentStyleException::new(read_name(attrs).unwrap_or_default());
+    for a in attrs {
+        let v = &a.value;
+        match a.name.local_name.as_str() {
+            "uiPriority" => e.ui_priority = v.parse().ok(),
+            "semiHidden" => e.semi_hidden = Some(!is_false(v)),
+            "unhideWhenUsed" => e.unhide_when_used = Some(!is_false(v)),
+            "qFormat" => e.q_format = Some(!is_false(v)),
+            _ => {}
+        }
+    }
+    e
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_latent_styles() {
+        let xml = r#"<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:latentStyles w:defLockedState="0" w:defUIPriority="99" w:defSemiHidden="0" w:defUnhideWhenUsed="0" w:defQFormat="0" w:count="376">
+        <w:lsdException w:name="Normal" w:uiPriority="0" w:qFormat="1"/>
+        <w:lsdException w:name="heading 2" w:semiHidden="1" w:unhideWhenUsed="1" w:uiPriority="9" w:qFormat="1"/>
+    </w:latentStyles>
+</w:styles>"#;
+        let s = Styles::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            s,
+            Styles::new().latent_styles(
+                LatentStyles::new()
+                    .add_exception(
+                        LatentStyleException::new("Normal")
+                            .ui_priority(0)
+                            .q_format()
+                    )
+                    .add_exception(
+                        LatentStyleException::new("heading 2")
+                            .ui_priority(9)
+                            .semi_hidden()
+                            .unhide_when_used()
+                            .q_format()
+                    )
+            )
+        );
+    }
+}
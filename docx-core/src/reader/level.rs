@@ -29,6 +29,7 @@ impl ElementReader for Level {
         let mut has_indent = false;
         let mut suffix = LevelSuffixType::Tab;
         let mut is_lgl = None;
+        let mut pic_bullet_id = None;
 
         loop {
             let e = r.next();
@@ -66,6 +67,9 @@ impl ElementReader for Level {
                         XMLElement::IsLgl => {
                             is_lgl = Some(IsLgl::new());
                         }
+                        XMLElement::LevelPicBulletId => {
+                            pic_bullet_id = usize::from_str(&attributes[0].value).ok();
+                        }
                         XMLElement::LevelText => {
                             level_text = LevelText::new(attributes[0].value.clone());
                         }
@@ -103,6 +107,7 @@ impl ElementReader for Level {
                         l.run_property = rpr;
                         l.level_restart = level_restart;
                         l.is_lgl = is_lgl;
+                        l.pic_bullet_id = pic_bullet_id;
                         return Ok(l);
                     }
                 }
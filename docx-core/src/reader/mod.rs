@@ -1,13 +1,21 @@
 mod a_graphic;
 mod a_graphic_data;
+mod app_properties;
 mod attributes;
 mod bookmark_end;
 mod bookmark_start;
 mod cell_margins;
+mod cell_revision;
+mod color_scheme;
 mod comment;
 mod comment_extended;
+mod comment_extensible;
+mod comment_id;
 mod comments;
 mod comments_extended;
+mod comments_extensible;
+mod comments_ids;
+mod core_properties;
 mod custom_properties;
 mod delete;
 mod div;
@@ -19,7 +27,10 @@ mod drawing;
 mod errors;
 mod font_group;
 mod font_scheme;
+mod font_table;
 mod footer;
+mod footnotes;
+mod form_field_data;
 mod frame_property;
 mod from_xml;
 mod header;
@@ -27,16 +38,25 @@ mod header_or_footer_rels;
 mod hyperlink;
 mod ignore;
 mod insert;
+mod latent_styles;
 mod level;
 mod level_override;
 mod mc_fallback;
+mod move_from;
+mod move_range;
+mod move_to;
+mod num_pic_bullet;
 mod numbering_property;
 mod numberings;
 mod page_num_type;
 mod paragraph;
 mod paragraph_property;
 mod paragraph_property_change;
+mod people;
+mod perm;
+mod person;
 mod pic;
+mod property_change;
 mod read_zip;
 mod rels;
 mod run;
@@ -45,6 +65,7 @@ mod section_property;
 mod settings;
 mod shading;
 mod shape;
+mod signature;
 mod structured_data_tag;
 mod style;
 mod styles;
@@ -55,9 +76,13 @@ mod table_cell;
 mod table_cell_borders;
 mod table_cell_margins;
 mod table_cell_property;
-mod table_property;
+mod table_look;
 mod table_position_property;
+mod table_property;
+mod table_property_exceptions;
 mod table_row;
+mod table_row_property;
+mod table_style_property;
 mod tabs;
 mod text_box_content;
 mod theme;
@@ -67,7 +92,12 @@ mod wps_shape;
 mod wps_text_box;
 mod xml_element;
 
-use std::{collections::HashMap, io::Cursor, path::PathBuf};
+use std::{
+    collections::HashMap,
+    io::Cursor,
+    path::{Component, Path, PathBuf},
+    str::FromStr,
+};
 
 use crate::documents::*;
 
@@ -77,6 +107,7 @@ pub use errors::ReaderError;
 pub use from_xml::*;
 pub use mc_fallback::*;
 pub use read_zip::*;
+pub use signature::*;
 pub use xml_element::*;
 use zip::ZipArchive;
 
@@ -85,6 +116,10 @@ use self::header_or_footer_rels::{read_header_or_footer_rels, ReadHeaderOrFooter
 // 2006
 const DOC_RELATIONSHIP_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument";
+const CORE_PROPERTIES_TYPE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties";
+const APP_PROPERTIES_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties";
 const CUSTOM_PROPERTIES_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties";
 const STYLE_RELATIONSHIP_TYPE: &str =
@@ -95,6 +130,10 @@ const SETTINGS_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/settings";
 const COMMENTS_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments";
+const FONT_TABLE_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/fontTable";
+const FOOTNOTES_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footnotes";
 const WEB_SETTINGS_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/webSettings";
 const HEADER_TYPE: &str =
@@ -107,9 +146,37 @@ const IMAGE_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image";
 const HYPERLINK_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+const CUSTOM_XML_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXml";
+const CUSTOM_XML_PROPS_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXmlProps";
 // 2011
 const COMMENTS_EXTENDED_TYPE: &str =
     "http://schemas.microsoft.com/office/2011/relationships/commentsExtended";
+const PEOPLE_TYPE: &str = "http://schemas.microsoft.com/office/2011/relationships/people";
+// 2016
+const COMMENTS_IDS_TYPE: &str =
+    "http://schemas.microsoft.com/office/2016/09/relationships/commentsIds";
+// 2018
+const COMMENTS_EXTENSIBLE_TYPE: &str =
+    "http://schemas.microsoft.com/office/2018/08/relationships/commentsExtensible";
+
+// Read a part which may be missing, such as people.xml or footnotes.xml.
+fn read_optional_part<T: FromXML + Default>(
+    rels: &ReadDocumentRels,
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    rel_type: &str,
+) -> Result<T, ReaderError> {
+    let path = rels
+        .find_target_path(rel_type)
+        .and_then(|paths| paths.get(0).map(|(_, path, ..)| path.clone()));
+    if let Some(path) = path {
+        if let Ok(data) = read_zip(archive, path.to_str().expect("should have path.")) {
+            return T::from_xml(&data[..]);
+        }
+    }
+    Ok(T::default())
+}
 
 fn read_headers(
     rels: &ReadDocumentRels,
@@ -172,7 +239,55 @@ fn read_themes(rels: &ReadDocumentRels, archive: &mut ZipArchive<Cursor<&[u8]>>)
         .collect()
 }
 
+// Resolve "." and ".." in a part path such as "word/../customXml/item1.xml".
+fn normalize_part_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for c in path.components() {
+        match c {
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::CurDir => {}
+            c => normalized.push(c),
+        }
+    }
+    normalized
+}
+
+fn read_custom_items(
+    rels: &ReadDocumentRels,
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+) -> Vec<(String, CustomItem)> {
+    let item_paths = rels.find_target_path(CUSTOM_XML_TYPE);
+    item_paths
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(_rid, path, ..)| {
+            let path = normalize_part_path(&path);
+            let data = read_zip(archive, path.to_str()?).ok()?;
+            let item = CustomItem::from_str(std::str::from_utf8(&data).ok()?).ok()?;
+            // The store item id lives in the itemProps part related to the item.
+            let rels_path = rels::find_rels_filename(&path).ok()?;
+            let rels_data = read_zip(archive, rels_path.to_str()?).ok()?;
+            let item_rels = rels::read_rels_xml(&rels_data[..], path.parent()?).ok()?;
+            let (_, props_path, _) = item_rels.get(CUSTOM_XML_PROPS_TYPE)?.iter().next()?;
+            let props_data = read_zip(archive, normalize_part_path(props_path).to_str()?).ok()?;
+            let props = crate::XmlDocument::from_reader(&props_data[..], true).ok()?;
+            let id = props.find_by_xpath("/datastoreItem/@itemID")?;
+            Some((id.trim_matches(|c| c == '{' || c == '}').to_owned(), item))
+        })
+        .collect()
+}
+
+// Read a docx encrypted with ECMA-376 Agile Encryption.
+pub fn read_docx_with_password(buf: &[u8], password: &str) -> Result<Docx, ReaderError> {
+    read_docx(&crate::crypto::decrypt_package(buf, password)?)
+}
+
 pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
+    if crate::crypto::is_compound_file(buf) {
+        return Err(ReaderError::PasswordRequiredError);
+    }
     let mut docx = Docx::new();
     let cur = Cursor::new(buf);
     let mut archive = zip::ZipArchive::new(cur)?;
@@ -202,6 +317,22 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
         "word/document.xml".to_owned()
     };
 
+    if let Some(core_props) = rels.find_target(CORE_PROPERTIES_TYPE) {
+        if let Ok(data) = read_zip(&mut archive, &core_props.2) {
+            if let Ok(core) = CoreProps::from_xml(&data[..]) {
+                docx.doc_props.core = core;
+            }
+        }
+    }
+
+    if let Some(app_props) = rels.find_target(APP_PROPERTIES_TYPE) {
+        if let Ok(data) = read_zip(&mut archive, &app_props.2) {
+            if let Ok(app) = AppProps::from_xml(&data[..]) {
+                docx.doc_props.app = app;
+            }
+        }
+    }
+
     if let Some(custom_props) = rels.find_target(CUSTOM_PROPERTIES_TYPE) {
         let data = read_zip(&mut archive, &custom_props.2);
         if let Ok(data) = data {
@@ -217,6 +348,14 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     let footers = read_footers(&rels, &mut archive);
 
     docx.themes = read_themes(&rels, &mut archive);
+    docx.theme = docx.themes.first().cloned();
+
+    // Fonts used in the document but missing in fontTable.xml are added when it is built.
+    docx.font_table = read_optional_part(&rels, &mut archive, FONT_TABLE_TYPE)?;
+
+    for (id, item) in read_custom_items(&rels, &mut archive) {
+        docx = docx.custom_item(&id, item);
+    }
 
     // Read commentsExtended
     let comments_extended_path = rels.find_target_path(COMMENTS_EXTENDED_TYPE);
@@ -240,6 +379,11 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
         CommentsExtended::default()
     };
 
+    let comments_ids: CommentsIds = read_optional_part(&rels, &mut archive, COMMENTS_IDS_TYPE)?;
+    let comments_extensible: CommentsExtensible =
+        read_optional_part(&rels, &mut archive, COMMENTS_EXTENSIBLE_TYPE)?;
+    let people: People = read_optional_part(&rels, &mut archive, PEOPLE_TYPE)?;
+
     // Read comments
     let comments_path = rels.find_target_path(COMMENTS_TYPE);
     let comments = if let Some(paths) = comments_path {
@@ -262,6 +406,19 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
                         }
                         false
                     });
+                    let durable_id = comments_ids
+                        .children
+                        .iter()
+                        .find(|id| {
+                            c.children.iter().any(|child| {
+                                matches!(child, CommentChild::Paragraph(p) if p.id == id.paragraph_id)
+                            })
+                        })
+                        .map(|id| id.durable_id.clone());
+                    comments[i].durable_id = durable_id;
+                    if let Some(ex) = extended {
+                        comments[i].done = ex.done;
+                    }
                     if let Some(CommentExtended {
                         parent_paragraph_id: Some(parent_paragraph_id),
                         ..
@@ -298,6 +455,10 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     };
     docx = docx.document(document);
 
+    // Footnote content is kept on the references, as when building a document.
+    let footnotes: Footnotes = read_optional_part(&rels, &mut archive, FOOTNOTES_TYPE)?;
+    docx.store_footnotes(&footnotes);
+
     // assign headers
     if let Some(h) = docx.document.section_property.header_reference.clone() {
         if let Some((header, rels)) = headers.get(&h.id) {
@@ -392,7 +553,10 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
         docx.store_comments(comments.inner());
         docx = docx.comments(comments);
         docx = docx.comments_extended(comments_extended);
+        docx = docx.comments_ids(comments_ids);
+        docx = docx.comments_extensible(comments_extensible);
     }
+    docx = docx.people(people);
 
     // Read document relationships
     // Read styles
@@ -418,8 +582,30 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
             )?;
             let nums = Numberings::from_xml(&data[..])?;
             docx = docx.numberings(nums);
+            // Read media of picture bullets
+            if let Ok(rels) = read_header_or_footer_rels(&mut archive, num_path) {
+                let media = rels.find_target_path(IMAGE_TYPE);
+                // Keep the image in the bullet so that it is written back.
+                for bullet in docx.numberings.num_pic_bullets.iter_mut() {
+                    let path = media
+                        .iter()
+                        .flatten()
+                        .find(|(id, ..)| id == &bullet.pic.id)
+                        .map(|(_, path, ..)| path.to_str().expect("should have media"));
+                    if let Some(Ok(data)) = path.map(|path| read_zip(&mut archive, path)) {
+                        bullet.pic.image = data;
+                    }
+                }
+                for (id, path, ..) in media.into_iter().flatten() {
+                    let path = path.to_str().expect("should have media");
+                    if let Ok(data) = read_zip(&mut archive, path) {
+                        docx = docx.add_numbering_image(id, path, data);
+                    }
+                }
+            }
         }
     }
+    docx.numberings.resolve_num_style_links(&docx.styles);
 
     // Read settings
     let settings_path = rels.find_target_path(SETTINGS_TYPE);
@@ -0,0 +1,63 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::EventReader;
+
+use super::*;
+
+impl ElementReader for MoveFromRangeStart {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let id = read(attrs, "id").ok_or(ReaderError::XMLReadError)?;
+        let name = read(attrs, "name").unwrap_or_default();
+        let mut s = MoveFromRangeStart::new(usize::from_str(&id)?, name);
+        if let Some(author) = read(attrs, "author") {
+            s = s.author(author);
+        }
+        if let Some(date) = read(attrs, "date") {
+            s = s.date(date);
+        }
+        Ok(s)
+    }
+}
+
+impl ElementReader for MoveFromRangeEnd {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let id = read(attrs, "id").ok_or(ReaderError::XMLReadError)?;
+        Ok(MoveFromRangeEnd::new(usize::from_str(&id)?))
+    }
+}
+
+impl ElementReader for MoveToRangeStart {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let id = read(attrs, "id").ok_or(ReaderError::XMLReadError)?;
+        let name = read(attrs, "name").unwrap_or_default();
+        let mut s = MoveToRangeStart::new(usize::from_str(&id)?, name);
+        if let Some(author) = read(attrs, "author") {
+            s = s.author(author);
+        }
+        if let Some(date) = read(attrs, "date") {
+            s = s.date(date);
+        }
+        Ok(s)
+    }
+}
+
+impl ElementReader for MoveToRangeEnd {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let id = read(attrs, "id").ok_or(ReaderError::XMLReadError)?;
+        Ok(MoveToRangeEnd::new(usize::from_str(&id)?))
+    }
+}
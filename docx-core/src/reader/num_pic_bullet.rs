@@ -0,0 +1,73 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+const EMU_PER_PT: f32 = 12700.0;
+
+impl ElementReader for NumPicBullet {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let id = match read(attrs, "numPicBulletId") {
+            Some(id) => usize::from_str(&id)?,
+            None => 0,
+        };
+        let mut pic = Pic::with_empty();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => match VXMLElement::from_str(&name.local_name) {
+                    Ok(VXMLElement::Shape) => {
+                        if let Some(style) = read(&attributes, "style") {
+                            let (w, h) = read_shape_size(&style);
+                            pic = pic.size(w, h);
+                        }
+                    }
+                    Ok(VXMLElement::ImageData) => {
+                        if let Some(id) = read(&attributes, "id") {
+                            pic = pic.id(id);
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::NumPicBullet {
+                        return Ok(NumPicBullet::new(id, pic));
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+// "width:9pt;height:9pt" in emu
+fn read_shape_size(style: &str) -> (u32, u32) {
+    let mut size = (0, 0);
+    for declaration in style.split(';') {
+        let mut kv = declaration.splitn(2, ':');
+        let (key, value) = match (kv.next(), kv.next()) {
+            (Some(k), Some(v)) => (k.trim(), v.trim()),
+            _ => continue,
+        };
+        let emu = match value.trim_end_matches("pt").parse::<f32>() {
+            Ok(pt) => (pt * EMU_PER_PT) as u32,
+            Err(_) => continue,
+        };
+        match key {
+            "width" => size.0 = emu,
+            "height" => size.1 = emu,
+            _ => {}
+        }
+    }
+    size
+}
@@ -61,6 +61,12 @@ impl FromXML for Numberings {
                             }
                             continue;
                         }
+                        XMLElement::NumPicBullet => {
+                            if let Ok(b) = NumPicBullet::read(&mut parser, &attributes) {
+                                nums = nums.add_num_pic_bullet(b);
+                            }
+                            continue;
+                        }
                         XMLElement::Num => {
                             let mut id = 0;
                             for a in attributes {
@@ -247,4 +253,67 @@ mod tests {
             .add_numbering(num);
         assert_eq!(n, nums)
     }
+
+    #[test]
+    fn test_numberings_from_xml_with_pic_bullet() {
+        let xml = r##"<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+            xmlns:v="urn:schemas-microsoft-com:vml" xmlns:o="urn:schemas-microsoft-com:office:office"
+            xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+    <w:numPicBullet w:numPicBulletId="0">
+        <w:pict>
+            <v:shape id="_x0000_i1025" type="#_x0000_t75" style="width:9pt;height:4.5pt" o:bullet="t">
+                <v:imagedata r:id="rId1" o:title="mso"/>
+            </v:shape>
+        </w:pict>
+    </w:numPicBullet>
+    <w:abstractNum w:abstractNumId="0">
+        <w:lvl w:ilvl="0">
+            <w:start w:val="1"/>
+            <w:numFmt w:val="bullet"/>
+            <w:lvlText w:val=""/>
+            <w:lvlPicBulletId w:val="0"/>
+            <w:lvlJc w:val="left"/>
+        </w:lvl>
+    </w:abstractNum>
+    <w:num w:numId="1">
+        <w:abstractNumId w:val="0"></w:abstractNumId>
+    </w:num>
+</w:numbering>"##;
+        let n = Numberings::from_xml(xml.as_bytes()).unwrap();
+        let pic = Pic::with_empty().id("rId1").size(114300, 57150);
+        assert_eq!(n.num_pic_bullets, vec![NumPicBullet::new(0, pic)]);
+        assert_eq!(n.abstract_nums[0].levels[0].pic_bullet_id, Some(0));
+    }
+
+    #[test]
+    fn test_resolve_num_style_links() {
+        let xml = r#"<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:abstractNum w:abstractNumId="0">
+        <w:styleLink w:val="ListStyle"/>
+        <w:lvl w:ilvl="0">
+            <w:start w:val="3"/>
+            <w:numFmt w:val="upperLetter"/>
+            <w:lvlText w:val="%1)"/>
+            <w:lvlJc w:val="left"/>
+        </w:lvl>
+    </w:abstractNum>
+    <w:abstractNum w:abstractNumId="1">
+        <w:numStyleLink w:val="ListStyle"/>
+    </w:abstractNum>
+    <w:num w:numId="1">
+        <w:abstractNumId w:val="0"/>
+    </w:num>
+    <w:num w:numId="2">
+        <w:abstractNumId w:val="1"/>
+    </w:num>
+</w:numbering>"#;
+        let mut n = Numberings::from_xml(xml.as_bytes()).unwrap();
+        let mut style = Style::new("ListStyle", StyleType::Numbering);
+        style.paragraph_property =
+            ParagraphProperty::new().numbering(NumberingId::new(1), IndentLevel::new(0));
+        let styles = Styles::new().add_style(style);
+        n.resolve_num_style_links(&styles);
+        assert_eq!(n.abstract_nums[1].levels, n.abstract_nums[0].levels);
+        assert_eq!(n.find_level(2, 0).unwrap().text, LevelText::new("%1)"));
+    }
 }
@@ -46,6 +46,12 @@ impl ElementReader for Paragraph {
                             p = p.add_delete(del);
                             continue;
                         }
+                        XMLElement::StructuredDataTag => {
+                            if let Ok(tag) = StructuredDataTag::read(r, &attributes) {
+                                p = p.add_structured_data_tag(tag);
+                            }
+                            continue;
+                        }
                         XMLElement::BookmarkStart => {
                             let s = BookmarkStart::read(r, &attributes)?;
                             p = p.add_bookmark_start(s.id, s.name);
@@ -56,6 +62,46 @@ impl ElementReader for Paragraph {
                             p = p.add_bookmark_end(e.id);
                             continue;
                         }
+                        XMLElement::PermStart => {
+                            let s = PermStart::read(r, &attributes)?;
+                            p = p.add_perm_start(s);
+                            continue;
+                        }
+                        XMLElement::PermEnd => {
+                            let e = PermEnd::read(r, &attributes)?;
+                            p = p.add_perm_end(e.id);
+                            continue;
+                        }
+                        XMLElement::MoveFrom => {
+                            let m = MoveFrom::read(r, &attributes)?;
+                            p = p.add_move_from(m);
+                            continue;
+                        }
+                        XMLElement::MoveTo => {
+                            let m = MoveTo::read(r, &attributes)?;
+                            p = p.add_move_to(m);
+                            continue;
+                        }
+                        XMLElement::MoveFromRangeStart => {
+                            let s = MoveFromRangeStart::read(r, &attributes)?;
+                            p = p.add_move_from_range_start(s);
+                            continue;
+                        }
+                        XMLElement::MoveFromRangeEnd => {
+                            let e = MoveFromRangeEnd::read(r, &attributes)?;
+                            p = p.add_move_from_range_end(e.id);
+                            continue;
+                        }
+                        XMLElement::MoveToRangeStart => {
+                            let s = MoveToRangeStart::read(r, &attributes)?;
+                            p = p.add_move_to_range_start(s);
+                            continue;
+                        }
+                        XMLElement::MoveToRangeEnd => {
+                            let e = MoveToRangeEnd::read(r, &attributes)?;
+                            p = p.add_move_to_range_end(e.id);
+                            continue;
+                        }
                         XMLElement::CommentRangeStart => {
                             if let Some(id) = read(&attributes, "id") {
                                 if let Ok(id) = usize::from_str(&id) {
@@ -324,6 +370,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_move() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:p>
+        <w:moveFromRangeStart w:id="1" w:name="move1" w:author="unknown" w:date="2019-11-15T14:19:04Z"/>
+        <w:moveFrom w:id="2" w:author="unknown" w:date="2019-11-15T14:19:04Z">
+            <w:r>
+                <w:rPr></w:rPr>
+                <w:delText xml:space="preserve">Hello</w:delText>
+            </w:r>
+        </w:moveFrom>
+        <w:moveFromRangeEnd w:id="1"/>
+        <w:moveToRangeStart w:id="3" w:name="move1" w:author="unknown" w:date="2019-11-15T14:19:04Z"/>
+        <w:moveTo w:id="4" w:author="unknown" w:date="2019-11-15T14:19:04Z">
+            <w:r>
+                <w:rPr>
+                    <w:b/>
+                    <w:rPrChange w:id="5" w:author="unknown" w:date="2019-11-15T14:19:04Z">
+                        <w:rPr><w:i/></w:rPr>
+                    </w:rPrChange>
+                </w:rPr>
+                <w:t>Hello</w:t>
+            </w:r>
+        </w:moveTo>
+        <w:moveToRangeEnd w:id="3"/>
+    </w:p>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let p = Paragraph::read(&mut parser, &[]).unwrap();
+        let date = "2019-11-15T14:19:04Z";
+        assert_eq!(
+            p.children,
+            vec![
+                ParagraphChild::MoveFromRangeStart(
+                    MoveFromRangeStart::new(1, "move1")
+                        .author("unknown")
+                        .date(date)
+                ),
+                ParagraphChild::MoveFrom(
+                    MoveFrom::new()
+                        .add_run(Run::new().add_delete_text("Hello"))
+                        .author("unknown")
+                        .date(date)
+                ),
+                ParagraphChild::MoveFromRangeEnd(MoveFromRangeEnd::new(1)),
+                ParagraphChild::MoveToRangeStart(
+                    MoveToRangeStart::new(3, "move1")
+                        .author("unknown")
+                        .date(date)
+                ),
+                ParagraphChild::MoveTo(
+                    MoveTo::new()
+                        .add_run(
+                            Run::new().add_text("Hello").bold().run_property_change(
+                                RunPropertyChange::new()
+                                    .property(RunProperty::new().italic())
+                                    .author("unknown")
+                                    .date(date)
+                            )
+                        )
+                        .author("unknown")
+                        .date(date)
+                ),
+                ParagraphChild::MoveToRangeEnd(MoveToRangeEnd::new(3)),
+            ]
+        );
+    }
+
     #[test]
     fn test_read_bookmark() {
         let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
@@ -0,0 +1,61 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl FromXML for People {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut people = People::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name)
+                        .expect("should convert to XMLElement");
+                    if let XMLElement::Person = e {
+                        if let Ok(p) = Person::read(&mut r, &attributes) {
+                            people = people.add_person(p);
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::People {
+                        return Ok(people);
+                    }
+                }
+                Ok(XmlEvent::EndDocument { .. }) => return Ok(people),
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_people() {
+        let xml = r#"<w15:people xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml">
+  <w15:person w15:author="bokuweb"><w15:presenceInfo w15:providerId="None" w15:userId="bokuweb"/></w15:person>
+  <w15:person w15:author="Tanaka"/>
+</w15:people>"#;
+        let p = People::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            p,
+            People::new()
+                .add_person(Person::new("bokuweb").presence_info("None", "bokuweb"))
+                .add_person(Person::new("Tanaka"))
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::EventReader;
+
+use super::*;
+use crate::types::EditGroupType;
+
+impl ElementReader for PermStart {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let id = read(attrs, "id").ok_or(ReaderError::XMLReadError)?;
+        let mut p = PermStart::new(usize::from_str(&id)?);
+        if let Some(ed) = read(attrs, "ed") {
+            p = p.ed(ed);
+        }
+        if let Some(Ok(g)) = read(attrs, "edGrp").map(|g| EditGroupType::from_str(&g)) {
+            p = p.ed_grp(g);
+        }
+        if let Some(Ok(c)) = read(attrs, "colFirst").map(|c| usize::from_str(&c)) {
+            p = p.col_first(c);
+        }
+        if let Some(Ok(c)) = read(attrs, "colLast").map(|c| usize::from_str(&c)) {
+            p = p.col_last(c);
+        }
+        Ok(p)
+    }
+}
+
+impl ElementReader for PermEnd {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let id = read(attrs, "id").ok_or(ReaderError::XMLReadError)?;
+        Ok(PermEnd::new(usize::from_str(&id)?))
+    }
+}
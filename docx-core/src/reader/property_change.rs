@@ -0,0 +1,94 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+// Reads the previous properties of a `w:rPrChange`, `w:tblPrChange`, `w:trPrChange`,
+// `w:tcPrChange` or `w:sectPrChange` element.
+fn read_previous_property<R: Read, P: ElementReader>(
+    r: &mut EventReader<R>,
+    property: XMLElement,
+    change: XMLElement,
+) -> Result<Option<P>, ReaderError> {
+    let mut p = None;
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                if e == property {
+                    p = Some(P::read(r, &attributes)?);
+                }
+            }
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                if e == change {
+                    return Ok(p);
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+macro_rules! impl_property_change_reader {
+    ($change: ident, $property: ident, $change_el: expr, $property_el: expr) => {
+        impl ElementReader for $change {
+            fn read<R: Read>(
+                r: &mut EventReader<R>,
+                attrs: &[OwnedAttribute],
+            ) -> Result<Self, ReaderError> {
+                let mut c = $change::new();
+                if let Some(author) = read(attrs, "author") {
+                    c = c.author(author);
+                }
+                if let Some(date) = read(attrs, "date") {
+                    c = c.date(date);
+                }
+                if let Some(p) =
+                    read_previous_property::<R, $property>(r, $property_el, $change_el)?
+                {
+                    c = c.property(p);
+                }
+                Ok(c)
+            }
+        }
+    };
+}
+
+impl_property_change_reader!(
+    RunPropertyChange,
+    RunProperty,
+    XMLElement::RunPropertyChange,
+    XMLElement::RunProperty
+);
+impl_property_change_reader!(
+    TablePropertyChange,
+    TableProperty,
+    XMLElement::TablePropertyChange,
+    XMLElement::TableProperty
+);
+impl_property_change_reader!(
+    TableRowPropertyChange,
+    TableRowProperty,
+    XMLElement::TableRowPropertyChange,
+    XMLElement::TableRowProperty
+);
+impl_property_change_reader!(
+    TableCellPropertyChange,
+    TableCellProperty,
+    XMLElement::TableCellPropertyChange,
+    XMLElement::TableCellProperty
+);
+impl_property_change_reader!(
+    SectionPropertyChange,
+    SectionProperty,
+    XMLElement::SectionPropertyChange,
+    XMLElement::SectionProperty
+);
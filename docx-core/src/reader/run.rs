@@ -65,8 +65,6 @@ impl ElementReader for Run {
                         Some("w") => {
                             let e = XMLElement::from_str(&name.local_name).unwrap();
 
-                            ignore::ignore_element(e.clone(), XMLElement::RunPropertyChange, r);
-
                             match e {
                                 XMLElement::Tab => {
                                     run = run.add_tab();
@@ -97,11 +95,26 @@ impl ElementReader for Run {
                                         run = run.add_drawing(drawing);
                                     }
                                 }
+                                XMLElement::FootnoteReference => {
+                                    if let Some(Ok(id)) =
+                                        read(&attributes, "id").map(|id| usize::from_str(&id))
+                                    {
+                                        run.children.push(RunChild::FootnoteReference(
+                                            FootnoteReference::new(id),
+                                        ));
+                                    }
+                                }
                                 XMLElement::FieldChar => {
                                     if let Ok(f) = read_field_char(&attributes) {
                                         run.children.push(RunChild::FieldChar(f));
                                     }
                                 }
+                                XMLElement::FormFieldData => {
+                                    let d = FormFieldData::read(r, &attributes)?;
+                                    if let Some(RunChild::FieldChar(f)) = run.children.last_mut() {
+                                        f.form_field_data = Some(d);
+                                    }
+                                }
                                 XMLElement::InstrText => loop {
                                     let e = r.next();
                                     match e {
@@ -309,4 +322,26 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_read_form_field_data() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:r><w:fldChar w:fldCharType="begin"><w:ffData><w:name w:val="Text1"/><w:enabled/><w:textInput><w:maxLength w:val="5"/></w:textInput></w:ffData></w:fldChar></w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            run,
+            Run {
+                children: vec![RunChild::FieldChar(
+                    FieldChar::new(FieldCharType::Begin).form_field_data(
+                        FormFieldData::new()
+                            .name("Text1")
+                            .text_input(TextInput::new().max_length(5))
+                    )
+                )],
+                run_property: RunProperty::default(),
+            }
+        );
+    }
 }
@@ -92,7 +92,17 @@ impl ElementReader for RunProperty {
                                 rp = rp.vert_align(v)
                             }
                         }
-                        XMLElement::Color => rp = rp.color(attributes[0].value.clone()),
+                        XMLElement::Color => {
+                            if let Some(v) = read_val(&attributes) {
+                                rp = rp.color(v);
+                            }
+                            if let Some(theme) = read_theme_color(
+                                &attributes,
+                                ["themeColor", "themeTint", "themeShade"],
+                            ) {
+                                rp = rp.theme_color(theme);
+                            }
+                        }
                         XMLElement::Size => {
                             rp = rp.size(f64::from_str(&attributes[0].value)? as usize)
                         }
@@ -118,6 +128,11 @@ impl ElementReader for RunProperty {
                         }
                         XMLElement::Vanish => rp = rp.vanish(),
                         XMLElement::SpecVanish => rp = rp.spec_vanish(),
+                        XMLElement::Shading => {
+                            if let Ok(shd) = Shading::read(r, &attributes) {
+                                rp = rp.shading(shd);
+                            }
+                        }
                         XMLElement::TextBorder => {
                             if let Ok(attr) = read_border(&attributes) {
                                 let mut border = TextBorder::new()
@@ -126,6 +141,9 @@ impl ElementReader for RunProperty {
                                 if let Some(size) = attr.size {
                                     border = border.size(size as usize);
                                 };
+                                if let Some(theme) = attr.theme_color {
+                                    border = border.theme_color(theme);
+                                }
                                 rp = rp.text_border(border);
                                 continue;
                             }
@@ -140,6 +158,11 @@ impl ElementReader for RunProperty {
                                 rp = rp.delete(del);
                             }
                         }
+                        XMLElement::RunPropertyChange => {
+                            if let Ok(c) = RunPropertyChange::read(r, &attributes) {
+                                rp = rp.run_property_change(c);
+                            }
+                        }
                         _ => {}
                     }
                 }
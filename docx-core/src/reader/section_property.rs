@@ -161,6 +161,11 @@ impl ElementReader for SectionProperty {
                             }
                         }
                         XMLElement::TitlePg => sp = sp.title_pg(),
+                        XMLElement::SectionPropertyChange => {
+                            if let Ok(c) = SectionPropertyChange::read(r, &attributes) {
+                                sp = sp.section_property_change(c);
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -1,15 +1,46 @@
 use std::io::Read;
 use std::str::FromStr;
+use xml::attribute::OwnedAttribute;
 use xml::reader::{EventReader, XmlEvent};
 
 use super::*;
 use crate::reader::{FromXML, ReaderError};
-use crate::types::CharacterSpacingValues;
+use crate::types::{CharacterSpacingValues, DocumentProtectionType, ProofStateType};
+
+// Reads both `w:hashValue` style and legacy `w:hash` style attributes.
+fn read_password_hash(attrs: &[OwnedAttribute]) -> Option<PasswordHash> {
+    let hash_value = read(attrs, "hashValue").or_else(|| read(attrs, "hash"))?;
+    let salt_value = read(attrs, "saltValue").or_else(|| read(attrs, "salt"))?;
+    let spin_count = read(attrs, "spinCount")
+        .or_else(|| read(attrs, "cryptSpinCount"))
+        .and_then(|c| u32::from_str(&c).ok())
+        .unwrap_or(0);
+    let algorithm_name = match read(attrs, "algorithmName") {
+        Some(name) => name,
+        None => match read(attrs, "cryptAlgorithmSid").as_deref() {
+            Some("4") => "SHA-1".to_owned(),
+            Some("12") => "SHA-256".to_owned(),
+            Some("13") => "SHA-384".to_owned(),
+            Some("14") => "SHA-512".to_owned(),
+            _ => return None,
+        },
+    };
+    Some(PasswordHash {
+        algorithm_name,
+        hash_value,
+        salt_value,
+        spin_count,
+    })
+}
+
+fn read_usize(attrs: &[OwnedAttribute], target: &str) -> Option<usize> {
+    read(attrs, target).and_then(|v| f32::from_str(&v).ok().map(|v| v as usize))
+}
 
 impl FromXML for Settings {
     fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
         let mut parser = EventReader::new(reader);
-        let mut settings = Self::default();
+        let mut settings = Self::default().clear_compatibility_mode();
 
         loop {
             let e = parser.next();
@@ -58,6 +89,48 @@ impl FromXML for Settings {
                         XMLElement::AdjustLineHeightInTable => {
                             settings = settings.adjust_line_height_in_table();
                         }
+                        XMLElement::TrackRevisions => {
+                            if read_bool(&attributes) {
+                                settings = settings.track_revisions();
+                            }
+                        }
+                        XMLElement::EmbedTrueTypeFonts => {
+                            if read_bool(&attributes) {
+                                settings = settings.embed_true_type_fonts();
+                            }
+                        }
+                        XMLElement::SaveSubsetFonts => {
+                            if read_bool(&attributes) {
+                                settings = settings.save_subset_fonts();
+                            }
+                        }
+                        XMLElement::DocumentProtection => {
+                            if let Some(edit) = read(&attributes, "edit") {
+                                if let Ok(edit) = DocumentProtectionType::from_str(&edit) {
+                                    let enforcement = read(&attributes, "enforcement")
+                                        .map(|v| !is_false(&v))
+                                        .unwrap_or(false);
+                                    let mut p =
+                                        DocumentProtection::new(edit).enforcement(enforcement);
+                                    if let Some(h) = read_password_hash(&attributes) {
+                                        p = p.password_hash(h);
+                                    }
+                                    settings = settings.document_protection(p);
+                                }
+                            }
+                        }
+                        XMLElement::WriteProtection => {
+                            let mut p = WriteProtection::new();
+                            if let Some(v) = read(&attributes, "recommended") {
+                                if !is_false(&v) {
+                                    p = p.recommended();
+                                }
+                            }
+                            if let Some(h) = read_password_hash(&attributes) {
+                                p = p.password_hash(h);
+                            }
+                            settings = settings.write_protection(p);
+                        }
                         XMLElement::CharacterSpacingControl => {
                             let val = read_val(&attributes);
                             if let Some(val) = val {
@@ -66,6 +139,91 @@ impl FromXML for Settings {
                                 }
                             }
                         }
+                        XMLElement::Zoom => {
+                            if let Some(v) = read_usize(&attributes, "percent") {
+                                settings = settings.zoom(v);
+                            }
+                        }
+                        XMLElement::UpdateFields => {
+                            if read_bool(&attributes) {
+                                settings = settings.update_fields();
+                            }
+                        }
+                        XMLElement::MirrorMargins => {
+                            if read_bool(&attributes) {
+                                settings = settings.mirror_margins();
+                            }
+                        }
+                        XMLElement::GutterAtTop => {
+                            if read_bool(&attributes) {
+                                settings = settings.gutter_at_top();
+                            }
+                        }
+                        XMLElement::BookFoldPrinting => {
+                            if read_bool(&attributes) {
+                                settings = settings.book_fold_printing();
+                            }
+                        }
+                        XMLElement::DisplayBackgroundShape => {
+                            if read_bool(&attributes) {
+                                settings = settings.display_background_shape();
+                            }
+                        }
+                        XMLElement::AutoHyphenation => {
+                            if read_bool(&attributes) {
+                                settings = settings.auto_hyphenation();
+                            }
+                        }
+                        XMLElement::HyphenationZone => {
+                            if let Some(v) = read_usize(&attributes, "val") {
+                                settings = settings.hyphenation_zone(v);
+                            }
+                        }
+                        XMLElement::ConsecutiveHyphenLimit => {
+                            if let Some(v) = read_usize(&attributes, "val") {
+                                settings = settings.consecutive_hyphen_limit(v);
+                            }
+                        }
+                        XMLElement::CompatSetting => {
+                            if read_name(&attributes).as_deref() == Some("compatibilityMode") {
+                                if let Some(v) = read_usize(&attributes, "val") {
+                                    settings = settings.compatibility_mode(v);
+                                }
+                            }
+                        }
+                        XMLElement::ThemeFontLang => {
+                            let lang = ThemeFontLang {
+                                val: read_val(&attributes),
+                                east_asia: read(&attributes, "eastAsia"),
+                                bidi: read(&attributes, "bidi"),
+                            };
+                            settings = settings.theme_font_lang(lang);
+                        }
+                        XMLElement::ProofState => {
+                            let state = |name| {
+                                read(&attributes, name)
+                                    .and_then(|v| ProofStateType::from_str(&v).ok())
+                            };
+                            let mut p = ProofState::new();
+                            p.spelling = state("spelling");
+                            p.grammar = state("grammar");
+                            settings = settings.proof_state(p);
+                        }
+                        XMLElement::RsidRoot => {
+                            if let Some(v) = read_val(&attributes) {
+                                settings = settings.rsid_root(v);
+                            }
+                        }
+                        XMLElement::Rsid => {
+                            if let Some(v) = read_val(&attributes) {
+                                settings = settings.add_rsid(v);
+                            }
+                        }
+                        XMLElement::DefaultTableStyle => {
+                            if let Some(v) = read_val(&attributes) {
+                                settings = settings.default_table_style(v);
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -83,3 +241,30 @@ impl FromXML for Settings {
         Ok(settings)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_settings_without_compatibility_mode() {
+        let xml = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:zoom w:percent="100"/>
+    <w:defaultTabStop w:val="720"/>
+</w:settings>"#;
+        let s = Settings::from_xml(xml.as_bytes()).unwrap();
+        let b = String::from_utf8(s.build()).unwrap();
+        assert!(!b.contains("compatibilityMode"));
+
+        let xml = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:compat>
+        <w:compatSetting w:name="compatibilityMode" w:uri="http://schemas.microsoft.com/office/word" w:val="14"/>
+    </w:compat>
+</w:settings>"#;
+        let s = Settings::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(s, Settings::new().compatibility_mode(14));
+    }
+}
@@ -13,6 +13,14 @@ impl ElementReader for Shading {
         attrs: &[OwnedAttribute],
     ) -> Result<Self, ReaderError> {
         let mut shd = Shading::new();
+        if let Some(theme) = read_theme_color(attrs, ["themeColor", "themeTint", "themeShade"]) {
+            shd = shd.theme_color(theme);
+        }
+        if let Some(theme) =
+            read_theme_color(attrs, ["themeFill", "themeFillTint", "themeFillShade"])
+        {
+            shd = shd.theme_fill(theme);
+        }
         for a in attrs {
             let local_name = &a.name.local_name;
             if local_name == "val" {
@@ -0,0 +1,304 @@
+use std::io::Cursor;
+use std::path::Path;
+
+use serde::Serialize;
+use xml::reader::{EventReader, XmlEvent};
+use zip::ZipArchive;
+
+use super::*;
+use crate::crypto::{self, DigestMethod, RELATIONSHIP_TRANSFORM_URI};
+
+// A package signature and the parts it covers.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageSignature {
+    // i.e. /_xmlsignatures/sig1.xml
+    pub path: String,
+    // DER encoded X.509 certificate of the signer.
+    pub certificate: Vec<u8>,
+    pub signing_time: Option<String>,
+    // i.e. /word/document.xml
+    pub signed_parts: Vec<String>,
+    // true when every digest and the signature value itself are correct.
+    pub valid: bool,
+}
+
+const OBJECT_REFERENCE_TYPE: &str = "http://www.w3.org/2000/09/xmldsig#Object";
+
+#[derive(Debug, Default)]
+struct Reference {
+    uri: String,
+    reference_type: String,
+    // Id of the Object element a Manifest reference lives in.
+    object_id: Option<String>,
+    transforms: Vec<String>,
+    source_ids: Vec<String>,
+    source_types: Vec<String>,
+    digest_method: String,
+    digest_value: String,
+}
+
+#[derive(Debug, Default)]
+struct SignatureXml {
+    // SignedInfo elements which are direct children of Signature.
+    signed_info_count: usize,
+    // SignedInfo elements found anywhere else.
+    misplaced_signed_info: bool,
+    duplicate_ids: bool,
+    signed_info_references: Vec<Reference>,
+    manifest_references: Vec<Reference>,
+    signature_method: String,
+    signature_value: String,
+    certificate: Option<String>,
+    signing_time: Option<(Option<String>, String)>,
+}
+
+// Verify the package signatures of a docx.
+pub fn verify_signatures(buf: &[u8]) -> Result<Vec<PackageSignature>, ReaderError> {
+    let cur = Cursor::new(buf);
+    let mut archive = ZipArchive::new(cur)?;
+    let content_types = {
+        let data = read_zip(&mut archive, "[Content_Types].xml")?;
+        ContentTypes::from_xml(&data[..])?
+    };
+    let rels = {
+        let data = read_zip(&mut archive, "_rels/.rels")?;
+        rels::read_rels_xml(&data[..], "")?
+    };
+    let origin = match rels
+        .get(DIGITAL_SIGNATURE_ORIGIN_TYPE)
+        .and_then(|r| r.iter().next())
+    {
+        Some((_, path, _)) => normalize_part_path(path),
+        None => return Ok(vec![]),
+    };
+    let origin_dir = origin.parent().unwrap_or_else(|| Path::new(""));
+    let origin_rels = {
+        let name = origin
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        let path = origin_dir.join("_rels").join(format!("{}.rels", name));
+        let data = read_zip(&mut archive, path.to_str().unwrap_or_default())?;
+        rels::read_rels_xml(&data[..], origin_dir)?
+    };
+
+    let mut signatures = vec![];
+    for (_, path, _) in origin_rels
+        .get(DIGITAL_SIGNATURE_TYPE)
+        .cloned()
+        .unwrap_or_default()
+    {
+        let path = normalize_part_path(&path);
+        let path = path.to_str().unwrap_or_default();
+        let xml = read_zip(&mut archive, path)?;
+        signatures.push(verify_signature(
+            &mut archive,
+            &content_types,
+            format!("/{}", path.trim_start_matches('/')),
+            &xml,
+        )?);
+    }
+    Ok(signatures)
+}
+
+fn verify_signature(
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    content_types: &ContentTypes,
+    path: String,
+    xml: &[u8],
+) -> Result<PackageSignature, ReaderError> {
+    let sig = read_signature_xml(xml)?;
+    let certificate = sig
+        .certificate
+        .as_ref()
+        .and_then(|c| decode_base64(c))
+        .unwrap_or_default();
+    let mut valid = !sig.signed_info_references.is_empty()
+        && sig.signed_info_count == 1
+        && !sig.misplaced_signed_info
+        && !sig.duplicate_ids;
+
+    // Only the Objects referenced from SignedInfo are covered by the signature value.
+    let signed_objects: Vec<&str> = sig
+        .signed_info_references
+        .iter()
+        .filter(|r| r.reference_type == OBJECT_REFERENCE_TYPE)
+        .map(|r| r.uri.trim_start_matches('#'))
+        .collect();
+    let is_signed = |object_id: &Option<String>| matches!(object_id, Some(id) if signed_objects.contains(&id.as_str()));
+
+    for r in sig.signed_info_references.iter() {
+        let id = r.uri.trim_start_matches('#');
+        let data = crypto::canonicalize(xml, None, Some(id));
+        valid &= match data {
+            Some(data) => digest_matches(r, &data),
+            None => false,
+        };
+    }
+
+    let mut signed_parts = vec![];
+    for r in sig.manifest_references.iter() {
+        if !is_signed(&r.object_id) {
+            valid = false;
+            continue;
+        }
+        let mut uri = r.uri.splitn(2, '?');
+        let part_name = uri.next().unwrap_or_default().to_owned();
+        let content_type = uri
+            .next()
+            .and_then(|q| q.strip_prefix("ContentType="))
+            .unwrap_or_default();
+        if content_types.content_type(&part_name) != Some(content_type) {
+            valid = false;
+        }
+        let data = match read_zip(archive, &part_name) {
+            Ok(data) => data,
+            Err(_) => {
+                valid = false;
+                continue;
+            }
+        };
+        let data = if r.transforms.iter().any(|t| t == RELATIONSHIP_TRANSFORM_URI) {
+            crypto::transform_relationships(&data, &r.source_ids, &r.source_types)
+        } else {
+            Some(data)
+        };
+        valid &= data.map(|d| digest_matches(r, &d)).unwrap_or_default();
+        signed_parts.push(part_name);
+    }
+
+    let signed_info = crypto::canonicalize(xml, Some("SignedInfo"), None);
+    valid &= match (signed_info, decode_base64(&sig.signature_value)) {
+        (Some(data), Some(value)) => {
+            crypto::verify_rsa(&certificate, &sig.signature_method, &data, &value)
+        }
+        _ => false,
+    };
+
+    Ok(PackageSignature {
+        path,
+        certificate,
+        signing_time: sig
+            .signing_time
+            .filter(|(object_id, _)| is_signed(object_id))
+            .map(|(_, time)| time),
+        signed_parts,
+        valid,
+    })
+}
+
+fn digest_matches(r: &Reference, data: &[u8]) -> bool {
+    match (
+        DigestMethod::from_uri(&r.digest_method),
+        decode_base64(&r.digest_value),
+    ) {
+        (Some(method), Some(expected)) => method.digest(data) == expected,
+        _ => false,
+    }
+}
+
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    base64::decode(s).ok()
+}
+
+fn read_signature_xml(xml: &[u8]) -> Result<SignatureXml, ReaderError> {
+    let mut sig = SignatureXml::default();
+    // Local names of the open elements.
+    let mut path: Vec<String> = vec![];
+    let mut ids: Vec<String> = vec![];
+    let mut object_id: Option<String> = None;
+    let mut in_signed_info = false;
+    let mut in_signature_time = false;
+    let mut reference: Option<Reference> = None;
+    let mut text = String::new();
+    for e in EventReader::new(xml) {
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                text.clear();
+                let attr = |n: &str| {
+                    attributes
+                        .iter()
+                        .find(|a| a.name.local_name == n)
+                        .map(|a| a.value.clone())
+                        .unwrap_or_default()
+                };
+                if let Some(id) = attributes.iter().find(|a| a.name.local_name == "Id") {
+                    if ids.contains(&id.value) {
+                        sig.duplicate_ids = true;
+                    }
+                    ids.push(id.value.clone());
+                }
+                let is_signature_child = path.len() == 1 && path[0] == "Signature";
+                match name.local_name.as_str() {
+                    "SignedInfo" if is_signature_child => {
+                        sig.signed_info_count += 1;
+                        in_signed_info = true;
+                    }
+                    "SignedInfo" => sig.misplaced_signed_info = true,
+                    "Object" if is_signature_child => object_id = Some(attr("Id")),
+                    "SignatureTime" => in_signature_time = true,
+                    "SignatureMethod" if in_signed_info => sig.signature_method = attr("Algorithm"),
+                    "Reference" => {
+                        reference = Some(Reference {
+                            uri: attr("URI"),
+                            reference_type: attr("Type"),
+                            object_id: object_id.clone(),
+                            ..Default::default()
+                        })
+                    }
+                    _ => {}
+                }
+                if let Some(ref mut r) = reference {
+                    match name.local_name.as_str() {
+                        "Transform" => r.transforms.push(attr("Algorithm")),
+                        "DigestMethod" => r.digest_method = attr("Algorithm"),
+                        "RelationshipReference" => r.source_ids.push(attr("SourceId")),
+                        "RelationshipsGroupReference" => r.source_types.push(attr("SourceType")),
+                        _ => {}
+                    }
+                }
+                path.push(name.local_name);
+            }
+            Ok(XmlEvent::Characters(t)) => text.push_str(&t),
+            Ok(XmlEvent::EndElement { name }) => {
+                path.pop();
+                let is_signature_child = path.len() == 1 && path[0] == "Signature";
+                match name.local_name.as_str() {
+                    "SignedInfo" => in_signed_info = false,
+                    "Object" if is_signature_child => object_id = None,
+                    "SignatureTime" => in_signature_time = false,
+                    "SignatureValue" if is_signature_child => sig.signature_value = text.clone(),
+                    "X509Certificate" if sig.certificate.is_none() => {
+                        sig.certificate = Some(text.clone())
+                    }
+                    "Value" if in_signature_time => {
+                        sig.signing_time = Some((object_id.clone(), text.clone()))
+                    }
+                    "DigestValue" => {
+                        if let Some(ref mut r) = reference {
+                            r.digest_value = text.clone();
+                        }
+                    }
+                    "Reference" => {
+                        if let Some(r) = reference.take() {
+                            if in_signed_info {
+                                sig.signed_info_references.push(r);
+                            } else if path.last().map(|p| p == "Manifest").unwrap_or_default() {
+                                sig.manifest_references.push(r);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                text.clear();
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+    Ok(sig)
+}
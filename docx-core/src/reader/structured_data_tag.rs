@@ -23,6 +23,33 @@ impl ElementReader for StructuredDataTag {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
 
                     match e {
+                        XMLElement::DataBinding => {
+                            let mut binding = DataBinding::new();
+                            if let Some(xpath) = read(&attributes, "xpath") {
+                                binding = binding.xpath(xpath);
+                            }
+                            if let Some(m) = read(&attributes, "prefixMappings") {
+                                binding = binding.prefix_mappings(m);
+                            }
+                            if let Some(id) = read(&attributes, "storeItemID") {
+                                binding = binding.store_item_id(id);
+                            }
+                            sdt = sdt.data_binding(binding);
+                            continue;
+                        }
+                        XMLElement::Alias => {
+                            if let Some(v) = read_val(&attributes) {
+                                sdt = sdt.alias(v);
+                            }
+                            continue;
+                        }
+                        XMLElement::StructuredDataTag => {
+                            if let Ok(tag) = StructuredDataTag::read(r, &attributes) {
+                                sdt.children
+                                    .push(StructuredDataTagChild::StructuredDataTag(Box::new(tag)));
+                            }
+                            continue;
+                        }
                         XMLElement::Paragraph => {
                             if let Ok(p) = Paragraph::read(r, &attributes) {
                                 sdt.children
@@ -70,6 +97,18 @@ impl ElementReader for StructuredDataTag {
                             }
                             continue;
                         }
+                        XMLElement::Insert => {
+                            if let Ok(ins) = Insert::read(r, &attributes) {
+                                sdt.children.push(StructuredDataTagChild::Insert(ins));
+                            }
+                            continue;
+                        }
+                        XMLElement::Delete => {
+                            if let Ok(del) = Delete::read(r, &attributes) {
+                                sdt.children.push(StructuredDataTagChild::Delete(del));
+                            }
+                            continue;
+                        }
                         XMLElement::Run => {
                             if let Ok(run) = Run::read(r, attrs) {
                                 sdt.children
@@ -92,3 +131,44 @@ impl ElementReader for StructuredDataTag {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_data_binding() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:sdt>
+        <w:sdtPr>
+            <w:alias w:val="Name" />
+            <w:dataBinding w:xpath="/root/name" w:storeItemID="{06AC5857-5C65-A94A-BCEC-37356A209BC3}" />
+        </w:sdtPr>
+        <w:sdtContent>
+            <w:p>
+                <w:r><w:t>Hello</w:t></w:r>
+            </w:p>
+        </w:sdtContent>
+    </w:sdt>
+  </w:body>
+</w:document>"#;
+        let doc = Document::from_xml(c.as_bytes()).unwrap();
+        assert_eq!(
+            doc.children[0],
+            DocumentChild::StructuredDataTag(Box::new(
+                StructuredDataTag::new()
+                    .alias("Name")
+                    .data_binding(
+                        DataBinding::new()
+                            .xpath("/root/name")
+                            .store_item_id("{06AC5857-5C65-A94A-BCEC-37356A209BC3}")
+                    )
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+            ))
+        );
+    }
+}
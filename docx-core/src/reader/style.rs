@@ -6,6 +6,8 @@ use xml::reader::{EventReader, XmlEvent};
 
 use super::*;
 
+use super::attributes::*;
+
 use crate::types::*;
 
 impl ElementReader for Style {
@@ -15,15 +17,22 @@ impl ElementReader for Style {
     ) -> Result<Self, ReaderError> {
         let mut id = "".to_owned();
         let mut style_type = StyleType::Paragraph;
+        let mut default = false;
         for a in attrs {
             let local_name = &a.name.local_name;
             if local_name == "styleId" {
                 id = a.value.clone();
             } else if local_name == "type" {
                 style_type = StyleType::from_str(&a.value)?;
+            } else if local_name == "default" {
+                default = !is_false(&a.value);
             }
         }
-        let mut style = Style::new(id, style_type);
+        // qFormat is only set by its element.
+        let mut style = Style::new(id, style_type).q_format(false);
+        if default {
+            style = style.default_style();
+        }
         loop {
             let e = r.next();
             match e {
@@ -48,6 +57,30 @@ impl ElementReader for Style {
                             }
                             continue;
                         }
+                        XMLElement::UiPriority => {
+                            if let Some(v) = read_val(&attributes) {
+                                if let Ok(v) = v.parse() {
+                                    style = style.ui_priority(v);
+                                }
+                            }
+                            continue;
+                        }
+                        XMLElement::SemiHidden => {
+                            if read_bool(&attributes) {
+                                style = style.semi_hidden();
+                            }
+                            continue;
+                        }
+                        XMLElement::UnhideWhenUsed => {
+                            if read_bool(&attributes) {
+                                style = style.unhide_when_used();
+                            }
+                            continue;
+                        }
+                        XMLElement::QFormat => {
+                            style = style.q_format(read_bool(&attributes));
+                            continue;
+                        }
                         // pPr
                         XMLElement::ParagraphProperty => {
                             if let Ok(pr) = ParagraphProperty::read(r, attrs) {
@@ -70,6 +103,11 @@ impl ElementReader for Style {
                                 style = style.table_cell_property(p);
                             }
                         }
+                        XMLElement::TableStyleProperty => {
+                            if let Ok(p) = TableStyleProperty::read(r, &attributes) {
+                                style = style.add_table_style_property(p);
+                            }
+                        }
                         _ => {}
                     }
                 }
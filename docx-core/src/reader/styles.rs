@@ -30,6 +30,12 @@ impl FromXML for Styles {
                             }
                             continue;
                         }
+                        XMLElement::LatentStyles => {
+                            if let Ok(l) = LatentStyles::read(&mut parser, &attributes) {
+                                styles = styles.latent_styles(l);
+                            }
+                            continue;
+                        }
                         _ => {}
                     }
                 }
@@ -79,8 +85,48 @@ mod tests {
                 .name("Footnote Text Char")
                 .size(20)
                 .based_on("DefaultParagraphFont")
-                .link("FootnoteText"),
+                .link("FootnoteText")
+                .ui_priority(99)
+                .unhide_when_used()
+                .semi_hidden()
+                .q_format(false),
         );
         assert_eq!(s, styles);
     }
+
+    #[test]
+    fn test_from_xml_with_table_style_property() {
+        let xml = r#"<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:style w:type="table" w:styleId="Zebra">
+        <w:name w:val="Zebra"></w:name>
+        <w:tblPr>
+            <w:tblLook w:val="04A0" w:firstRow="1" w:lastRow="0" w:firstColumn="1" w:lastColumn="0" w:noHBand="0" w:noVBand="1"></w:tblLook>
+        </w:tblPr>
+        <w:tblStylePr w:type="firstRow">
+            <w:rPr>
+                <w:b></w:b>
+            </w:rPr>
+        </w:tblStylePr>
+        <w:tblStylePr w:type="band1Horz">
+            <w:tcPr>
+                <w:shd w:val="clear" w:color="auto" w:fill="D9E2F3"></w:shd>
+            </w:tcPr>
+        </w:tblStylePr>
+    </w:style>
+</w:styles>"#;
+        let s = Styles::from_xml(xml.as_bytes()).unwrap();
+        let style = s.find_style_by_id("Zebra").unwrap();
+        assert_eq!(
+            style.table_property,
+            TableProperty::without_borders().look(TableLook::new())
+        );
+        assert_eq!(
+            style.table_style_properties,
+            vec![
+                TableStyleProperty::new(TableStyleOverrideType::FirstRow).bold(),
+                TableStyleProperty::new(TableStyleOverrideType::Band1Horz)
+                    .shading(Shading::new().fill("D9E2F3")),
+            ]
+        );
+    }
 }
@@ -11,6 +11,7 @@ impl ElementReader for Table {
     fn read<R: Read>(r: &mut EventReader<R>, _: &[OwnedAttribute]) -> Result<Self, ReaderError> {
         let mut t = Table::without_borders(vec![]);
         let mut grid_col: Vec<usize> = vec![];
+        let mut pending: Vec<ParagraphChild> = vec![];
         loop {
             let e = r.next();
             match e {
@@ -19,14 +20,26 @@ impl ElementReader for Table {
                 }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
 
-                    ignore::ignore_element(e.clone(), XMLElement::TablePropertyChange, r);
                     ignore::ignore_element(e.clone(), XMLElement::TableGridChange, r);
 
                     match e {
                         XMLElement::TableRow => {
-                            t = t.add_row(TableRow::read(r, &attributes)?);
+                            let mut row = TableRow::read(r, &attributes)?;
+                            if let Some(TableRowChild::TableCell(cell)) = row.cells.first_mut() {
+                                table_row::prepend_to_cell(cell, &mut pending);
+                            }
+                            t = t.add_row(row);
                             continue;
                         }
+                        XMLElement::CommentRangeStart | XMLElement::CommentRangeEnd => {
+                            if let Some(marker) = table_row::read_comment_marker(&e, &attributes) {
+                                pending.push(marker);
+                            }
+                            // A range end closes the preceding row.
+                            if e == XMLElement::CommentRangeEnd {
+                                append_to_last_cell(&mut t, &mut pending);
+                            }
+                        }
                         XMLElement::TableWidth => {
                             let (w, width_type) = read_width(&attributes)?;
                             t = t.width(w as usize, width_type);
@@ -70,6 +83,7 @@ impl ElementReader for Table {
                 Ok(XmlEvent::EndElement { name, .. }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
                     if e == XMLElement::Table {
+                        append_to_last_cell(&mut t, &mut pending);
                         t = t.set_grid(grid_col);
                         return Ok(t);
                     }
@@ -81,6 +95,14 @@ impl ElementReader for Table {
     }
 }
 
+fn append_to_last_cell(t: &mut Table, markers: &mut Vec<ParagraphChild>) {
+    if let Some(TableChild::TableRow(row)) = t.rows.last_mut() {
+        if let Some(TableRowChild::TableCell(cell)) = row.cells.last_mut() {
+            table_row::append_to_cell(cell, markers);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -112,6 +134,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_table_with_revisions() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tbl>
+    <w:tblPr>
+        <w:tblStyle w:val="Grid"/>
+        <w:tblPrChange w:id="1" w:author="bokuweb" w:date="2024-01-01T00:00:00Z">
+            <w:tblPr/>
+        </w:tblPrChange>
+    </w:tblPr>
+    <w:tr>
+        <w:trPr>
+            <w:trPrChange w:id="2" w:author="bokuweb" w:date="2024-01-01T00:00:00Z">
+                <w:trPr><w:trHeight w:val="100"/></w:trPr>
+            </w:trPrChange>
+        </w:trPr>
+        <w:tc>
+            <w:tcPr>
+                <w:gridSpan w:val="2"/>
+                <w:cellIns w:id="3" w:author="bokuweb" w:date="2024-01-01T00:00:00Z"/>
+                <w:tcPrChange w:id="4" w:author="bokuweb" w:date="2024-01-01T00:00:00Z">
+                    <w:tcPr><w:vMerge w:val="restart"/></w:tcPr>
+                </w:tcPrChange>
+            </w:tcPr>
+        </w:tc>
+    </w:tr>
+</w:tbl>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let t = Table::read(&mut parser, &[]).unwrap();
+        let date = "2024-01-01T00:00:00Z";
+        let cell = TableCell::new()
+            .grid_span(2)
+            .cell_insert(CellInsert::new().author("bokuweb").date(date))
+            .table_cell_property_change(
+                TableCellPropertyChange::new()
+                    .property(TableCellProperty::new().vertical_merge(VMergeType::Restart))
+                    .author("bokuweb")
+                    .date(date),
+            );
+        let row = TableRow::new(vec![cell]).table_row_property_change(
+            TableRowPropertyChange::new()
+                .property(TableRowProperty::new().row_height(100.0))
+                .author("bokuweb")
+                .date(date),
+        );
+        assert_eq!(
+            t,
+            Table::without_borders(vec![row])
+                .style("Grid")
+                .table_property_change(
+                    TablePropertyChange::new()
+                        .property(TableProperty::without_borders())
+                        .author("bokuweb")
+                        .date(date)
+                )
+        );
+    }
+
     #[test]
     fn test_read_table_with_layout() {
         let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
@@ -131,4 +212,134 @@ mod tests {
                 .indent(100)
         );
     }
+
+    #[test]
+    fn test_read_table_with_comment_range_between_rows() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tbl>
+    <w:commentRangeStart w:id="1"/>
+    <w:tr>
+        <w:tc><w:p><w:r><w:t>A</w:t></w:r></w:p></w:tc>
+    </w:tr>
+    <w:commentRangeEnd w:id="1"/>
+    <w:tr>
+        <w:tc><w:p><w:r><w:t>B</w:t></w:r></w:p></w:tc>
+        <w:commentRangeStart w:id="2"/>
+        <w:tc><w:p><w:r><w:t>C</w:t></w:r></w:p></w:tc>
+    </w:tr>
+    <w:commentRangeEnd w:id="2"/>
+</w:tbl>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let t = Table::read(&mut parser, &[]).unwrap();
+        let run = |text: &str| Run::new().add_text(text);
+        assert_eq!(
+            t,
+            Table::without_borders(vec![
+                TableRow::new(vec![TableCell::new().add_paragraph(
+                    Paragraph::new()
+                        .add_comment_start(Comment::new(1))
+                        .add_run(run("A"))
+                        .add_comment_end(1)
+                )]),
+                TableRow::new(vec![
+                    TableCell::new().add_paragraph(Paragraph::new().add_run(run("B"))),
+                    TableCell::new().add_paragraph(
+                        Paragraph::new()
+                            .add_comment_start(Comment::new(2))
+                            .add_run(run("C"))
+                            .add_comment_end(2)
+                    ),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_read_table_with_header_row_and_accessibility() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tbl>
+    <w:tblPr>
+        <w:tblCaption w:val="Sales"/>
+        <w:tblDescription w:val="Quarterly sales"/>
+    </w:tblPr>
+    <w:tr>
+        <w:trPr>
+            <w:cnfStyle w:val="100000000000"/>
+            <w:cantSplit/>
+            <w:tblHeader/>
+            <w:tblCellSpacing w:w="15" w:type="dxa"/>
+        </w:trPr>
+        <w:tc>
+            <w:tcPr>
+                <w:noWrap/>
+                <w:tcFitText/>
+                <w:hideMark/>
+            </w:tcPr>
+            <w:p/>
+        </w:tc>
+    </w:tr>
+    <w:tr>
+        <w:trPr>
+            <w:tblHeader w:val="0"/>
+            <w:hidden/>
+        </w:trPr>
+        <w:tc>
+            <w:p/>
+        </w:tc>
+    </w:tr>
+</w:tbl>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let t = Table::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            t,
+            Table::without_borders(vec![
+                TableRow::new(vec![TableCell::new()
+                    .add_paragraph(Paragraph::new())
+                    .no_wrap()
+                    .fit_text()
+                    .hide_mark()])
+                .cnf_style("100000000000")
+                .cant_split()
+                .table_header()
+                .cell_spacing(15, WidthType::Dxa),
+                TableRow::new(vec![TableCell::new().add_paragraph(Paragraph::new())]).hidden(),
+            ])
+            .caption("Sales")
+            .description("Quarterly sales")
+        );
+    }
+
+    #[test]
+    fn test_read_table_with_row_exceptions() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tbl>
+    <w:tr>
+        <w:tblPrEx>
+            <w:tblW w:w="4000" w:type="dxa"/>
+            <w:tblInd w:w="120" w:type="dxa"/>
+            <w:tblLayout w:type="fixed"/>
+        </w:tblPrEx>
+        <w:tc>
+            <w:p/>
+        </w:tc>
+    </w:tr>
+</w:tbl>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let t = Table::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            t,
+            Table::without_borders(vec![TableRow::new(vec![
+                TableCell::new().add_paragraph(Paragraph::new())
+            ])
+            .property_exceptions(
+                TablePropertyExceptions::new()
+                    .width(4000, WidthType::Dxa)
+                    .indent(120)
+                    .layout(TableLayoutType::Fixed)
+            )])
+        );
+    }
 }
@@ -26,6 +26,9 @@ impl ElementReader for TableBorders {
                             if let Some(size) = attr.size {
                                 border = border.size(size as usize);
                             };
+                            if let Some(theme) = attr.theme_color {
+                                border = border.theme_color(theme);
+                            }
                             borders = borders.set(border);
                             continue;
                         }
@@ -37,6 +40,9 @@ impl ElementReader for TableBorders {
                             if let Some(size) = attr.size {
                                 border = border.size(size as usize);
                             };
+                            if let Some(theme) = attr.theme_color {
+                                border = border.theme_color(theme);
+                            }
                             borders = borders.set(border);
                             continue;
                         }
@@ -48,6 +54,9 @@ impl ElementReader for TableBorders {
                             if let Some(size) = attr.size {
                                 border = border.size(size as usize);
                             };
+                            if let Some(theme) = attr.theme_color {
+                                border = border.theme_color(theme);
+                            }
                             borders = borders.set(border);
                             continue;
                         }
@@ -59,6 +68,9 @@ impl ElementReader for TableBorders {
                             if let Some(size) = attr.size {
                                 border = border.size(size as usize);
                             };
+                            if let Some(theme) = attr.theme_color {
+                                border = border.theme_color(theme);
+                            }
                             borders = borders.set(border);
                             continue;
                         }
@@ -70,6 +82,9 @@ impl ElementReader for TableBorders {
                             if let Some(size) = attr.size {
                                 border = border.size(size as usize);
                             };
+                            if let Some(theme) = attr.theme_color {
+                                border = border.theme_color(theme);
+                            }
                             borders = borders.set(border);
                             continue;
                         }
@@ -81,6 +96,9 @@ impl ElementReader for TableBorders {
                             if let Some(size) = attr.size {
                                 border = border.size(size as usize);
                             };
+                            if let Some(theme) = attr.theme_color {
+                                border = border.theme_color(theme);
+                            }
                             borders = borders.set(border);
                             continue;
                         }
@@ -17,8 +17,6 @@ impl ElementReader for TableCellProperty {
                     attributes, name, ..
                 }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
-                    ignore::ignore_element(e.clone(), XMLElement::TableCellPropertyChange, r);
-
                     match e {
                         XMLElement::TableCellWidth => {
                             let mut w = 0;
@@ -72,6 +70,46 @@ impl ElementReader for TableCellProperty {
                                 property = property.margins(margins);
                             }
                         }
+                        XMLElement::CnfStyle => {
+                            if let Some(v) = read_val(&attributes) {
+                                property = property.cnf_style(v);
+                            }
+                        }
+                        XMLElement::NoWrap => {
+                            if read_bool(&attributes) {
+                                property = property.no_wrap();
+                            }
+                        }
+                        XMLElement::TableCellFitText => {
+                            if read_bool(&attributes) {
+                                property = property.fit_text();
+                            }
+                        }
+                        XMLElement::HideMark => {
+                            if read_bool(&attributes) {
+                                property = property.hide_mark();
+                            }
+                        }
+                        XMLElement::CellInsert => {
+                            if let Ok(c) = CellInsert::read(r, &attributes) {
+                                property = property.cell_insert(c);
+                            }
+                        }
+                        XMLElement::CellDelete => {
+                            if let Ok(c) = CellDelete::read(r, &attributes) {
+                                property = property.cell_delete(c);
+                            }
+                        }
+                        XMLElement::CellMerge => {
+                            if let Ok(c) = CellMerge::read(r, &attributes) {
+                                property = property.cell_merge(c);
+                            }
+                        }
+                        XMLElement::TableCellPropertyChange => {
+                            if let Ok(c) = TableCellPropertyChange::read(r, &attributes) {
+                                property = property.table_cell_property_change(c);
+                            }
+                        }
                         _ => {}
                     }
                 }
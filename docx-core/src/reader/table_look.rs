@@ -0,0 +1,38 @@
+use std::io::Read;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::EventReader;
+
+use super::*;
+
+impl ElementReader for TableLook {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        // Transitional documents often only have the w:val bitmask.
+        let mut look = read_val(attrs)
+            .map(|v| TableLook::from_val(&v))
+            .unwrap_or_default();
+        let flag = |name: &str| read(attrs, name).map(|v| !is_false(&v));
+        if let Some(v) = flag("firstRow") {
+            look = look.first_row(v);
+        }
+        if let Some(v) = flag("lastRow") {
+            look = look.last_row(v);
+        }
+        if let Some(v) = flag("firstColumn") {
+            look = look.first_column(v);
+        }
+        if let Some(v) = flag("lastColumn") {
+            look = look.last_column(v);
+        }
+        if let Some(v) = flag("noHBand") {
+            look = look.no_h_band(v);
+        }
+        if let Some(v) = flag("noVBand") {
+            look = look.no_v_band(v);
+        }
+        Ok(look)
+    }
+}
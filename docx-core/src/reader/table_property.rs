@@ -61,6 +61,26 @@ impl ElementReader for TableProperty {
                                 tp = tp.position(p);
                             }
                         }
+                        XMLElement::TableCaption => {
+                            if let Some(v) = read_val(&attributes) {
+                                tp = tp.caption(v);
+                            }
+                        }
+                        XMLElement::TableDescription => {
+                            if let Some(v) = read_val(&attributes) {
+                                tp = tp.description(v);
+                            }
+                        }
+                        XMLElement::TableLook => {
+                            if let Ok(l) = TableLook::read(r, &attributes) {
+                                tp = tp.look(l);
+                            }
+                        }
+                        XMLElement::TablePropertyChange => {
+                            if let Ok(c) = TablePropertyChange::read(r, &attributes) {
+                                tp = tp.table_property_change(c);
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -0,0 +1,84 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+use crate::types::*;
+
+impl ElementReader for TablePropertyExceptions {
+    fn read<R: Read>(r: &mut EventReader<R>, _: &[OwnedAttribute]) -> Result<Self, ReaderError> {
+        let mut p = TablePropertyExceptions::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        XMLElement::TableWidth => {
+                            if let Ok((w, t)) = read_width(&attributes) {
+                                p = p.width(w.max(0) as usize, t);
+                            }
+                        }
+                        XMLElement::Justification => {
+                            if let Some(v) = read_val(&attributes) {
+                                if let Ok(v) = TableAlignmentType::from_str(&v) {
+                                    p = p.align(v);
+                                }
+                            }
+                        }
+                        XMLElement::TableCellSpacing => {
+                            if let Ok((w, t)) = read_width(&attributes) {
+                                p = p.cell_spacing(w.max(0) as usize, t);
+                            }
+                        }
+                        XMLElement::TableIndent => {
+                            if let Ok((w, _)) = read_width(&attributes) {
+                                p = p.indent(w as i32);
+                            }
+                        }
+                        XMLElement::TableBorders => {
+                            if let Ok(borders) = TableBorders::read(r, &attributes) {
+                                p = p.set_borders(borders);
+                            }
+                        }
+                        XMLElement::Shading => {
+                            if let Ok(shd) = Shading::read(r, &attributes) {
+                                p = p.shading(shd);
+                            }
+                        }
+                        XMLElement::TableLayout => {
+                            if let Some(v) = read(&attributes, "type") {
+                                if let Ok(t) = TableLayoutType::from_str(&v) {
+                                    p = p.layout(t);
+                                }
+                            }
+                        }
+                        XMLElement::TableCellMargin => {
+                            if let Ok(margins) = TableCellMargins::read(r, &attributes) {
+                                p = p.set_margins(margins);
+                            }
+                        }
+                        XMLElement::TableLook => {
+                            if let Ok(l) = TableLook::read(r, &attributes) {
+                                p = p.look(l);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::TablePropertyExceptions {
+                        return Ok(p);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -4,22 +4,14 @@ use std::str::FromStr;
 use xml::attribute::OwnedAttribute;
 use xml::reader::{EventReader, XmlEvent};
 
-use crate::HeightRule;
-
-use super::attributes::*;
 use super::*;
 
 impl ElementReader for TableRow {
     fn read<R: Read>(r: &mut EventReader<R>, _: &[OwnedAttribute]) -> Result<Self, ReaderError> {
-        let mut cells = vec![];
-        let mut grid_after = None;
-        let mut width_after = None;
-        let mut grid_before = None;
-        let mut width_before = None;
-        let mut row_height = None;
-        let mut del = None;
-        let mut ins = None;
-        let mut height_rule = None;
+        let mut cells: Vec<TableCell> = vec![];
+        let mut property = TableRowProperty::new();
+        let mut property_exceptions = None;
+        let mut pending: Vec<ParagraphChild> = vec![];
         loop {
             let e = r.next();
             match e {
@@ -28,57 +20,31 @@ impl ElementReader for TableRow {
                 }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
 
-                    ignore::ignore_element(e.clone(), XMLElement::TableRowPropertyChange, r);
-
                     match e {
                         XMLElement::TableCell => {
-                            cells.push(TableCell::read(r, &attributes)?);
+                            let mut cell = TableCell::read(r, &attributes)?;
+                            prepend_to_cell(&mut cell, &mut pending);
+                            cells.push(cell);
                             continue;
                         }
-                        XMLElement::GridAfter => {
-                            if let Some(v) = read_val(&attributes) {
-                                grid_after = Some(u32::from_str(&v)?);
-                            }
-                        }
-                        XMLElement::WidthAfter => {
-                            if let Ok(v) = read_width(&attributes) {
-                                width_after = Some(v.0 as f32);
-                            }
-                        }
-                        XMLElement::GridBefore => {
-                            if let Some(v) = read_val(&attributes) {
-                                grid_before = Some(u32::from_str(&v)?);
-                            }
-                        }
-                        XMLElement::WidthBefore => {
-                            if let Ok(v) = read_width(&attributes) {
-                                width_before = Some(v.0 as f32);
-                            }
-                        }
-                        XMLElement::TableRowHeight => {
-                            if let Some(v) = read_val(&attributes) {
-                                let h = f32::from_str(&v);
-                                if let Ok(h) = h {
-                                    row_height = Some(h);
-                                }
+                        XMLElement::CommentRangeStart | XMLElement::CommentRangeEnd => {
+                            if let Some(marker) = read_comment_marker(&e, &attributes) {
+                                pending.push(marker);
                             }
-
-                            if let Some(v) = read(&attributes, "hRule") {
-                                let h = HeightRule::from_str(&v);
-                                if let Ok(h) = h {
-                                    height_rule = Some(h);
-                                }
+                            // A range end closes the preceding cell.
+                            if let (XMLElement::CommentRangeEnd, Some(cell)) =
+                                (&e, cells.last_mut())
+                            {
+                                append_to_cell(cell, &mut pending);
                             }
                         }
-                        XMLElement::Delete => {
-                            if let Ok(d) = Delete::read(r, &attributes) {
-                                del = Some(d);
+                        XMLElement::TablePropertyExceptions => {
+                            if let Ok(p) = TablePropertyExceptions::read(r, &attributes) {
+                                property_exceptions = Some(p);
                             }
                         }
-                        XMLElement::Insert => {
-                            if let Ok(i) = Insert::read(r, &attributes) {
-                                ins = Some(i);
-                            }
+                        XMLElement::TableRowProperty => {
+                            property = TableRowProperty::read(r, &attributes)?;
                         }
                         _ => {}
                     }
@@ -86,39 +52,12 @@ impl ElementReader for TableRow {
                 Ok(XmlEvent::EndElement { name, .. }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
                     if e == XMLElement::TableRow {
-                        let mut row = TableRow::new(cells);
-                        if let Some(grid_after) = grid_after {
-                            row = row.grid_after(grid_after);
-                        }
-
-                        if let Some(grid_before) = grid_before {
-                            row = row.grid_before(grid_before);
-                        }
-
-                        if let Some(row_height) = row_height {
-                            row = row.row_height(row_height);
-                        }
-
-                        if let Some(width_after) = width_after {
-                            row = row.width_after(width_after);
-                        }
-
-                        if let Some(width_before) = width_before {
-                            row = row.width_before(width_before);
-                        }
-
-                        if let Some(height_rule) = height_rule {
-                            row = row.height_rule(height_rule);
-                        }
-
-                        if let Some(del) = del {
-                            row = row.delete(del);
+                        if let Some(cell) = cells.last_mut() {
+                            append_to_cell(cell, &mut pending);
                         }
-
-                        if let Some(ins) = ins {
-                            row = row.insert(ins);
-                        }
-
+                        let mut row = TableRow::new(cells);
+                        row.property = property;
+                        row.property_exceptions = property_exceptions;
                         return Ok(row);
                     }
                 }
@@ -128,3 +67,45 @@ impl ElementReader for TableRow {
         }
     }
 }
+
+// Comment ranges spanning tables may put their markers between rows or cells.
+// They are moved into the nearest cell paragraph since rows and cells only hold cells and content.
+pub(super) fn read_comment_marker(
+    e: &XMLElement,
+    attrs: &[OwnedAttribute],
+) -> Option<ParagraphChild> {
+    let id = usize::from_str(&read(attrs, "id")?).ok()?;
+    match e {
+        XMLElement::CommentRangeStart => Some(ParagraphChild::CommentStart(Box::new(
+            CommentRangeStart::new(Comment::new(id)),
+        ))),
+        XMLElement::CommentRangeEnd => Some(ParagraphChild::CommentEnd(CommentRangeEnd::new(id))),
+        _ => None,
+    }
+}
+
+pub(super) fn prepend_to_cell(cell: &mut TableCell, markers: &mut Vec<ParagraphChild>) {
+    if markers.is_empty() {
+        return;
+    }
+    let first = cell.children.iter_mut().find_map(|c| match c {
+        TableCellContent::Paragraph(p) => Some(p),
+        _ => None,
+    });
+    if let Some(p) = first {
+        p.children.splice(0..0, markers.drain(..));
+    }
+}
+
+pub(super) fn append_to_cell(cell: &mut TableCell, markers: &mut Vec<ParagraphChild>) {
+    if markers.is_empty() {
+        return;
+    }
+    let last = cell.children.iter_mut().rev().find_map(|c| match c {
+        TableCellContent::Paragraph(p) => Some(p),
+        _ => None,
+    });
+    if let Some(p) = last {
+        p.children.append(markers);
+    }
+}
@@ -0,0 +1,120 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::HeightRule;
+
+use super::attributes::*;
+use super::*;
+
+impl ElementReader for TableRowProperty {
+    fn read<R: Read>(r: &mut EventReader<R>, _: &[OwnedAttribute]) -> Result<Self, ReaderError> {
+        let mut p = TableRowProperty::new();
+        let mut row_height = None;
+        let mut height_rule = None;
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        XMLElement::GridAfter => {
+                            if let Some(v) = read_val(&attributes) {
+                                p = p.grid_after(u32::from_str(&v)?);
+                            }
+                        }
+                        XMLElement::WidthAfter => {
+                            if let Ok(v) = read_width(&attributes) {
+                                p = p.width_after(v.0 as f32);
+                            }
+                        }
+                        XMLElement::GridBefore => {
+                            if let Some(v) = read_val(&attributes) {
+                                p = p.grid_before(u32::from_str(&v)?);
+                            }
+                        }
+                        XMLElement::WidthBefore => {
+                            if let Ok(v) = read_width(&attributes) {
+                                p = p.width_before(v.0 as f32);
+                            }
+                        }
+                        XMLElement::TableRowHeight => {
+                            if let Some(v) = read_val(&attributes) {
+                                let h = f32::from_str(&v);
+                                if let Ok(h) = h {
+                                    row_height = Some(h);
+                                }
+                            }
+
+                            if let Some(v) = read(&attributes, "hRule") {
+                                let h = HeightRule::from_str(&v);
+                                if let Ok(h) = h {
+                                    height_rule = Some(h);
+                                }
+                            }
+                        }
+                        XMLElement::CantSplit => {
+                            if read_bool(&attributes) {
+                                p = p.cant_split();
+                            }
+                        }
+                        XMLElement::TableHeader => {
+                            if read_bool(&attributes) {
+                                p = p.table_header();
+                            }
+                        }
+                        XMLElement::TableRowHidden => {
+                            if read_bool(&attributes) {
+                                p = p.hidden();
+                            }
+                        }
+                        XMLElement::CnfStyle => {
+                            if let Some(v) = read_val(&attributes) {
+                                p = p.cnf_style(v);
+                            }
+                        }
+                        XMLElement::TableCellSpacing => {
+                            if let Ok((w, t)) = read_width(&attributes) {
+                                p = p.cell_spacing(w.max(0) as usize, t);
+                            }
+                        }
+                        XMLElement::Delete => {
+                            if let Ok(d) = Delete::read(r, &attributes) {
+                                p = p.delete(d);
+                            }
+                        }
+                        XMLElement::Insert => {
+                            if let Ok(i) = Insert::read(r, &attributes) {
+                                p = p.insert(i);
+                            }
+                        }
+                        XMLElement::TableRowPropertyChange => {
+                            if let Ok(c) = TableRowPropertyChange::read(r, &attributes) {
+                                p = p.table_row_property_change(c);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::TableRowProperty {
+                        if let Some(row_height) = row_height {
+                            p = p.row_height(row_height);
+                        }
+                        if let Some(height_rule) = height_rule {
+                            p = p.height_rule(height_rule);
+                        }
+                        return Ok(p);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+use crate::types::*;
+
+impl ElementReader for TableStyleProperty {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let style_type = read(attrs, "type").ok_or(ReaderError::XMLReadError)?;
+        let mut p = TableStyleProperty::new(TableStyleOverrideType::from_str(&style_type)?);
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        XMLElement::ParagraphProperty => {
+                            if let Ok(pr) = ParagraphProperty::read(r, &attributes) {
+                                p = p.paragraph_property(pr);
+                            }
+                        }
+                        XMLElement::RunProperty => {
+                            if let Ok(pr) = RunProperty::read(r, &attributes) {
+                                p = p.run_property(pr);
+                            }
+                        }
+                        XMLElement::TableProperty => {
+                            if let Ok(pr) = TableProperty::read(r, &attributes) {
+                                p = p.table_property(pr);
+                            }
+                        }
+                        XMLElement::TableRowProperty => {
+                            if let Ok(pr) = TableRowProperty::read(r, &attributes) {
+                                p = p.table_row_property(pr);
+                            }
+                        }
+                        XMLElement::TableCellProperty => {
+                            if let Ok(pr) = TableCellProperty::read(r, &attributes) {
+                                p = p.table_cell_property(pr);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::TableStyleProperty {
+                        return Ok(p);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
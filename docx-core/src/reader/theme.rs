@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 use crate::reader::*;
 use xml::reader::{EventReader, XmlEvent};
+use xml::writer::EmitterConfig;
 
 impl FromXML for Theme {
     fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
@@ -12,11 +13,32 @@ impl FromXML for Theme {
             let e = parser.next();
             match e {
                 Ok(XmlEvent::StartElement {
-                    attributes, name, ..
+                    attributes,
+                    name,
+                    namespace,
                 }) => {
                     let e = AXMLElement::from_str(&name.local_name).unwrap();
-                    #[allow(clippy::single_match)]
                     match e {
+                        AXMLElement::Theme => {
+                            if let Some(name) = read(&attributes, "name") {
+                                theme.name = name;
+                            }
+                        }
+                        AXMLElement::ClrScheme => {
+                            if let Ok(s) = ColorScheme::read(&mut parser, &attributes) {
+                                theme.color_scheme = s;
+                            }
+                            continue;
+                        }
+                        AXMLElement::FmtScheme => {
+                            let start = XmlEvent::StartElement {
+                                name,
+                                attributes,
+                                namespace,
+                            };
+                            theme.format_scheme = read_format_scheme(&mut parser, &start)?;
+                            continue;
+                        }
                         AXMLElement::FontScheme => {
                             if let Ok(f) = FontScheme::read(&mut parser, &attributes) {
                                 theme.font_schema = f;
@@ -34,3 +56,42 @@ impl FromXML for Theme {
         Ok(theme)
     }
 }
+
+// The style matrices are not modeled, so the read a:fmtScheme is kept as it is.
+fn read_format_scheme<R: Read>(
+    parser: &mut EventReader<R>,
+    start: &XmlEvent,
+) -> Result<FormatScheme, ReaderError> {
+    let mut scheme = FormatScheme::new();
+    if let XmlEvent::StartElement { attributes, .. } = start {
+        if let Some(name) = read(attributes, "name") {
+            scheme = scheme.name(name);
+        }
+    }
+    let mut writer = EmitterConfig::new()
+        .write_document_declaration(false)
+        .create_writer(vec![]);
+    let mut depth = 0;
+    let mut e = Ok(start.clone());
+    loop {
+        match e {
+            Ok(e) => {
+                match e {
+                    XmlEvent::StartElement { .. } => depth += 1,
+                    XmlEvent::EndElement { .. } => depth -= 1,
+                    _ => {}
+                }
+                if let Some(w) = e.as_writer_event() {
+                    writer.write(w).map_err(|_| ReaderError::XMLReadError)?;
+                }
+                if depth == 0 {
+                    break;
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+        }
+        e = parser.next();
+    }
+    let raw = String::from_utf8(writer.into_inner()).map_err(|_| ReaderError::XMLReadError)?;
+    Ok(scheme.raw(raw))
+}
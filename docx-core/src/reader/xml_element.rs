@@ -69,11 +69,41 @@ pub enum XMLElement {
     BookmarkEnd,
     Comment,
     Comments,
+    Footnote,
+    Footnotes,
+    FootnoteReference,
+    Fonts,
+    Font,
+    Panose1,
+    Charset,
+    Family,
+    Pitch,
     CommentRangeStart,
     CommentRangeEnd,
     CommentExtended,
     Property,
     CommentsExtended,
+    TableLook,
+    TableStyleProperty,
+    TableCaption,
+    TableLayout,
+    TablePropertyExceptions,
+    TableDescription,
+    TableHeader,
+    TableRowHidden,
+    TableCellSpacing,
+    CantSplit,
+    CnfStyle,
+    NoWrap,
+    TableCellFitText,
+    HideMark,
+    CommentId,
+    CommentsIds,
+    CommentExtensible,
+    CommentsExtensible,
+    People,
+    Person,
+    PresenceInfo,
     VAlign,
     Shading,
     Strike,
@@ -81,6 +111,7 @@ pub enum XMLElement {
     Table,
     TableProperty,
     TableRow,
+    TableRowProperty,
     TableRowHeight,
     HeightRule,
     TableCell,
@@ -100,6 +131,34 @@ pub enum XMLElement {
     TablePropertyChange,
     TableRowPropertyChange,
     TableCellPropertyChange,
+    SectionPropertyChange,
+    CellInsert,
+    CellDelete,
+    CellMerge,
+    MoveFrom,
+    MoveTo,
+    MoveFromRangeStart,
+    MoveFromRangeEnd,
+    MoveToRangeStart,
+    MoveToRangeEnd,
+    TrackRevisions,
+    EmbedTrueTypeFonts,
+    SaveSubsetFonts,
+    Zoom,
+    UpdateFields,
+    MirrorMargins,
+    GutterAtTop,
+    BookFoldPrinting,
+    DisplayBackgroundShape,
+    AutoHyphenation,
+    HyphenationZone,
+    ConsecutiveHyphenLimit,
+    CompatSetting,
+    ThemeFontLang,
+    ProofState,
+    RsidRoot,
+    Rsid,
+    DefaultTableStyle,
     CellMargins,
     Top,
     Right,
@@ -138,6 +197,8 @@ pub enum XMLElement {
     LevelRestart,
     StyleLink,
     NumStyleLink,
+    NumPicBullet,
+    LevelPicBulletId,
     Drawing,
     TxbxContent,
     Pict,
@@ -146,6 +207,12 @@ pub enum XMLElement {
     DocVar,
     DocGrid,
     DocDefaults,
+    LatentStyles,
+    LatentStyleException,
+    UiPriority,
+    SemiHidden,
+    UnhideWhenUsed,
+    QFormat,
     DefaultTabStop,
     RunPropertyDefault,
     AdjustLineHeightInTable,
@@ -159,6 +226,8 @@ pub enum XMLElement {
     TitlePg,
     EvenAndOddHeaders,
     StructuredDataTag,
+    DataBinding,
+    Alias,
     Type,
     PageNumType,
     FrameProperty,
@@ -175,6 +244,26 @@ pub enum XMLElement {
     XAlign,
     Y,
     YAlign,
+    FormFieldData,
+    Enabled,
+    CalcOnExit,
+    HelpText,
+    StatusText,
+    TextInput,
+    MaxLength,
+    Format,
+    Default,
+    CheckBox,
+    CheckBoxSize,
+    SizeAuto,
+    Checked,
+    DropDownList,
+    DropDownResult,
+    ListEntry,
+    DocumentProtection,
+    WriteProtection,
+    PermStart,
+    PermEnd,
     Unsupported,
 }
 
@@ -219,6 +308,11 @@ pub enum AXMLElement {
     Ea,
     Cs,
     Font,
+    Theme,
+    ClrScheme,
+    FmtScheme,
+    SrgbClr,
+    SysClr,
     Unsupported,
 }
 
@@ -246,6 +340,13 @@ pub enum VXMLElement {
 
 pub enum VtXMLElement {
     Lpwstr,
+    Lpstr,
+    I4,
+    Int,
+    R8,
+    R4,
+    Bool,
+    Filetime,
     Unsupported,
 }
 
@@ -301,15 +402,46 @@ impl FromStr for XMLElement {
             "bookmarkEnd" => Ok(XMLElement::BookmarkEnd),
             "comments" => Ok(XMLElement::Comments),
             "comment" => Ok(XMLElement::Comment),
+            "footnotes" => Ok(XMLElement::Footnotes),
+            "footnote" => Ok(XMLElement::Footnote),
+            "footnoteReference" => Ok(XMLElement::FootnoteReference),
+            "fonts" => Ok(XMLElement::Fonts),
+            "font" => Ok(XMLElement::Font),
+            "panose1" => Ok(XMLElement::Panose1),
+            "charset" => Ok(XMLElement::Charset),
+            "family" => Ok(XMLElement::Family),
+            "pitch" => Ok(XMLElement::Pitch),
             "commentRangeStart" => Ok(XMLElement::CommentRangeStart),
             "commentRangeEnd" => Ok(XMLElement::CommentRangeEnd),
             "commentEx" => Ok(XMLElement::CommentExtended),
             "commentsEx" => Ok(XMLElement::CommentsExtended),
+            "commentId" => Ok(XMLElement::CommentId),
+            "commentsIds" => Ok(XMLElement::CommentsIds),
+            "commentExtensible" => Ok(XMLElement::CommentExtensible),
+            "commentsExtensible" => Ok(XMLElement::CommentsExtensible),
+            "people" => Ok(XMLElement::People),
+            "person" => Ok(XMLElement::Person),
+            "presenceInfo" => Ok(XMLElement::PresenceInfo),
             "shd" => Ok(XMLElement::Shading),
             "property" => Ok(XMLElement::Property),
             "tbl" => Ok(XMLElement::Table),
             "tblPr" => Ok(XMLElement::TableProperty),
             "tr" => Ok(XMLElement::TableRow),
+            "trPr" => Ok(XMLElement::TableRowProperty),
+            "tblLook" => Ok(XMLElement::TableLook),
+            "tblStylePr" => Ok(XMLElement::TableStyleProperty),
+            "tblCaption" => Ok(XMLElement::TableCaption),
+            "tblLayout" => Ok(XMLElement::TableLayout),
+            "tblPrEx" => Ok(XMLElement::TablePropertyExceptions),
+            "tblDescription" => Ok(XMLElement::TableDescription),
+            "tblHeader" => Ok(XMLElement::TableHeader),
+            "hidden" => Ok(XMLElement::TableRowHidden),
+            "tblCellSpacing" => Ok(XMLElement::TableCellSpacing),
+            "cantSplit" => Ok(XMLElement::CantSplit),
+            "cnfStyle" => Ok(XMLElement::CnfStyle),
+            "noWrap" => Ok(XMLElement::NoWrap),
+            "tcFitText" => Ok(XMLElement::TableCellFitText),
+            "hideMark" => Ok(XMLElement::HideMark),
             "trHeight" => Ok(XMLElement::TableRowHeight),
             "hRule" => Ok(XMLElement::HeightRule),
             "tc" => Ok(XMLElement::TableCell),
@@ -343,6 +475,34 @@ impl FromStr for XMLElement {
             "tblPrChange" => Ok(XMLElement::TablePropertyChange),
             "trPrChange" => Ok(XMLElement::TableRowPropertyChange),
             "tcPrChange" => Ok(XMLElement::TableCellPropertyChange),
+            "sectPrChange" => Ok(XMLElement::SectionPropertyChange),
+            "cellIns" => Ok(XMLElement::CellInsert),
+            "cellDel" => Ok(XMLElement::CellDelete),
+            "cellMerge" => Ok(XMLElement::CellMerge),
+            "moveFrom" => Ok(XMLElement::MoveFrom),
+            "moveTo" => Ok(XMLElement::MoveTo),
+            "moveFromRangeStart" => Ok(XMLElement::MoveFromRangeStart),
+            "moveFromRangeEnd" => Ok(XMLElement::MoveFromRangeEnd),
+            "moveToRangeStart" => Ok(XMLElement::MoveToRangeStart),
+            "moveToRangeEnd" => Ok(XMLElement::MoveToRangeEnd),
+            "trackRevisions" => Ok(XMLElement::TrackRevisions),
+            "embedTrueTypeFonts" => Ok(XMLElement::EmbedTrueTypeFonts),
+            "saveSubsetFonts" => Ok(XMLElement::SaveSubsetFonts),
+            "zoom" => Ok(XMLElement::Zoom),
+            "updateFields" => Ok(XMLElement::UpdateFields),
+            "mirrorMargins" => Ok(XMLElement::MirrorMargins),
+            "gutterAtTop" => Ok(XMLElement::GutterAtTop),
+            "bookFoldPrinting" => Ok(XMLElement::BookFoldPrinting),
+            "displayBackgroundShape" => Ok(XMLElement::DisplayBackgroundShape),
+            "autoHyphenation" => Ok(XMLElement::AutoHyphenation),
+            "hyphenationZone" => Ok(XMLElement::HyphenationZone),
+            "consecutiveHyphenLimit" => Ok(XMLElement::ConsecutiveHyphenLimit),
+            "compatSetting" => Ok(XMLElement::CompatSetting),
+            "themeFontLang" => Ok(XMLElement::ThemeFontLang),
+            "proofState" => Ok(XMLElement::ProofState),
+            "rsidRoot" => Ok(XMLElement::RsidRoot),
+            "rsid" => Ok(XMLElement::Rsid),
+            "defaultTableStyle" => Ok(XMLElement::DefaultTableStyle),
             "tcMar" => Ok(XMLElement::CellMargins),
             "tblGridChange" => Ok(XMLElement::TableGridChange),
             "gridCol" => Ok(XMLElement::GridCol),
@@ -371,6 +531,8 @@ impl FromStr for XMLElement {
             "outlineLvl" => Ok(XMLElement::OutlineLvl),
             "numStyleLink" => Ok(XMLElement::NumStyleLink),
             "styleLink" => Ok(XMLElement::StyleLink),
+            "numPicBullet" => Ok(XMLElement::NumPicBullet),
+            "lvlPicBulletId" => Ok(XMLElement::LevelPicBulletId),
             "vAlign" => Ok(XMLElement::VAlign),
             "drawing" => Ok(XMLElement::Drawing),
             "txbxContent" => Ok(XMLElement::TxbxContent),
@@ -386,6 +548,12 @@ impl FromStr for XMLElement {
             "rFonts" => Ok(XMLElement::RunFonts),
             "pgMar" => Ok(XMLElement::PageMargin),
             "docDefaults" => Ok(XMLElement::DocDefaults),
+            "latentStyles" => Ok(XMLElement::LatentStyles),
+            "lsdException" => Ok(XMLElement::LatentStyleException),
+            "uiPriority" => Ok(XMLElement::UiPriority),
+            "semiHidden" => Ok(XMLElement::SemiHidden),
+            "unhideWhenUsed" => Ok(XMLElement::UnhideWhenUsed),
+            "qFormat" => Ok(XMLElement::QFormat),
             "docGrid" => Ok(XMLElement::DocGrid),
             "rPrDefault" => Ok(XMLElement::RunPropertyDefault),
             "adjustLineHeightInTable" => Ok(XMLElement::AdjustLineHeightInTable),
@@ -409,6 +577,8 @@ impl FromStr for XMLElement {
             "titlePg" => Ok(XMLElement::TitlePg),
             "evenAndOddHeaders" => Ok(XMLElement::EvenAndOddHeaders),
             "sdt" => Ok(XMLElement::StructuredDataTag),
+            "dataBinding" => Ok(XMLElement::DataBinding),
+            "alias" => Ok(XMLElement::Alias),
             "pgNumType" => Ok(XMLElement::PageNumType),
             "framePr" => Ok(XMLElement::FrameProperty),
             "textAlignment" => Ok(XMLElement::TextAlignment),
@@ -425,6 +595,26 @@ impl FromStr for XMLElement {
             "y" => Ok(XMLElement::Y),
             "yAlign" => Ok(XMLElement::YAlign),
             "type" => Ok(XMLElement::Type),
+            "ffData" => Ok(XMLElement::FormFieldData),
+            "enabled" => Ok(XMLElement::Enabled),
+            "calcOnExit" => Ok(XMLElement::CalcOnExit),
+            "helpText" => Ok(XMLElement::HelpText),
+            "statusText" => Ok(XMLElement::StatusText),
+            "textInput" => Ok(XMLElement::TextInput),
+            "maxLength" => Ok(XMLElement::MaxLength),
+            "format" => Ok(XMLElement::Format),
+            "default" => Ok(XMLElement::Default),
+            "checkBox" => Ok(XMLElement::CheckBox),
+            "size" => Ok(XMLElement::CheckBoxSize),
+            "sizeAuto" => Ok(XMLElement::SizeAuto),
+            "checked" => Ok(XMLElement::Checked),
+            "ddList" => Ok(XMLElement::DropDownList),
+            "result" => Ok(XMLElement::DropDownResult),
+            "listEntry" => Ok(XMLElement::ListEntry),
+            "documentProtection" => Ok(XMLElement::DocumentProtection),
+            "writeProtection" => Ok(XMLElement::WriteProtection),
+            "permStart" => Ok(XMLElement::PermStart),
+            "permEnd" => Ok(XMLElement::PermEnd),
             _ => Ok(XMLElement::Unsupported),
         }
     }
@@ -481,6 +671,11 @@ impl FromStr for AXMLElement {
             "ea" => Ok(AXMLElement::Ea),
             "cs" => Ok(AXMLElement::Cs),
             "font" => Ok(AXMLElement::Font),
+            "theme" => Ok(AXMLElement::Theme),
+            "clrScheme" => Ok(AXMLElement::ClrScheme),
+            "fmtScheme" => Ok(AXMLElement::FmtScheme),
+            "srgbClr" => Ok(AXMLElement::SrgbClr),
+            "sysClr" => Ok(AXMLElement::SysClr),
             _ => Ok(AXMLElement::Unsupported),
         }
     }
@@ -521,6 +716,13 @@ impl FromStr for VtXMLElement {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "lpwstr" => Ok(VtXMLElement::Lpwstr),
+            "lpstr" => Ok(VtXMLElement::Lpstr),
+            "i4" => Ok(VtXMLElement::I4),
+            "int" => Ok(VtXMLElement::Int),
+            "r8" => Ok(VtXMLElement::R8),
+            "r4" => Ok(VtXMLElement::R4),
+            "bool" => Ok(VtXMLElement::Bool),
+            "filetime" => Ok(VtXMLElement::Filetime),
             _ => Ok(VtXMLElement::Unsupported),
         }
     }
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see https://c-rex.net/projects/samples/ooxml/e1/Part4/OOXML_P4_DOCX_ST_AnnotationVMerge_topic_ID0EMZ3X.html
+//
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum CellMergeType {
+    // The cell is merged with the cell above.
+    Continue,
+    // The cell starts a new vertical merge.
+    Restart,
+}
+
+impl fmt::Display for CellMergeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CellMergeType::Continue => write!(f, "cont"),
+            CellMergeType::Restart => write!(f, "rest"),
+        }
+    }
+}
+
+impl FromStr for CellMergeType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cont" => Ok(CellMergeType::Continue),
+            "rest" => Ok(CellMergeType::Restart),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see https://c-rex.net/projects/samples/ooxml/e1/Part4/OOXML_P4_DOCX_ST_DocProtect_topic_ID0EPZ4Y.html
+//
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum DocumentProtectionType {
+    None,
+    ReadOnly,
+    Comments,
+    TrackedChanges,
+    Forms,
+}
+
+impl fmt::Display for DocumentProtectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DocumentProtectionType::None => write!(f, "none"),
+            DocumentProtectionType::ReadOnly => write!(f, "readOnly"),
+            DocumentProtectionType::Comments => write!(f, "comments"),
+            DocumentProtectionType::TrackedChanges => write!(f, "trackedChanges"),
+            DocumentProtectionType::Forms => write!(f, "forms"),
+        }
+    }
+}
+
+impl FromStr for DocumentProtectionType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(DocumentProtectionType::None),
+            "readOnly" => Ok(DocumentProtectionType::ReadOnly),
+            "comments" => Ok(DocumentProtectionType::Comments),
+            "trackedChanges" => Ok(DocumentProtectionType::TrackedChanges),
+            "forms" => Ok(DocumentProtectionType::Forms),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see https://c-rex.net/projects/samples/ooxml/e1/Part4/OOXML_P4_DOCX_ST_EdGrp_topic_ID0E6OMY.html
+//
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum EditGroupType {
+    None,
+    Everyone,
+    Administrators,
+    Contributors,
+    Editors,
+    Owners,
+    Current,
+}
+
+impl fmt::Display for EditGroupType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EditGroupType::None => write!(f, "none"),
+            EditGroupType::Everyone => write!(f, "everyone"),
+            EditGroupType::Administrators => write!(f, "administrators"),
+            EditGroupType::Contributors => write!(f, "contributors"),
+            EditGroupType::Editors => write!(f, "editors"),
+            EditGroupType::Owners => write!(f, "owners"),
+            EditGroupType::Current => write!(f, "current"),
+        }
+    }
+}
+
+impl FromStr for EditGroupType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(EditGroupType::None),
+            "everyone" => Ok(EditGroupType::Everyone),
+            "administrators" => Ok(EditGroupType::Administrators),
+            "contributors" => Ok(EditGroupType::Contributors),
+            "editors" => Ok(EditGroupType::Editors),
+            "owners" => Ok(EditGroupType::Owners),
+            "current" => Ok(EditGroupType::Current),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::Serialize;
+use std::fmt;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+// Style of an embedded font, written as w:embedRegular, w:embedBold, w:embedItalic
+// or w:embedBoldItalic.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FontEmbedType {
+    Regular,
+    Bold,
+    Italic,
+    BoldItalic,
+}
+
+impl fmt::Display for FontEmbedType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FontEmbedType::Regular => write!(f, "regular"),
+            FontEmbedType::Bold => write!(f, "bold"),
+            FontEmbedType::Italic => write!(f, "italic"),
+            FontEmbedType::BoldItalic => write!(f, "boldItalic"),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+// Whether the spelling or grammar of the document has been checked since the last edit.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProofStateType {
+    Clean,
+    Dirty,
+}
+
+impl fmt::Display for ProofStateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ProofStateType::Clean => write!(f, "clean"),
+            ProofStateType::Dirty => write!(f, "dirty"),
+        }
+    }
+}
+
+impl FromStr for ProofStateType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "clean" => Ok(ProofStateType::Clean),
+            "dirty" => Ok(ProofStateType::Dirty),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+use std::str::FromStr;
+
+// Conditional formatting regions of a table style (w:tblStylePr w:type).
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TableStyleOverrideType {
+    WholeTable,
+    FirstRow,
+    LastRow,
+    FirstCol,
+    LastCol,
+    Band1Vert,
+    Band2Vert,
+    Band1Horz,
+    Band2Horz,
+    NeCell,
+    NwCell,
+    SeCell,
+    SwCell,
+}
+
+impl fmt::Display for TableStyleOverrideType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TableStyleOverrideType::WholeTable => write!(f, "wholeTable"),
+            TableStyleOverrideType::FirstRow => write!(f, "firstRow"),
+            TableStyleOverrideType::LastRow => write!(f, "lastRow"),
+            TableStyleOverrideType::FirstCol => write!(f, "firstCol"),
+            TableStyleOverrideType::LastCol => write!(f, "lastCol"),
+            TableStyleOverrideType::Band1Vert => write!(f, "band1Vert"),
+            TableStyleOverrideType::Band2Vert => write!(f, "band2Vert"),
+            TableStyleOverrideType::Band1Horz => write!(f, "band1Horz"),
+            TableStyleOverrideType::Band2Horz => write!(f, "band2Horz"),
+            TableStyleOverrideType::NeCell => write!(f, "neCell"),
+            TableStyleOverrideType::NwCell => write!(f, "nwCell"),
+            TableStyleOverrideType::SeCell => write!(f, "seCell"),
+            TableStyleOverrideType::SwCell => write!(f, "swCell"),
+        }
+    }
+}
+
+impl FromStr for TableStyleOverrideType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wholeTable" => Ok(TableStyleOverrideType::WholeTable),
+            "firstRow" => Ok(TableStyleOverrideType::FirstRow),
+            "lastRow" => Ok(TableStyleOverrideType::LastRow),
+            "firstCol" => Ok(TableStyleOverrideType::FirstCol),
+            "lastCol" => Ok(TableStyleOverrideType::LastCol),
+            "band1Vert" => Ok(TableStyleOverrideType::Band1Vert),
+            "band2Vert" => Ok(TableStyleOverrideType::Band2Vert),
+            "band1Horz" => Ok(TableStyleOverrideType::Band1Horz),
+            "band2Horz" => Ok(TableStyleOverrideType::Band2Horz),
+            "neCell" => Ok(TableStyleOverrideType::NeCell),
+            "nwCell" => Ok(TableStyleOverrideType::NwCell),
+            "seCell" => Ok(TableStyleOverrideType::SeCell),
+            "swCell" => Ok(TableStyleOverrideType::SwCell),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+/*
+<xsd:enumeration value="dark1"/>
+<xsd:enumeration value="light1"/>
+<xsd:enumeration value="dark2"/>
+<xsd:enumeration value="light2"/>
+<xsd:enumeration value="accent1"/>
+<xsd:enumeration value="accent2"/>
+<xsd:enumeration value="accent3"/>
+<xsd:enumeration value="accent4"/>
+<xsd:enumeration value="accent5"/>
+<xsd:enumeration value="accent6"/>
+<xsd:enumeration value="hyperlink"/>
+<xsd:enumeration value="followedHyperlink"/>
+<xsd:enumeration value="none"/>
+<xsd:enumeration value="background1"/>
+<xsd:enumeration value="text1"/>
+<xsd:enumeration value="background2"/>
+<xsd:enumeration value="text2"/>
+*/
+#[cfg_attr(feature = "wasm", wasm_bindgen, derive(ts_rs::TS), ts(export))]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ThemeColorType {
+    Dark1,
+    Light1,
+    Dark2,
+    Light2,
+    Accent1,
+    Accent2,
+    Accent3,
+    Accent4,
+    Accent5,
+    Accent6,
+    Hyperlink,
+    FollowedHyperlink,
+    None,
+    Background1,
+    Text1,
+    Background2,
+    Text2,
+}
+
+impl ThemeColorType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match *self {
+            ThemeColorType::Dark1 => "dark1",
+            ThemeColorType::Light1 => "light1",
+            ThemeColorType::Dark2 => "dark2",
+            ThemeColorType::Light2 => "light2",
+            ThemeColorType::Accent1 => "accent1",
+            ThemeColorType::Accent2 => "accent2",
+            ThemeColorType::Accent3 => "accent3",
+            ThemeColorType::Accent4 => "accent4",
+            ThemeColorType::Accent5 => "accent5",
+            ThemeColorType::Accent6 => "accent6",
+            ThemeColorType::Hyperlink => "hyperlink",
+            ThemeColorType::FollowedHyperlink => "followedHyperlink",
+            ThemeColorType::None => "none",
+            ThemeColorType::Background1 => "background1",
+            ThemeColorType::Text1 => "text1",
+            ThemeColorType::Background2 => "background2",
+            ThemeColorType::Text2 => "text2",
+        }
+    }
+}
+
+impl fmt::Display for ThemeColorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ThemeColorType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dark1" => Ok(ThemeColorType::Dark1),
+            "light1" => Ok(ThemeColorType::Light1),
+            "dark2" => Ok(ThemeColorType::Dark2),
+            "light2" => Ok(ThemeColorType::Light2),
+            "accent1" => Ok(ThemeColorType::Accent1),
+            "accent2" => Ok(ThemeColorType::Accent2),
+            "accent3" => Ok(ThemeColorType::Accent3),
+            "accent4" => Ok(ThemeColorType::Accent4),
+            "accent5" => Ok(ThemeColorType::Accent5),
+            "accent6" => Ok(ThemeColorType::Accent6),
+            "hyperlink" => Ok(ThemeColorType::Hyperlink),
+            "followedHyperlink" => Ok(ThemeColorType::FollowedHyperlink),
+            "background1" => Ok(ThemeColorType::Background1),
+            "text1" => Ok(ThemeColorType::Text1),
+            "background2" => Ok(ThemeColorType::Background2),
+            "text2" => Ok(ThemeColorType::Text2),
+            _ => Ok(ThemeColorType::None),
+        }
+    }
+}
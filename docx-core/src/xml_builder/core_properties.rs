@@ -21,4 +21,7 @@ impl XMLBuilder {
     closed_with_child!(cp_revision, "cp:revision");
     closed_with_child!(dc_subject, "dc:subject");
     closed_with_child!(dc_title, "dc:title");
+    closed_with_child!(cp_keywords, "cp:keywords");
+    closed_with_child!(cp_category, "cp:category");
+    closed_with_child!(cp_content_status, "cp:contentStatus");
 }
@@ -4,5 +4,17 @@ use super::XmlEvent;
 impl XMLBuilder {
     open!(open_custom_properties, "Properties", "xmlns", "xmlns:vt");
     open!(open_property, "property", "fmtid", "pid", "name");
+    open!(
+        open_linked_property,
+        "property",
+        "fmtid",
+        "pid",
+        "name",
+        "linkTarget"
+    );
     closed_with_child!(lpwstr, "vt:lpwstr");
+    closed_with_child!(i4, "vt:i4");
+    closed_with_child!(r8, "vt:r8");
+    closed_with_child!(vt_bool, "vt:bool");
+    closed_with_child!(filetime, "vt:filetime");
 }
@@ -2,10 +2,34 @@ use super::XMLBuilder;
 use super::XmlEvent;
 use crate::types::*;
 use crate::FrameProperty;
+use crate::PasswordHash;
 use crate::TablePositionProperty;
+use crate::ThemeColor;
+use xml::writer::events::StartElementBuilder;
 
 const EXPECT_MESSAGE: &str = "should write buf";
 
+const THEME_COLOR_ATTRS: [&str; 3] = ["w:themeColor", "w:themeTint", "w:themeShade"];
+const THEME_FILL_ATTRS: [&str; 3] = ["w:themeFill", "w:themeFillTint", "w:themeFillShade"];
+
+// Adds the theme reference of a color as `names`, i.e. w:themeColor, w:themeTint and w:themeShade.
+fn with_theme_color<'a>(
+    mut e: StartElementBuilder<'a>,
+    names: [&'a str; 3],
+    theme: Option<&'a ThemeColor>,
+) -> StartElementBuilder<'a> {
+    if let Some(t) = theme {
+        e = e.attr(names[0], t.color.as_str());
+        if let Some(ref tint) = t.tint {
+            e = e.attr(names[1], tint);
+        }
+        if let Some(ref shade) = t.shade {
+            e = e.attr(names[2], shade);
+        }
+    }
+    e
+}
+
 impl XMLBuilder {
     // i.e. <w:body... >
     open!(open_body, "w:body");
@@ -132,6 +156,9 @@ impl XMLBuilder {
     open!(open_paragraph_property_default, "w:pPrDefault");
     // i.e. <w:qFormat ... >
     closed!(q_format, "w:qFormat");
+    closed_with_usize!(ui_priority, "w:uiPriority");
+    closed!(semi_hidden, "w:semiHidden");
+    closed!(unhide_when_used, "w:unhideWhenUsed");
     // i.e. <w:p ... >
     // open!(open_paragraph, "w:p");
     open!(open_paragraph, "w:p", "w14:paraId");
@@ -170,6 +197,29 @@ impl XMLBuilder {
     closed_with_str!(text_alignment, "w:textAlignment");
 
     closed!(field_character, "w:fldChar", "w:fldCharType", "w:dirty");
+    open!(
+        open_field_character,
+        "w:fldChar",
+        "w:fldCharType",
+        "w:dirty"
+    );
+
+    open!(open_form_field_data, "w:ffData");
+    closed!(form_field_enabled, "w:enabled", "w:val");
+    closed_with_str!(calc_on_exit, "w:calcOnExit");
+    closed!(help_text, "w:helpText", "w:type", "w:val");
+    closed!(status_text, "w:statusText", "w:type", "w:val");
+    open!(open_text_input, "w:textInput");
+    closed_with_str!(form_field_default, "w:default");
+    closed_with_usize!(max_length, "w:maxLength");
+    closed_with_str!(text_input_format, "w:format");
+    open!(open_check_box, "w:checkBox");
+    closed!(size_auto, "w:sizeAuto");
+    closed_with_usize!(check_box_size, "w:size");
+    closed_with_str!(checked, "w:checked");
+    open!(open_drop_down_list, "w:ddList");
+    closed_with_usize!(drop_down_result, "w:result");
+    closed_with_str!(list_entry, "w:listEntry");
 
     open!(open_instr_text, "w:instrText");
     open!(open_delete_instr_text, "w:delInstrText");
@@ -188,13 +238,14 @@ impl XMLBuilder {
 
     // Build w:style element
     // i.e. <w:style ... >
-    pub(crate) fn open_style(mut self, style_type: StyleType, id: &str) -> Self {
+    pub(crate) fn open_style(mut self, style_type: StyleType, id: &str, default: bool) -> Self {
+        let style_type = style_type.to_string();
+        let mut e = XmlEvent::start_element("w:style").attr("w:type", &style_type);
+        if default {
+            e = e.attr("w:default", "1");
+        }
         self.writer
-            .write(
-                XmlEvent::start_element("w:style")
-                    .attr("w:type", &style_type.to_string())
-                    .attr("w:styleId", id),
-            )
+            .write(e.attr("w:styleId", id))
             .expect(EXPECT_MESSAGE);
         self
     }
@@ -204,7 +255,13 @@ impl XMLBuilder {
     closed_with_str!(link, "w:link");
 
     // i.e. <w:color ... >
-    closed_with_str!(color, "w:color");
+    pub(crate) fn color(mut self, val: &str, theme: Option<&ThemeColor>) -> Self {
+        let e = XmlEvent::start_element("w:color").attr("w:val", val);
+        self.writer
+            .write(with_theme_color(e, THEME_COLOR_ATTRS, theme))
+            .expect(EXPECT_MESSAGE);
+        self.close()
+    }
 
     // i.e. <w:highlight ... >
     closed_with_str!(highlight, "w:highlight");
@@ -317,6 +374,7 @@ impl XMLBuilder {
     open!(open_table_grid, "w:tblGrid");
     open!(open_table_row, "w:tr");
     open!(open_table_row_property, "w:trPr");
+    open!(open_table_property_exceptions, "w:tblPrEx");
     open!(open_table_cell, "w:tc");
     open!(open_table_cell_property, "w:tcPr");
     open!(open_table_cell_borders, "w:tcBorders");
@@ -326,12 +384,37 @@ impl XMLBuilder {
 
     closed!(table_layout, "w:tblLayout", "w:type");
     closed_with_str!(table_style, "w:tblStyle");
+    closed!(
+        table_look,
+        "w:tblLook",
+        "w:val",
+        "w:firstRow",
+        "w:lastRow",
+        "w:firstColumn",
+        "w:lastColumn",
+        "w:noHBand",
+        "w:noVBand"
+    );
+    open!(open_table_style_property, "w:tblStylePr", "w:type");
     closed_w_with_type_el!(table_width, "w:tblW");
     closed_w_with_type_el!(table_indent, "w:tblInd");
+    closed_w_with_type_el!(table_cell_spacing, "w:tblCellSpacing");
+    closed_with_str!(table_caption, "w:tblCaption");
+    closed_with_str!(table_description, "w:tblDescription");
     closed_w_with_type_el!(grid_column, "w:gridCol");
     closed_w_with_type_el!(table_cell_width, "w:tcW");
 
+    closed_with_usize!(grid_before, "w:gridBefore");
+    closed_with_usize!(grid_after, "w:gridAfter");
+    closed_w_with_type_el!(width_before, "w:wBefore");
+    closed_w_with_type_el!(width_after, "w:wAfter");
     closed!(table_row_height, "w:trHeight", "w:val", "w:hRule");
+    closed!(table_header, "w:tblHeader");
+    closed!(table_row_hidden, "w:hidden");
+    closed_with_str!(cnf_style, "w:cnfStyle");
+    closed!(no_wrap, "w:noWrap");
+    closed!(table_cell_fit_text, "w:tcFitText");
+    closed!(hide_mark, "w:hideMark");
 
     closed_with_usize!(grid_span, "w:gridSpan");
     closed_with_str!(vertical_merge, "w:vMerge");
@@ -353,7 +436,24 @@ impl XMLBuilder {
 
     closed_border_el!(text_border, "w:bdr");
 
-    closed!(shd, "w:shd", "w:val", "w:color", "w:fill");
+    pub(crate) fn shd(
+        mut self,
+        shd_type: &str,
+        color: &str,
+        fill: &str,
+        theme_color: Option<&ThemeColor>,
+        theme_fill: Option<&ThemeColor>,
+    ) -> Self {
+        let e = XmlEvent::start_element("w:shd")
+            .attr("w:val", shd_type)
+            .attr("w:color", color)
+            .attr("w:fill", fill);
+        let e = with_theme_color(e, THEME_COLOR_ATTRS, theme_color);
+        self.writer
+            .write(with_theme_color(e, THEME_FILL_ATTRS, theme_fill))
+            .expect(EXPECT_MESSAGE);
+        self.close()
+    }
 
     closed!(tab_with_pos, "w:tab", "w:val", "w:pos");
 
@@ -366,6 +466,24 @@ impl XMLBuilder {
     closed_with_str!(pitch, "w:pitch");
     closed_with_str!(family, "w:family");
     closed_with_str!(charset, "w:charset");
+    closed_with_str!(panose, "w:panose1");
+
+    pub(crate) fn embed_font(mut self, t: FontEmbedType, id: &str, font_key: &str) -> Self {
+        let name = match t {
+            FontEmbedType::Regular => "w:embedRegular",
+            FontEmbedType::Bold => "w:embedBold",
+            FontEmbedType::Italic => "w:embedItalic",
+            FontEmbedType::BoldItalic => "w:embedBoldItalic",
+        };
+        self.writer
+            .write(
+                XmlEvent::start_element(name)
+                    .attr("r:id", id)
+                    .attr("w:fontKey", font_key),
+            )
+            .expect(EXPECT_MESSAGE);
+        self.close()
+    }
 
     open!(open_section_property, "w:sectPr");
     closed!(header_reference, "w:headerReference", "w:type", "r:id");
@@ -400,12 +518,123 @@ impl XMLBuilder {
         "w:author",
         "w:date"
     );
+    open!(
+        open_run_property_change,
+        "w:rPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
+    open!(
+        open_table_property_change,
+        "w:tblPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
+    open!(
+        open_table_row_property_change,
+        "w:trPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
+    open!(
+        open_table_cell_property_change,
+        "w:tcPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
+    open!(
+        open_section_property_change,
+        "w:sectPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
+    closed!(cell_insert, "w:cellIns", "w:id", "w:author", "w:date");
+    closed!(cell_delete, "w:cellDel", "w:id", "w:author", "w:date");
+
+    pub(crate) fn cell_merge(
+        mut self,
+        id: &str,
+        author: &str,
+        date: &str,
+        v_merge: Option<CellMergeType>,
+        v_merge_orig: Option<CellMergeType>,
+    ) -> Self {
+        let v_merge = v_merge.map(|v| v.to_string());
+        let v_merge_orig = v_merge_orig.map(|v| v.to_string());
+        let mut e = XmlEvent::start_element("w:cellMerge")
+            .attr("w:id", id)
+            .attr("w:author", author)
+            .attr("w:date", date);
+        if let Some(ref v) = v_merge {
+            e = e.attr("w:vMerge", v);
+        }
+        if let Some(ref v) = v_merge_orig {
+            e = e.attr("w:vMergeOrig", v);
+        }
+        self.writer.write(e).expect(EXPECT_MESSAGE);
+        self.close()
+    }
+
+    open!(open_move_from, "w:moveFrom", "w:id", "w:author", "w:date");
+    open!(open_move_to, "w:moveTo", "w:id", "w:author", "w:date");
+    closed!(
+        move_from_range_start,
+        "w:moveFromRangeStart",
+        "w:id",
+        "w:name",
+        "w:author",
+        "w:date"
+    );
+    closed!(move_from_range_end, "w:moveFromRangeEnd", "w:id");
+    closed!(
+        move_to_range_start,
+        "w:moveToRangeStart",
+        "w:id",
+        "w:name",
+        "w:author",
+        "w:date"
+    );
+    closed!(move_to_range_end, "w:moveToRangeEnd", "w:id");
     // cantSplit for table row
     closed!(cant_split, "w:cantSplit");
 
     closed!(bookmark_start, "w:bookmarkStart", "w:id", "w:name");
     closed!(bookmark_end, "w:bookmarkEnd", "w:id");
 
+    pub(crate) fn perm_start(
+        mut self,
+        id: &str,
+        ed: Option<&String>,
+        ed_grp: Option<EditGroupType>,
+        col_first: Option<usize>,
+        col_last: Option<usize>,
+    ) -> Self {
+        let ed_grp = ed_grp.map(|g| g.to_string());
+        let col_first = col_first.map(|c| format!("{}", c));
+        let col_last = col_last.map(|c| format!("{}", c));
+        let mut e = XmlEvent::start_element("w:permStart").attr("w:id", id);
+        if let Some(ed) = ed {
+            e = e.attr("w:ed", ed);
+        }
+        if let Some(ref ed_grp) = ed_grp {
+            e = e.attr("w:edGrp", ed_grp);
+        }
+        if let Some(ref c) = col_first {
+            e = e.attr("w:colFirst", c);
+        }
+        if let Some(ref c) = col_last {
+            e = e.attr("w:colLast", c);
+        }
+        self.writer.write(e).expect(EXPECT_MESSAGE);
+        self.close()
+    }
+    closed!(perm_end, "w:permEnd", "w:id");
+
     closed!(comment_range_start, "w:commentRangeStart", "w:id");
     closed!(comment_range_end, "w:commentRangeEnd", "w:id");
     closed!(comment_reference, "w:commentReference", "w:id");
@@ -456,6 +685,9 @@ impl XMLBuilder {
     closed!(ul_trail_space, "w:ulTrailSpace");
     closed!(do_not_expand_shift_return, "w:doNotExpandShiftReturn");
     closed!(adjust_line_height_table, "w:adjustLineHeightInTable");
+    closed!(track_revisions, "w:trackRevisions");
+    closed!(embed_true_type_fonts, "w:embedTrueTypeFonts");
+    closed!(save_subset_fonts, "w:saveSubsetFonts");
     closed!(
         character_spacing_control,
         "w:characterSpacingControl",
@@ -488,6 +720,94 @@ impl XMLBuilder {
     open!(open_doc_vars, "w:docVars");
     closed!(doc_var, "w:docVar", "w:name", "w:val");
 
+    closed!(update_fields, "w:updateFields", "w:val");
+    closed!(mirror_margins, "w:mirrorMargins");
+    closed!(gutter_at_top, "w:gutterAtTop");
+    closed!(book_fold_printing, "w:bookFoldPrinting");
+    closed!(display_background_shape, "w:displayBackgroundShape");
+    closed!(auto_hyphenation, "w:autoHyphenation");
+    closed_with_usize!(consecutive_hyphen_limit, "w:consecutiveHyphenLimit");
+    closed_with_usize!(hyphenation_zone, "w:hyphenationZone");
+    closed_with_str!(default_table_style, "w:defaultTableStyle");
+
+    open!(open_rsids, "w:rsids");
+    closed_with_str!(rsid_root, "w:rsidRoot");
+    closed_with_str!(rsid, "w:rsid");
+
+    pub(crate) fn proof_state(mut self, spelling: Option<&str>, grammar: Option<&str>) -> Self {
+        let mut e = XmlEvent::start_element("w:proofState");
+        if let Some(spelling) = spelling {
+            e = e.attr("w:spelling", spelling);
+        }
+        if let Some(grammar) = grammar {
+            e = e.attr("w:grammar", grammar);
+        }
+        self.writer.write(e).expect(EXPECT_MESSAGE);
+        self.close()
+    }
+
+    pub(crate) fn theme_font_lang(
+        mut self,
+        val: Option<&str>,
+        east_asia: Option<&str>,
+        bidi: Option<&str>,
+    ) -> Self {
+        let mut e = XmlEvent::start_element("w:themeFontLang");
+        if let Some(val) = val {
+            e = e.attr("w:val", val);
+        }
+        if let Some(east_asia) = east_asia {
+            e = e.attr("w:eastAsia", east_asia);
+        }
+        if let Some(bidi) = bidi {
+            e = e.attr("w:bidi", bidi);
+        }
+        self.writer.write(e).expect(EXPECT_MESSAGE);
+        self.close()
+    }
+
+    pub(crate) fn document_protection(
+        mut self,
+        edit: &str,
+        enforcement: &str,
+        hash: Option<&PasswordHash>,
+    ) -> Self {
+        let spin_count = hash.map(|h| format!("{}", h.spin_count));
+        let mut e = XmlEvent::start_element("w:documentProtection")
+            .attr("w:edit", edit)
+            .attr("w:enforcement", enforcement);
+        if let (Some(h), Some(spin_count)) = (hash, spin_count.as_ref()) {
+            e = e
+                .attr("w:algorithmName", &h.algorithm_name)
+                .attr("w:hashValue", &h.hash_value)
+                .attr("w:saltValue", &h.salt_value)
+                .attr("w:spinCount", spin_count);
+        }
+        self.writer.write(e).expect(EXPECT_MESSAGE);
+        self.close()
+    }
+
+    pub(crate) fn write_protection(
+        mut self,
+        recommended: bool,
+        hash: Option<&PasswordHash>,
+    ) -> Self {
+        let spin_count = hash.map(|h| format!("{}", h.spin_count));
+        let mut e = XmlEvent::start_element("w:writeProtection");
+        if recommended {
+            e = e.attr("w:recommended", "1");
+        }
+        if let (Some(h), Some(spin_count)) = (hash, spin_count.as_ref()) {
+            e = e
+                .attr("w:algorithmName", &h.algorithm_name)
+                .attr("w:hashValue", &h.hash_value)
+                .attr("w:saltValue", &h.salt_value)
+                .attr("w:spinCount", spin_count);
+        }
+        self.writer.write(e).expect(EXPECT_MESSAGE);
+        self.close()
+    }
+
     // webextension
     open!(open_webextension, "we:webextension", "xmlns:we", "id");
     closed!(
@@ -785,7 +1105,7 @@ mod tests {
     fn test_declaration() {
         let b = XMLBuilder::new();
         let r = b
-            .open_style(StyleType::Paragraph, "Heading")
+            .open_style(StyleType::Paragraph, "Heading", false)
             .close()
             .build();
         assert_eq!(
@@ -811,7 +1131,7 @@ mod tests {
     #[test]
     fn test_color() {
         let b = XMLBuilder::new();
-        let r = b.color("2E74B5").build();
+        let r = b.color("2E74B5", None).build();
         assert_eq!(str::from_utf8(&r).unwrap(), r#"<w:color w:val="2E74B5" />"#);
     }
 
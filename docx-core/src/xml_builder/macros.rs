@@ -556,15 +556,18 @@ macro_rules! closed_border_el {
             size: usize,
             space: usize,
             color: &str,
+            theme: Option<&ThemeColor>,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr("w:val", &val.to_string())
-                        .attr("w:sz", &format!("{}", size))
-                        .attr("w:space", &format!("{}", space))
-                        .attr("w:color", color),
-                )
+            let val = val.to_string();
+            let size = format!("{}", size);
+            let space = format!("{}", space);
+            let e = XmlEvent::start_element($el_name)
+                .attr("w:val", &val)
+                .attr("w:sz", &size)
+                .attr("w:space", &space)
+                .attr("w:color", color);
+            self.writer
+                .write(with_theme_color(e, THEME_COLOR_ATTRS, theme))
                 .expect(EXPECT_MESSAGE);
             self.close()
         }
@@ -573,15 +576,21 @@ macro_rules! closed_border_el {
 
 macro_rules! closed_paragraph_border_el {
     ($name: ident, $ el_name: expr) => {
-        pub(crate) fn $name<'a>(mut self, val: &str, space: &str, size: &str, color: &str) -> Self {
+        pub(crate) fn $name<'a>(
+            mut self,
+            val: &str,
+            space: &str,
+            size: &str,
+            color: &str,
+            theme: Option<&ThemeColor>,
+        ) -> Self {
+            let e = XmlEvent::start_element($el_name)
+                .attr("w:val", val)
+                .attr("w:space", space)
+                .attr("w:sz", size)
+                .attr("w:color", color);
             self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr("w:val", val)
-                        .attr("w:space", space)
-                        .attr("w:sz", size)
-                        .attr("w:color", color),
-                )
+                .write(with_theme_color(e, THEME_COLOR_ATTRS, theme))
                 .expect(EXPECT_MESSAGE);
             self.close()
         }
@@ -14,10 +14,13 @@ mod footer;
 mod footnotes;
 mod header;
 mod numbering;
+mod people;
 mod pic;
 mod properties;
 mod relationship;
 mod settings;
+mod signature;
+mod theme;
 
 mod styles;
 
@@ -20,4 +20,17 @@ impl XMLBuilder {
             .expect("should write to buf");
         self
     }
+
+    open!(open_num_pic_bullet, "w:numPicBullet", "w:numPicBulletId");
+    open!(open_pict, "w:pict");
+    open!(
+        open_bullet_shape,
+        "v:shape",
+        "id",
+        "type",
+        "style",
+        "o:bullet"
+    );
+    closed!(image_data, "v:imagedata", "r:id", "o:title");
+    closed_with_usize!(level_pic_bullet_id, "w:lvlPicBulletId");
 }
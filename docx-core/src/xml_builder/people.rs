@@ -0,0 +1,74 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+
+impl XMLBuilder {
+    // Build people element
+    // i.e. <w15:people xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml">
+    pub(crate) fn open_people(mut self) -> Self {
+        self.writer
+            .write(
+                XmlEvent::start_element("w15:people")
+                    .attr(
+                        "xmlns:w",
+                        "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+                    )
+                    .attr(
+                        "xmlns:w15",
+                        "http://schemas.microsoft.com/office/word/2012/wordml",
+                    ),
+            )
+            .expect("should write to buf");
+        self
+    }
+
+    open!(open_person, "w15:person", "w15:author");
+    closed!(
+        presence_info,
+        "w15:presenceInfo",
+        "w15:providerId",
+        "w15:userId"
+    );
+
+    // Build commentsIds element
+    // i.e. <w16cid:commentsIds xmlns:w16cid="http://schemas.microsoft.com/office/word/2016/wordml/cid">
+    pub(crate) fn open_comments_ids(mut self) -> Self {
+        self.writer
+            .write(XmlEvent::start_element("w16cid:commentsIds").attr(
+                "xmlns:w16cid",
+                "http://schemas.microsoft.com/office/word/2016/wordml/cid",
+            ))
+            .expect("should write to buf");
+        self
+    }
+
+    closed!(
+        comment_id,
+        "w16cid:commentId",
+        "w16cid:paraId",
+        "w16cid:durableId"
+    );
+
+    // Build commentsExtensible element
+    // i.e. <w16cex:commentsExtensible xmlns:w16cex="http://schemas.microsoft.com/office/word/2018/wordml/cex">
+    pub(crate) fn open_comments_extensible(mut self) -> Self {
+        self.writer
+            .write(XmlEvent::start_element("w16cex:commentsExtensible").attr(
+                "xmlns:w16cex",
+                "http://schemas.microsoft.com/office/word/2018/wordml/cex",
+            ))
+            .expect("should write to buf");
+        self
+    }
+
+    closed!(
+        comment_extensible,
+        "w16cex:commentExtensible",
+        "w16cex:durableId",
+        "w16cex:dateUtc"
+    );
+    closed!(
+        comment_extensible_without_date,
+        "w16cex:commentExtensible",
+        "w16cex:durableId"
+    );
+}
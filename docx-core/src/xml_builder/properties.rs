@@ -14,6 +14,10 @@ impl XMLBuilder {
     closed_with_child!(characters, "Characters");
     closed_with_child!(characters_with_spaces, "CharactersWithSpaces");
     closed_with_child!(paragraphs, "Paragraphs");
+    closed_with_child!(lines, "Lines");
+    closed_with_child!(manager, "Manager");
+    closed_with_child!(company, "Company");
+    closed_with_child!(app_version, "AppVersion");
 }
 
 #[cfg(test)]
@@ -0,0 +1,62 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+
+impl XMLBuilder {
+    // Build Signature element
+    // i.e. <Signature xmlns="http://www.w3.org/2000/09/xmldsig#" Id="idPackageSignature">
+    open!(open_signature, "Signature", "xmlns", "Id");
+    open!(open_signed_info, "SignedInfo");
+    closed!(
+        canonicalization_method,
+        "CanonicalizationMethod",
+        "Algorithm"
+    );
+    closed!(signature_method, "SignatureMethod", "Algorithm");
+    open!(open_object_reference, "Reference", "Type", "URI");
+    open!(open_part_reference, "Reference", "URI");
+    open!(open_transforms, "Transforms");
+    open!(open_transform, "Transform", "Algorithm");
+    closed!(transform, "Transform", "Algorithm");
+    closed!(
+        relationship_reference,
+        "mdssi:RelationshipReference",
+        "xmlns:mdssi",
+        "SourceId"
+    );
+    closed!(digest_method, "DigestMethod", "Algorithm");
+    closed_with_child!(digest_value, "DigestValue");
+    closed_with_child!(signature_value, "SignatureValue");
+    open!(open_key_info, "KeyInfo");
+    open!(open_x509_data, "X509Data");
+    closed_with_child!(x509_certificate, "X509Certificate");
+    open!(open_object, "Object", "Id");
+    open!(open_manifest, "Manifest");
+    open!(open_signature_properties, "SignatureProperties");
+    open!(open_signature_property, "SignatureProperty", "Id", "Target");
+    open!(open_signature_time, "mdssi:SignatureTime", "xmlns:mdssi");
+    closed_with_child!(signature_time_format, "mdssi:Format");
+    closed_with_child!(signature_time_value, "mdssi:Value");
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::XMLBuilder;
+    use std::str;
+
+    #[test]
+    fn test_signature() {
+        let b = XMLBuilder::new();
+        let r = b
+            .open_signature("http://www.w3.org/2000/09/xmldsig#", "idPackageSignature")
+            .signature_value("AAAA")
+            .close()
+            .build();
+        assert_eq!(
+            str::from_utf8(&r).unwrap(),
+            r#"<Signature xmlns="http://www.w3.org/2000/09/xmldsig#" Id="idPackageSignature">
+  <SignatureValue>AAAA</SignatureValue>
+</Signature>"#
+        );
+    }
+}
@@ -33,6 +33,45 @@ impl XMLBuilder {
             .expect("should write to buf");
         self
     }
+
+    open!(
+        open_latent_styles,
+        "w:latentStyles",
+        "w:defLockedState",
+        "w:defUIPriority",
+        "w:defSemiHidden",
+        "w:defUnhideWhenUsed",
+        "w:defQFormat",
+        "w:count"
+    );
+
+    // i.e. <w:lsdException w:name="heading 1" w:uiPriority="9" w:qFormat="1" />
+    pub(crate) fn lsd_exception(
+        mut self,
+        name: &str,
+        ui_priority: Option<usize>,
+        semi_hidden: Option<bool>,
+        unhide_when_used: Option<bool>,
+        q_format: Option<bool>,
+    ) -> Self {
+        let ui_priority = ui_priority.map(|v| format!("{}", v));
+        let flag = |v: bool| if v { "1" } else { "0" };
+        let mut e = XmlEvent::start_element("w:lsdException").attr("w:name", name);
+        if let Some(ref v) = ui_priority {
+            e = e.attr("w:uiPriority", v);
+        }
+        if let Some(v) = semi_hidden {
+            e = e.attr("w:semiHidden", flag(v));
+        }
+        if let Some(v) = unhide_when_used {
+            e = e.attr("w:unhideWhenUsed", flag(v));
+        }
+        if let Some(v) = q_format {
+            e = e.attr("w:qFormat", flag(v));
+        }
+        self.writer.write(e).expect("should write to buf");
+        self.close()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,89 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+
+impl XMLBuilder {
+    pub(crate) fn open_theme(mut self, name: &str) -> Self {
+        self.writer
+            .write(
+                XmlEvent::start_element("a:theme")
+                    .attr(
+                        "xmlns:a",
+                        "http://schemas.openxmlformats.org/drawingml/2006/main",
+                    )
+                    .attr("name", name),
+            )
+            .expect("should write to buf");
+        self
+    }
+
+    open!(open_theme_elements, "a:themeElements");
+    open!(open_clr_scheme, "a:clrScheme", "name");
+    open!(open_font_scheme, "a:fontScheme", "name");
+    open!(open_major_font, "a:majorFont");
+    open!(open_minor_font, "a:minorFont");
+    open!(open_fmt_scheme, "a:fmtScheme", "name");
+
+    closed!(theme_latin_font, "a:latin", "typeface");
+    closed!(theme_ea_font, "a:ea", "typeface");
+    closed!(theme_cs_font, "a:cs", "typeface");
+    closed!(theme_script_font, "a:font", "script", "typeface");
+
+    closed!(object_defaults, "a:objectDefaults");
+    closed!(extra_clr_scheme_lst, "a:extraClrSchemeLst");
+
+    // i.e. <a:accent1><a:srgbClr val="4472C4" /></a:accent1>
+    pub(crate) fn scheme_color(mut self, name: &str, val: &str) -> Self {
+        self.writer
+            .write(XmlEvent::start_element(format!("a:{}", name).as_str()))
+            .expect("should write to buf");
+        self.writer
+            .write(XmlEvent::start_element("a:srgbClr").attr("val", val))
+            .expect("should write to buf");
+        self.close().close()
+    }
+
+    open!(open_fill_style_lst, "a:fillStyleLst");
+    open!(open_ln_style_lst, "a:lnStyleLst");
+    open!(open_effect_style_lst, "a:effectStyleLst");
+    open!(open_bg_fill_style_lst, "a:bgFillStyleLst");
+    open!(open_theme_solid_fill, "a:solidFill");
+    open!(open_theme_ln, "a:ln", "w", "cap", "cmpd", "algn");
+    open!(open_effect_style, "a:effectStyle");
+    closed!(placeholder_color, "a:schemeClr", "val");
+    closed!(theme_prst_dash, "a:prstDash", "val");
+    closed!(theme_miter, "a:miter", "lim");
+    closed!(effect_lst, "a:effectLst");
+
+    fn placeholder_fill(self) -> Self {
+        self.open_theme_solid_fill()
+            .placeholder_color("phClr")
+            .close()
+    }
+
+    // Fill, line and effect styles are three levels each (subtle, moderate and intense);
+    // solid placeholder fills keep the matrix valid without any decoration.
+    pub(crate) fn default_format_matrices(self) -> Self {
+        let mut b = self.open_fill_style_lst();
+        for _ in 0..3 {
+            b = b.placeholder_fill();
+        }
+        b = b.close().open_ln_style_lst();
+        for w in ["6350", "12700", "19050"] {
+            b = b
+                .open_theme_ln(w, "flat", "sng", "ctr")
+                .placeholder_fill()
+                .theme_prst_dash("solid")
+                .theme_miter("800000")
+                .close();
+        }
+        b = b.close().open_effect_style_lst();
+        for _ in 0..3 {
+            b = b.open_effect_style().effect_lst().close();
+        }
+        b = b.close().open_bg_fill_style_lst();
+        for _ in 0..3 {
+            b = b.placeholder_fill();
+        }
+        b.close()
+    }
+}
@@ -256,6 +256,78 @@ impl XmlDocument {
             .map(|(data, _)| XmlDocument { data })
             .map_err(ParseXmlError)
     }
+
+    /// Evaluate a simple absolute XPath such as `/ns0:root[1]/ns0:item[2]` or
+    /// `/root/item/@name` and return the text of the first matched node.
+    /// Steps are compared by local name, so namespace prefixes are ignored.
+    pub fn find_by_xpath(&self, xpath: &str) -> Option<String> {
+        let mut nodes: &[XmlData] = &self.data;
+        let mut current: Option<&XmlData> = None;
+        for step in xpath.split('/').filter(|s| !s.is_empty()) {
+            match XPathStep::parse(step)? {
+                XPathStep::Element { name, position } => {
+                    let node = nodes
+                        .iter()
+                        .filter(|d| name == "*" || local_name(&d.name) == name)
+                        .nth(position.checked_sub(1)?)?;
+                    nodes = &node.children;
+                    current = Some(node);
+                }
+                XPathStep::Attribute(name) => {
+                    return current?
+                        .attributes
+                        .iter()
+                        .find(|(k, _)| local_name(k) == name)
+                        .map(|(_, v)| v.clone());
+                }
+                XPathStep::Text => return current.map(|d| d.text()),
+            }
+        }
+        current.map(|d| d.text())
+    }
+}
+
+impl XmlData {
+    // Concatenated text of this node and its descendants.
+    fn text(&self) -> String {
+        let mut s = self.data.clone().unwrap_or_default();
+        for c in &self.children {
+            s.push_str(&c.text());
+        }
+        s
+    }
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+enum XPathStep {
+    Element { name: String, position: usize },
+    Attribute(String),
+    Text,
+}
+
+impl XPathStep {
+    fn parse(s: &str) -> Option<Self> {
+        if let Some(attr) = s.strip_prefix('@') {
+            return Some(XPathStep::Attribute(local_name(attr).to_string()));
+        }
+        if s == "text()" {
+            return Some(XPathStep::Text);
+        }
+        let (name, position) = match s.find('[') {
+            Some(i) => {
+                let pos = s[i + 1..].strip_suffix(']')?.trim();
+                (&s[..i], usize::from_str(pos).ok()?)
+            }
+            None => (s, 1),
+        };
+        Some(XPathStep::Element {
+            name: local_name(name).to_string(),
+            position,
+        })
+    }
 }
 
 /// Error when parsing XML
@@ -39,12 +39,34 @@ where
     zip.write_all(&xml.settings)?;
     zip.start_file("word/fontTable.xml", options)?;
     zip.write_all(&xml.font_table)?;
+    if let Some(rels) = xml.font_table_rels {
+        zip.start_file("word/_rels/fontTable.xml.rels", options)?;
+        zip.write_all(&rels)?;
+    }
+    for (i, f) in xml.fonts.iter().enumerate() {
+        zip.start_file(format!("word/fonts/font{}.odttf", i + 1), options)?;
+        zip.write_all(f)?;
+    }
     zip.start_file("word/comments.xml", options)?;
     zip.write_all(&xml.comments)?;
     zip.start_file("word/numbering.xml", options)?;
     zip.write_all(&xml.numberings)?;
+    if let Some(rels) = xml.numbering_rels {
+        zip.start_file("word/_rels/numbering.xml.rels", options)?;
+        zip.write_all(&rels)?;
+    }
+    if let Some(theme) = xml.theme {
+        zip.start_file("word/theme/theme1.xml", options)?;
+        zip.write_all(&theme)?;
+    }
     zip.start_file("word/commentsExtended.xml", options)?;
     zip.write_all(&xml.comments_extended)?;
+    zip.start_file("word/commentsIds.xml", options)?;
+    zip.write_all(&xml.comments_ids)?;
+    zip.start_file("word/commentsExtensible.xml", options)?;
+    zip.write_all(&xml.comments_extensible)?;
+    zip.start_file("word/people.xml", options)?;
+    zip.write_all(&xml.people)?;
     zip.start_file("word/footnotes.xml", options)?;
     zip.write_all(&xml.footnotes)?;
 
@@ -71,8 +93,7 @@ where
     if !xml.media.is_empty() {
         zip.add_directory("word/media/", Default::default())?;
         for m in xml.media {
-            // For now only png supported
-            zip.start_file(format!("word/media/{}.png", m.0), options)?;
+            zip.start_file(format!("word/media/{}", m.0), options)?;
             zip.write_all(&m.1)?;
         }
     }
@@ -309,6 +309,136 @@ pub fn comments_to_table() -> Result<(), DocxError> {
     Ok(())
 }
 
+#[test]
+pub fn resolved_comment_thread() -> Result<(), DocxError> {
+    use std::io::Write;
+
+    let path = std::path::Path::new("./tests/output/resolved_comment_thread.docx");
+    let mut file = std::fs::File::create(path).unwrap();
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_person(Person::new("bokuweb").presence_info("AD", "S::bokuweb@example.com"))
+        .add_paragraph(
+            Paragraph::new()
+                .add_comment_start(
+                    Comment::new(1)
+                        .author("bokuweb")
+                        .date("2019-01-01T00:00:00Z")
+                        .durable_id("1A2B3C4D")
+                        .done()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Fix this"))),
+                )
+                .add_comment_start(
+                    Comment::new(2)
+                        .author("Tanaka")
+                        .date("2019-01-02T00:00:00Z")
+                        .parent_comment_id(1)
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Done"))),
+                )
+                .add_run(Run::new().add_text("Hello"))
+                .add_comment_end(2)
+                .add_comment_end(1),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let buf = buf.into_inner();
+    file.write_all(&buf).unwrap();
+
+    let docx = read_docx(&buf).unwrap();
+    let comments = docx.comments.inner();
+    assert_eq!(comments.len(), 2);
+    assert!(comments[0].done);
+    assert_eq!(comments[0].durable_id.as_deref(), Some("1A2B3C4D"));
+    assert!(!comments[1].done);
+    assert!(comments[1].durable_id.is_some());
+    assert_eq!(comments[1].parent_comment_id, Some(1));
+    assert_eq!(
+        docx.people,
+        People::new()
+            .add_person(Person::new("bokuweb").presence_info("AD", "S::bokuweb@example.com"))
+            .add_person(Person::new("Tanaka").presence_info("None", "Tanaka"))
+    );
+
+    // The local w:date is not written as dateUtc, a read dateUtc is kept.
+    assert!(docx
+        .comments_extensible
+        .children
+        .iter()
+        .all(|c| c.date_utc.is_none()));
+    let mut docx = docx;
+    docx.comments_extensible.children[0].date_utc = Some("2018-12-31T15:00:00Z".to_owned());
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(
+        docx.comments_extensible.children,
+        vec![
+            CommentExtensible::new("1A2B3C4D").date_utc("2018-12-31T15:00:00Z"),
+            CommentExtensible::new(docx.comments.inner()[1].durable_id.clone().unwrap()),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn comments_in_all_stories() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/comments_in_all_stories.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let comment = |id: usize, text: &str| {
+        Comment::new(id)
+            .author("bokuweb")
+            .date("2019-01-01T00:00:00Z")
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text(text)))
+    };
+    let header = Header::new().add_paragraph(
+        Paragraph::new()
+            .add_comment_start(comment(1, "In header"))
+            .add_run(Run::new().add_text("Header"))
+            .add_comment_end(1),
+    );
+    let table = Table::new(vec![TableRow::new(vec![TableCell::new().add_paragraph(
+        Paragraph::new()
+            .add_comment_start(comment(2, "Across table"))
+            .add_run(Run::new().add_text("Cell")),
+    )])]);
+    let footnote = Footnote::new().add_content(
+        Paragraph::new()
+            .add_comment_start(comment(3, "In footnote"))
+            .add_run(Run::new().add_text("Note"))
+            .add_comment_end(3),
+    );
+    let docx = Docx::new().header(header).add_table(table).add_paragraph(
+        Paragraph::new()
+            .add_run(Run::new().add_text("After table"))
+            .add_comment_end(2)
+            .add_run(Run::new().add_footnote_reference(footnote)),
+    );
+    docx.clone().build().pack(file)?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    let ids: Vec<usize> = read.comments.inner().iter().map(|c| c.id()).collect();
+    assert_eq!(ids, vec![2, 3, 1]);
+
+    // Comments in headers are attached again when reading.
+    let header = read.document.section_property.header.clone().unwrap();
+    match &header.children[0] {
+        HeaderChild::Paragraph(p) => match &p.children[0] {
+            ParagraphChild::CommentStart(c) => assert_eq!(c.comment.author, "bokuweb"),
+            _ => panic!("should start a comment"),
+        },
+        _ => panic!("should be a paragraph"),
+    }
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    read.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    let ids: Vec<usize> = read.comments.inner().iter().map(|c| c.id()).collect();
+    assert_eq!(ids, vec![2, 3, 1]);
+    Ok(())
+}
+
 #[test]
 pub fn default_numbering() -> Result<(), DocxError> {
     let path = std::path::Path::new("./tests/output/default_numbering.docx");
@@ -455,3 +585,1114 @@ pub fn line_spacing() -> Result<(), DocxError> {
         .pack(file)?;
     Ok(())
 }
+
+#[test]
+pub fn data_binding() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/data_binding.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let docx = Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_structured_data_tag(
+                    StructuredDataTag::new().data_binding(
+                        DataBinding::new()
+                            .xpath("/root/item1")
+                            .store_item_id("{06AC5857-5C65-A94A-BCEC-37356A209BC3}"),
+                    ),
+                )
+                .add_structured_data_tag(
+                    StructuredDataTag::new()
+                        .data_binding(DataBinding::new().xpath("/root/item2"))
+                        .add_run(Run::new().add_text("placeholder").bold()),
+                ),
+        )
+        .add_custom_item(
+            "06AC5857-5C65-A94A-BCEC-37356A209BC3",
+            "<root><item1>Hello</item1><item2>World!</item2></root>",
+        );
+    docx.clone().build().pack(file)?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    let values: Vec<String> = read.bound_values().into_iter().map(|(_, v)| v).collect();
+    assert_eq!(values, vec!["Hello".to_owned(), "World!".to_owned()]);
+    if let DocumentChild::Paragraph(p) = &read.document.children[0] {
+        if let ParagraphChild::StructuredDataTag(tag) = &p.children[1] {
+            assert_eq!(tag.raw_text(), "World!");
+            return Ok(());
+        }
+    }
+    panic!("should read data bound tag");
+}
+
+#[test]
+pub fn legacy_form_fields() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/legacy_form_fields.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let docx = Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Name: "))
+                .add_form_field(
+                    FormFieldData::new()
+                        .name("Name")
+                        .text_input(TextInput::new().max_length(20).default_value("Alice")),
+                ),
+        )
+        .add_paragraph(
+            Paragraph::new().add_form_field(
+                FormFieldData::new()
+                    .name("Agree")
+                    .check_box(CheckBox::new().size(20).checked(true)),
+            ),
+        )
+        .add_paragraph(
+            Paragraph::new().add_form_field(
+                FormFieldData::new().name("Fruit").drop_down_list(
+                    DropDownList::new()
+                        .add_entry("Apple")
+                        .add_entry("Banana")
+                        .result(1),
+                ),
+            ),
+        )
+        .document_protection(DocumentProtection::new(DocumentProtectionType::Forms));
+    docx.clone().build().pack(file)?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    let values: Vec<FormFieldValue> = read
+        .form_field_values()
+        .into_iter()
+        .map(|(_, v)| v)
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            FormFieldValue::Text("Alice".to_owned()),
+            FormFieldValue::CheckBox(true),
+            FormFieldValue::DropDownList(Some("Banana".to_owned())),
+        ]
+    );
+    assert_eq!(
+        read.settings,
+        Settings::new().document_protection(DocumentProtection::new(DocumentProtectionType::Forms))
+    );
+    Ok(())
+}
+
+#[test]
+pub fn document_protection() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/document_protection.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let docx = Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Read only")))
+        .add_paragraph(
+            Paragraph::new()
+                .add_perm_start(PermStart::new(0).ed_grp(EditGroupType::Everyone))
+                .add_run(Run::new().add_text("Editable"))
+                .add_perm_end(0),
+        )
+        .document_protection(DocumentProtection::read_only().password("docx"))
+        .write_protection(WriteProtection::new().recommended());
+    docx.clone().build().pack(file)?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    let protection = read.settings.document_protection.unwrap();
+    assert_eq!(protection.edit, DocumentProtectionType::ReadOnly);
+    assert!(protection.enforcement);
+    assert!(protection.verify_password("docx"));
+    assert!(!protection.verify_password("xcod"));
+    assert!(read.settings.write_protection.unwrap().recommended);
+    if let DocumentChild::Paragraph(p) = &read.document.children[1] {
+        assert_eq!(
+            p.children[0],
+            ParagraphChild::PermStart(PermStart::new(0).ed_grp(EditGroupType::Everyone))
+        );
+        assert_eq!(p.children[2], ParagraphChild::PermEnd(PermEnd::new(0)));
+        return Ok(());
+    }
+    panic!("should read permission range");
+}
+
+#[test]
+pub fn track_changes() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/track_changes.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let date = "2024-01-01T00:00:00Z";
+    let moved = Paragraph::new()
+        .add_move_from_range_start(
+            MoveFromRangeStart::new(1, "move1")
+                .author("bokuweb")
+                .date(date),
+        )
+        .add_move_from(
+            MoveFrom::new()
+                .add_run(Run::new().add_delete_text("Moved"))
+                .author("bokuweb")
+                .date(date),
+        )
+        .add_move_from_range_end(1);
+    let destination = Paragraph::new()
+        .add_move_to_range_start(
+            MoveToRangeStart::new(2, "move1")
+                .author("bokuweb")
+                .date(date),
+        )
+        .add_move_to(
+            MoveTo::new()
+                .add_run(Run::new().add_text("Moved"))
+                .author("bokuweb")
+                .date(date),
+        )
+        .add_move_to_range_end(2);
+    let formatted = Paragraph::new().add_run(
+        Run::new().add_text("Bold").bold().run_property_change(
+            RunPropertyChange::new()
+                .property(RunProperty::new().italic())
+                .author("bokuweb")
+                .date(date),
+        ),
+    );
+    let table = Table::new(vec![TableRow::new(vec![
+        TableCell::new()
+            .add_paragraph(Paragraph::new())
+            .cell_insert(CellInsert::new().author("bokuweb").date(date)),
+        TableCell::new().add_paragraph(Paragraph::new()).cell_merge(
+            CellMerge::new()
+                .v_merge(CellMergeType::Continue)
+                .author("bokuweb")
+                .date(date),
+        ),
+    ])
+    .table_row_property_change(
+        TableRowPropertyChange::new()
+            .property(TableRowProperty::new().row_height(100.0))
+            .author("bokuweb")
+            .date(date),
+    )]);
+    let docx = Docx::new()
+        .add_paragraph(moved)
+        .add_paragraph(destination)
+        .add_paragraph(formatted)
+        .add_table(table)
+        .track_revisions();
+    docx.clone().build().pack(file)?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(read.settings, Settings::new().track_revisions());
+    if let DocumentChild::Paragraph(p) = &read.document.children[1] {
+        assert_eq!(
+            p.children[1],
+            ParagraphChild::MoveTo(
+                MoveTo::new()
+                    .add_run(Run::new().add_text("Moved"))
+                    .author("bokuweb")
+                    .date(date)
+            )
+        );
+    }
+    if let DocumentChild::Paragraph(p) = &read.document.children[2] {
+        if let ParagraphChild::Run(r) = &p.children[0] {
+            let change = r.run_property.run_property_change.as_ref().unwrap();
+            assert_eq!(change.property.italic, Some(Italic::new()));
+        }
+    }
+    if let DocumentChild::Table(t) = &read.document.children[3] {
+        let TableChild::TableRow(row) = &t.rows[0];
+        assert!(row.property.table_row_property_change.is_some());
+        return Ok(());
+    }
+    panic!("should read revisions");
+}
+
+#[test]
+pub fn compare() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/compare.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let read = |docx: Docx| {
+        let mut buf = std::io::Cursor::new(vec![]);
+        docx.build().pack(&mut buf).unwrap();
+        read_docx(&buf.into_inner()).unwrap()
+    };
+    let texts = |docx: &Docx| -> Vec<String> {
+        docx.document
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(p.raw_text()),
+                _ => None,
+            })
+            .collect()
+    };
+    let original = read(
+        Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("The term is one year.")))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Obsolete clause."))),
+    );
+    let revised = read(
+        Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("The term is two years.")))
+            .add_paragraph(
+                Paragraph::new().add_run(Run::new().add_text("Signed by both parties.").bold()),
+            ),
+    );
+
+    let redline = original.compare(&revised, "bokuweb", "2024-01-01T00:00:00Z");
+    redline.clone().build().pack(file)?;
+
+    assert_eq!(
+        texts(&redline.clone().accept_all_revisions()),
+        texts(&revised)
+    );
+    assert_eq!(texts(&redline.reject_all_revisions()), texts(&original));
+    Ok(())
+}
+
+#[test]
+pub fn encrypted() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/encrypted.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let docx = Docx::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Secret")));
+    docx.clone().build().pack_encrypted(file, "docx")?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack_encrypted(&mut buf, "docx")?;
+    let buf = buf.into_inner();
+    assert!(matches!(
+        read_docx(&buf),
+        Err(ReaderError::PasswordRequiredError)
+    ));
+    assert!(matches!(
+        read_docx_with_password(&buf, "xcod"),
+        Err(ReaderError::InvalidPasswordError)
+    ));
+    let read = read_docx_with_password(&buf, "docx").unwrap();
+    if let DocumentChild::Paragraph(p) = &read.document.children[0] {
+        assert_eq!(p.raw_text(), "Secret");
+        return Ok(());
+    }
+    panic!("should read encrypted document");
+}
+
+#[test]
+pub fn signed() -> Result<(), DocxError> {
+    use std::io::{Read, Write};
+
+    let path = std::path::Path::new("./tests/output/signed.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let signer = PackageSigner::from_pem(
+        include_str!("../../fixtures/signature/key.pem"),
+        include_str!("../../fixtures/signature/cert.pem"),
+    )?
+    .signing_time("2024-01-01T00:00:00Z");
+    let docx = Docx::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Contract")));
+    docx.clone().build().pack_signed(file, &signer)?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.clone().build().pack_signed(&mut buf, &signer)?;
+    let buf = buf.into_inner();
+    let signatures = verify_signatures(&buf).unwrap();
+    assert_eq!(signatures.len(), 1);
+    assert!(signatures[0].valid);
+    assert_eq!(signatures[0].path, "/_xmlsignatures/sig1.xml");
+    assert_eq!(
+        signatures[0].signing_time.as_deref(),
+        Some("2024-01-01T00:00:00Z")
+    );
+    assert!(signatures[0]
+        .signed_parts
+        .contains(&"/word/document.xml".to_owned()));
+    assert!(signatures[0]
+        .signed_parts
+        .contains(&"/_rels/.rels".to_owned()));
+    assert!(read_docx(&buf).is_ok());
+
+    // Any change to a signed part invalidates the signature.
+    let rewrite = |name: &str, f: &dyn Fn(String) -> String| -> Result<Vec<u8>, DocxError> {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(&buf[..]))?;
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(vec![]));
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            let mut data = vec![];
+            file.read_to_end(&mut data).unwrap();
+            if file.name() == name {
+                data = f(String::from_utf8(data).unwrap()).into_bytes();
+            }
+            zip.start_file(file.name(), Default::default())?;
+            zip.write_all(&data).unwrap();
+        }
+        Ok(zip.finish()?.into_inner())
+    };
+    let tampered = rewrite("word/document.xml", &|xml| {
+        xml.replace("Contract", "Cantract")
+    })?;
+    assert!(!verify_signatures(&tampered).unwrap()[0].valid);
+
+    // A Manifest outside of the signed Object is not trusted.
+    let wrapped = rewrite("_xmlsignatures/sig1.xml", &|xml| {
+        let start = xml.find("<Reference URI=\"/word/document.xml").unwrap();
+        let end = start + xml[start..].find("</Reference>").unwrap() + "</Reference>".len();
+        let object = format!(
+            "<Object Id=\"idUnsignedObject\"><Manifest>{}</Manifest></Object></Signature>",
+            &xml[start..end]
+        );
+        xml.replace("</Signature>", &object)
+    })?;
+    assert!(!verify_signatures(&wrapped).unwrap()[0].valid);
+
+    // Ids must be unique so that a reference resolves to one element.
+    let duplicated = rewrite("_xmlsignatures/sig1.xml", &|xml| {
+        xml.replace(
+            "</Signature>",
+            "<Object Id=\"idPackageObject\" /></Signature>",
+        )
+    })?;
+    assert!(!verify_signatures(&duplicated).unwrap()[0].valid);
+
+    let unsigned_time = PackageSigner::from_pem(
+        include_str!("../../fixtures/signature/key.pem"),
+        include_str!("../../fixtures/signature/cert.pem"),
+    )?;
+    assert!(docx
+        .build()
+        .pack_signed(std::io::Cursor::new(vec![]), &unsigned_time)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+pub fn table_style() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/table_style.docx");
+    let file = std::fs::File::create(path).unwrap();
+
+    let style = Style::new("Zebra", StyleType::Table)
+        .name("Zebra")
+        .add_table_style_property(
+            TableStyleProperty::new(TableStyleOverrideType::FirstRow)
+                .bold()
+                .color("FFFFFF")
+                .shading(Shading::new().fill("4472C4")),
+        )
+        .add_table_style_property(
+            TableStyleProperty::new(TableStyleOverrideType::Band1Horz)
+                .shading(Shading::new().fill("D9E2F3")),
+        );
+    let rows = ["Name", "Alice", "Bob", "Carol"]
+        .iter()
+        .map(|t| {
+            TableRow::new(vec![
+                TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(*t)))
+            ])
+        })
+        .collect();
+    let table = Table::new(rows).style("Zebra").look(TableLook::new());
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_style(style)
+        .add_table(table)
+        .build()
+        .pack(&mut buf)?;
+    let buf = buf.into_inner();
+    std::io::Write::write_all(&mut &file, &buf).unwrap();
+
+    let docx = read_docx(&buf).unwrap();
+    let style = docx.styles.find_style_by_id("Zebra").unwrap();
+    assert_eq!(style.table_style_properties.len(), 2);
+    assert_eq!(
+        style.table_style_properties[1].style_type,
+        TableStyleOverrideType::Band1Horz
+    );
+    Ok(())
+}
+
+#[test]
+pub fn table_header_row() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/table_header_row.docx");
+    let file = std::fs::File::create(path).unwrap();
+
+    let mut rows = vec![TableRow::new(vec![
+        TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("No.").bold())),
+        TableCell::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Item").bold())),
+    ])
+    .table_header()
+    .cant_split()
+    .cnf_style("100000000000")
+    .cell_spacing(15, WidthType::Dxa)];
+    for i in 0..100 {
+        rows.push(TableRow::new(vec![
+            TableCell::new()
+                .no_wrap()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text(format!("{}", i)))),
+            TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(DUMMY))),
+        ]));
+    }
+    rows.push(TableRow::new(vec![TableCell::from("Hidden"), TableCell::from("")]).hidden());
+    let table = Table::new(rows)
+        .set_grid(vec![1000, 8000])
+        .caption("Items")
+        .description("All items with their number");
+    let docx = Docx::new().add_table(table);
+    docx.clone().build().pack(file)?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    let rows = match &read.document.children[0] {
+        DocumentChild::Table(t) => &t.rows,
+        _ => panic!("should be a table"),
+    };
+    let TableChild::TableRow(header) = &rows[0];
+    assert_eq!(
+        header.property,
+        TableRowProperty::new()
+            .cant_split()
+            .table_header()
+            .cnf_style("100000000000")
+            .cell_spacing(15, WidthType::Dxa)
+    );
+    let TableChild::TableRow(last) = &rows[rows.len() - 1];
+    assert_eq!(last.property.hidden, Some(true));
+    Ok(())
+}
+
+#[test]
+pub fn table_grid_before_insert_column() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/table_grid_before_insert_column.docx");
+    let file = std::fs::File::create(path).unwrap();
+
+    let table = Table::new(vec![
+        TableRow::new(vec![TableCell::from("1"), TableCell::from("2")])
+            .grid_before(1)
+            .width_before(500.0),
+        TableRow::new(vec![
+            TableCell::from("A"),
+            TableCell::from("B"),
+            TableCell::from("C"),
+        ]),
+    ])
+    .set_grid(vec![500, 1000, 1000]);
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new().add_table(table).build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    let table = match &read.document.children[0] {
+        DocumentChild::Table(t) => (**t).clone(),
+        _ => panic!("should be a table"),
+    };
+    let TableChild::TableRow(first) = &table.rows[0];
+    assert_eq!(
+        first.property,
+        TableRowProperty::new().grid_before(1).width_before(500.0)
+    );
+
+    let table = table.insert_column(0, 400)?;
+    let docx = Docx::new().add_table(table);
+    docx.clone().build().pack(file)?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    let rows = match &read.document.children[0] {
+        DocumentChild::Table(t) => &t.rows,
+        _ => panic!("should be a table"),
+    };
+    let TableChild::TableRow(first) = &rows[0];
+    assert_eq!(
+        first.property,
+        TableRowProperty::new().grid_before(2).width_before(500.0)
+    );
+    assert_eq!(first.cells.len(), 2);
+    let TableChild::TableRow(second) = &rows[1];
+    assert_eq!(second.property, TableRowProperty::new());
+    assert_eq!(second.cells.len(), 4);
+    Ok(())
+}
+
+#[test]
+pub fn table_auto_grid() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/table_auto_grid.docx");
+    let file = std::fs::File::create(path).unwrap();
+
+    let table = Table::new(vec![
+        TableRow::new(vec![TableCell::from("No"), TableCell::from("Description")]),
+        TableRow::new(vec![TableCell::from("1"), TableCell::from(DUMMY)]),
+    ]);
+    let docx = Docx::new()
+        .page_margin(PageMargin::new().left(1000).right(1000))
+        .add_table(table);
+
+    // "No" keeps its natural width and the description takes the rest of the text width.
+    let xml = docx.clone().build();
+    let document = std::str::from_utf8(&xml.document).unwrap();
+    assert!(document.contains(r#"<w:gridCol w:w="416" w:type="dxa" />"#));
+    assert!(document.contains(r#"<w:gridCol w:w="9490" w:type="dxa" />"#));
+
+    docx.build().pack(file)?;
+    Ok(())
+}
+
+#[test]
+pub fn effective_formatting() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/effective_formatting.docx");
+    let file = std::fs::File::create(path).unwrap();
+
+    let table = Table::new(vec![
+        TableRow::new(vec![TableCell::from("Name")]),
+        TableRow::new(vec![TableCell::from("Alice")]),
+    ])
+    .style("Grid");
+    let docx = Docx::new()
+        .add_style(
+            Style::new("Grid", StyleType::Table).add_table_style_property(
+                TableStyleProperty::new(TableStyleOverrideType::FirstRow).bold(),
+            ),
+        )
+        .add_style(
+            Style::new("Title", StyleType::Paragraph)
+                .name("Title")
+                .size(48)
+                .align(AlignmentType::Center),
+        )
+        .add_paragraph(
+            Paragraph::new()
+                .style("Title")
+                .add_run(Run::new().add_text("Members").color("FF0000")),
+        )
+        .add_table(table)
+        .add_paragraph(
+            Paragraph::new()
+                .add_insert(Insert::new(Run::new().add_text("Added ").italic()))
+                .add_hyperlink(
+                    Hyperlink::new("https://example.com", HyperlinkType::External)
+                        .add_run(Run::new().add_text("link").bold()),
+                ),
+        );
+    docx.clone().build().pack(file)?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+
+    let title = read
+        .effective_paragraph_property(&DocumentPath::new(0))
+        .unwrap();
+    assert_eq!(
+        title.source("alignment"),
+        Some(&FormattingSource::Style {
+            style_id: "Title".to_owned()
+        })
+    );
+    let run = read
+        .effective_run_property(&DocumentPath::new(0).run(0))
+        .unwrap();
+    assert_eq!(run.property.sz, Some(Sz::new(48)));
+    assert_eq!(run.source("color"), Some(&FormattingSource::Direct));
+
+    let header = read
+        .effective_run_property(&DocumentPath::new(1).cell(0, 0).child(0).run(0))
+        .unwrap();
+    assert_eq!(
+        header.source("bold"),
+        Some(&FormattingSource::TableStyle {
+            style_id: "Grid".to_owned(),
+            condition: TableStyleOverrideType::FirstRow
+        })
+    );
+    let body = read
+        .effective_run_property(&DocumentPath::new(1).cell(1, 0).child(0).run(0))
+        .unwrap();
+    assert_eq!(body.property.bold, None);
+    assert!(read
+        .effective_run_property(&DocumentPath::new(1).cell(2, 0).child(0).run(0))
+        .is_none());
+
+    // Runs inside insertions and hyperlinks are counted in reading order.
+    let inserted = read
+        .effective_run_property(&DocumentPath::new(2).run(0))
+        .unwrap();
+    assert_eq!(inserted.source("italic"), Some(&FormattingSource::Direct));
+    let linked = read
+        .effective_run_property(&DocumentPath::new(2).run(1))
+        .unwrap();
+    assert_eq!(linked.source("bold"), Some(&FormattingSource::Direct));
+    Ok(())
+}
+
+#[test]
+pub fn preset_lists() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/preset_lists.docx");
+    let file = std::fs::File::create(path).unwrap();
+
+    let mut docx = Docx::new()
+        .add_abstract_numbering(AbstractNumbering::new(2))
+        .add_numbering(Numbering::new(2, 2));
+    let outline = docx.create_list(ListPreset::Outline);
+    let bullets = docx.create_list(ListPreset::Bullet);
+    let restarted = docx.restart_list(outline).unwrap();
+    // Ids never collide with the default numbering or existing ones.
+    assert_eq!((outline, bullets, restarted), (3, 4, 5));
+    assert_eq!(docx.restart_list(100), None);
+
+    let item = |text: &str, id: usize, level: usize| {
+        Paragraph::new()
+            .add_run(Run::new().add_text(text))
+            .numbering(NumberingId::new(id), IndentLevel::new(level))
+    };
+    let docx = docx
+        .add_paragraph(item("Scope", outline, 0))
+        .add_paragraph(item("Terms", outline, 1))
+        .add_paragraph(item("Apples", bullets, 0))
+        .add_paragraph(item("Oranges", bullets, 1))
+        .add_paragraph(item("Definitions", outline, 1))
+        .add_paragraph(item("Again", restarted, 0))
+        .add_paragraph(item("Nested", restarted, 1));
+    let labels: Vec<String> = docx.list_labels().into_iter().map(|l| l.text).collect();
+    assert_eq!(labels, vec!["1.", "1.1.", "•", "◦", "1.2.", "1.", "1.1."]);
+
+    docx.build().pack(file)?;
+    Ok(())
+}
+
+#[test]
+pub fn picture_bullets() -> Result<(), DocxError> {
+    use std::io::Read;
+
+    let path = std::path::Path::new("./tests/output/picture_bullets.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+    let mut buf = Vec::new();
+    let _ = img.read_to_end(&mut buf).unwrap();
+
+    let level = Level::new(
+        0,
+        Start::new(1),
+        NumberFormat::new("bullet"),
+        LevelText::new("•"),
+        LevelJc::new("left"),
+    )
+    .pic_bullet(0)
+    .indent(Some(720), Some(SpecialIndentType::Hanging(360)), None, None);
+    // The same image in the body shares its media file with the bullet.
+    let docx = Docx::new()
+        .add_num_pic_bullet(NumPicBullet::new(0, Pic::new(&buf).size(114300, 114300)))
+        .add_abstract_numbering(AbstractNumbering::new(2).add_level(level))
+        .add_numbering(Numbering::new(2, 2))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_image(Pic::new(&buf))))
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Branded"))
+                .numbering(NumberingId::new(2), IndentLevel::new(0)),
+        );
+    docx.clone().build().pack(file)?;
+
+    let xml = docx.clone().build();
+    let rels = String::from_utf8(xml.numbering_rels.unwrap()).unwrap();
+    assert!(rels.contains(r#"Id="numberingPic0""#));
+    assert_eq!(xml.media.len(), 1);
+    assert!(rels.contains(&format!(r#"Target="media/{}""#, xml.media[0].0)));
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    let bullet = &read.numberings.num_pic_bullets[0];
+    assert_eq!(bullet.pic.size, (114300, 114300));
+    // The image is read back through numbering.xml.rels, apart from the images of the document.
+    assert!(read
+        .numbering_images
+        .iter()
+        .any(|(id, ..)| id == &bullet.pic.id));
+    assert!(read.images.iter().all(|(id, ..)| id != &bullet.pic.id));
+    assert!(!bullet.pic.image.is_empty());
+    let level = read
+        .numberings
+        .abstract_nums
+        .iter()
+        .find(|a| a.id == 2)
+        .map(|a| &a.levels[0]);
+    assert_eq!(level.and_then(|l| l.pic_bullet_id), Some(0));
+    Ok(())
+}
+
+#[test]
+pub fn picture_bullets_keep_image_format() -> Result<(), DocxError> {
+    use std::io::Read;
+
+    let path = std::path::Path::new("./tests/output/picture_bullets_keep_image_format.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+    let mut buf = Vec::new();
+    let _ = img.read_to_end(&mut buf).unwrap();
+    let gif = b"GIF89a\x01\x00\x01\x00\x80\x00\x00\xff\xff\xff\x00\x00\x00!\xf9\x04\x01\x00\x00\x00\x00,\x00\x00\x00\x00\x01\x00\x01\x00\x00\x02\x02D\x01\x00;".to_vec();
+    let mut emf = vec![0u8; 88];
+    emf[0] = 1;
+    emf[40..44].copy_from_slice(b" EMF");
+
+    let mut gif_pic = Pic::new(&buf).size(114300, 114300);
+    gif_pic.image = gif.clone();
+    let mut emf_pic = Pic::new(&buf).size(114300, 114300);
+    emf_pic.image = emf;
+    let docx = Docx::new()
+        .add_num_pic_bullet(NumPicBullet::new(0, gif_pic))
+        .add_num_pic_bullet(NumPicBullet::new(1, emf_pic))
+        .add_abstract_numbering(
+            AbstractNumbering::new(2).add_level(
+                Level::new(
+                    0,
+                    Start::new(1),
+                    NumberFormat::new("bullet"),
+                    LevelText::new("•"),
+                    LevelJc::new("left"),
+                )
+                .pic_bullet(0),
+            ),
+        )
+        .add_numbering(Numbering::new(2, 2))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_image(Pic::new(&buf))))
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Branded"))
+                .numbering(NumberingId::new(2), IndentLevel::new(0)),
+        );
+    docx.clone().build().pack(file)?;
+
+    let xml = docx.clone().build();
+    let rels = String::from_utf8(xml.numbering_rels.unwrap()).unwrap();
+    assert!(rels.contains(r#"Target="media/numberingPic0.gif""#));
+    assert!(rels.contains(r#"Target="media/numberingPic1.emf""#));
+    let names: Vec<&str> = xml.media.iter().map(|m| m.0.as_str()).collect();
+    assert!(names.contains(&"numberingPic0.gif") && names.contains(&"numberingPic1.emf"));
+    let content_types = String::from_utf8(xml.content_type).unwrap();
+    assert!(content_types.contains(r#"<Default ContentType="image/x-emf" Extension="emf" />"#));
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(read.numberings.num_pic_bullets[0].pic.image, gif);
+    assert_eq!(read.images.len(), 1);
+    Ok(())
+}
+
+#[test]
+pub fn builtin_styles() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/builtin_styles.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let custom = Style::new("Heading1", StyleType::Paragraph)
+        .name("heading 1")
+        .color("FF0000");
+    let docx = Docx::new()
+        .add_style(custom.clone())
+        .add_builtin_styles(
+            StylePalette::new().body_fonts(RunFonts::new().ascii("Georgia").hi_ansi("Georgia")),
+        )
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Overview"))
+                .style("Heading1"),
+        )
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Quoted"))
+                .style("Quote"),
+        );
+    // Styles defined before are kept as is.
+    assert_eq!(docx.styles.find_style_by_id("Heading1"), Some(&custom));
+    assert!(docx.styles.find_style_by_id("Heading1Char").is_some());
+
+    let xml = docx.clone().build();
+    let styles = std::str::from_utf8(&xml.styles).unwrap();
+    assert_eq!(styles.matches(r#"w:styleId="Normal""#).count(), 1);
+    assert!(
+        styles.contains(r#"<w:lsdException w:name="heading 1" w:uiPriority="9" w:qFormat="1" />"#)
+    );
+    docx.build().pack(file)?;
+    Ok(())
+}
+
+#[test]
+pub fn theme_colors() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/theme_colors.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let accent = ThemeColor::new(ThemeColorType::Accent1).shade("BF");
+    let theme = Theme::new().name("Corporate").color_scheme(
+        ColorScheme::new()
+            .name("Corporate")
+            .color(ThemeColorType::Accent1, "C00000"),
+    );
+    let docx = Docx::new().theme(theme).add_paragraph(
+        Paragraph::new().add_run(
+            Run::new()
+                .add_text("Accent")
+                .theme_color(accent.clone())
+                .shading(
+                    Shading::new()
+                        .fill("F2F2F2")
+                        .theme_fill(ThemeColor::new(ThemeColorType::Background1).shade("F2")),
+                ),
+        ),
+    );
+    docx.clone().build().pack(file)?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    let theme = &read.themes[0];
+    assert_eq!(theme.name, "Corporate");
+    assert_eq!(theme.color_scheme.accent1, "C00000");
+    assert_eq!(theme.font_schema.minor_font.latin, "Calibri");
+    assert_eq!(theme.resolve_color(&accent), Some("900000".to_owned()));
+    // Read themes are written back.
+    let mut buf = std::io::Cursor::new(vec![]);
+    read.clone().build().pack(&mut buf)?;
+    assert_eq!(read_docx(&buf.into_inner()).unwrap().themes, read.themes);
+    if let DocumentChild::Paragraph(p) = &read.document.children[0] {
+        if let ParagraphChild::Run(r) = &p.children[0] {
+            assert_eq!(r.run_property.color, Some(Color::new("auto").theme(accent)));
+            let shd = r.run_property.shading.as_ref().unwrap();
+            assert_eq!(
+                shd.theme_fill.as_ref().unwrap().shade.as_deref(),
+                Some("F2")
+            );
+            return Ok(());
+        }
+    }
+    panic!("themed run should be read");
+}
+
+#[test]
+pub fn read_theme_round_trip() -> Result<(), DocxError> {
+    use std::io::Read;
+
+    let mut file = std::fs::File::open("../fixtures/font/font.docx").unwrap();
+    let mut buf = vec![];
+    file.read_to_end(&mut buf).unwrap();
+    let read = read_docx(&buf).unwrap();
+    let xml = String::from_utf8(read.clone().build().theme.unwrap()).unwrap();
+    // The style matrices written by Word are kept.
+    assert!(xml.contains("<a:gradFill"));
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    read.clone().build().pack(&mut buf)?;
+    assert_eq!(read_docx(&buf.into_inner()).unwrap().themes, read.themes);
+    Ok(())
+}
+
+#[test]
+pub fn font_table_and_embedded_fonts() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/font_table_and_embedded_fonts.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let font = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x80];
+    let docx = Docx::new()
+        .add_style(
+            Style::new("Heading1", StyleType::Paragraph)
+                .name("Heading 1")
+                .fonts(RunFonts::new().ascii("Cambria")),
+        )
+        .add_paragraph(
+            Paragraph::new()
+                .style("Heading1")
+                .add_run(Run::new().add_text("Corporate")),
+        )
+        .add_paragraph(
+            Paragraph::new().add_run(
+                Run::new().add_text("Hello").fonts(
+                    RunFonts::new()
+                        .ascii("Corporate Sans")
+                        .east_asia("Yu Gothic"),
+                ),
+            ),
+        )
+        .add_font(
+            Font::new("Corporate Sans", "80", "swiss", FontPitchType::Variable)
+                .panose("020B0604020202020204"),
+        )
+        .embed_font("Corporate Sans", FontEmbedType::Regular, vec![0x00])
+        .embed_font("Corporate Sans", FontEmbedType::Regular, font.clone());
+    docx.clone().build().pack(file)?;
+
+    let xml = docx.clone().build();
+    let font_table = String::from_utf8(xml.font_table).unwrap();
+    assert!(font_table.contains(
+        r#"<w:font w:name="Cambria">
+  <w:panose1 w:val="02040503050406030204" />"#
+    ));
+    assert!(font_table.contains(r#"<w:font w:name="Yu Gothic">"#));
+    assert!(!font_table.contains("Times New Roman"));
+    assert!(font_table.contains(r#"<w:embedRegular r:id="rIdFont1" w:fontKey="{"#));
+    assert!(String::from_utf8(xml.settings)
+        .unwrap()
+        .contains("<w:embedTrueTypeFonts />"));
+    assert!(String::from_utf8(xml.content_type)
+        .unwrap()
+        .contains(r#"<Default ContentType="application/vnd.openxmlformats-officedocument.obfuscatedFont" Extension="odttf" />"#));
+    assert!(String::from_utf8(xml.font_table_rels.unwrap())
+        .unwrap()
+        .contains(r#"Target="fonts/font1.odttf""#));
+    // Only the first 32 bytes are obfuscated, with a key taken from the font.
+    assert_eq!(xml.fonts.len(), 1);
+    assert_eq!(xml.fonts[0].len(), font.len());
+    assert_ne!(xml.fonts[0], font);
+
+    // Fonts read from fontTable.xml keep their metadata when written again.
+    let mut buf = vec![];
+    docx.build().pack(std::io::Cursor::new(&mut buf))?;
+    let read = read_docx(&buf).unwrap();
+    assert_eq!(
+        read.font_table.find_font("Corporate Sans"),
+        Some(
+            &Font::new("Corporate Sans", "80", "swiss", FontPitchType::Variable)
+                .panose("020B0604020202020204")
+        )
+    );
+    let font_table = String::from_utf8(read.build().font_table).unwrap();
+    assert!(font_table.contains(
+        r#"<w:font w:name="Corporate Sans">
+  <w:panose1 w:val="020B0604020202020204" />
+  <w:charset w:val="80" />
+  <w:family w:val="swiss" />"#
+    ));
+    Ok(())
+}
+
+#[test]
+pub fn core_and_app_props() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/core_and_app_props.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let docx = Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+        .core_props(
+            CorePropsConfig::new()
+                .title("Quarterly <Report>")
+                .subject("Finance")
+                .creator("bokuweb")
+                .keywords("budget, plan")
+                .category("Report")
+                .content_status("Draft")
+                .revision(3)
+                .created("2024-01-01T00:00:00Z"),
+        )
+        .app_props(
+            AppProps::new()
+                .company("Acme & Co")
+                .manager("Jane")
+                .template("Normal.dotm")
+                .pages(1)
+                .words(1)
+                .app_version("16.0000"),
+        );
+    docx.clone().build().pack(file)?;
+
+    let mut buf = vec![];
+    docx.build().pack(std::io::Cursor::new(&mut buf))?;
+    let read = read_docx(&buf).unwrap();
+    let core = &read.doc_props.core.config;
+    assert_eq!(core.title, Some("Quarterly <Report>".to_owned()));
+    assert_eq!(core.subject, Some("Finance".to_owned()));
+    assert_eq!(core.creator, Some("bokuweb".to_owned()));
+    assert_eq!(core.keywords, Some("budget, plan".to_owned()));
+    assert_eq!(core.category, Some("Report".to_owned()));
+    assert_eq!(core.content_status, Some("Draft".to_owned()));
+    assert_eq!(core.revision, Some(3));
+    assert_eq!(core.created, Some("2024-01-01T00:00:00Z".to_owned()));
+    let app = &read.doc_props.app;
+    assert_eq!(app.company, Some("Acme & Co".to_owned()));
+    assert_eq!(app.manager, Some("Jane".to_owned()));
+    assert_eq!(app.template, Some("Normal.dotm".to_owned()));
+    assert_eq!(app.pages, Some(1));
+    assert_eq!(app.app_version, Some("16.0000".to_owned()));
+    Ok(())
+}
+
+#[test]
+pub fn typed_custom_properties() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/typed_custom_properties.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let docx = Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_bookmark_start(1, "_Ref1")
+                .add_run(Run::new().add_text("A-1"))
+                .add_bookmark_end(1),
+        )
+        .custom_property("Client", "Acme")
+        .add_custom_property(CustomProperty::new("Count", 3))
+        .add_custom_property(CustomProperty::new("Rate", 1.5))
+        .add_custom_property(CustomProperty::new("Approved", false))
+        .add_custom_property(CustomProperty::date("Due", "2024-01-01T00:00:00Z").unwrap())
+        .add_custom_property(CustomProperty::new("Ref", "A-1").link_to_content("_Ref1"));
+    docx.clone().build().pack(file)?;
+
+    let mut buf = vec![];
+    docx.build().pack(std::io::Cursor::new(&mut buf))?;
+    let read = read_docx(&buf).unwrap();
+    let names: Vec<_> = read
+        .doc_props
+        .custom
+        .properties
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["Client", "Count", "Rate", "Approved", "Due", "Ref"]
+    );
+    let custom = &read.doc_props.custom;
+    assert_eq!(custom.get("Count"), Some(&CustomPropertyValue::Integer(3)));
+    assert_eq!(custom.get("Rate"), Some(&CustomPropertyValue::Float(1.5)));
+    assert_eq!(
+        custom.get("Approved"),
+        Some(&CustomPropertyValue::Bool(false))
+    );
+    assert_eq!(
+        custom.get("Due"),
+        Some(&CustomPropertyValue::Date(
+            "2024-01-01T00:00:00Z".to_owned()
+        ))
+    );
+    assert_eq!(custom.properties[5].link_target, Some("_Ref1".to_owned()));
+    Ok(())
+}
+
+#[test]
+pub fn rich_settings() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/rich_settings.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let settings = Settings::new()
+        .zoom(120)
+        .update_fields()
+        .mirror_margins()
+        .gutter_at_top()
+        .book_fold_printing()
+        .display_background_shape()
+        .auto_hyphenation()
+        .hyphenation_zone(357)
+        .consecutive_hyphen_limit(2)
+        .compatibility_mode(14)
+        .theme_font_lang(ThemeFontLang::new("en-US").east_asia("ja-JP"))
+        .proof_state(
+            ProofState::new()
+                .spelling(ProofStateType::Clean)
+                .grammar(ProofStateType::Dirty),
+        )
+        .rsid_root("00A1B2C3")
+        .add_rsid("00A1B2C3")
+        .add_rsid("00D4E5F6")
+        .default_table_style("TableGrid");
+    let docx = Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+        .settings(settings.clone());
+    docx.clone().build().pack(file)?;
+
+    let mut buf = vec![];
+    docx.build().pack(std::io::Cursor::new(&mut buf))?;
+    let read = read_docx(&buf).unwrap();
+    assert_eq!(read.settings, settings);
+    Ok(())
+}
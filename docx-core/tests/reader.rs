@@ -289,3 +289,21 @@ pub fn read_footnotes() {
     file.write_all(json.as_bytes()).unwrap();
     file.flush().unwrap();
 }
+
+#[test]
+pub fn read_history_and_resolve_revisions() {
+    let mut file = File::open("../fixtures/history_libre_office/history.docx").unwrap();
+    let mut buf = vec![];
+    file.read_to_end(&mut buf).unwrap();
+    let docx = read_docx(&buf).unwrap();
+    assert!(docx.json().contains(r#""type": "insert""#));
+
+    for json in [
+        docx.clone().accept_all_revisions().json(),
+        docx.reject_all_revisions().json(),
+    ] {
+        assert!(!json.contains(r#""type": "insert""#));
+        assert!(!json.contains(r#""type": "delete""#));
+        assert!(!json.contains(r#""type": "deleteText""#));
+    }
+}
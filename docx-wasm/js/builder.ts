@@ -136,6 +136,12 @@ function buildComment(child: Comment) {
   if (child._parentCommentId) {
     comment = comment.parent_comment_id(child._parentCommentId);
   }
+  if (child._done) {
+    comment = comment.done();
+  }
+  if (child._durableId) {
+    comment = comment.durable_id(child._durableId);
+  }
   return comment;
 }
 
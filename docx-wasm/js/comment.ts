@@ -7,6 +7,8 @@ export class Comment {
   _date: string;
   children: (Paragraph | Table)[] = [];
   _parentCommentId: number;
+  _done: boolean = false;
+  _durableId: string;
 
   constructor(id: number) {
     this.id = id;
@@ -31,4 +33,14 @@ export class Comment {
     this._parentCommentId = id;
     return this;
   }
+
+  done() {
+    this._done = true;
+    return this;
+  }
+
+  durableId(id: string) {
+    this._durableId = id;
+    return this;
+  }
 }
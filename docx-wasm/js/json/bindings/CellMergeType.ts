@@ -0,0 +1 @@
+export type CellMergeType = "continue" | "restart";
\ No newline at end of file
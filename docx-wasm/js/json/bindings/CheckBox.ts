@@ -0,0 +1,2 @@
+
+export interface CheckBox { size: number | null, default: boolean, checked: boolean | null, }
\ No newline at end of file
@@ -0,0 +1,2 @@
+
+export interface DropDownList { result: number | null, default: number | null, entries: Array<string>, }
\ No newline at end of file
@@ -0,0 +1 @@
+export type EditGroupType = "none" | "everyone" | "administrators" | "contributors" | "editors" | "owners" | "current";
\ No newline at end of file
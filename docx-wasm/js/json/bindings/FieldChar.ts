@@ -1,3 +1,4 @@
 import type { FieldCharType } from "./FieldCharType";
+import type { FormFieldData } from "./FormFieldData";
 
-export interface FieldChar { fieldCharType: FieldCharType, dirty: boolean, }
\ No newline at end of file
+export interface FieldChar { fieldCharType: FieldCharType, dirty: boolean, formFieldData?: FormFieldData, }
\ No newline at end of file
@@ -1,3 +1,3 @@
 import type { FontGroup } from "./FontGroup";
 
-export interface FontScheme { majorFont: FontGroup, minorFont: FontGroup, }
\ No newline at end of file
+export interface FontScheme { name: string, majorFont: FontGroup, minorFont: FontGroup, }
\ No newline at end of file
@@ -0,0 +1,5 @@
+import type { CheckBox } from "./CheckBox";
+import type { DropDownList } from "./DropDownList";
+import type { TextInput } from "./TextInput";
+
+export type FormField = { type: "textInput", data: TextInput } | { type: "checkBox", data: CheckBox } | { type: "dropDownList", data: DropDownList };
\ No newline at end of file
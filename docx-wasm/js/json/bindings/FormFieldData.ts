@@ -0,0 +1,3 @@
+import type { FormField } from "./FormField";
+
+export interface FormFieldData { name: string | null, enabled: boolean, calcOnExit: boolean, helpText: string | null, statusText: string | null, field: FormField | null, }
\ No newline at end of file
@@ -0,0 +1,2 @@
+
+export interface FormatScheme { name: string, }
\ No newline at end of file
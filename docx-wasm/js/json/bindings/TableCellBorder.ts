@@ -1,4 +1,5 @@
 import type { BorderType } from "./BorderType";
 import type { TableCellBorderPosition } from "./TableCellBorderPosition";
+import type { ThemeColor } from "./ThemeColor";
 
-export interface TableCellBorder { borderType: BorderType, size: number, color: string, position: TableCellBorderPosition, space: number, }
\ No newline at end of file
+export interface TableCellBorder { borderType: BorderType, size: number, color: string, position: TableCellBorderPosition, space: number, themeColor?: ThemeColor, }
\ No newline at end of file
@@ -0,0 +1,3 @@
+import type { TextInputType } from "./TextInputType";
+
+export interface TextInput { inputType: TextInputType | null, default: string | null, maxLength: number | null, format: string | null, }
\ No newline at end of file
@@ -0,0 +1,2 @@
+
+export type TextInputType = "regular" | "number" | "date" | "currentDate" | "currentTime" | "calculated";
\ No newline at end of file
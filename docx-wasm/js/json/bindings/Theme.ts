@@ -1,3 +1,5 @@
+import type { ColorScheme } from "./ColorScheme";
 import type { FontScheme } from "./FontScheme";
+import type { FormatScheme } from "./FormatScheme";
 
-export interface Theme { fontSchema: FontScheme, }
\ No newline at end of file
+export interface Theme { name: string, colorScheme: ColorScheme, fontSchema: FontScheme, formatScheme: FormatScheme, }
\ No newline at end of file
@@ -0,0 +1,3 @@
+import type { ThemeColorType } from "./ThemeColorType";
+
+export interface ThemeColor { color: ThemeColorType, tint?: string, shade?: string, }
\ No newline at end of file
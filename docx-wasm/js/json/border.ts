@@ -1,7 +1,10 @@
+import { ThemeColor } from "./bindings/ThemeColor";
+
 export type BorderJSON = {
   position: string;
   borderType: string;
   size: number;
   space: number;
   color: string;
+  themeColor?: ThemeColor;
 };
@@ -1,12 +1,25 @@
 import { StylesJSON } from "./styles";
 import { DocumentJSON } from "./document";
 import { NumberingsJSON } from "./numbering";
-import { CommentJSON } from "./comment";
+import { CommentJSON, PersonJSON } from "./comment";
 import { WebSettingsJSON } from "./web-settings";
 
 import { Theme as ThemeJSON } from "./bindings/Theme";
 import { CharacterSpacingValues } from "../settings";
 
+export type CustomPropertyValueJSON =
+  | { type: "text"; value: string }
+  | { type: "integer"; value: number }
+  | { type: "float"; value: number }
+  | { type: "bool"; value: boolean }
+  | { type: "date"; value: string };
+
+export type CustomPropertyJSON = {
+  name: string;
+  value: CustomPropertyValueJSON;
+  linkTarget?: string;
+};
+
 export type DocxJSON = {
   contentType: {
     types: {
@@ -19,23 +32,38 @@ export type DocxJSON = {
     hasNumberings: boolean;
   };
   docProps: {
-    app: {};
+    app: {
+      template?: string;
+      totalTime?: number;
+      pages?: number;
+      words?: number;
+      characters?: number;
+      application?: string;
+      lines?: number;
+      paragraphs?: number;
+      manager?: string;
+      company?: string;
+      charactersWithSpaces?: number;
+      appVersion?: string;
+    };
     core: {
       config: {
+        created: string | null;
         creator: string | null;
         description: string | null;
         language: string | null;
         lastModifiedBy: string | null;
         modified: string | null;
-        revision: string | null;
+        revision: number | null;
         subject: string | null;
         title: string | null;
+        keywords?: string;
+        category?: string;
+        contentStatus?: string;
       };
     };
     custom: {
-      properties: {
-        [key: string]: string;
-      };
+      properties: CustomPropertyJSON[];
     };
   };
   styles: StylesJSON;
@@ -43,16 +71,36 @@ export type DocxJSON = {
   comments: {
     comments: CommentJSON[];
   };
+  people: {
+    people: PersonJSON[];
+  };
   numberings: NumberingsJSON;
   settings: SettingsJSON;
   webSettings: WebSettingsJSON;
-  fontTable: {};
+  fontTable: FontTableJSON;
   themes: ThemeJSON[];
   //(id, path, base64 encoded original image data, base64 encoded png image data)
   images: [string, string, string, string][];
   hyperlinks: [string, string, string][];
 };
 
+export type FontJSON = {
+  name: string;
+  panose?: string;
+  charset: string;
+  family: string;
+  pitch: "default" | "fixed" | "variable";
+  embeds?: {
+    embedType: "regular" | "bold" | "italic" | "boldItalic";
+    id: string;
+    fontKey: string;
+  }[];
+};
+
+export type FontTableJSON = {
+  fonts: FontJSON[];
+};
+
 export type SettingsJSON = {
   // w15:docId
   docId: string | null;
@@ -60,8 +108,25 @@ export type SettingsJSON = {
   adjustLineHeightInTable: boolean;
   characterSpacingControl?: CharacterSpacingValues | null;
   evenAndOddHeaders: boolean;
+  trackRevisions: boolean;
+  embedTrueTypeFonts: boolean;
+  saveSubsetFonts: boolean;
   zoom: number;
   docVars: { name: string; val: string }[];
+  updateFields: boolean;
+  mirrorMargins: boolean;
+  gutterAtTop: boolean;
+  bookFoldPrinting: boolean;
+  displayBackgroundShape: boolean;
+  autoHyphenation: boolean;
+  hyphenationZone?: number;
+  consecutiveHyphenLimit?: number;
+  compatibilityMode?: number;
+  themeFontLang?: { val?: string; eastAsia?: string; bidi?: string };
+  proofState?: { spelling?: "clean" | "dirty"; grammar?: "clean" | "dirty" };
+  rsidRoot?: string;
+  rsids: string[];
+  defaultTableStyle?: string;
 };
 
 export * from "../settings";
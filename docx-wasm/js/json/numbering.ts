@@ -1,5 +1,6 @@
 import { ParagraphPropertyJSON } from "./paragraph";
 import { RunPropertyJSON } from "./run";
+import { Pic } from "./drawing";
 
 export type LevelJSON = {
   level: number;
@@ -13,6 +14,7 @@ export type LevelJSON = {
   pstyle: string | null;
   levelRestart: number | null;
   isLgl?: boolean | null; 
+  picBulletId?: number;
 };
 
 export type AbstractNumberingJSON = {
@@ -34,7 +36,13 @@ export type LevelOverrideJSON = {
   overrideLevel: LevelJSON | null;
 };
 
+export type NumPicBulletJSON = {
+  id: number;
+  pic: Pic;
+};
+
 export type NumberingsJSON = {
+  numPicBullets?: NumPicBulletJSON[];
   abstractNums: AbstractNumberingJSON[];
   numberings: NumberingJSON[];
 };
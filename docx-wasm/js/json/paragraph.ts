@@ -20,7 +20,13 @@ export type ParagraphChildJSON =
   | CommentRangeStartJSON
   | CommentRangeEndJSON
   | BookmarkStartJSON
-  | BookmarkEndJSON;
+  | BookmarkEndJSON
+  | MoveFromJSON
+  | MoveToJSON
+  | MoveFromRangeStartJSON
+  | MoveFromRangeEndJSON
+  | MoveToRangeStartJSON
+  | MoveToRangeEndJSON;
 
 export type HyperlinkChildJSON =
   | RunJSON
@@ -114,6 +120,47 @@ export type DeleteJSON = {
   data: DeleteJSONData;
 };
 
+export type MoveFromJSON = {
+  type: "moveFrom";
+  data: DeleteJSONData;
+};
+
+export type MoveToJSON = {
+  type: "moveTo";
+  data: InsertJSONData;
+};
+
+export type MoveRangeStartJSONData = {
+  id: number;
+  name: string;
+  author: string;
+  date: string;
+};
+
+export type MoveFromRangeStartJSON = {
+  type: "moveFromRangeStart";
+  data: MoveRangeStartJSONData;
+};
+
+export type MoveFromRangeEndJSON = {
+  type: "moveFromRangeEnd";
+  data: {
+    id: number;
+  };
+};
+
+export type MoveToRangeStartJSON = {
+  type: "moveToRangeStart";
+  data: MoveRangeStartJSONData;
+};
+
+export type MoveToRangeEndJSON = {
+  type: "moveToRangeEnd";
+  data: {
+    id: number;
+  };
+};
+
 export type HyperlinkJSON = {
   type: "hyperlink";
   data:
@@ -11,12 +11,22 @@ import { VertAlignType } from "../run";
 import { FieldChar } from "./bindings/FieldChar";
 import { InstrHyperlink } from "./bindings/InstrHyperlink";
 import { InstrToC } from "./bindings/InstrToC";
+import { ThemeColor } from "./bindings/ThemeColor";
+import { ThemeColorType } from "./bindings/ThemeColorType";
 
 export type TextBorderJSON = {
   borderType: BorderType;
   size: number;
   space: number;
   color: string;
+  themeColor?: ThemeColor;
+};
+
+export type ThemedColorJSON = {
+  val: string;
+  themeColor: ThemeColorType;
+  themeTint: string | null;
+  themeShade: string | null;
 };
 
 export type RunFontsJSON = {
@@ -36,7 +46,7 @@ export type RunPropertyJSON = {
   sz?: number | null;
   szCs?: number | null;
   fonts?: RunFontsJSON | null;
-  color?: string | null;
+  color?: string | ThemedColorJSON | null;
   highlight?: string | null;
   vertAlign?: VertAlignType | null;
   underline?: string | null;
@@ -52,6 +62,11 @@ export type RunPropertyJSON = {
   ins?: InsertJSONData | null;
   del?: DeleteJSONData | null;
   strike?: boolean;
+  runPropertyChange?: {
+    author: string;
+    date: string;
+    property: RunPropertyJSON;
+  };
 };
 
 export type RunChildJSON =
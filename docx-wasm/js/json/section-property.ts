@@ -52,4 +52,9 @@ export type SectionPropertyJSON = {
   evenFooterReference?: FooterReferenceJSON;
   evenFooter?: FooterJSON;
   pageNumType?: PageNumTypeJSON;
+  sectionPropertyChange?: {
+    author: string;
+    date: string;
+    property: SectionPropertyJSON;
+  };
 };
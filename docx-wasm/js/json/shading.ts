@@ -1,5 +1,9 @@
+import { ThemeColor } from "./bindings/ThemeColor";
+
 export type ShadingJSON = {
   shdType: string;
   color: string;
   fill: string;
+  themeColor?: ThemeColor;
+  themeFill?: ThemeColor;
 };
@@ -3,7 +3,10 @@ import {
   BookmarkStartJSON,
   CommentRangeEndJSON,
   CommentRangeStartJSON,
+  DeleteJSON,
+  InsertJSON,
   ParagraphJSON,
+  RunJSON,
   TableJSON,
 } from "..";
 
@@ -15,6 +18,9 @@ export type StructuredTagJSON = {
 };
 
 export type StructuredDataTagChildJSON =
+  | RunJSON
+  | InsertJSON
+  | DeleteJSON
   | ParagraphJSON
   | TableJSON
   | CommentRangeStartJSON
@@ -1,9 +1,33 @@
 import { RunPropertyJSON } from "./run";
 import { ParagraphPropertyJSON } from "./paragraph";
-import { TablePropertyJSON } from "./table";
+import { TablePropertyJSON, TableRowPropertyJSON } from "./table";
 import { TableCellPropertyJSON } from "..";
 import { StyleType } from "../style";
 
+export type TableStyleOverrideType =
+  | "wholeTable"
+  | "firstRow"
+  | "lastRow"
+  | "firstCol"
+  | "lastCol"
+  | "band1Vert"
+  | "band2Vert"
+  | "band1Horz"
+  | "band2Horz"
+  | "neCell"
+  | "nwCell"
+  | "seCell"
+  | "swCell";
+
+export type TableStylePropertyJSON = {
+  styleType: TableStyleOverrideType;
+  runProperty: RunPropertyJSON;
+  paragraphProperty: ParagraphPropertyJSON;
+  tableProperty?: TablePropertyJSON;
+  tableRowProperty?: TableRowPropertyJSON;
+  tableCellProperty: TableCellPropertyJSON;
+};
+
 export type StyleJSON = {
   styleId: string;
   name: string;
@@ -12,9 +36,33 @@ export type StyleJSON = {
   paragraphProperty: ParagraphPropertyJSON;
   tableProperty: TablePropertyJSON;
   tableCellProperty: TableCellPropertyJSON;
+  tableStyleProperties?: TableStylePropertyJSON[];
   basedOn: string | null;
   link?: string | null | undefined;
+  default?: boolean;
   next?: string | null;
+  uiPriority?: number;
+  semiHidden?: boolean;
+  unhideWhenUsed?: boolean;
+  qFormat: boolean;
+};
+
+export type LatentStyleExceptionJSON = {
+  name: string;
+  uiPriority?: number;
+  semiHidden?: boolean;
+  unhideWhenUsed?: boolean;
+  qFormat?: boolean;
+};
+
+export type LatentStylesJSON = {
+  defLockedState: boolean;
+  defUiPriority: number;
+  defSemiHidden: boolean;
+  defUnhideWhenUsed: boolean;
+  defQFormat: boolean;
+  count: number;
+  exceptions: LatentStyleExceptionJSON[];
 };
 
 export type StylesJSON = {
@@ -26,5 +74,6 @@ export type StylesJSON = {
       paragraphProperty: ParagraphPropertyJSON;
     };
   };
+  latentStyles?: LatentStylesJSON;
   styles: StyleJSON[];
 };
@@ -8,6 +8,7 @@ import { DeleteJSONData, InsertJSONData, TableCellBordersJSON } from "..";
 import { StructuredTagJSON } from "./structured-data-tag";
 
 import { TablePositionProperty as TablePositionPropertyJSON } from "./bindings/TablePositionProperty";
+import { CellMergeType } from "./bindings/CellMergeType";
 
 export { TablePositionProperty as TablePositionPropertyJSON } from "./bindings/TablePositionProperty";
 export { TableCellBorder as TableCellBorderJSON } from "./bindings/TableCellBorder";
@@ -34,6 +35,26 @@ export type TableCellPropertyJSON = {
   textDirection: TextDirectionType | null;
   shading: ShadingJSON | null;
   margins?: CellMarginsJSON;
+  cnfStyle?: string;
+  noWrap?: boolean;
+  fitText?: boolean;
+  hideMark?: boolean;
+  cellInsert?: CellRevisionJSON;
+  cellDelete?: CellRevisionJSON;
+  cellMerge?: CellRevisionJSON & {
+    vMerge?: CellMergeType;
+    vMergeOrig?: CellMergeType;
+  };
+  tableCellPropertyChange?: {
+    author: string;
+    date: string;
+    property: TableCellPropertyJSON;
+  };
+};
+
+export type CellRevisionJSON = {
+  author: string;
+  date: string;
 };
 
 export type TableRowPropertyJSON = {
@@ -45,6 +66,20 @@ export type TableRowPropertyJSON = {
   widthBefore: number | null;
   del?: DeleteJSONData;
   ins?: InsertJSONData;
+  cantSplit?: "cantSplit";
+  tableHeader?: boolean;
+  hidden?: boolean;
+  cnfStyle?: string;
+  cellSpacing?: {
+    width: number;
+    widthType: WidthType;
+  };
+  tableRowPropertyChange?: {
+    author: string;
+    date: string;
+    property: TableRowPropertyJSON;
+    propertyExceptions?: TablePropertyExceptionsJSON;
+  };
 };
 
 export type TableCellJSON = {
@@ -79,6 +114,15 @@ export type CellMarginsJSON = {
   right: TableCellMarginJSON;
 };
 
+export type TableLookJSON = {
+  firstRow: boolean;
+  lastRow: boolean;
+  firstColumn: boolean;
+  lastColumn: boolean;
+  noHBand: boolean;
+  noVBand: boolean;
+};
+
 export type TablePropertyJSON = {
   width: {
     width: number;
@@ -101,6 +145,27 @@ export type TablePropertyJSON = {
   style?: string | null;
   layout?: TableLayoutType | null;
   position?: TablePositionPropertyJSON;
+  look?: TableLookJSON;
+  caption?: string;
+  description?: string;
+  tablePropertyChange?: {
+    author: string;
+    date: string;
+    property: TablePropertyJSON;
+  };
+};
+
+export type TablePropertyExceptionsJSON = Partial<
+  Pick<
+    TablePropertyJSON,
+    "width" | "justification" | "borders" | "margins" | "indent" | "layout" | "look"
+  >
+> & {
+  cellSpacing?: {
+    width: number;
+    widthType: WidthType;
+  };
+  shading?: ShadingJSON;
 };
 
 export type TableJSON = {
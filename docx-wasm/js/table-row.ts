@@ -7,6 +7,9 @@ export class TableRow {
   hasNumberings = false;
   height: number | null = null;
   hRule: HeightRule | null = null;
+  cantSplitRow = false;
+  header = false;
+  hiddenRow = false;
   del: { author: string; date: string } | null = null;
   ins: { author: string; date: string } | null = null;
 
@@ -28,6 +31,22 @@ export class TableRow {
     return this;
   }
 
+  cantSplit() {
+    this.cantSplitRow = true;
+    return this;
+  }
+
+  // Repeat this row at the top of each page the table continues on.
+  tableHeader() {
+    this.header = true;
+    return this;
+  }
+
+  hidden() {
+    this.hiddenRow = true;
+    return this;
+  }
+
   delete(author: string, date: string) {
     this.del = { author, date };
     return this;
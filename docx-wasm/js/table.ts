@@ -96,6 +96,8 @@ export type TableProperty = {
   };
   layout?: TableLayoutType;
   position?: TablePosition;
+  caption?: string;
+  description?: string;
 };
 
 export const createDefaultTableCellMargins = () => {
@@ -188,6 +190,16 @@ export class Table {
     return this;
   }
 
+  caption(c: string) {
+    this.property.caption = c;
+    return this;
+  }
+
+  description(d: string) {
+    this.property.description = d;
+    return this;
+  }
+
   build() {
     let table = wasm.createTable();
     this.rows.forEach((r) => {
@@ -201,6 +213,18 @@ export class Table {
         row = row.row_height(r.height);
       }
 
+      if (r.cantSplitRow) {
+        row = row.cant_split();
+      }
+
+      if (r.header) {
+        row = row.table_header();
+      }
+
+      if (r.hiddenRow) {
+        row = row.hidden();
+      }
+
       if (r.del) {
         row = row.delete(r.del.author, r.del.date);
       }
@@ -238,6 +262,14 @@ export class Table {
       table = table.position(this.property.position.build());
     }
 
+    if (this.property.caption) {
+      table = table.caption(this.property.caption);
+    }
+
+    if (this.property.description) {
+      table = table.description(this.property.description);
+    }
+
     table = setTableProperty(table, this.property);
 
     return table;
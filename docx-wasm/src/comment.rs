@@ -38,6 +38,16 @@ impl Comment {
         self
     }
 
+    pub fn done(mut self) -> Comment {
+        self.0 = self.0.done();
+        self
+    }
+
+    pub fn durable_id(mut self, id: String) -> Comment {
+        self.0 = self.0.durable_id(id);
+        self
+    }
+
     pub fn id(&self) -> usize {
         self.0.id
     }
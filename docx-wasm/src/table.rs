@@ -59,6 +59,16 @@ impl Table {
         self
     }
 
+    pub fn caption(mut self, c: &str) -> Table {
+        self.0 = self.0.caption(c);
+        self
+    }
+
+    pub fn description(mut self, d: &str) -> Table {
+        self.0 = self.0.description(d);
+        self
+    }
+
     pub fn set_cell_margins(
         mut self,
         top: usize,
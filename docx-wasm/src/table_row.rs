@@ -35,6 +35,21 @@ impl TableRow {
         self
     }
 
+    pub fn cant_split(mut self) -> TableRow {
+        self.0 = self.0.cant_split();
+        self
+    }
+
+    pub fn table_header(mut self) -> TableRow {
+        self.0 = self.0.table_header();
+        self
+    }
+
+    pub fn hidden(mut self) -> TableRow {
+        self.0 = self.0.hidden();
+        self
+    }
+
     pub fn delete(mut self, author: &str, date: &str) -> Self {
         self.0 = self
             .0